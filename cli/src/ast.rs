@@ -0,0 +1,120 @@
+//! AST 的 JSON 形式，结构与 npm 包的 `AstNode` 一致，另附字节区间与书写形式
+
+use std::collections::HashMap;
+
+use ptdgrp_markdown::ast::link::Link;
+use ptdgrp_markdown::ast::text::TextRef;
+use ptdgrp_markdown::{ColumnEncoding, Document, MarkdownNode, NodeLocation};
+use serde_json::{Map, Value, json};
+
+pub fn to_json(document: &Document, encoding: ColumnEncoding) -> Value {
+    let locations = document
+        .node_locations(encoding)
+        .into_iter()
+        .map(|location| (location.node_id, location))
+        .collect::<HashMap<_, _>>();
+    node(document, &locations, 0)
+}
+
+fn node(document: &Document, locations: &HashMap<usize, NodeLocation>, id: usize) -> Value {
+    let tree = &document.tree;
+    let mut object = Map::new();
+    object.insert("kind".into(), kind(&tree[id].body).into());
+    if let Some(block_id) = &tree[id].id {
+        object.insert("id".into(), block_id.as_str().into());
+    }
+    if let Some(content) = content(document, &tree[id].body) {
+        object.insert("content".into(), content);
+    }
+    // 仅在启用 `--source-fidelity` 时记录
+    if let Some(syntax) = document.node_syntax(id) {
+        object.insert("syntax".into(), json!(syntax));
+    }
+    let location = &locations[&id];
+    object.insert("start".into(), json!(location.start));
+    object.insert("end".into(), json!(location.end));
+    object.insert("span".into(), json!(tree[id].span));
+    let mut children = Vec::new();
+    let mut child = tree.get_first_child(id);
+    while let Some(child_id) = child {
+        children.push(node(document, locations, child_id));
+        child = tree.get_next(child_id);
+    }
+    object.insert("children".into(), children.into());
+    Value::Object(object)
+}
+
+/// 节点载荷；源码区间形式的文本解析为字符串，无载荷的节点返回 `None`
+fn content(document: &Document, body: &MarkdownNode) -> Option<Value> {
+    let owned = |text: &TextRef| TextRef::Owned(document.text(text).to_string());
+    match body {
+        MarkdownNode::Text(text) => Some(document.text(text).into()),
+        MarkdownNode::Link(link) => {
+            let mut link = link.as_ref().clone();
+            if let Link::Default(link) = &mut link {
+                link.url = owned(&link.url);
+                link.title = link.title.as_ref().map(owned);
+            }
+            Some(json!(link))
+        }
+        MarkdownNode::Image(image) => {
+            let mut image = image.as_ref().clone();
+            image.url = owned(&image.url);
+            image.title = image.title.as_ref().map(owned);
+            Some(json!(image))
+        }
+        other => match json!(other) {
+            Value::Null => None,
+            value => Some(value),
+        },
+    }
+}
+
+/// 节点类型名，与 npm 包的 `kind` 取值一致
+fn kind(body: &MarkdownNode) -> &'static str {
+    match body {
+        MarkdownNode::Document => "document",
+        MarkdownNode::FrontMatter(..) => "frontmatter",
+        MarkdownNode::Paragraph => "paragraph",
+        MarkdownNode::SoftBreak => "soft-break",
+        MarkdownNode::HardBreak => "hard-break",
+        MarkdownNode::Text(..) => "text",
+        MarkdownNode::Embed(..) => "embed",
+        MarkdownNode::Heading(..) => "heading",
+        MarkdownNode::Strong => "strong",
+        MarkdownNode::Emphasis => "emphasis",
+        MarkdownNode::List(..) => "list",
+        MarkdownNode::ListItem(..) => "list-item",
+        MarkdownNode::Image(..) => "image",
+        MarkdownNode::Link(..) => "link",
+        MarkdownNode::Tag(..) => "tag",
+        MarkdownNode::Emoji(..) => "emoji",
+        MarkdownNode::BlockQuote => "block-quote",
+        MarkdownNode::Code(..) => "code",
+        MarkdownNode::Table(..) => "table",
+        MarkdownNode::TableHead => "table-head",
+        MarkdownNode::TableHeadCol => "table-head-col",
+        MarkdownNode::TableBody => "table-body",
+        MarkdownNode::TableRow => "table-row",
+        MarkdownNode::TableDataCol => "table-data-col",
+        MarkdownNode::Strikethrough => "strikethrough",
+        MarkdownNode::Highlighting => "highlighting",
+        MarkdownNode::ThematicBreak => "thematic-break",
+        MarkdownNode::Footnote(..) => "footnote",
+        MarkdownNode::FootnoteList => "footnote-list",
+        MarkdownNode::Math(..) => "math",
+        MarkdownNode::Callout(..) => "callout",
+        MarkdownNode::Html(..) => "html",
+        MarkdownNode::DefinitionList(..) => "definition-list",
+        MarkdownNode::DefinitionTerm => "definition-term",
+        MarkdownNode::DefinitionDetails(..) => "definition-details",
+        MarkdownNode::Superscript => "superscript",
+        MarkdownNode::Subscript => "subscript",
+        MarkdownNode::Insert => "insert",
+        MarkdownNode::Spoiler => "spoiler",
+        MarkdownNode::Abbreviation(..) => "abbreviation",
+        MarkdownNode::Ruby(..) => "ruby",
+        MarkdownNode::Transclusion(..) => "transclusion",
+        MarkdownNode::LinkReferenceDefinition(..) => "link-reference-definition",
+    }
+}
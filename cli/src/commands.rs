@@ -0,0 +1,327 @@
+//! 各子命令的实现；输出先写入缓冲区，最后一次性写到标准输出
+
+use std::io::{self, Write};
+
+use ptdgrp_markdown::ast::frontmatter::FrontMatterFormat;
+use ptdgrp_markdown::ast::heading::Heading;
+use ptdgrp_markdown::ast::link::Link;
+use ptdgrp_markdown::ast::reference::Reference;
+use ptdgrp_markdown::ast::text::SourceSpan;
+use ptdgrp_markdown::{ColumnEncoding, Document, MarkdownNode, Parser};
+use serde::{Serialize, Serializer};
+use serde_json::{Value, json};
+
+use crate::input::{Input, read_inputs};
+use crate::{InputArgs, ListArgs, StatsArgs};
+
+pub fn html(args: &InputArgs) -> Result<bool, String> {
+    let mut out = String::new();
+    let ok = each_document(args, |_, document, _| {
+        out.push_str(&document.to_html());
+        if !out.ends_with('\n') {
+            out.push('\n');
+        }
+    })?;
+    emit(&out);
+    Ok(ok)
+}
+
+pub fn ast(args: &InputArgs, encoding: ColumnEncoding) -> Result<bool, String> {
+    let mut values = Vec::new();
+    let ok = each_document(args, |input, document, _| {
+        values.push((input.path.clone(), crate::ast::to_json(document, encoding)));
+    })?;
+    emit_json(values);
+    Ok(ok)
+}
+
+pub fn frontmatter(args: &InputArgs) -> Result<bool, String> {
+    let mut values = Vec::new();
+    let mut valid = true;
+    let ok = each_document(args, |input, document, _| {
+        // 围栏完整但内容无效时输出 `null`，诊断写到标准错误
+        for diagnostic in document.frontmatter_diagnostics() {
+            let format = match diagnostic.format {
+                FrontMatterFormat::Yaml => "YAML",
+                FrontMatterFormat::Toml => "TOML",
+                FrontMatterFormat::Json => "JSON",
+            };
+            report(
+                input,
+                &format!(
+                    "{}:{}: invalid {} frontmatter: {}",
+                    diagnostic.location.line,
+                    diagnostic.location.column,
+                    format,
+                    diagnostic.message
+                ),
+            );
+            valid = false;
+        }
+        // 直接序列化 `YamlMap`，保持源码中的键顺序
+        let data = document
+            .frontmatter()
+            .map(|frontmatter| frontmatter.data.clone());
+        values.push((input.path.clone(), data));
+    })?;
+    emit_json(values);
+    Ok(ok && valid)
+}
+
+pub fn tags(args: &ListArgs) -> Result<bool, String> {
+    list(args, |document| {
+        document
+            .tag_occurrences()
+            .into_iter()
+            .map(|occurrence| Entry {
+                span: occurrence.span,
+                text: format!("#{}", occurrence.tag),
+                json: json!({ "tag": occurrence.tag, "source": occurrence.source }),
+            })
+            .collect()
+    })
+}
+
+pub fn headings(args: &ListArgs) -> Result<bool, String> {
+    list(args, |document| {
+        let tree = &document.tree;
+        tree.descendants(0)
+            .filter_map(|id| {
+                let level = match &tree[id].body {
+                    MarkdownNode::Heading(Heading::ATX(heading)) => heading.level as u8,
+                    MarkdownNode::Heading(Heading::SETEXT(heading)) => heading.level as u8,
+                    _ => return None,
+                };
+                let title = document.plain_text(id);
+                Some(Entry {
+                    span: tree[id].span,
+                    text: format!("{} {title}", "#".repeat(level as usize)),
+                    json: json!({ "level": level, "title": title, "id": tree[id].id }),
+                })
+            })
+            .collect()
+    })
+}
+
+pub fn links(args: &ListArgs) -> Result<bool, String> {
+    list(args, |document| {
+        let tree = &document.tree;
+        tree.descendants(0)
+            .filter_map(|id| {
+                let (kind, target) = match &tree[id].body {
+                    MarkdownNode::Link(link) => match link.as_ref() {
+                        Link::Default(link) => ("link", document.text(&link.url).to_string()),
+                        Link::Wikilink(link) => ("wikilink", target(&link.path, &link.reference)),
+                        Link::Footnote(link) => ("footnote", link.footnote_label.clone()),
+                        Link::FootnoteBackref(_) => return None,
+                    },
+                    MarkdownNode::Embed(embed) => ("embed", target(&embed.path, &embed.reference)),
+                    MarkdownNode::Image(image) => ("image", document.text(&image.url).to_string()),
+                    _ => return None,
+                };
+                Some(Entry {
+                    span: tree[id].span,
+                    text: format!("{kind}\t{target}"),
+                    json: json!({ "kind": kind, "target": target }),
+                })
+            })
+            .collect()
+    })
+}
+
+pub fn select(args: &InputArgs, target: &str) -> Result<bool, String> {
+    let reference = match target.strip_prefix('^') {
+        Some(id) => Reference::BlockId(id.to_string()),
+        None if target.contains('#') => {
+            Reference::MultiHeading(target.split('#').map(str::to_string).collect())
+        }
+        None => Reference::Heading(target.to_string()),
+    };
+    let inputs = read_inputs(&args.files)?;
+    let mut out = String::new();
+    let mut ok = true;
+    let mut found = false;
+    for input in &inputs {
+        let parser = Parser::new_with_options(&input.source, args.options.parser_options());
+        match parser.resolve_reference(&reference) {
+            Ok(Some(resolved)) => {
+                if inputs.len() > 1 {
+                    header(&mut out, &input.path);
+                }
+                out.push_str(resolved.text());
+                out.push('\n');
+                found = true;
+            }
+            Ok(None) => {}
+            Err(err) => {
+                report(input, &format!("{err:?}"));
+                ok = false;
+            }
+        }
+    }
+    emit(&out);
+    if !found {
+        return Err(format!("`{target}` not found"));
+    }
+    Ok(ok)
+}
+
+pub fn stats(args: &StatsArgs) -> Result<bool, String> {
+    let config = args.config();
+    let mut values = Vec::new();
+    let ok = each_document(&args.list.input, |input, document, _| {
+        values.push((input.path.clone(), document.statistics_with(&config)));
+    })?;
+    if args.list.json {
+        emit_json(values);
+        return Ok(ok);
+    }
+    let mut out = String::new();
+    let multiple = values.len() > 1;
+    for (path, statistics) in values {
+        if multiple {
+            header(&mut out, &path);
+        }
+        let rows = [
+            ("words", statistics.words),
+            ("cjk words", statistics.cjk_words),
+            ("characters", statistics.characters),
+            (
+                "characters without spaces",
+                statistics.characters_without_spaces,
+            ),
+            ("paragraphs", statistics.paragraphs),
+            ("headings", statistics.headings),
+            ("links", statistics.links),
+            ("images", statistics.images),
+            ("code blocks", statistics.code_blocks),
+        ];
+        for (name, count) in rows {
+            out.push_str(&format!("{name}: {count}\n"));
+        }
+        out.push_str(&format!(
+            "reading time: {} min\n",
+            statistics.reading_minutes()
+        ));
+    }
+    emit(&out);
+    Ok(ok)
+}
+
+/// 列表类子命令的一行：文本输出为 `行:列<TAB>text`，JSON 输出附加位置与区间
+struct Entry {
+    span: SourceSpan,
+    text: String,
+    json: Value,
+}
+
+fn list(args: &ListArgs, entries: impl Fn(&Document) -> Vec<Entry>) -> Result<bool, String> {
+    let mut out = String::new();
+    let mut values = Vec::new();
+    let ok = each_document(&args.input, |input, document, multiple| {
+        let entries = entries(document);
+        let starts = entries
+            .iter()
+            .map(|entry| entry.span.start as usize)
+            .collect::<Vec<_>>();
+        let locations = document.locations_at(&starts, ColumnEncoding::Utf32);
+        let mut items = Vec::new();
+        for (entry, location) in entries.into_iter().zip(locations) {
+            if args.json {
+                let mut item = entry.json;
+                if let Value::Object(item) = &mut item {
+                    item.insert("start".into(), json!(location));
+                    item.insert("span".into(), json!(entry.span));
+                }
+                items.push(item);
+                continue;
+            }
+            if multiple {
+                out.push_str(&input.path);
+                out.push(':');
+            }
+            out.push_str(&format!(
+                "{}:{}\t{}\n",
+                location.line, location.column, entry.text
+            ));
+        }
+        values.push((input.path.clone(), Value::Array(items)));
+    })?;
+    if args.json {
+        emit_json(values);
+    } else {
+        emit(&out);
+    }
+    Ok(ok)
+}
+
+/// 逐个解析输入；解析失败的文件报告到标准错误并跳过，此时返回 `false`
+/// 回调的第三个参数表示是否有多个输入
+fn each_document(
+    args: &InputArgs,
+    mut each: impl FnMut(&Input, &Document, bool),
+) -> Result<bool, String> {
+    let inputs = read_inputs(&args.files)?;
+    let multiple = inputs.len() > 1;
+    let mut ok = true;
+    for input in &inputs {
+        match Parser::new_with_options(&input.source, args.options.parser_options()).parse() {
+            Ok(document) => each(input, &document, multiple),
+            Err(err) => {
+                report(input, &format!("{err:?}"));
+                ok = false;
+            }
+        }
+    }
+    Ok(ok)
+}
+
+fn report(input: &Input, message: &str) {
+    eprintln!("ptdgrp-markdown: {}: {message}", input.path);
+}
+
+/// 多个输入时各段输出前的分隔行，同 `head` 的格式
+fn header(out: &mut String, path: &str) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(&format!("==> {path} <==\n"));
+}
+
+/// 单个输入直接输出其值，多个输入输出以路径为键的对象
+fn emit_json<T: Serialize>(values: Vec<(String, T)>) {
+    let out = match values.as_slice() {
+        [(_, value)] => serde_json::to_string_pretty(value),
+        values => serde_json::to_string_pretty(&ByPath(values)),
+    };
+    let mut out = out.unwrap_or_default();
+    out.push('\n');
+    emit(&out);
+}
+
+/// 以路径为键的对象；不经过 `Map`，键按输入顺序输出，重复给出的文件各占一项
+struct ByPath<'a, T>(&'a [(String, T)]);
+
+impl<T: Serialize> Serialize for ByPath<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(path, value)| (path, value)))
+    }
+}
+
+/// 写到标准输出；下游提前关闭管道（如 `| head`）时静默结束
+fn emit(out: &str) {
+    let mut stdout = io::stdout().lock();
+    let _ = stdout
+        .write_all(out.as_bytes())
+        .and_then(|()| stdout.flush());
+}
+
+/// `path#heading`、`path#^block` 形式的链接目标
+fn target(path: &str, reference: &Option<Reference>) -> String {
+    match reference {
+        None => path.to_string(),
+        Some(Reference::Heading(heading)) => format!("{path}#{heading}"),
+        Some(Reference::MultiHeading(headings)) => format!("{path}#{}", headings.join("#")),
+        Some(Reference::BlockId(id)) => format!("{path}#^{id}"),
+    }
+}
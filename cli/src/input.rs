@@ -0,0 +1,62 @@
+//! 命令行输入：文件路径、glob 模式与标准输入
+
+use std::fs;
+use std::io::{self, Read};
+
+/// 一个输入文件；标准输入的路径为 `-`
+pub struct Input {
+    pub path: String,
+    pub source: String,
+}
+
+/// 展开参数并读入全部输入；没有参数时读取标准输入
+pub fn read_inputs(patterns: &[String]) -> Result<Vec<Input>, String> {
+    if patterns.is_empty() {
+        return Ok(vec![read_stdin()?]);
+    }
+    let mut inputs = Vec::new();
+    for pattern in patterns {
+        if pattern == "-" {
+            inputs.push(read_stdin()?);
+            continue;
+        }
+        // shell 未展开的模式（例如加了引号或在 Windows 上）由这里展开
+        if !pattern.contains(['*', '?', '[']) {
+            inputs.push(read_file(pattern)?);
+            continue;
+        }
+        let paths =
+            glob::glob(pattern).map_err(|err| format!("invalid pattern `{pattern}`: {err}"))?;
+        let mut matched = false;
+        for path in paths {
+            let path = path.map_err(|err| err.to_string())?;
+            if path.is_file() {
+                matched = true;
+                inputs.push(read_file(&path.to_string_lossy())?);
+            }
+        }
+        if !matched {
+            return Err(format!("no files match `{pattern}`"));
+        }
+    }
+    Ok(inputs)
+}
+
+fn read_file(path: &str) -> Result<Input, String> {
+    let source = fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+    Ok(Input {
+        path: path.to_string(),
+        source,
+    })
+}
+
+fn read_stdin() -> Result<Input, String> {
+    let mut source = String::new();
+    io::stdin()
+        .read_to_string(&mut source)
+        .map_err(|err| format!("<stdin>: {err}"))?;
+    Ok(Input {
+        path: "-".to_string(),
+        source,
+    })
+}
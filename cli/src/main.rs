@@ -0,0 +1,247 @@
+//! `ptdgrp-markdown` 命令行工具：转换与检查 Markdown 文档
+
+mod ast;
+mod commands;
+mod input;
+
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use ptdgrp_markdown::{ColumnEncoding, ParserOptions, StatisticsConfig};
+
+#[derive(Parser)]
+#[command(
+    name = "ptdgrp-markdown",
+    version,
+    about = "Convert and inspect Markdown documents"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Render documents to HTML
+    Html(InputArgs),
+    /// Print the syntax tree as JSON, with one-based locations
+    Ast {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Unit of location columns: utf-8, utf-16 or utf-32
+        #[arg(long, default_value = "utf-32", value_parser = column_encoding)]
+        columns: ColumnEncoding,
+    },
+    /// Print the frontmatter as JSON
+    Frontmatter(InputArgs),
+    /// List tag occurrences
+    Tags(ListArgs),
+    /// List headings
+    Headings(ListArgs),
+    /// List links, wikilinks, embeds, images and footnote references
+    Links(ListArgs),
+    /// Print the heading section or block a reference points to
+    Select {
+        /// `Heading`, `Heading#Subheading` or `^block-id`
+        target: String,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Print word counts, block counts and reading time
+    Stats(StatsArgs),
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Files or glob patterns; reads stdin when omitted or `-`
+    files: Vec<String>,
+    #[command(flatten)]
+    options: OptionArgs,
+}
+
+#[derive(Args)]
+struct ListArgs {
+    #[command(flatten)]
+    input: InputArgs,
+    /// Print JSON instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct StatsArgs {
+    #[command(flatten)]
+    list: ListArgs,
+    /// Count words in code
+    #[arg(long)]
+    code: bool,
+    /// Count words in math
+    #[arg(long)]
+    math: bool,
+    /// Count words in the frontmatter
+    #[arg(long)]
+    frontmatter: bool,
+    /// Count words in HTML
+    #[arg(long)]
+    html: bool,
+}
+
+impl StatsArgs {
+    fn config(&self) -> StatisticsConfig {
+        StatisticsConfig::default()
+            .with_code(self.code)
+            .with_math(self.math)
+            .with_frontmatter(self.frontmatter)
+            .with_html(self.html)
+    }
+}
+
+/// 与 `ParserOptions` 的构造方法一一对应
+#[derive(Args)]
+#[command(next_help_heading = "Parser options")]
+struct OptionArgs {
+    /// Enable GitHub Flavored Markdown
+    #[arg(long)]
+    gfm: bool,
+    /// Enable GFM extended autolinks
+    #[arg(long)]
+    gfm_autolink: bool,
+    /// Enable Obsidian Flavored Markdown
+    #[arg(long)]
+    ofm: bool,
+    /// Enable JSX-like components
+    #[arg(long)]
+    jsx: bool,
+    /// Insert spaces between CJK and Latin text
+    #[arg(long)]
+    cjk_autocorrect: bool,
+    /// Convert quotes, dashes and ellipses to typographic forms
+    #[arg(long)]
+    smart_punctuation: bool,
+    /// Normalize punctuation in Chinese text
+    #[arg(long)]
+    normalize_chinese_punctuation: bool,
+    /// Let emphasis delimiters touch CJK punctuation
+    #[arg(long)]
+    cjk_friendly_delimiters: bool,
+    /// Enable definition lists
+    #[arg(long)]
+    definition_list: bool,
+    /// Enable `^superscript^`
+    #[arg(long)]
+    superscript: bool,
+    /// Enable `~subscript~`
+    #[arg(long)]
+    subscript: bool,
+    /// Enable `++inserted text++`
+    #[arg(long)]
+    insert: bool,
+    /// Enable `||spoiler||`
+    #[arg(long)]
+    spoiler: bool,
+    /// Enable abbreviation definitions
+    #[arg(long)]
+    abbreviation: bool,
+    /// Enable `{base|annotation}` ruby annotations
+    #[arg(long)]
+    ruby: bool,
+    /// Keep link reference definitions in the tree
+    #[arg(long)]
+    link_reference_definitions: bool,
+    /// Record which syntax each node was written with
+    #[arg(long)]
+    source_fidelity: bool,
+    /// Read a JSON object that starts the document as frontmatter
+    #[arg(long)]
+    leading_json_frontmatter: bool,
+    /// Enable every syntax extension and text-processing option
+    #[arg(long)]
+    all: bool,
+    /// Reject inputs larger than this many bytes
+    #[arg(long, value_name = "BYTES")]
+    max_input_bytes: Option<usize>,
+    /// Reject documents with more than this many nodes
+    #[arg(long, value_name = "COUNT")]
+    max_nodes: Option<usize>,
+}
+
+impl OptionArgs {
+    fn parser_options(&self) -> ParserOptions {
+        let flags = [
+            (self.gfm, ParserOptions::enabled_gfm as fn(_) -> _),
+            (self.gfm_autolink, ParserOptions::enabled_gfm_autolink),
+            (self.ofm, ParserOptions::enabled_ofm),
+            (self.jsx, ParserOptions::enabled_jsx_like_component),
+            (self.cjk_autocorrect, ParserOptions::enabled_cjk_autocorrect),
+            (
+                self.smart_punctuation,
+                ParserOptions::enabled_smart_punctuation,
+            ),
+            (
+                self.normalize_chinese_punctuation,
+                ParserOptions::enabled_normalize_chinese_punctuation,
+            ),
+            (
+                self.cjk_friendly_delimiters,
+                ParserOptions::enabled_cjk_friendly_delimiters,
+            ),
+            (self.definition_list, ParserOptions::enabled_definition_list),
+            (self.superscript, ParserOptions::enabled_superscript),
+            (self.subscript, ParserOptions::enabled_subscript),
+            (self.insert, ParserOptions::enabled_insert),
+            (self.spoiler, ParserOptions::enabled_spoiler),
+            (self.abbreviation, ParserOptions::enabled_abbreviation),
+            (self.ruby, ParserOptions::enabled_ruby),
+            (
+                self.link_reference_definitions,
+                ParserOptions::enabled_link_reference_definitions,
+            ),
+            (self.source_fidelity, ParserOptions::enabled_source_fidelity),
+            (
+                self.leading_json_frontmatter,
+                ParserOptions::enabled_leading_json_frontmatter,
+            ),
+            (self.all, ParserOptions::enabled_all),
+        ];
+        let mut options = flags
+            .into_iter()
+            .filter(|&(enabled, _)| enabled)
+            .fold(ParserOptions::default(), |options, (_, enable)| {
+                enable(options)
+            });
+        if let Some(max) = self.max_input_bytes {
+            options = options.with_max_input_bytes(max);
+        }
+        if let Some(max) = self.max_nodes {
+            options = options.with_max_nodes(max);
+        }
+        options
+    }
+}
+
+fn column_encoding(name: &str) -> Result<ColumnEncoding, String> {
+    ColumnEncoding::from_name(name)
+        .ok_or_else(|| format!("expected utf-8, utf-16 or utf-32, got `{name}`"))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Html(args) => commands::html(args),
+        Command::Ast { input, columns } => commands::ast(input, *columns),
+        Command::Frontmatter(args) => commands::frontmatter(args),
+        Command::Tags(args) => commands::tags(args),
+        Command::Headings(args) => commands::headings(args),
+        Command::Links(args) => commands::links(args),
+        Command::Select { target, input } => commands::select(input, target),
+        Command::Stats(args) => commands::stats(args),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("ptdgrp-markdown: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
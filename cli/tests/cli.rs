@@ -0,0 +1,263 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::{Value, json};
+
+const GUIDE: &str = "---\ntitle: Guide\ntags: [docs]\n---\n# Guide\n\nSee [[Setup#Linux|setup]], [site](https://example.com) and ![logo](logo.png) #intro\n\n## Install\n\nRun **cargo**. ^step\n\n```sh\ncargo build\n```\n\n## Usage\n\nDone.\n";
+const SETUP: &str = "# Setup\n\n## Linux\n\nUse 😀 apt.\n";
+
+/// 临时目录，测试结束时删除
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let root =
+            std::env::temp_dir().join(format!("ptdgrp-markdown-cli-{}-{name}", std::process::id()));
+        fs::create_dir_all(root.join("notes")).unwrap();
+        fs::write(root.join("notes/guide.md"), GUIDE).unwrap();
+        fs::write(root.join("notes/setup.md"), SETUP).unwrap();
+        Self(root)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn run(dir: &TempDir, args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ptdgrp-markdown"))
+        .args(args)
+        .current_dir(&dir.0)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut input = child.stdin.take().unwrap();
+    input
+        .write_all(stdin.unwrap_or_default().as_bytes())
+        .unwrap();
+    drop(input);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(dir: &TempDir, args: &[&str], stdin: Option<&str>) -> String {
+    let output = run(dir, args, stdin);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn json(dir: &TempDir, args: &[&str], stdin: Option<&str>) -> Value {
+    serde_json::from_str(&stdout(dir, args, stdin)).unwrap()
+}
+
+#[test]
+fn renders_files_and_stdin_with_parser_options() {
+    let dir = TempDir::new("html");
+    assert_eq!(
+        stdout(&dir, &["html"], Some("~~old~~ *new*")),
+        "<p>~~old~~ <em>new</em></p>\n"
+    );
+    assert_eq!(
+        stdout(&dir, &["html", "--gfm", "-"], Some("~~old~~")),
+        "<p><del>old</del></p>\n"
+    );
+    let html = stdout(&dir, &["html", "--ofm", "notes/setup.md"], None);
+    assert_eq!(html, "<h1>Setup</h1>\n<h2>Linux</h2>\n<p>Use 😀 apt.</p>\n");
+}
+
+#[test]
+fn expands_globs_and_prefixes_paths() {
+    let dir = TempDir::new("glob");
+    let out = stdout(&dir, &["headings", "notes/*.md"], None);
+    assert_eq!(
+        out,
+        "notes/guide.md:5:1\t# Guide\n\
+         notes/guide.md:9:1\t## Install\n\
+         notes/guide.md:17:1\t## Usage\n\
+         notes/setup.md:1:1\t# Setup\n\
+         notes/setup.md:3:1\t## Linux\n"
+    );
+    let headings = json(&dir, &["headings", "--json", "notes/*.md"], None);
+    assert_eq!(headings["notes/setup.md"][1]["title"], "Linux");
+    assert_eq!(headings["notes/setup.md"][1]["level"], 2);
+    assert_eq!(
+        headings["notes/setup.md"][1]["start"],
+        json!({ "line": 3, "column": 1 })
+    );
+
+    let output = run(&dir, &["html", "notes/*.txt"], None);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "ptdgrp-markdown: no files match `notes/*.txt`\n"
+    );
+}
+
+#[test]
+fn lists_links_tags_and_frontmatter() {
+    let dir = TempDir::new("lists");
+    let args = ["--ofm", "notes/guide.md"];
+    assert_eq!(
+        stdout(&dir, &[&["links"][..], &args].concat(), None),
+        "7:5\twikilink\tSetup#Linux\n7:28\tlink\thttps://example.com\n7:60\timage\tlogo.png\n"
+    );
+    let tags = json(&dir, &[&["tags", "--json"][..], &args].concat(), None);
+    let tags = tags.as_array().unwrap();
+    assert_eq!(tags.len(), 2);
+    assert_eq!(tags[0]["tag"], "docs");
+    assert_eq!(tags[0]["source"], "front_matter");
+    assert_eq!(tags[1]["tag"], "intro");
+    assert_eq!(tags[1]["start"], json!({ "line": 7, "column": 78 }));
+    assert_eq!(
+        json(&dir, &[&["frontmatter"][..], &args].concat(), None),
+        json!({ "title": "Guide", "tags": ["docs"] })
+    );
+    assert_eq!(json(&dir, &["frontmatter"], Some("# None")), Value::Null);
+    // 键保持源码顺序，多个输入按给出顺序输出，重复的输入各占一项
+    assert_eq!(
+        stdout(&dir, &["frontmatter"], Some("---\nz: 1\na: 2\n---\n")),
+        "{\n  \"z\": 1,\n  \"a\": 2\n}\n"
+    );
+    let out = stdout(
+        &dir,
+        &[
+            "frontmatter",
+            "notes/setup.md",
+            "notes/guide.md",
+            "notes/setup.md",
+        ],
+        None,
+    );
+    assert_eq!(
+        out,
+        "{\n  \"notes/setup.md\": null,\n  \"notes/guide.md\": {\n    \"title\": \"Guide\",\n    \"tags\": [\n      \"docs\"\n    ]\n  },\n  \"notes/setup.md\": null\n}\n"
+    );
+    let output = run(&dir, &["frontmatter"], Some("---\na: \"open\n---\n"));
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "null\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "ptdgrp-markdown: -: 2:4: invalid YAML frontmatter: unterminated quoted string\n"
+    );
+
+    let source = "{\"a\": 1}\n\nBody\n";
+    assert_eq!(json(&dir, &["frontmatter"], Some(source)), Value::Null);
+    assert_eq!(
+        json(
+            &dir,
+            &["frontmatter", "--leading-json-frontmatter"],
+            Some(source)
+        ),
+        json!({ "a": 1 })
+    );
+}
+
+#[test]
+fn dumps_ast_with_locations() {
+    let dir = TempDir::new("ast");
+    let ast = json(&dir, &["ast", "--columns", "utf-16"], Some("😀 **a**"));
+    assert_eq!(ast["kind"], "document");
+    let paragraph = &ast["children"][0];
+    assert_eq!(paragraph["kind"], "paragraph");
+    let strong = &paragraph["children"][1];
+    assert_eq!(strong["kind"], "strong");
+    assert_eq!(strong["start"], json!({ "line": 1, "column": 4 }));
+    assert_eq!(strong["span"], json!({ "start": 5, "end": 10 }));
+    assert_eq!(strong["children"][0]["content"], "a");
+
+    assert!(strong.get("syntax").is_none());
+    let ast = json(
+        &dir,
+        &["ast", "--source-fidelity"],
+        Some("__a__ [x][r]\n\n[r]: /u\n"),
+    );
+    let paragraph = &ast["children"][0];
+    assert_eq!(
+        paragraph["children"][0]["syntax"],
+        json!({ "kind": "strong", "marker": "_" })
+    );
+    assert_eq!(
+        paragraph["children"][2]["syntax"],
+        json!({ "kind": "link", "form": "full" })
+    );
+
+    let output = run(&dir, &["ast", "--columns", "utf-7"], Some(""));
+    assert!(!output.status.success());
+}
+
+#[test]
+fn selects_sections_and_blocks() {
+    let dir = TempDir::new("select");
+    assert_eq!(
+        stdout(
+            &dir,
+            &["select", "Install", "--ofm", "notes/guide.md"],
+            None
+        ),
+        "## Install\n\nRun **cargo**. ^step\n\n```sh\ncargo build\n```\n"
+    );
+    assert_eq!(
+        stdout(&dir, &["select", "^step", "--ofm", "notes/guide.md"], None),
+        "Run **cargo**. ^step\n"
+    );
+    assert_eq!(
+        stdout(
+            &dir,
+            &["select", "Setup#Linux", "--ofm", "notes/*.md"],
+            None
+        ),
+        "==> notes/setup.md <==\n## Linux\n\nUse 😀 apt.\n"
+    );
+    let output = run(&dir, &["select", "Missing", "notes/guide.md"], None);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "ptdgrp-markdown: `Missing` not found\n"
+    );
+}
+
+#[test]
+fn prints_statistics_and_reports_parse_errors() {
+    let dir = TempDir::new("stats");
+    let stats = json(&dir, &["stats", "--json", "--ofm", "notes/guide.md"], None);
+    assert_eq!(stats["headings"], 3);
+    assert_eq!(stats["links"], 2);
+    assert_eq!(stats["images"], 1);
+    assert_eq!(stats["code_blocks"], 1);
+    assert_eq!(stats["words"], 20);
+    let stats = json(
+        &dir,
+        &["stats", "--json", "--ofm", "--code", "notes/guide.md"],
+        None,
+    );
+    assert_eq!(stats["words"], 23);
+    assert_eq!(
+        stdout(&dir, &["stats"], Some("# 标题\n\n中文 and English\n")),
+        "words: 6\ncjk words: 4\ncharacters: 21\ncharacters without spaces: 15\n\
+         paragraphs: 1\nheadings: 1\nlinks: 0\nimages: 0\ncode blocks: 0\nreading time: 1 min\n"
+    );
+
+    // 超出上限的文件被跳过，其余文件照常输出，退出码为失败
+    let output = run(
+        &dir,
+        &["headings", "--max-input-bytes", "64", "notes/*.md"],
+        None,
+    );
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "notes/setup.md:1:1\t# Setup\nnotes/setup.md:3:1\t## Linux\n"
+    );
+    assert!(
+        String::from_utf8_lossy(&output.stderr).starts_with("ptdgrp-markdown: notes/guide.md: ")
+    );
+}
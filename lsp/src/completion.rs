@@ -0,0 +1,208 @@
+//! 补全：维基链接目标、标题与块引用、标签
+
+use ptdgrp_markdown::{ColumnEncoding, Document, TagNode, TagTree};
+use serde_json::{Value, json};
+
+use crate::convert::{count, range};
+use crate::symbols::sections;
+use crate::uri::percent_decode;
+use crate::workspace::Workspace;
+
+/// LSP `CompletionItemKind`
+const KIND_FILE: u32 = 17;
+const KIND_REFERENCE: u32 = 18;
+const KIND_KEYWORD: u32 = 14;
+
+/// `textDocument/completion`
+pub fn completion(
+    workspace: &Workspace,
+    encoding: ColumnEncoding,
+    key: &str,
+    offset: usize,
+) -> Value {
+    let Some(document) = workspace.note(key) else {
+        return Value::Null;
+    };
+    let source = document.source();
+    let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let prefix = &source[line_start..offset];
+    let items = if let Some(inner) = open_wikilink(prefix) {
+        wikilink_items(workspace, key, inner, offset - inner.len())
+    } else if let Some(url) = open_markdown_link(prefix) {
+        markdown_link_items(workspace, key, url, offset - url.len())
+    } else if let Some(tag) = open_tag(prefix) {
+        tag_items(workspace, key, offset, offset - tag.len())
+    } else {
+        Vec::new()
+    };
+    let items = items
+        .into_iter()
+        .map(|item| {
+            json!({
+                "label": item.label,
+                "kind": item.kind,
+                "detail": item.detail,
+                "filterText": item.text,
+                "textEdit": {
+                    "range": range(document, encoding, item.start, offset),
+                    "newText": item.text,
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({ "isIncomplete": false, "items": items })
+}
+
+struct Item {
+    label: String,
+    kind: u32,
+    detail: String,
+    /// 插入文本，替换 `start` 到光标之间的内容
+    text: String,
+    start: usize,
+}
+
+/// 光标前未闭合的 `[[` 之后的内容
+fn open_wikilink(prefix: &str) -> Option<&str> {
+    let inner = &prefix[prefix.rfind("[[")? + 2..];
+    (!inner.contains("]]") && !inner.contains('|')).then_some(inner)
+}
+
+/// 光标前未闭合的 `](` 之后的内容
+fn open_markdown_link(prefix: &str) -> Option<&str> {
+    let url = &prefix[prefix.rfind("](")? + 2..];
+    (!url.contains([')', ' '])).then_some(url)
+}
+
+/// 光标前正在输入的 `#tag`（不含 `#`）
+fn open_tag(prefix: &str) -> Option<&str> {
+    let hash = prefix.rfind('#')?;
+    let tag = &prefix[hash + 1..];
+    let boundary = prefix[..hash]
+        .chars()
+        .next_back()
+        .is_none_or(char::is_whitespace);
+    (boundary && !tag.contains(char::is_whitespace)).then_some(tag)
+}
+
+fn wikilink_items(workspace: &Workspace, key: &str, inner: &str, start: usize) -> Vec<Item> {
+    let Some((note, fragment)) = inner.split_once('#') else {
+        return note_items(workspace, start);
+    };
+    let Some(document) = workspace
+        .vault()
+        .resolve(key, note)
+        .and_then(|target| workspace.note(target))
+    else {
+        return Vec::new();
+    };
+    let fragment_start = start + note.len() + 1;
+    if fragment.starts_with('^') {
+        return block_items(document, fragment_start + 1);
+    }
+    // 多级标题 `[[Note#Guide#Linux]]` 只补全最后一段
+    let last = fragment.rfind('#').map_or(0, |i| i + 1);
+    heading_items(document, fragment_start + last, |title| title.to_string())
+}
+
+fn markdown_link_items(workspace: &Workspace, key: &str, url: &str, start: usize) -> Vec<Item> {
+    let Some((path, _)) = url.split_once('#') else {
+        return Vec::new();
+    };
+    let Some(document) = workspace
+        .vault()
+        .resolve(key, &percent_decode(path))
+        .and_then(|target| workspace.note(target))
+    else {
+        return Vec::new();
+    };
+    heading_items(document, start + path.len() + 1, |title| {
+        title.replace(' ', "%20")
+    })
+}
+
+fn note_items(workspace: &Workspace, start: usize) -> Vec<Item> {
+    let keys = workspace.notes().map(|(key, _)| key).collect::<Vec<_>>();
+    keys.iter()
+        .map(|&key| {
+            let path = key.strip_suffix(".md").unwrap_or(key);
+            let name = path.rsplit('/').next().unwrap_or(path);
+            // 文件名唯一时按 Obsidian 的习惯只写文件名
+            let unique = keys
+                .iter()
+                .filter(|other| {
+                    let other = other.strip_suffix(".md").unwrap_or(other);
+                    other.rsplit('/').next() == Some(name)
+                })
+                .count()
+                == 1;
+            Item {
+                label: name.to_string(),
+                kind: KIND_FILE,
+                detail: key.to_string(),
+                text: if unique { name } else { path }.to_string(),
+                start,
+            }
+        })
+        .collect()
+}
+
+fn heading_items(document: &Document, start: usize, text: impl Fn(&str) -> String) -> Vec<Item> {
+    sections(document)
+        .into_iter()
+        .filter(|section| !section.title.is_empty())
+        .map(|section| Item {
+            text: text(&section.title),
+            detail: format!("H{}", section.level),
+            label: section.title,
+            kind: KIND_REFERENCE,
+            start,
+        })
+        .collect()
+}
+
+fn block_items(document: &Document, start: usize) -> Vec<Item> {
+    let tree = &document.tree;
+    tree.descendants(0)
+        .filter_map(|id| {
+            let block = tree[id].id.as_ref()?.as_str();
+            let detail = document.plain_text(id);
+            let detail = detail.lines().next().unwrap_or_default();
+            Some(Item {
+                label: block.to_string(),
+                kind: KIND_REFERENCE,
+                detail: detail.to_string(),
+                text: block.to_string(),
+                start,
+            })
+        })
+        .collect()
+}
+
+fn tag_items(workspace: &Workspace, key: &str, offset: usize, start: usize) -> Vec<Item> {
+    // 不计入光标处正在输入的标签
+    let mut tags = TagTree::new();
+    for (note, document) in workspace.notes() {
+        for occurrence in document.tag_occurrences() {
+            let span = occurrence.span;
+            if note == key && (span.start as usize) < offset && offset <= span.end as usize {
+                continue;
+            }
+            tags.add(note, occurrence);
+        }
+    }
+    let mut items = Vec::new();
+    let mut stack = tags.roots().collect::<Vec<&TagNode>>();
+    while let Some(node) = stack.pop() {
+        items.push(Item {
+            label: node.path().to_string(),
+            kind: KIND_KEYWORD,
+            detail: count(node.count(), "occurrence"),
+            text: node.path().to_string(),
+            start,
+        });
+        stack.extend(node.children());
+    }
+    items.sort_by(|a, b| a.label.cmp(&b.label));
+    items
+}
@@ -0,0 +1,42 @@
+//! 字节偏移与 LSP 位置互转，以及节点文本提取
+
+use ptdgrp_markdown::ast::text::SourceSpan;
+use ptdgrp_markdown::{ColumnEncoding, Document, Location};
+use serde_json::{Value, json};
+
+/// 字节偏移对应的零起始 LSP `Position`
+pub fn position(document: &Document, encoding: ColumnEncoding, offset: usize) -> Value {
+    let location = document.location_at_with(offset, encoding);
+    json!({ "line": location.line - 1, "character": location.column - 1 })
+}
+
+pub fn range(document: &Document, encoding: ColumnEncoding, start: usize, end: usize) -> Value {
+    json!({
+        "start": position(document, encoding, start),
+        "end": position(document, encoding, end),
+    })
+}
+
+pub fn span_range(document: &Document, encoding: ColumnEncoding, span: SourceSpan) -> Value {
+    range(document, encoding, span.start as usize, span.end as usize)
+}
+
+/// LSP `Position` 对应的字节偏移
+pub fn offset(document: &Document, encoding: ColumnEncoding, position: &Value) -> Option<usize> {
+    let line = position.get("line")?.as_u64()?;
+    let character = position.get("character")?.as_u64()?;
+    Some(document.offset_at_with(Location::new(line + 1, character + 1), encoding))
+}
+
+pub fn source_text<'a>(document: &'a Document, span: SourceSpan) -> &'a str {
+    &document.source()[span.start as usize..span.end as usize]
+}
+
+/// `1 note`、`3 notes` 形式的计数
+pub fn count(n: usize, noun: &str) -> String {
+    if n == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{n} {noun}s")
+    }
+}
@@ -0,0 +1,80 @@
+//! 链接诊断：文档内链接检查与跨笔记的未解析链接
+
+use ptdgrp_markdown::ast::reference::Reference;
+use ptdgrp_markdown::ast::text::SourceSpan;
+use ptdgrp_markdown::{ColumnEncoding, Document};
+use serde_json::{Value, json};
+
+use crate::convert::span_range;
+use crate::workspace::Workspace;
+
+/// LSP `DiagnosticSeverity.Warning`
+const SEVERITY_WARNING: u32 = 2;
+
+/// `textDocument/publishDiagnostics` 的诊断列表
+pub fn diagnostics(workspace: &Workspace, encoding: ColumnEncoding, key: &str) -> Vec<Value> {
+    let Some(document) = workspace.note(key) else {
+        return Vec::new();
+    };
+    let mut found = document
+        .check_links()
+        .into_iter()
+        .map(|diagnostic| {
+            let code = serde_json::to_value(diagnostic.kind).unwrap_or_default();
+            (diagnostic.span, code, diagnostic.message)
+        })
+        .collect::<Vec<_>>();
+    for edge in workspace.vault().outgoing(key) {
+        let link = edge.link;
+        let Some(target) = edge.target else {
+            found.push((
+                link.span,
+                json!("unresolved_link"),
+                format!("unresolved link to `{}`", link.path),
+            ));
+            continue;
+        };
+        // 当前笔记内的引用已由 `check_links` 检查
+        if target == key {
+            continue;
+        }
+        if let (Some(reference), Some(note)) = (&link.reference, workspace.note(target))
+            && note.find_reference(reference).is_none()
+        {
+            found.push((
+                link.span,
+                json!("missing_target"),
+                format!("`{}#{}` does not exist", link.path, fragment(reference)),
+            ));
+        }
+    }
+    found.sort_by_key(|(span, _, _)| span.start);
+    found
+        .into_iter()
+        .map(|(span, code, message)| diagnostic(document, encoding, span, code, message))
+        .collect()
+}
+
+fn diagnostic(
+    document: &Document,
+    encoding: ColumnEncoding,
+    span: SourceSpan,
+    code: Value,
+    message: String,
+) -> Value {
+    json!({
+        "range": span_range(document, encoding, span),
+        "severity": SEVERITY_WARNING,
+        "code": code,
+        "source": "markdown",
+        "message": message,
+    })
+}
+
+fn fragment(reference: &Reference) -> String {
+    match reference {
+        Reference::Heading(heading) => heading.clone(),
+        Reference::MultiHeading(headings) => headings.join("#"),
+        Reference::BlockId(id) => format!("^{id}"),
+    }
+}
@@ -0,0 +1,52 @@
+//! A Language Server Protocol server for Obsidian Flavored Markdown notes.
+//!
+//! The server speaks JSON-RPC over stdio and treats the workspace folder as a
+//! vault. It provides document symbols from headings, folding ranges for
+//! sections, lists, callouts and code blocks, go-to-definition for wikilinks,
+//! embeds, reference links and footnotes, completion of wikilink targets,
+//! headings, block IDs and tags, link diagnostics, and hover previews.
+//!
+//! ```no_run
+//! let stdin = std::io::stdin();
+//! let stdout = std::io::stdout();
+//! let clean = markdown_lsp::run(stdin.lock(), stdout.lock())?;
+//! std::process::exit(if clean { 0 } else { 1 });
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+mod completion;
+mod convert;
+mod diagnostics;
+mod navigation;
+pub mod rpc;
+mod server;
+mod symbols;
+mod uri;
+mod workspace;
+
+use std::io::{self, BufRead, Write};
+
+pub use server::Server;
+
+/// Serves one client until it sends `exit` or closes the input.
+///
+/// Returns whether the client requested `shutdown` before exiting.
+pub fn run(mut reader: impl BufRead, mut writer: impl Write) -> io::Result<bool> {
+    let mut server = Server::new();
+    while let Some(message) = rpc::read_message(&mut reader)? {
+        let message = match message {
+            Ok(message) => message,
+            Err(err) => {
+                rpc::write_message(&mut writer, &rpc::parse_error(&err))?;
+                continue;
+            }
+        };
+        for reply in server.handle(message) {
+            rpc::write_message(&mut writer, &reply)?;
+        }
+        if server.exited() {
+            break;
+        }
+    }
+    Ok(server.shut_down_cleanly())
+}
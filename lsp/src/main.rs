@@ -0,0 +1,11 @@
+use std::io;
+use std::process::ExitCode;
+
+fn main() -> io::Result<ExitCode> {
+    let clean = markdown_lsp::run(io::stdin().lock(), io::stdout().lock())?;
+    Ok(if clean {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,200 @@
+//! 跳转到定义与悬停预览
+
+use ptdgrp_markdown::ast::link::{Link, LinkReferenceDefinition};
+use ptdgrp_markdown::ast::reference::Reference;
+use ptdgrp_markdown::ast::text::SourceSpan;
+use ptdgrp_markdown::{ColumnEncoding, Document, MarkdownNode};
+use serde_json::{Value, json};
+
+use crate::convert::{count, source_text, span_range};
+use crate::uri::percent_decode;
+use crate::workspace::Workspace;
+
+/// 悬停预览最多展示的行数
+const PREVIEW_LINES: usize = 20;
+
+/// 光标下可跳转的目标
+enum Target<'a> {
+    /// 维基链接、嵌入或站内 Markdown 链接；空路径指向当前笔记
+    Note {
+        path: &'a str,
+        reference: Option<&'a Reference>,
+    },
+    /// 完整、折叠或捷径引用链接
+    Definition(&'a LinkReferenceDefinition),
+    /// 脚注引用，标签已解码
+    Footnote(String),
+    Tag(&'a str),
+}
+
+/// 从光标处最深的节点向上查找链接、脚注引用或标签
+fn target_at<'a>(
+    workspace: &'a Workspace,
+    key: &str,
+    document: &'a Document<'static>,
+    offset: usize,
+) -> Option<(Target<'a>, SourceSpan)> {
+    let tree = &document.tree;
+    let mut id = document.node_at(offset)?;
+    while id != 0 {
+        let span = tree[id].span;
+        let target = match &tree[id].body {
+            MarkdownNode::Link(link) => match link.as_ref() {
+                Link::Footnote(footnote) => {
+                    Some(Target::Footnote(percent_decode(&footnote.footnote_label)))
+                }
+                Link::FootnoteBackref(_) => None,
+                _ => match document.resolved_link_definition(id) {
+                    Some(definition) => Some(Target::Definition(definition)),
+                    None => note_link(workspace, key, span),
+                },
+            },
+            MarkdownNode::Embed(_) => note_link(workspace, key, span),
+            MarkdownNode::Tag(tag) => Some(Target::Tag(tag)),
+            _ => None,
+        };
+        if let Some(target) = target {
+            return Some((target, span));
+        }
+        id = tree.get_parent(id);
+    }
+    None
+}
+
+/// 链接图中与该节点区间对应的链接
+fn note_link<'a>(workspace: &'a Workspace, key: &str, span: SourceSpan) -> Option<Target<'a>> {
+    let edges = workspace.vault().outgoing(key);
+    let edge = edges.into_iter().find(|edge| edge.link.span == span)?;
+    Some(Target::Note {
+        path: &edge.link.path,
+        reference: edge.link.reference.as_ref(),
+    })
+}
+
+/// 解析后的笔记目标：路径、文档（附件为 `None`）与引用区间
+fn resolve_note<'a>(
+    workspace: &'a Workspace,
+    key: &str,
+    path: &str,
+    reference: Option<&Reference>,
+) -> Option<(&'a str, Option<&'a Document<'static>>, Option<SourceSpan>)> {
+    let target = workspace.vault().resolve(key, path)?;
+    let document = workspace.note(target);
+    let span = document
+        .zip(reference)
+        .and_then(|(document, reference)| document.find_reference(reference))
+        .map(|found| found.span);
+    Some((target, document, span))
+}
+
+fn footnote_definition(document: &Document, label: &str) -> Option<SourceSpan> {
+    let tree = &document.tree;
+    tree.descendants(0).find_map(|id| match &tree[id].body {
+        MarkdownNode::Footnote(footnote) if footnote.label == label => Some(tree[id].span),
+        _ => None,
+    })
+}
+
+/// `textDocument/definition`
+pub fn definition(
+    workspace: &Workspace,
+    encoding: ColumnEncoding,
+    key: &str,
+    offset: usize,
+) -> Value {
+    let Some(document) = workspace.note(key) else {
+        return Value::Null;
+    };
+    let location = |target: &str, document: &Document, span: SourceSpan| {
+        json!({
+            "uri": workspace.uri(target),
+            "range": span_range(document, encoding, span),
+        })
+    };
+    match target_at(workspace, key, document, offset) {
+        Some((Target::Note { path, reference }, _)) => {
+            match resolve_note(workspace, key, path, reference) {
+                Some((target, Some(note), span)) => {
+                    location(target, note, span.unwrap_or(SourceSpan::new(0, 0)))
+                }
+                Some((target, None, _)) => json!({
+                    "uri": workspace.uri(target),
+                    "range": { "start": { "line": 0, "character": 0 }, "end": { "line": 0, "character": 0 } },
+                }),
+                None => Value::Null,
+            }
+        }
+        Some((Target::Definition(definition), _)) => location(key, document, definition.span),
+        Some((Target::Footnote(label), _)) => footnote_definition(document, &label)
+            .map_or(Value::Null, |span| location(key, document, span)),
+        Some((Target::Tag(_), _)) | None => Value::Null,
+    }
+}
+
+/// `textDocument/hover`
+pub fn hover(workspace: &Workspace, encoding: ColumnEncoding, key: &str, offset: usize) -> Value {
+    let Some(document) = workspace.note(key) else {
+        return Value::Null;
+    };
+    let Some((target, span)) = target_at(workspace, key, document, offset) else {
+        return Value::Null;
+    };
+    let contents = match target {
+        Target::Note { path, reference } => match resolve_note(workspace, key, path, reference) {
+            Some((target, Some(note), span)) => {
+                let preview = match span {
+                    Some(span) => source_text(note, span),
+                    None => body(note),
+                };
+                format!("**{target}**\n\n---\n\n{}", truncate(preview))
+            }
+            Some((target, None, _)) => format!("**{target}**"),
+            None => format!("Unresolved link to `{path}`"),
+        },
+        Target::Definition(definition) => match &definition.title {
+            Some(title) => format!("`{}`\n\n{title}", definition.url),
+            None => format!("`{}`", definition.url),
+        },
+        Target::Footnote(label) => match footnote_definition(document, &label) {
+            Some(span) => truncate(source_text(document, span)),
+            None => format!("Footnote `{label}` is not defined"),
+        },
+        Target::Tag(tag) => {
+            let tags = workspace.tag_tree();
+            let notes = tags.notes_under(tag).len();
+            let occurrences = tags.get(tag).map_or(0, |node| node.count());
+            format!(
+                "**#{tag}**\n\n{} in {}",
+                count(occurrences, "occurrence"),
+                count(notes, "note")
+            )
+        }
+    };
+    json!({
+        "contents": { "kind": "markdown", "value": contents },
+        "range": span_range(document, encoding, span),
+    })
+}
+
+/// 去掉 frontmatter 后的笔记正文
+fn body<'a>(document: &'a Document) -> &'a str {
+    let tree = &document.tree;
+    let start = tree
+        .get_first_child(0)
+        .filter(|&id| matches!(tree[id].body, MarkdownNode::FrontMatter(_)))
+        .map_or(0, |id| tree[id].span.end as usize);
+    document.source()[start..].trim()
+}
+
+fn truncate(text: &str) -> String {
+    let mut lines = text.lines();
+    let mut preview = lines
+        .by_ref()
+        .take(PREVIEW_LINES)
+        .collect::<Vec<_>>()
+        .join("\n");
+    if lines.next().is_some() {
+        preview.push_str("\n\n…");
+    }
+    preview
+}
@@ -0,0 +1,57 @@
+//! JSON-RPC 消息的 `Content-Length` 分帧读写
+
+use std::io::{self, BufRead, Write};
+
+use serde_json::{Value, json};
+
+/// JSON-RPC 错误码：消息体不是合法 JSON
+const PARSE_ERROR: i64 = -32700;
+
+/// 读取一条消息；输入结束时返回 `None`。
+/// 分帧错误无法恢复，作为 `io::Error` 返回；消息体不是合法 JSON 时作为值返回，可继续读取下一条
+pub fn read_message(
+    reader: &mut impl BufRead,
+) -> io::Result<Option<Result<Value, serde_json::Error>>> {
+    let mut length = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            length = value.trim().parse::<usize>().ok();
+        }
+    }
+    let Some(length) = length else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing Content-Length header",
+        ));
+    };
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)))
+}
+
+/// 消息体无法解析时的错误响应；请求 ID 未知，按规范为 `null`
+pub fn parse_error(err: &serde_json::Error) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": null,
+        "error": { "code": PARSE_ERROR, "message": format!("Parse error: {err}") },
+    })
+}
+
+/// 写出一条消息并立即刷新
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    writer.flush()
+}
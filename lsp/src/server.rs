@@ -0,0 +1,230 @@
+//! 请求分发与生命周期
+
+use ptdgrp_markdown::ColumnEncoding;
+use serde_json::{Value, json};
+
+use crate::workspace::Workspace;
+use crate::{completion, convert, diagnostics, navigation, symbols, uri};
+
+/// JSON-RPC 错误码
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INVALID_REQUEST: i64 = -32600;
+const SERVER_NOT_INITIALIZED: i64 = -32002;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Uninitialized,
+    Running,
+    ShutDown,
+    Exited,
+}
+
+/// A language server session that turns incoming JSON-RPC messages into
+/// responses and notifications.
+///
+/// [`crate::run`] drives a server over stdio; tests and embedders can call
+/// [`Server::handle`] directly.
+pub struct Server {
+    workspace: Workspace,
+    encoding: ColumnEncoding,
+    state: State,
+    /// 是否在 `exit` 前收到过 `shutdown`
+    shutdown: bool,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self {
+            workspace: Workspace::new(None),
+            encoding: ColumnEncoding::Utf16,
+            state: State::Uninitialized,
+            shutdown: false,
+        }
+    }
+
+    /// Handles one request or notification and returns the messages to send
+    /// back, in order.
+    pub fn handle(&mut self, message: Value) -> Vec<Value> {
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // 客户端对服务器请求的响应，目前不发送请求
+            return Vec::new();
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+        match message.get("id").cloned() {
+            Some(id) => {
+                let response = match self.request(method, &params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err((code, message)) => json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": { "code": code, "message": message },
+                    }),
+                };
+                vec![response]
+            }
+            None => self.notification(method, &params),
+        }
+    }
+
+    /// Returns whether the client sent `exit`.
+    pub fn exited(&self) -> bool {
+        self.state == State::Exited
+    }
+
+    /// Returns whether `shutdown` was requested before `exit`, which decides
+    /// the process exit code.
+    pub fn shut_down_cleanly(&self) -> bool {
+        self.shutdown
+    }
+
+    fn request(&mut self, method: &str, params: &Value) -> Result<Value, (i64, String)> {
+        match (self.state, method) {
+            (State::Uninitialized, "initialize") => return Ok(self.initialize(params)),
+            (State::Uninitialized, _) => {
+                return Err((SERVER_NOT_INITIALIZED, "server not initialized".to_string()));
+            }
+            (State::Running, "shutdown") => {
+                self.state = State::ShutDown;
+                self.shutdown = true;
+                return Ok(Value::Null);
+            }
+            (State::Running, _) => {}
+            _ => return Err((INVALID_REQUEST, format!("unexpected request `{method}`"))),
+        }
+        let workspace = &self.workspace;
+        let encoding = self.encoding;
+        let Some(key) = params["textDocument"]["uri"]
+            .as_str()
+            .and_then(|uri| workspace.key(uri))
+        else {
+            return match method {
+                "textDocument/documentSymbol"
+                | "textDocument/foldingRange"
+                | "textDocument/definition"
+                | "textDocument/hover"
+                | "textDocument/completion" => {
+                    Err((INVALID_PARAMS, "missing textDocument.uri".to_string()))
+                }
+                _ => Err((METHOD_NOT_FOUND, format!("unknown method `{method}`"))),
+            };
+        };
+        let Some(document) = workspace.note(&key) else {
+            return Ok(Value::Null);
+        };
+        let offset = convert::offset(document, encoding, &params["position"]);
+        let result = match (method, offset) {
+            ("textDocument/documentSymbol", _) => symbols::document_symbols(document, encoding),
+            ("textDocument/foldingRange", _) => symbols::folding_ranges(document, encoding),
+            ("textDocument/definition", Some(offset)) => {
+                navigation::definition(workspace, encoding, &key, offset)
+            }
+            ("textDocument/hover", Some(offset)) => {
+                navigation::hover(workspace, encoding, &key, offset)
+            }
+            ("textDocument/completion", Some(offset)) => {
+                completion::completion(workspace, encoding, &key, offset)
+            }
+            (
+                "textDocument/definition" | "textDocument/hover" | "textDocument/completion",
+                None,
+            ) => {
+                return Err((INVALID_PARAMS, "missing position".to_string()));
+            }
+            _ => return Err((METHOD_NOT_FOUND, format!("unknown method `{method}`"))),
+        };
+        Ok(result)
+    }
+
+    fn initialize(&mut self, params: &Value) -> Value {
+        let root = params["workspaceFolders"][0]["uri"]
+            .as_str()
+            .or(params["rootUri"].as_str())
+            .and_then(uri::to_path)
+            .or(params["rootPath"].as_str().map(Into::into));
+        // 按客户端给出的偏好选择第一个支持的列编码，缺省为 UTF-16
+        if let Some(encodings) = params["capabilities"]["general"]["positionEncodings"].as_array()
+            && let Some(encoding) = encodings
+                .iter()
+                .filter_map(Value::as_str)
+                .find_map(ColumnEncoding::from_name)
+        {
+            self.encoding = encoding;
+        }
+        self.workspace = Workspace::new(root);
+        self.workspace.scan();
+        self.state = State::Running;
+        json!({
+            "capabilities": {
+                "positionEncoding": self.encoding.name(),
+                "textDocumentSync": { "openClose": true, "change": 1 },
+                "documentSymbolProvider": true,
+                "foldingRangeProvider": true,
+                "definitionProvider": true,
+                "hoverProvider": true,
+                "completionProvider": { "triggerCharacters": ["[", "#", "^", "("] },
+            },
+            "serverInfo": { "name": "markdown-lsp", "version": env!("CARGO_PKG_VERSION") },
+        })
+    }
+
+    fn notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        if method == "exit" {
+            self.state = State::Exited;
+            return Vec::new();
+        }
+        if self.state != State::Running {
+            return Vec::new();
+        }
+        let document = &params["textDocument"];
+        let Some(uri) = document["uri"].as_str() else {
+            return Vec::new();
+        };
+        let Some(key) = self.workspace.key(uri) else {
+            return Vec::new();
+        };
+        let mut messages = Vec::new();
+        match method {
+            "textDocument/didOpen" => {
+                let text = document["text"].as_str().unwrap_or_default();
+                self.workspace.open(key, uri.to_string(), text.to_string());
+            }
+            "textDocument/didChange" => {
+                // 全量同步：最后一次变更即完整内容
+                let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                else {
+                    return Vec::new();
+                };
+                self.workspace.change(key, text.to_string());
+            }
+            "textDocument/didClose" => {
+                self.workspace.close(&key);
+                messages.push(publish(uri, Vec::new()));
+            }
+            _ => return Vec::new(),
+        }
+        // 一篇笔记的变化可能影响其他打开笔记的链接解析
+        for key in self.workspace.open_notes() {
+            let diagnostics = diagnostics::diagnostics(&self.workspace, self.encoding, key);
+            messages.push(publish(&self.workspace.uri(key), diagnostics));
+        }
+        messages
+    }
+}
+
+fn publish(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
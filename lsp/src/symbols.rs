@@ -0,0 +1,135 @@
+//! 文档大纲（标题层级）与折叠区间
+
+use ptdgrp_markdown::ast::code::Code;
+use ptdgrp_markdown::ast::heading::Heading;
+use ptdgrp_markdown::ast::text::SourceSpan;
+use ptdgrp_markdown::{ColumnEncoding, Document, MarkdownNode};
+use serde_json::{Value, json};
+
+use crate::convert::{position, range, span_range};
+
+/// LSP `SymbolKind.String`，与 VS Code 内置 Markdown 支持一致
+const HEADING_SYMBOL_KIND: u32 = 15;
+
+pub struct Section {
+    pub level: u8,
+    pub title: String,
+    pub span: SourceSpan,
+    /// 章节结束的字节偏移：下一个同级或更高级标题之前，去掉末尾空白
+    pub end: usize,
+}
+
+/// 按文档顺序列出全部标题及其章节范围
+pub fn sections(document: &Document) -> Vec<Section> {
+    let tree = &document.tree;
+    let headings = tree
+        .descendants(0)
+        .filter_map(|id| match &tree[id].body {
+            MarkdownNode::Heading(Heading::ATX(heading)) => Some((id, heading.level as u8)),
+            MarkdownNode::Heading(Heading::SETEXT(heading)) => Some((id, heading.level as u8)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let source = document.source();
+    headings
+        .iter()
+        .enumerate()
+        .map(|(i, &(node_id, level))| {
+            let span = tree[node_id].span;
+            let next = headings[i + 1..]
+                .iter()
+                .find(|&&(_, next_level)| next_level <= level)
+                .map_or(source.len(), |&(id, _)| tree[id].span.start as usize);
+            let start = span.start as usize;
+            Section {
+                level,
+                title: document.plain_text(node_id),
+                span,
+                end: start + source[start..next].trim_end().len(),
+            }
+        })
+        .collect()
+}
+
+/// `textDocument/documentSymbol`：按标题级别嵌套的 `DocumentSymbol`
+pub fn document_symbols(document: &Document, encoding: ColumnEncoding) -> Value {
+    let mut roots = Vec::new();
+    let mut stack: Vec<(u8, Value)> = Vec::new();
+    for section in sections(document) {
+        while stack
+            .last()
+            .is_some_and(|&(level, _)| level >= section.level)
+        {
+            close(&mut stack, &mut roots);
+        }
+        let name = if section.title.is_empty() {
+            format!("{} (empty)", "#".repeat(section.level as usize))
+        } else {
+            section.title
+        };
+        stack.push((
+            section.level,
+            json!({
+                "name": name,
+                "detail": format!("H{}", section.level),
+                "kind": HEADING_SYMBOL_KIND,
+                "range": range(document, encoding, section.span.start as usize, section.end),
+                "selectionRange": span_range(document, encoding, section.span),
+                "children": [],
+            }),
+        ));
+    }
+    while !stack.is_empty() {
+        close(&mut stack, &mut roots);
+    }
+    Value::Array(roots)
+}
+
+/// 弹出栈顶符号，挂到上一级标题或顶层
+fn close(stack: &mut Vec<(u8, Value)>, roots: &mut Vec<Value>) {
+    let Some((_, symbol)) = stack.pop() else {
+        return;
+    };
+    match stack.last_mut() {
+        Some((_, parent)) => parent["children"].as_array_mut().unwrap().push(symbol),
+        None => roots.push(symbol),
+    }
+}
+
+/// `textDocument/foldingRange`：章节、列表、callout 与代码块
+pub fn folding_ranges(document: &Document, encoding: ColumnEncoding) -> Value {
+    let line = |offset: usize| position(document, encoding, offset)["line"].clone();
+    let mut ranges = Vec::new();
+    let mut push = |start: usize, end: usize| {
+        if end > start {
+            let (start_line, end_line) = (line(start), line(end - 1));
+            if start_line != end_line {
+                ranges.push((
+                    start,
+                    json!({ "startLine": start_line, "endLine": end_line }),
+                ));
+            }
+        }
+    };
+    for section in sections(document) {
+        push(section.span.start as usize, section.end);
+    }
+    let tree = &document.tree;
+    for id in tree.descendants(0) {
+        let foldable = match &tree[id].body {
+            MarkdownNode::List(_) | MarkdownNode::Callout(_) => true,
+            MarkdownNode::Code(code) => !matches!(code.as_ref(), Code::Inline(_)),
+            _ => false,
+        };
+        if foldable {
+            let span = tree[id].span;
+            let text = &document.source()[span.start as usize..span.end as usize];
+            push(
+                span.start as usize,
+                span.start as usize + text.trim_end().len(),
+            );
+        }
+    }
+    ranges.sort_by_key(|&(start, _)| start);
+    Value::Array(ranges.into_iter().map(|(_, range)| range).collect())
+}
@@ -0,0 +1,162 @@
+//! 工作区内的笔记：磁盘上的 Markdown 文件与编辑器中打开的文档
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ptdgrp_markdown::{Document, Parser, ParserOptions, TagTree, Vault};
+
+use crate::uri;
+
+pub struct Workspace {
+    root: Option<PathBuf>,
+    options: ParserOptions,
+    /// 笔记路径 -> 文档；根目录下的笔记使用相对路径，其余使用绝对路径
+    notes: BTreeMap<String, Document<'static>>,
+    vault: Vault,
+    /// 编辑器中打开的笔记及其 URI，其内容以编辑器为准
+    open: BTreeMap<String, String>,
+}
+
+impl Workspace {
+    pub fn new(root: Option<PathBuf>) -> Self {
+        let options = ParserOptions::default().enabled_gfm().enabled_ofm();
+        Self {
+            root,
+            vault: Vault::with_options(options.clone()),
+            options,
+            notes: BTreeMap::new(),
+            open: BTreeMap::new(),
+        }
+    }
+
+    /// 读入根目录下的全部文件，跳过隐藏目录（如 `.obsidian`、`.git`）
+    pub fn scan(&mut self) {
+        let Some(root) = self.root.clone() else {
+            return;
+        };
+        let mut dirs = vec![root];
+        while let Some(dir) = dirs.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if entry.file_name().to_string_lossy().starts_with('.') {
+                    continue;
+                }
+                if path.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+                let Some(key) = self.key_of(&path) else {
+                    continue;
+                };
+                if is_markdown(&key) {
+                    if let Ok(source) = fs::read_to_string(&path) {
+                        self.insert(key, source);
+                    }
+                } else {
+                    self.vault.insert_attachment(key);
+                }
+            }
+        }
+    }
+
+    /// 文档 URI 对应的笔记路径
+    pub fn key(&self, uri: &str) -> Option<String> {
+        self.key_of(&uri::to_path(uri)?)
+    }
+
+    fn key_of(&self, path: &Path) -> Option<String> {
+        let key = match self
+            .root
+            .as_deref()
+            .and_then(|root| path.strip_prefix(root).ok())
+        {
+            Some(relative) => relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            None => path.to_string_lossy().replace('\\', "/"),
+        };
+        (!key.is_empty()).then_some(key)
+    }
+
+    /// 笔记路径对应的文档 URI；打开的笔记沿用编辑器给出的 URI
+    pub fn uri(&self, key: &str) -> String {
+        if let Some(uri) = self.open.get(key) {
+            return uri.clone();
+        }
+        match &self.root {
+            Some(root) if !Path::new(key).is_absolute() => uri::from_path(&root.join(key)),
+            _ => uri::from_path(Path::new(key)),
+        }
+    }
+
+    pub fn open(&mut self, key: String, uri: String, source: String) {
+        self.open.insert(key.clone(), uri);
+        self.insert(key, source);
+    }
+
+    pub fn change(&mut self, key: String, source: String) {
+        self.insert(key, source);
+    }
+
+    /// 关闭后恢复为磁盘内容；文件不存在时移除笔记
+    pub fn close(&mut self, key: &str) {
+        self.open.remove(key);
+        let on_disk = self
+            .root
+            .as_ref()
+            .map(|root| root.join(key))
+            .and_then(|path| fs::read_to_string(path).ok());
+        match on_disk {
+            Some(source) => self.insert(key.to_string(), source),
+            None => {
+                self.notes.remove(key);
+                self.vault.remove(key);
+            }
+        }
+    }
+
+    fn insert(&mut self, key: String, source: String) {
+        let Ok(document) = Parser::parse_string(source, self.options.clone()) else {
+            return;
+        };
+        self.vault.insert_document(key.clone(), &document);
+        self.notes.insert(key, document);
+    }
+
+    pub fn note(&self, key: &str) -> Option<&Document<'static>> {
+        self.notes.get(key)
+    }
+
+    pub fn notes(&self) -> impl Iterator<Item = (&str, &Document<'static>)> {
+        self.notes
+            .iter()
+            .map(|(key, document)| (key.as_str(), document))
+    }
+
+    pub fn open_notes(&self) -> impl Iterator<Item = &str> {
+        self.open.keys().map(String::as_str)
+    }
+
+    pub fn vault(&self) -> &Vault {
+        &self.vault
+    }
+
+    pub fn tag_tree(&self) -> TagTree {
+        let mut tree = TagTree::new();
+        for (key, document) in &self.notes {
+            tree.add_document(key.clone(), document);
+        }
+        tree
+    }
+}
+
+fn is_markdown(path: &str) -> bool {
+    path.rsplit_once('.')
+        .is_some_and(|(_, ext)| ext.eq_ignore_ascii_case("md"))
+}
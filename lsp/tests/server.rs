@@ -0,0 +1,385 @@
+use std::fs;
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use markdown_lsp::{Server, rpc};
+use serde_json::{Value, json};
+
+const INDEX: &str = "---\ntags: [project]\n---\n# Index\n\nSee [[Alpha#Install]] and [[Missing]] and [docs][guide].\n\nA footnote[^1] and #project/alpha tag.\n\n- one\n- two\n\n> [!note] Callout\n> body\n\n[guide]: https://example.com \"Guide\"\n\n[^1]: The footnote.\n";
+const ALPHA: &str = "# Alpha 😀\n\n## Install\n\nRun it. ^step\n\n## Usage\n\nDone.\n";
+
+/// 临时工作区，测试结束时删除
+struct TempVault(PathBuf);
+
+impl TempVault {
+    fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("markdown-lsp-{}-{name}", std::process::id()));
+        fs::create_dir_all(root.join("projects")).unwrap();
+        fs::write(root.join("index.md"), INDEX).unwrap();
+        fs::write(root.join("projects/alpha.md"), ALPHA).unwrap();
+        Self(root)
+    }
+
+    fn uri(&self, path: &str) -> String {
+        format!("file://{}", self.0.join(path).display())
+    }
+}
+
+impl Drop for TempVault {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+struct Client {
+    server: Server,
+    next_id: u64,
+}
+
+impl Client {
+    fn start(root: &Path, capabilities: Value) -> (Self, Value) {
+        let mut client = Client {
+            server: Server::new(),
+            next_id: 0,
+        };
+        let result = client.request(
+            "initialize",
+            json!({ "rootUri": format!("file://{}", root.display()), "capabilities": capabilities }),
+        );
+        client.notify("initialized", json!({}));
+        (client, result)
+    }
+
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        self.next_id += 1;
+        let replies = self.server.handle(
+            json!({ "jsonrpc": "2.0", "id": self.next_id, "method": method, "params": params }),
+        );
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0]["id"], self.next_id);
+        replies[0]["result"].clone()
+    }
+
+    fn notify(&mut self, method: &str, params: Value) -> Vec<Value> {
+        self.server
+            .handle(json!({ "jsonrpc": "2.0", "method": method, "params": params }))
+    }
+
+    fn open(&mut self, uri: &str, text: &str) -> Vec<Value> {
+        self.notify(
+            "textDocument/didOpen",
+            json!({ "textDocument": { "uri": uri, "languageId": "markdown", "version": 1, "text": text } }),
+        )
+    }
+
+    fn at(&mut self, method: &str, uri: &str, position: Value) -> Value {
+        self.request(
+            method,
+            json!({ "textDocument": { "uri": uri }, "position": position }),
+        )
+    }
+}
+
+/// `needle` 首次出现处之后 `delta` 字节的 UTF-16 位置
+fn position(text: &str, needle: &str, delta: usize) -> Value {
+    let offset = text.find(needle).unwrap() + delta;
+    let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    json!({
+        "line": text[..offset].matches('\n').count(),
+        "character": text[line_start..offset].encode_utf16().count(),
+    })
+}
+
+fn labels(completion: &Value) -> Vec<&str> {
+    completion["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn serves_a_session_over_stdio() {
+    let vault = TempVault::new("stdio");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_markdown-lsp"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let messages = [
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": { "rootUri": vault.uri(""), "capabilities": {} } }),
+        json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "textDocument/documentSymbol", "params": { "textDocument": { "uri": vault.uri("projects/alpha.md") } } }),
+        json!({ "jsonrpc": "2.0", "id": 3, "method": "shutdown" }),
+        json!({ "jsonrpc": "2.0", "method": "exit" }),
+    ];
+    for message in &messages {
+        rpc::write_message(&mut stdin, message).unwrap();
+    }
+    stdin.flush().unwrap();
+    drop(stdin);
+
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut replies = Vec::new();
+    while let Some(reply) = rpc::read_message(&mut stdout).unwrap() {
+        replies.push(reply.unwrap());
+    }
+    assert!(child.wait().unwrap().success());
+    assert_eq!(replies.len(), 3);
+    let capabilities = &replies[0]["result"]["capabilities"];
+    assert_eq!(capabilities["positionEncoding"], "utf-16");
+    assert_eq!(capabilities["definitionProvider"], true);
+    assert_eq!(replies[1]["result"][0]["name"], "Alpha 😀");
+    assert_eq!(replies[2]["result"], Value::Null);
+
+    // 未经 shutdown 的 exit 以失败状态退出
+    let mut child = Command::new(env!("CARGO_BIN_EXE_markdown-lsp"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    rpc::write_message(&mut stdin, &json!({ "jsonrpc": "2.0", "method": "exit" })).unwrap();
+    drop(stdin);
+    assert!(!child.wait().unwrap().success());
+}
+
+#[test]
+fn answers_malformed_json_and_keeps_serving() {
+    let mut input = b"Content-Length: 9\r\n\r\n{invalid}".to_vec();
+    for message in [
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": { "capabilities": {} } }),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "shutdown" }),
+        json!({ "jsonrpc": "2.0", "method": "exit" }),
+    ] {
+        rpc::write_message(&mut input, &message).unwrap();
+    }
+    let mut output = Vec::new();
+    assert!(markdown_lsp::run(input.as_slice(), &mut output).unwrap());
+
+    let mut output = output.as_slice();
+    let mut replies = Vec::new();
+    while let Some(reply) = rpc::read_message(&mut output).unwrap() {
+        replies.push(reply.unwrap());
+    }
+    assert_eq!(replies.len(), 3);
+    assert_eq!(replies[0]["id"], Value::Null);
+    assert_eq!(replies[0]["error"]["code"], -32700);
+    assert_eq!(replies[1]["id"], 1);
+    assert!(replies[1]["result"]["capabilities"].is_object());
+    assert_eq!(replies[2]["id"], 2);
+}
+
+#[test]
+fn outlines_and_folds_documents() {
+    let vault = TempVault::new("symbols");
+    let (mut client, _) = Client::start(&vault.0, json!({}));
+    let alpha = vault.uri("projects/alpha.md");
+    let symbols = client.request(
+        "textDocument/documentSymbol",
+        json!({ "textDocument": { "uri": alpha } }),
+    );
+    assert_eq!(symbols.as_array().unwrap().len(), 1);
+    let children = symbols[0]["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|symbol| symbol["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(children, ["Install", "Usage"]);
+    // UTF-16 列：emoji 占两个单元
+    assert_eq!(
+        symbols[0]["selectionRange"]["end"],
+        json!({ "line": 0, "character": 10 })
+    );
+    assert_eq!(
+        symbols[0]["children"][0]["range"],
+        json!({ "start": { "line": 2, "character": 0 }, "end": { "line": 4, "character": 13 } })
+    );
+
+    let index = vault.uri("index.md");
+    let folds = client.request(
+        "textDocument/foldingRange",
+        json!({ "textDocument": { "uri": index } }),
+    );
+    let folds = folds
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|fold| {
+            (
+                fold["startLine"].as_u64().unwrap(),
+                fold["endLine"].as_u64().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(folds, [(3, 17), (9, 10), (12, 13)]);
+}
+
+#[test]
+fn resolves_definitions_and_hovers() {
+    let vault = TempVault::new("definition");
+    let (mut client, _) = Client::start(&vault.0, json!({}));
+    let index = vault.uri("index.md");
+    client.open(&index, INDEX);
+
+    let wikilink = client.at(
+        "textDocument/definition",
+        &index,
+        position(INDEX, "Alpha#", 2),
+    );
+    assert_eq!(wikilink["uri"], vault.uri("projects/alpha.md"));
+    assert_eq!(
+        wikilink["range"]["start"],
+        json!({ "line": 2, "character": 0 })
+    );
+
+    let reference = client.at(
+        "textDocument/definition",
+        &index,
+        position(INDEX, "[docs]", 1),
+    );
+    assert_eq!(reference["uri"], index);
+    assert_eq!(
+        reference["range"]["start"],
+        json!({ "line": 15, "character": 0 })
+    );
+
+    let footnote = client.at(
+        "textDocument/definition",
+        &index,
+        position(INDEX, "[^1]", 1),
+    );
+    assert_eq!(
+        footnote["range"]["start"],
+        json!({ "line": 17, "character": 0 })
+    );
+
+    let missing = client.at(
+        "textDocument/definition",
+        &index,
+        position(INDEX, "Missing", 0),
+    );
+    assert_eq!(missing, Value::Null);
+
+    let hover = client.at("textDocument/hover", &index, position(INDEX, "Alpha#", 0));
+    let contents = hover["contents"]["value"].as_str().unwrap();
+    assert!(contents.starts_with("**projects/alpha.md**"), "{contents}");
+    assert!(contents.contains("Run it."));
+    assert!(!contents.contains("Usage"));
+
+    let hover = client.at("textDocument/hover", &index, position(INDEX, "[docs]", 1));
+    assert_eq!(hover["contents"]["value"], "`https://example.com`\n\nGuide");
+
+    let hover = client.at(
+        "textDocument/hover",
+        &index,
+        position(INDEX, "#project/alpha", 1),
+    );
+    assert_eq!(
+        hover["contents"]["value"],
+        "**#project/alpha**\n\n1 occurrence in 1 note"
+    );
+}
+
+#[test]
+fn completes_links_headings_blocks_and_tags() {
+    let vault = TempVault::new("completion");
+    let (mut client, _) = Client::start(&vault.0, json!({}));
+    let uri = vault.uri("draft.md");
+    let text = "Link [[al\nHeading [[alpha#In\nBlock [[alpha#^\nTag #pro\nLocal [[#\n";
+    client.open(&uri, text);
+
+    let notes = client.at("textDocument/completion", &uri, position(text, "al\n", 2));
+    assert_eq!(labels(&notes), ["draft", "index", "alpha"]);
+    let alpha = &notes["items"][2];
+    assert_eq!(alpha["detail"], "projects/alpha.md");
+    assert_eq!(alpha["textEdit"]["newText"], "alpha");
+    assert_eq!(
+        alpha["textEdit"]["range"],
+        json!({ "start": { "line": 0, "character": 7 }, "end": { "line": 0, "character": 9 } })
+    );
+
+    let headings = client.at("textDocument/completion", &uri, position(text, "#In", 3));
+    assert_eq!(labels(&headings), ["Alpha 😀", "Install", "Usage"]);
+    assert_eq!(
+        headings["items"][1]["textEdit"]["range"]["start"]["character"],
+        16
+    );
+
+    let blocks = client.at("textDocument/completion", &uri, position(text, "#^", 2));
+    assert_eq!(labels(&blocks), ["step"]);
+
+    let tags = client.at("textDocument/completion", &uri, position(text, "#pro", 4));
+    assert_eq!(labels(&tags), ["project", "project/alpha"]);
+
+    let local = client.at("textDocument/completion", &uri, position(text, "[[#", 3));
+    assert!(labels(&local).is_empty());
+}
+
+#[test]
+fn publishes_link_diagnostics() {
+    let vault = TempVault::new("diagnostics");
+    let (mut client, result) = Client::start(
+        &vault.0,
+        json!({ "general": { "positionEncodings": ["utf-32", "utf-16"] } }),
+    );
+    assert_eq!(result["capabilities"]["positionEncoding"], "utf-32");
+    let index = vault.uri("index.md");
+    let text = format!("{INDEX}\n😀 [[Alpha#Nope]] [[#Index]] [[#Gone]]\n");
+    let published = client.open(&index, &text);
+    assert_eq!(published.len(), 1);
+    assert_eq!(published[0]["method"], "textDocument/publishDiagnostics");
+    assert_eq!(published[0]["params"]["uri"], index);
+    let diagnostics = published[0]["params"]["diagnostics"].as_array().unwrap();
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic["code"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        ["unresolved_link", "missing_target", "missing_target"]
+    );
+    assert_eq!(diagnostics[0]["message"], "unresolved link to `Missing`");
+    assert_eq!(diagnostics[1]["message"], "`Alpha#Nope` does not exist");
+    // UTF-32 列：emoji 只占一列
+    assert_eq!(
+        diagnostics[1]["range"]["start"],
+        json!({ "line": 19, "character": 2 })
+    );
+
+    // 新建被链接的笔记后，未解析链接随之消失
+    let missing = vault.uri("Missing.md");
+    let published = client.open(&missing, "# Missing\n");
+    let index_diagnostics = published
+        .iter()
+        .find(|message| message["params"]["uri"] == index)
+        .unwrap();
+    assert_eq!(
+        index_diagnostics["params"]["diagnostics"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+
+    let closed = client.notify(
+        "textDocument/didClose",
+        json!({ "textDocument": { "uri": missing } }),
+    );
+    assert_eq!(closed[0]["params"]["uri"], missing);
+    assert_eq!(closed[0]["params"]["diagnostics"], json!([]));
+    assert_eq!(
+        closed[1]["params"]["diagnostics"].as_array().unwrap().len(),
+        3
+    );
+
+    let shutdown = client.request("shutdown", Value::Null);
+    assert_eq!(shutdown, Value::Null);
+    client.notify("exit", Value::Null);
+    assert!(client.server.exited());
+    assert!(client.server.shut_down_cleanly());
+}
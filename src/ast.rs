@@ -2,11 +2,15 @@
 
 use serde::Serialize;
 
+pub mod abbreviation;
 pub mod block_quote;
 pub mod callout;
 pub mod code;
+pub mod definition_list;
 pub mod embed;
+pub mod emoji;
 pub mod footnote;
+pub mod frontmatter;
 pub mod heading;
 pub mod html;
 pub mod image;
@@ -14,9 +18,12 @@ pub mod link;
 pub mod list;
 pub mod math;
 pub mod reference;
+pub mod ruby;
+pub mod syntax;
 pub mod table;
 pub mod text;
 pub mod thematic_break;
+pub mod transclusion;
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(untagged)]
@@ -28,8 +35,8 @@ pub mod thematic_break;
 pub enum MarkdownNode {
     /// Document root. This is always node ID `0`.
     Document,
-    /// Parsed frontmatter at the start of the document.
-    FrontMatter(Box<crate::exts::yaml::YamlMap>),
+    /// Parsed YAML, TOML, or JSON frontmatter at the start of the document.
+    FrontMatter(Box<frontmatter::FrontMatter>),
     /// Paragraph container.
     Paragraph,
     /// Soft line break.
@@ -54,10 +61,13 @@ pub enum MarkdownNode {
     Image(Box<image::Image>),
     /// Link, wikilink, or footnote link.
     Link(Box<link::Link>),
+    /// Link reference definition, kept only with
+    /// [`crate::ParserOptions::enabled_link_reference_definitions`].
+    LinkReferenceDefinition(Box<link::LinkReferenceDefinition>),
     /// OFM tag.
     Tag(String),
-    /// Emoji shortcode or value.
-    Emoji(String),
+    /// Emoji shortcode resolved to its Unicode value.
+    Emoji(Box<emoji::Emoji>),
     /// Block quote container.
     BlockQuote,
     /// Inline, fenced, or indented code.
@@ -78,6 +88,18 @@ pub enum MarkdownNode {
     Strikethrough,
     /// OFM highlighting.
     Highlighting,
+    /// `^superscript^`.
+    Superscript,
+    /// `~subscript~`.
+    Subscript,
+    /// `++inserted text++`.
+    Insert,
+    /// `||spoiler||`.
+    Spoiler,
+    /// Occurrence of a defined abbreviation.
+    Abbreviation(Box<abbreviation::Abbreviation>),
+    /// Ruby annotation (furigana) over CJK base text.
+    Ruby(Box<ruby::Ruby>),
     /// Thematic break.
     ThematicBreak,
     /// Footnote definition or inline footnote.
@@ -90,17 +112,36 @@ pub enum MarkdownNode {
     Callout(Box<callout::Callout>),
     /// Raw HTML block, inline HTML, comment, or JSX-like element.
     Html(Box<html::Html>),
+    /// Definition list container.
+    DefinitionList(Box<definition_list::DefinitionList>),
+    /// Definition list term.
+    DefinitionTerm,
+    /// Definition list details.
+    DefinitionDetails(Box<definition_list::DefinitionDetails>),
+    /// Embedded note content expanded by [`crate::Transcluder`].
+    Transclusion(Box<transclusion::Transclusion>),
 }
 impl MarkdownNode {
     /// Returns whether this node kind may directly contain `target`.
     pub fn can_contain(&self, target: &MarkdownNode) -> bool {
         match self {
             MarkdownNode::List(..) => matches!(target, MarkdownNode::ListItem(..)),
+            MarkdownNode::DefinitionList(..) => matches!(
+                target,
+                MarkdownNode::DefinitionTerm | MarkdownNode::DefinitionDetails(..)
+            ),
             MarkdownNode::Document
             | MarkdownNode::BlockQuote
             | MarkdownNode::Callout(..)
             | MarkdownNode::Footnote(..)
-            | MarkdownNode::ListItem(..) => !matches!(target, MarkdownNode::ListItem(..)),
+            | MarkdownNode::ListItem(..)
+            | MarkdownNode::DefinitionDetails(..)
+            | MarkdownNode::Transclusion(..) => !matches!(
+                target,
+                MarkdownNode::ListItem(..)
+                    | MarkdownNode::DefinitionTerm
+                    | MarkdownNode::DefinitionDetails(..)
+            ),
             MarkdownNode::Table(..) => {
                 matches!(target, MarkdownNode::TableHead | MarkdownNode::TableBody)
             }
@@ -125,6 +166,7 @@ impl MarkdownNode {
                 | MarkdownNode::TableHeadCol
                 | MarkdownNode::TableDataCol
                 | MarkdownNode::Heading(..)
+                | MarkdownNode::DefinitionTerm
         )
     }
     /// Returns whether this node kind may be reprocessed by the block parser.
@@ -154,7 +196,12 @@ impl MarkdownNode {
             | MarkdownNode::ThematicBreak
             | MarkdownNode::Footnote(..)
             | MarkdownNode::FootnoteList
-            | MarkdownNode::Callout(..) => true,
+            | MarkdownNode::Callout(..)
+            | MarkdownNode::DefinitionList(..)
+            | MarkdownNode::DefinitionTerm
+            | MarkdownNode::DefinitionDetails(..)
+            | MarkdownNode::LinkReferenceDefinition(..)
+            | MarkdownNode::Transclusion(..) => true,
             MarkdownNode::Code(c) => matches!(
                 c.as_ref(),
                 code::Code::Fenced(..) | code::Code::Indented(..)
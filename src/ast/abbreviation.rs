@@ -0,0 +1,19 @@
+use crate::ast::text::SourceSpan;
+use serde::Serialize;
+
+/// An abbreviation definition such as `*[HTML]: Hyper Text Markup Language`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AbbreviationDefinition {
+    /// Abbreviated text matched in the document, e.g. `HTML`.
+    pub label: String,
+    /// Expansion rendered as the `title` attribute. May be empty.
+    pub title: String,
+    /// Source range of the whole definition line.
+    pub span: SourceSpan,
+}
+
+/// An occurrence of a defined abbreviation; the matched text is its child.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Abbreviation {
+    pub title: String,
+}
@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DefinitionList {
+    pub(crate) tight: bool,
+}
+
+impl DefinitionList {
+    pub fn tight(&self) -> bool {
+        self.tight
+    }
+}
+
+/// `: definition` 块；`padding` 为续行所需缩进（标记 + 其后空白）
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DefinitionDetails {
+    pub(crate) padding: usize,
+    pub(crate) marker_offset: usize,
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+/// A resolved emoji shortcode such as `:smile:` or `:+1::skin-tone-3:`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Emoji {
+    /// Shortcode as written, without the surrounding colons.
+    pub name: String,
+    /// Resolved Unicode sequence, or the custom alias value.
+    pub value: String,
+    /// gemoji skin tone modifier (`2..=6`) when `:skin-tone-N:` was applied.
+    pub skin_tone: Option<u8>,
+}
@@ -0,0 +1,152 @@
+use serde::Serialize;
+
+use crate::Location;
+use crate::ast::text::SourceSpan;
+pub use crate::exts::yaml::{YamlMap, YamlValue};
+
+pub mod edit;
+pub mod schema;
+
+/// Source syntax of a frontmatter block.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FrontMatterFormat {
+    /// `---` fenced YAML.
+    Yaml,
+    /// `+++` fenced TOML.
+    Toml,
+    /// `;;;` fenced JSON, or with
+    /// [`crate::ParserOptions::enabled_leading_json_frontmatter`] a JSON object
+    /// at the very start of the document.
+    Json,
+}
+
+/// Parsed frontmatter. TOML and JSON values share the YAML value model.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FrontMatter {
+    /// Syntax the block was written in.
+    pub format: FrontMatterFormat,
+    /// Top-level keys in source order.
+    pub data: YamlMap,
+    /// Source ranges of every key and value, in source order.
+    pub spans: Vec<FrontMatterSpan>,
+}
+
+impl FrontMatter {
+    /// Returns the source ranges of the entry at a dotted path such as
+    /// `tags`, `author.name`, or `aliases.1`.
+    pub fn span_of(&self, path: &str) -> Option<&FrontMatterSpan> {
+        self.spans.iter().find(|it| it.path == path)
+    }
+
+    /// Returns the Obsidian `tags` property, falling back to the legacy `tag`.
+    ///
+    /// A string value may list several tags separated by commas or spaces.
+    /// Leading `#` characters are removed, and entries with no letters, such
+    /// as `2024`, are skipped because Obsidian does not treat them as tags.
+    pub fn tags(&self) -> Vec<String> {
+        self.tag_entries().into_iter().map(|(tag, _)| tag).collect()
+    }
+
+    /// 同 [`FrontMatter::tags`]，并附带每个标签所在的值区间
+    pub(crate) fn tag_entries(&self) -> Vec<(String, SourceSpan)> {
+        self.property_entries(&["tags", "tag"], &[',', ' '])
+            .into_iter()
+            .map(|(tag, span)| (tag.trim_start_matches('#').to_string(), span))
+            .filter(|(tag, _)| tag.chars().any(|ch| ch.is_alphabetic()))
+            .collect()
+    }
+
+    /// Returns the Obsidian `aliases` property, falling back to the legacy
+    /// `alias`. A string value may list several aliases separated by commas.
+    pub fn aliases(&self) -> Vec<String> {
+        self.property(&["aliases", "alias"], &[','])
+    }
+
+    /// Returns the Obsidian `cssclasses` property, falling back to the legacy
+    /// `cssclass`. A string value may list several classes separated by
+    /// commas or spaces.
+    pub fn cssclasses(&self) -> Vec<String> {
+        self.property(&["cssclasses", "cssclass"], &[',', ' '])
+    }
+
+    /// Returns the Obsidian Publish `publish` flag, if it is a boolean.
+    pub fn publish(&self) -> Option<bool> {
+        self.data.get("publish").and_then(YamlValue::as_bool)
+    }
+
+    /// 取第一个存在的键；字符串按分隔符拆分，列表只取字符串与数字项
+    fn property(&self, keys: &[&str], separators: &[char]) -> Vec<String> {
+        self.property_entries(keys, separators)
+            .into_iter()
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    /// 列表项使用各自的区间，拆分出的字符串项共用整个值的区间
+    fn property_entries(&self, keys: &[&str], separators: &[char]) -> Vec<(String, SourceSpan)> {
+        let Some((key, value)) = keys
+            .iter()
+            .find_map(|key| self.data.get(key).map(|value| (*key, value)))
+        else {
+            return Vec::new();
+        };
+        let span = |path: &str| {
+            self.span_of(path)
+                .map_or(SourceSpan::new(0, 0), |it| it.value)
+        };
+        let item = |value: &YamlValue| match value {
+            YamlValue::String(s) => Some(s.trim().to_string()),
+            YamlValue::Integer(i) => Some(i.to_string()),
+            YamlValue::Float(f) => Some(f.to_string()),
+            _ => None,
+        };
+        let items = match value {
+            YamlValue::String(s) => {
+                let span = span(key);
+                s.split(separators)
+                    .map(|it| (it.trim().to_string(), span))
+                    .collect()
+            }
+            YamlValue::List(items) => items
+                .iter()
+                .enumerate()
+                .filter_map(|(index, value)| {
+                    item(value).map(|it| (it, span(&format!("{key}.{index}"))))
+                })
+                .collect(),
+            value => item(value)
+                .map(|it| (it, span(key)))
+                .into_iter()
+                .collect::<Vec<_>>(),
+        };
+        items.into_iter().filter(|(it, _)| !it.is_empty()).collect()
+    }
+}
+
+/// Source ranges of one frontmatter entry.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct FrontMatterSpan {
+    /// Dotted path of the entry. Sequence items use their zero-based index.
+    pub path: String,
+    /// Range of the key text, or `None` for sequence items.
+    pub key: Option<SourceSpan>,
+    /// Range of the value, excluding trailing comments. Empty for a missing value.
+    pub value: SourceSpan,
+}
+
+/// A frontmatter block that was fenced but could not be parsed.
+///
+/// The block is then treated as ordinary Markdown, so no
+/// [`crate::MarkdownNode::FrontMatter`] node is produced.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct FrontMatterDiagnostic {
+    /// Format implied by the fence.
+    pub format: FrontMatterFormat,
+    /// Human-readable reason, e.g. `duplicate mapping key`.
+    pub message: String,
+    /// Range from the error position to the end of its line.
+    pub span: SourceSpan,
+    /// One-based position of the error in the document.
+    pub location: Location,
+}
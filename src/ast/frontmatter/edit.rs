@@ -0,0 +1,479 @@
+use crate::ast::frontmatter::{FrontMatter, FrontMatterFormat, FrontMatterSpan, YamlValue};
+use crate::ast::text::SourceSpan;
+use crate::exts::{json, toml, yaml};
+
+/// Reasons a [`FrontMatterEdit`] operation was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrontMatterEditError {
+    /// The document has a fenced frontmatter block that failed to parse.
+    /// See [`crate::Document::frontmatter_diagnostics`].
+    InvalidFrontMatter,
+    /// No entry exists at the path.
+    NotFound(String),
+    /// A sibling key with the new name already exists.
+    KeyExists(String),
+    /// The entry at the path is not a list.
+    NotAList(String),
+    /// The edit cannot be expressed without restructuring the block, such as
+    /// adding a nested key, removing a TOML table, or writing `null` to TOML.
+    Unsupported(String),
+    /// The edit touches text already changed by an earlier edit.
+    Conflict(String),
+}
+
+/// A set of edits to a document's frontmatter that rewrites only the changed
+/// entries.
+///
+/// Unchanged keys, comments, quoting, and ordering are kept as written. All
+/// paths refer to the original document; [`FrontMatterEdit::finish`] returns
+/// the updated source.
+///
+/// ```
+/// use ptdgrp_markdown::Parser;
+///
+/// let source = "---\ntitle: Note # keep\ntags:\n  - a\ndraft: true\n---\nBody\n";
+/// let document = Parser::new(source).parse()?;
+/// let mut edit = document.edit_frontmatter().unwrap();
+/// edit.push("tags", "b").unwrap();
+/// edit.set("modified", "2024-05-01").unwrap();
+/// edit.remove("draft").unwrap();
+/// assert_eq!(
+///     edit.finish(),
+///     "---\ntitle: Note # keep\ntags:\n  - a\n  - b\nmodified: 2024-05-01\n---\nBody\n"
+/// );
+/// # Ok::<(), ptdgrp_markdown::ParseError>(())
+/// ```
+#[derive(Debug)]
+pub struct FrontMatterEdit<'doc> {
+    source: &'doc str,
+    frontmatter: Option<&'doc FrontMatter>,
+    /// 围栏内正文的起点
+    content_start: usize,
+    newline: &'static str,
+    /// 按调用顺序记录的 (起点, 终点, 替换文本)
+    edits: Vec<(usize, usize, String)>,
+    /// 新增的顶层键，避免重复插入
+    added: Vec<String>,
+    /// 文档没有 frontmatter 时新建的 YAML 条目
+    created: String,
+}
+
+type EditResult = Result<(), FrontMatterEditError>;
+
+impl<'doc> FrontMatterEdit<'doc> {
+    pub(crate) fn new(
+        source: &'doc str,
+        frontmatter: Option<&'doc FrontMatter>,
+        block: SourceSpan,
+    ) -> Self {
+        let start = block.start as usize;
+        let content_start = match frontmatter {
+            Some(frontmatter)
+                if frontmatter.format == FrontMatterFormat::Json
+                    && source[start..].starts_with('{') =>
+            {
+                start
+            }
+            _ => next_line_start(source, start),
+        };
+        let first_line = &source[..next_line_start(source, 0)];
+        Self {
+            source,
+            frontmatter,
+            content_start,
+            newline: if first_line.ends_with("\r\n") {
+                "\r\n"
+            } else {
+                "\n"
+            },
+            edits: Vec::new(),
+            added: Vec::new(),
+            created: String::new(),
+        }
+    }
+
+    /// Sets the value at a dotted path, replacing it in place or appending a
+    /// new top-level key.
+    ///
+    /// A document without frontmatter gains a YAML block.
+    pub fn set(&mut self, path: &str, value: impl Into<YamlValue>) -> EditResult {
+        let value = value.into();
+        match self.span(path) {
+            Some(span) => self.replace_value(path, span, &value),
+            None => self.insert_key(path, &value),
+        }
+    }
+
+    /// Appends an item to the list at a dotted path. A missing or empty entry
+    /// is set to a one-item list.
+    pub fn push(&mut self, path: &str, item: impl Into<YamlValue>) -> EditResult {
+        let item = item.into();
+        let current = self
+            .frontmatter
+            .and_then(|frontmatter| frontmatter.data.get_path(path));
+        let (items, span) = match (current, self.span(path)) {
+            (None | Some(YamlValue::Null), _) | (_, None) => {
+                return self.set(path, YamlValue::List(vec![item]));
+            }
+            (Some(YamlValue::List(items)), Some(span)) => (items.len(), span),
+            _ => return Err(FrontMatterEditError::NotAList(path.to_string())),
+        };
+        let text = self.render(path, &item)?;
+        if self.source.as_bytes()[span.value.start as usize] == b'[' {
+            // 流式序列：插入到最后一项之后或 `[` 之后
+            return match items.checked_sub(1) {
+                Some(last) => {
+                    let last = self.item_span(path, last)?.value.end as usize;
+                    self.push_edit(path, last, last, format!(", {text}"))
+                }
+                None => {
+                    let at = span.value.start as usize + 1;
+                    self.push_edit(path, at, at, text)
+                }
+            };
+        }
+        // 块式序列：沿用最后一项的缩进与 `- `
+        let last = self.item_span(path, items - 1)?.value;
+        let start = last.start as usize;
+        let prefix = &self.source[line_start(self.source, start)..start];
+        if prefix.trim() != "-" {
+            return Err(FrontMatterEditError::Unsupported(path.to_string()));
+        }
+        let at = next_line_start(self.source, last.end as usize);
+        let text = format!("{}{prefix}{text}{}", self.line_break(at), self.newline);
+        self.push_edit(path, at, at, text)
+    }
+
+    /// Removes the entry at a dotted path together with its line when it
+    /// occupies whole lines.
+    pub fn remove(&mut self, path: &str) -> EditResult {
+        let Some(span) = self.span(path) else {
+            return Err(FrontMatterEditError::NotFound(path.to_string()));
+        };
+        if self.is_table_header(span) {
+            return Err(FrontMatterEditError::Unsupported(path.to_string()));
+        }
+        let start = entry_start(span);
+        let end = span.value.end as usize;
+        let (start, end) = match self.owned_lines(span) {
+            Some(lines) => lines,
+            None => {
+                // 流式集合与 JSON：连同相邻的分隔符一起删除
+                let siblings = self.siblings(path);
+                let index = siblings.iter().position(|it| it.path == path).unwrap_or(0);
+                if let Some(next) = siblings.get(index + 1) {
+                    (start, entry_start(next))
+                } else if let Some(prev) = index.checked_sub(1).map(|i| siblings[i]) {
+                    (prev.value.end as usize, end)
+                } else {
+                    (start, end)
+                }
+            }
+        };
+        self.push_edit(path, start, end, String::new())
+    }
+
+    /// Renames the key at a dotted path, keeping its value and position.
+    pub fn rename(&mut self, path: &str, new_key: &str) -> EditResult {
+        let Some(key) = self.span(path).and_then(|span| span.key) else {
+            return Err(FrontMatterEditError::NotFound(path.to_string()));
+        };
+        let frontmatter = self.frontmatter.expect("a span implies frontmatter");
+        let (siblings, old_key) = match path.rsplit_once('.') {
+            Some((parent, key)) => (
+                frontmatter
+                    .data
+                    .get_path(parent)
+                    .and_then(YamlValue::as_map),
+                key,
+            ),
+            None => (Some(&frontmatter.data), path),
+        };
+        if old_key != new_key && siblings.is_some_and(|map| map.contains_key(new_key)) {
+            return Err(FrontMatterEditError::KeyExists(new_key.to_string()));
+        }
+        let mut text = String::new();
+        match frontmatter.format {
+            FrontMatterFormat::Yaml => yaml::write_key(new_key, &mut text),
+            FrontMatterFormat::Toml => toml::write_key(new_key, &mut text),
+            FrontMatterFormat::Json => json::write_string(new_key, &mut text),
+        }
+        self.push_edit(path, key.start as usize, key.end as usize, text)
+    }
+
+    /// Applies the edits and returns the updated document source.
+    pub fn finish(self) -> String {
+        let mut edits = self.edits;
+        edits.sort_by_key(|(start, _, _)| *start);
+        let mut out = String::with_capacity(self.source.len() + self.created.len() + 8);
+        if !self.created.is_empty() {
+            out.push_str("---");
+            out.push_str(self.newline);
+            out.push_str(&self.created);
+            out.push_str("---");
+            out.push_str(self.newline);
+        }
+        let mut pos = 0;
+        for (start, end, text) in edits {
+            out.push_str(&self.source[pos..start]);
+            out.push_str(&text);
+            pos = end;
+        }
+        out.push_str(&self.source[pos..]);
+        out
+    }
+
+    fn format(&self) -> FrontMatterFormat {
+        self.frontmatter
+            .map_or(FrontMatterFormat::Yaml, |frontmatter| frontmatter.format)
+    }
+
+    fn span(&self, path: &str) -> Option<&'doc FrontMatterSpan> {
+        self.frontmatter
+            .and_then(|frontmatter| frontmatter.span_of(path))
+    }
+
+    fn item_span(
+        &self,
+        path: &str,
+        index: usize,
+    ) -> Result<&'doc FrontMatterSpan, FrontMatterEditError> {
+        self.span(&format!("{path}.{index}"))
+            .ok_or_else(|| FrontMatterEditError::Unsupported(path.to_string()))
+    }
+
+    /// TOML 表头 `[table]` 的键位于值（整个表头）之内
+    fn is_table_header(&self, span: &FrontMatterSpan) -> bool {
+        self.format() == FrontMatterFormat::Toml
+            && span.key.is_some_and(|key| key.start > span.value.start)
+    }
+
+    /// 同一父路径下的条目，按源文本顺序
+    fn siblings(&self, path: &str) -> Vec<&'doc FrontMatterSpan> {
+        let parent = path.rsplit_once('.').map(|(parent, _)| parent);
+        let depth = path.matches('.').count();
+        self.frontmatter
+            .map(|frontmatter| {
+                frontmatter
+                    .spans
+                    .iter()
+                    .filter(|it| {
+                        it.path.matches('.').count() == depth
+                            && it.path.rsplit_once('.').map(|(parent, _)| parent) == parent
+                            && !self.is_table_header(it)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 条目独占若干整行时返回这些行的范围；行尾只允许空白或注释
+    fn owned_lines(&self, span: &FrontMatterSpan) -> Option<(usize, usize)> {
+        if self.format() == FrontMatterFormat::Json {
+            return None;
+        }
+        let start = entry_start(span);
+        let end = span.value.end as usize;
+        let first = line_start(self.source, start);
+        let before = self.source[first..start].trim();
+        // `- key: value` 这样的紧凑映射首键不能单独删除整行
+        let is_item = span.key.is_none() && before == "-";
+        if !before.is_empty() && !is_item {
+            return None;
+        }
+        let next = next_line_start(self.source, end);
+        let after = self.source[end..next].trim_start();
+        if !after.is_empty() && !after.starts_with('#') {
+            return None;
+        }
+        Some((first, next))
+    }
+
+    fn replace_value(
+        &mut self,
+        path: &str,
+        span: &FrontMatterSpan,
+        value: &YamlValue,
+    ) -> EditResult {
+        if self.is_table_header(span) {
+            return Err(FrontMatterEditError::Unsupported(path.to_string()));
+        }
+        let start = span.value.start as usize;
+        let end = span.value.end as usize;
+        let old = &self.source[start..end];
+        if self.format() != FrontMatterFormat::Yaml {
+            let text = self.render(path, value)?;
+            return self.push_edit(path, start, end, text);
+        }
+        let is_block = |value: &YamlValue| match value {
+            YamlValue::List(items) => !items.is_empty(),
+            YamlValue::Map(map) => !map.is_empty(),
+            _ => false,
+        };
+        if let Some(key) = span.key {
+            let key_end = key.end as usize;
+            let old_block = self.source[key_end..start].contains('\n');
+            let new_block = is_block(value);
+            if old_block && new_block {
+                // 沿用原有块的缩进，保留键所在行
+                let indent = start - line_start(self.source, start);
+                let mut text = String::new();
+                yaml::write_block(value, indent, &mut text);
+                let text = text[1 + indent..].replace('\n', self.newline);
+                return self.push_edit(path, start, end, text);
+            }
+            if old_block || new_block {
+                let indent = key.start as usize - line_start(self.source, key.start as usize) + 2;
+                let mut text = String::from(":");
+                yaml::write_block(value, indent, &mut text);
+                let colon = key_end + self.source[key_end..].find(':').unwrap_or(0);
+                let text = text.replace('\n', self.newline);
+                return self.push_edit(path, colon, end, text);
+            }
+        }
+        let mut text = String::new();
+        match value {
+            // 保留原有的引号风格
+            YamlValue::String(s) if old.starts_with(['\'', '"']) => {
+                yaml::write_quoted(s, old.starts_with('\''), &mut text)
+            }
+            value => yaml::write_flow(value, &mut text),
+        }
+        if start == end && self.source[..start].ends_with(':') {
+            text.insert(0, ' ');
+        }
+        self.push_edit(path, start, end, text)
+    }
+
+    fn insert_key(&mut self, path: &str, value: &YamlValue) -> EditResult {
+        let exists = self
+            .frontmatter
+            .is_some_and(|frontmatter| frontmatter.data.get_path(path).is_some());
+        if path.contains('.') || exists {
+            return Err(FrontMatterEditError::Unsupported(path.to_string()));
+        }
+        if self.added.iter().any(|it| it == path) {
+            return Err(FrontMatterEditError::Conflict(path.to_string()));
+        }
+        let Some(frontmatter) = self.frontmatter else {
+            let mut line = String::new();
+            yaml::write_key(path, &mut line);
+            line.push(':');
+            yaml::write_block(value, 2, &mut line);
+            line.push('\n');
+            self.created.push_str(&line.replace('\n', self.newline));
+            self.added.push(path.to_string());
+            return Ok(());
+        };
+        let top_level = frontmatter
+            .data
+            .keys()
+            .filter_map(|key| frontmatter.span_of(key))
+            .filter(|span| !self.is_table_header(span));
+        let last_end = top_level.map(|span| span.value.end as usize).max();
+        let (at, text) = match frontmatter.format {
+            FrontMatterFormat::Yaml => {
+                let last_end = frontmatter
+                    .spans
+                    .iter()
+                    .map(|it| it.value.end as usize)
+                    .max();
+                let at =
+                    last_end.map_or(self.content_start, |end| next_line_start(self.source, end));
+                let mut text = String::new();
+                yaml::write_key(path, &mut text);
+                text.push(':');
+                yaml::write_block(value, 2, &mut text);
+                text.push('\n');
+                let text = text.replace('\n', self.newline);
+                (at, format!("{}{text}", self.line_break(at)))
+            }
+            FrontMatterFormat::Toml => {
+                // 新键必须位于第一个表头之前
+                let at =
+                    last_end.map_or(self.content_start, |end| next_line_start(self.source, end));
+                let mut text = String::new();
+                toml::write_key(path, &mut text);
+                text.push_str(" = ");
+                text.push_str(&self.render(path, value)?);
+                (at, format!("{}{text}{}", self.line_break(at), self.newline))
+            }
+            FrontMatterFormat::Json => {
+                let last = frontmatter
+                    .data
+                    .keys()
+                    .filter_map(|key| frontmatter.span_of(key))
+                    .max_by_key(|span| span.value.end)
+                    .ok_or_else(|| FrontMatterEditError::Unsupported(path.to_string()))?;
+                let key_start = entry_start(last);
+                let line = line_start(self.source, key_start);
+                let indent = &self.source[line..key_start];
+                let separator = if indent.trim().is_empty() {
+                    format!("{}{indent}", self.newline)
+                } else {
+                    " ".to_string()
+                };
+                let mut text = format!(",{separator}");
+                json::write_string(path, &mut text);
+                text.push_str(": ");
+                text.push_str(&self.render(path, value)?);
+                (last.value.end as usize, text)
+            }
+        };
+        self.push_edit(path, at, at, text)?;
+        self.added.push(path.to_string());
+        Ok(())
+    }
+
+    /// 以单行形式写出值
+    fn render(&self, path: &str, value: &YamlValue) -> Result<String, FrontMatterEditError> {
+        let mut text = String::new();
+        let ok = match self.format() {
+            FrontMatterFormat::Yaml => {
+                yaml::write_flow(value, &mut text);
+                true
+            }
+            FrontMatterFormat::Toml => toml::write_value(value, &mut text),
+            FrontMatterFormat::Json => json::write_value(value, &mut text),
+        };
+        ok.then_some(text)
+            .ok_or_else(|| FrontMatterEditError::Unsupported(path.to_string()))
+    }
+
+    /// 在没有换行结尾的最后一行之后插入时补一个换行
+    fn line_break(&self, at: usize) -> &'static str {
+        if at > 0 && !self.source[..at].ends_with('\n') {
+            self.newline
+        } else {
+            ""
+        }
+    }
+
+    fn push_edit(&mut self, path: &str, start: usize, end: usize, text: String) -> EditResult {
+        let overlaps = self.edits.iter().any(|&(s, e, _)| {
+            (start < e && s < end)
+                || (start == end && s < start && start < e)
+                || (s == e && start < s && s < end)
+        });
+        if overlaps {
+            return Err(FrontMatterEditError::Conflict(path.to_string()));
+        }
+        self.edits.push((start, end, text));
+        Ok(())
+    }
+}
+
+/// 条目起点：键的起点，序列项为值的起点
+fn entry_start(span: &FrontMatterSpan) -> usize {
+    span.key.map_or(span.value.start, |key| key.start) as usize
+}
+
+fn line_start(source: &str, pos: usize) -> usize {
+    source[..pos].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn next_line_start(source: &str, pos: usize) -> usize {
+    source[pos..]
+        .find('\n')
+        .map_or(source.len(), |i| pos + i + 1)
+}
@@ -0,0 +1,311 @@
+use serde::Serialize;
+
+use crate::ast::frontmatter::{FrontMatter, YamlValue};
+use crate::ast::text::SourceSpan;
+
+/// Expected type of a frontmatter value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldType {
+    /// Any value, including `null`.
+    Any,
+    /// A string.
+    String,
+    /// A boolean.
+    Bool,
+    /// An integer.
+    Integer,
+    /// An integer or a float.
+    Number,
+    /// A `YYYY-MM-DD` date, optionally followed by a time such as
+    /// `2024-05-01T10:00:00Z`. YAML and TOML dates are stored as strings.
+    Date,
+    /// A list whose items all have the given type.
+    List(Box<FieldType>),
+    /// A string equal to one of the given values.
+    Enum(Vec<String>),
+    /// A nested mapping.
+    Map,
+}
+
+impl FieldType {
+    /// A list whose items all have type `item`.
+    pub fn list_of(item: FieldType) -> Self {
+        Self::List(Box::new(item))
+    }
+
+    /// A string restricted to `values`.
+    pub fn one_of<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::Enum(values.into_iter().map(Into::into).collect())
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            FieldType::Any => "any value".to_string(),
+            FieldType::String => "a string".to_string(),
+            FieldType::Bool => "a boolean".to_string(),
+            FieldType::Integer => "an integer".to_string(),
+            FieldType::Number => "a number".to_string(),
+            FieldType::Date => "a date (YYYY-MM-DD)".to_string(),
+            FieldType::List(item) => match item.as_ref() {
+                FieldType::String => "a list of strings".to_string(),
+                item => format!("a list of {}", item.describe()),
+            },
+            FieldType::Enum(values) => {
+                let values = values
+                    .iter()
+                    .map(|it| format!("`{it}`"))
+                    .collect::<Vec<_>>();
+                format!("one of {}", values.join(", "))
+            }
+            FieldType::Map => "a mapping".to_string(),
+        }
+    }
+}
+
+/// One expected frontmatter key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSchema {
+    /// Dotted path such as `title` or `author.name`.
+    pub path: String,
+    /// Expected value type.
+    pub ty: FieldType,
+    /// Whether a missing key is an error.
+    pub required: bool,
+}
+
+/// Expected frontmatter keys and value types.
+///
+/// ```
+/// use ptdgrp_markdown::Parser;
+/// use ptdgrp_markdown::frontmatter::schema::{FieldType, FrontMatterSchema};
+///
+/// let schema = FrontMatterSchema::new()
+///     .required("title", FieldType::String)
+///     .required("date", FieldType::Date)
+///     .required("tags", FieldType::list_of(FieldType::String))
+///     .optional("status", FieldType::one_of(["draft", "published"]));
+///
+/// let document = Parser::new("---\ntitle: Hi\ndate: 2024-02-30\ntags: [a]\n---\n").parse()?;
+/// let errors = document.validate_frontmatter(&schema);
+/// assert_eq!(errors[0].path, "date");
+/// # Ok::<(), ptdgrp_markdown::ParseError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrontMatterSchema {
+    /// Expected keys in declaration order.
+    pub fields: Vec<FieldSchema>,
+    /// Whether top-level keys not listed in `fields` are errors.
+    pub deny_unknown_keys: bool,
+}
+
+impl FrontMatterSchema {
+    /// Creates an empty schema that accepts any frontmatter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a key that must be present.
+    pub fn required(mut self, path: impl Into<String>, ty: FieldType) -> Self {
+        self.fields.push(FieldSchema {
+            path: path.into(),
+            ty,
+            required: true,
+        });
+        self
+    }
+
+    /// Adds a key that is checked only when present.
+    pub fn optional(mut self, path: impl Into<String>, ty: FieldType) -> Self {
+        self.fields.push(FieldSchema {
+            path: path.into(),
+            ty,
+            required: false,
+        });
+        self
+    }
+
+    /// Reports top-level keys that the schema does not list.
+    pub fn deny_unknown_keys(self) -> Self {
+        Self {
+            deny_unknown_keys: true,
+            ..self
+        }
+    }
+
+    /// Validates parsed frontmatter against the schema.
+    ///
+    /// Missing keys are reported with an empty span just after the last entry,
+    /// where the key could be inserted.
+    pub fn validate(&self, frontmatter: &FrontMatter) -> Vec<FrontMatterValidationError> {
+        let insert_at = frontmatter
+            .spans
+            .iter()
+            .map(|it| it.value.end)
+            .max()
+            .unwrap_or(0);
+        self.validate_with(Some(frontmatter), SourceSpan::new(insert_at, insert_at))
+    }
+
+    /// `missing` 为缺失键报告的区间
+    pub(crate) fn validate_with(
+        &self,
+        frontmatter: Option<&FrontMatter>,
+        missing: SourceSpan,
+    ) -> Vec<FrontMatterValidationError> {
+        let mut errors = Vec::new();
+        for field in &self.fields {
+            let value = frontmatter.and_then(|it| it.data.get_path(&field.path));
+            match value {
+                Some(value) => check(frontmatter, &field.path, &field.ty, value, &mut errors),
+                None if field.required => errors.push(FrontMatterValidationError {
+                    path: field.path.clone(),
+                    kind: ValidationErrorKind::MissingKey,
+                    message: format!("missing required key `{}`", field.path),
+                    span: missing,
+                }),
+                None => {}
+            }
+        }
+        if self.deny_unknown_keys
+            && let Some(frontmatter) = frontmatter
+        {
+            for key in frontmatter.data.keys() {
+                // `author.name` 这样的点分字段也声明了顶层键 `author`
+                let known = self.fields.iter().any(|field| {
+                    field.path == key
+                        || field
+                            .path
+                            .strip_prefix(key)
+                            .is_some_and(|rest| rest.starts_with('.'))
+                });
+                if !known {
+                    errors.push(FrontMatterValidationError {
+                        path: key.to_string(),
+                        kind: ValidationErrorKind::UnknownKey,
+                        message: format!("unknown key `{key}`"),
+                        span: entry_span(Some(frontmatter), key, missing),
+                    });
+                }
+            }
+        }
+        errors.sort_by_key(|it| it.span.start);
+        errors
+    }
+}
+
+/// Category of a [`FrontMatterValidationError`].
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationErrorKind {
+    /// A required key is absent.
+    MissingKey,
+    /// The value has a different type than the schema expects.
+    TypeMismatch,
+    /// The value has the right type but is not allowed, e.g. an invalid date
+    /// or a string outside an enum.
+    InvalidValue,
+    /// The key is not listed in a schema that denies unknown keys.
+    UnknownKey,
+}
+
+/// A frontmatter value that does not match a [`FrontMatterSchema`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct FrontMatterValidationError {
+    /// Dotted path of the offending entry. List items use their index.
+    pub path: String,
+    /// Error category.
+    pub kind: ValidationErrorKind,
+    /// Human-readable description, e.g. `expected a list of strings`.
+    pub message: String,
+    /// Source range of the offending value, or the insertion point for a
+    /// missing key.
+    pub span: SourceSpan,
+}
+
+fn check(
+    frontmatter: Option<&FrontMatter>,
+    path: &str,
+    ty: &FieldType,
+    value: &YamlValue,
+    errors: &mut Vec<FrontMatterValidationError>,
+) {
+    let mut error = |kind: ValidationErrorKind, message: String| {
+        errors.push(FrontMatterValidationError {
+            path: path.to_string(),
+            kind,
+            message,
+            span: entry_span(frontmatter, path, SourceSpan::new(0, 0)),
+        });
+    };
+    let type_mismatch = |ty: &FieldType| format!("expected {}", ty.describe());
+    match (ty, value) {
+        (FieldType::Any, _)
+        | (FieldType::String, YamlValue::String(_))
+        | (FieldType::Bool, YamlValue::Bool(_))
+        | (FieldType::Integer, YamlValue::Integer(_))
+        | (FieldType::Number, YamlValue::Integer(_) | YamlValue::Float(_))
+        | (FieldType::Map, YamlValue::Map(_)) => {}
+        (FieldType::Date, YamlValue::String(text)) => {
+            if !is_date(text) {
+                error(
+                    ValidationErrorKind::InvalidValue,
+                    format!("`{text}` is not a valid date"),
+                );
+            }
+        }
+        (FieldType::Enum(values), YamlValue::String(text)) => {
+            if !values.iter().any(|it| it == text) {
+                error(ValidationErrorKind::InvalidValue, type_mismatch(ty));
+            }
+        }
+        (FieldType::List(item), YamlValue::List(items)) => {
+            for (index, value) in items.iter().enumerate() {
+                check(frontmatter, &format!("{path}.{index}"), item, value, errors);
+            }
+        }
+        _ => error(ValidationErrorKind::TypeMismatch, type_mismatch(ty)),
+    }
+}
+
+/// 条目值的区间；没有记录时使用 `fallback`
+fn entry_span(frontmatter: Option<&FrontMatter>, path: &str, fallback: SourceSpan) -> SourceSpan {
+    frontmatter
+        .and_then(|it| it.span_of(path))
+        .map_or(fallback, |it| it.value)
+}
+
+/// `YYYY-MM-DD`，其后可跟 `T` 或空格开始的时间部分
+fn is_date(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    if bytes.len() < 10
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes.get(10), None | Some(b'T' | b't' | b' '))
+    {
+        return false;
+    }
+    let number = |range: std::ops::Range<usize>| {
+        let digits = &text[range];
+        digits
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| digits.parse::<u32>().ok())
+            .flatten()
+    };
+    let (Some(year), Some(month), Some(day)) = (number(0..4), number(5..7), number(8..10)) else {
+        return false;
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days).contains(&day)
+}
@@ -16,6 +16,23 @@ pub struct DefaultLink {
     pub title: Option<crate::ast::text::TextRef>,
 }
 
+/// A link reference definition such as `[label]: /url "title"`.
+///
+/// Definitions are kept in the tree as [`crate::MarkdownNode::LinkReferenceDefinition`]
+/// nodes only with [`crate::ParserOptions::enabled_link_reference_definitions`];
+/// [`crate::Document::link_definitions`] is always available.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LinkReferenceDefinition {
+    /// Label as written between the brackets, e.g. `Guide`.
+    pub label: String,
+    /// Destination with entities and escapes resolved, percent-encoded like
+    /// the `href` of the links that use it.
+    pub url: String,
+    pub title: Option<String>,
+    /// Source range of the whole definition, without the trailing line ending.
+    pub span: crate::ast::text::SourceSpan,
+}
+
 #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct Wikilink {
     pub path: String,
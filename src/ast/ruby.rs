@@ -0,0 +1,18 @@
+use serde::Serialize;
+
+/// Ruby annotation such as `{漢字|かんじ}` or `[漢字]^(かんじ)`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Ruby {
+    /// The whole annotated base text, e.g. `漢字`.
+    pub base: String,
+    /// `<rb>`/`<rt>` pairs in order. A single annotation covers the whole base;
+    /// `{漢字|かん|じ}` yields one pair per base character.
+    pub annotations: Vec<RubyAnnotation>,
+}
+
+/// One base segment and the annotation text rendered above it.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RubyAnnotation {
+    pub base: String,
+    pub text: String,
+}
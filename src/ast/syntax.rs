@@ -0,0 +1,54 @@
+use serde::Serialize;
+
+/// How a link or image was written.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkSyntax {
+    /// `[text](/url "title")`.
+    Inline,
+    /// `[text][label]`.
+    Full,
+    /// `[label][]`.
+    Collapsed,
+    /// `[label]`.
+    Shortcut,
+    /// `<https://example.com>` or `<me@example.com>`.
+    Autolink,
+    /// GFM extended autolink such as `www.example.com`.
+    ExtendedAutolink,
+}
+
+/// How a hard line break was written.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HardBreakSyntax {
+    /// A backslash before the line ending.
+    Backslash,
+    /// Two or more spaces before the line ending.
+    Spaces,
+}
+
+/// Source syntax of a node, recorded with
+/// [`crate::ParserOptions::enabled_source_fidelity`] and returned by
+/// [`crate::Document::node_syntax`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NodeSyntax {
+    /// A [`crate::ast::link::Link::Default`] link.
+    Link { form: LinkSyntax },
+    /// An image; never an autolink.
+    Image { form: LinkSyntax },
+    /// Emphasis delimited by `*` or `_`.
+    Emphasis { marker: char },
+    /// Strong emphasis delimited by `**` or `__`.
+    Strong { marker: char },
+    /// Inline code and the length of its backtick string.
+    Code { backticks: usize },
+    /// A hard line break.
+    HardBreak { form: HardBreakSyntax },
+    /// ATX heading and the length of its closing `#` sequence; `0` when there
+    /// is none.
+    AtxHeading { closing: usize },
+    /// List item marker as written, e.g. `-`, `*`, `1.` or `07)`.
+    ListItem { marker: String },
+}
@@ -96,6 +96,22 @@ impl TextRef {
             }
         }
     }
+    /// 丢弃开头 `count` 字节。`Source` 直接前移区间起点，`Owned` 原地删除；
+    /// `count` 必须落在 UTF-8 边界。
+    #[inline]
+    pub(crate) fn truncate_front(&mut self, count: usize, source: &str) {
+        match self {
+            TextRef::Owned(text) => {
+                text.drain(..count);
+            }
+            TextRef::Source(span) => {
+                debug_assert!(count <= span.len());
+                debug_assert!(source.is_char_boundary(span.start as usize + count));
+                let _ = source;
+                span.start += count as u32;
+            }
+        }
+    }
     /// 追加另一段文本。两段均为 `Source` 且区间相邻时直接扩展区间
     /// （零拷贝），否则按需物化后拼接。
     #[inline]
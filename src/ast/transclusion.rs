@@ -0,0 +1,22 @@
+use crate::ast::reference::Reference;
+use crate::ast::text::SourceSpan;
+use serde::Serialize;
+
+/// Content of another note grafted in place of an OFM embed such as
+/// `![[Note#Section]]`.
+///
+/// The children are copied from the embedded note and their text is owned.
+/// Every grafted node takes the span of the embed in the host document, so
+/// spans always index the host source; the range of the content in the
+/// embedded note is kept in [`Transclusion::span`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Transclusion {
+    /// Identifier of the embedded note, as returned by the loader.
+    pub note: String,
+    /// Embed path as written, e.g. `Note` for `![[Note#Section]]`.
+    pub path: String,
+    /// Heading or block reference of the embed, if any.
+    pub reference: Option<Reference>,
+    /// Source range of the grafted content in the embedded note.
+    pub span: SourceSpan,
+}
@@ -5,6 +5,7 @@ use crate::span::Span;
 mod block_quote;
 mod callout;
 mod code;
+mod definition_list;
 mod footnote;
 mod heading;
 pub(crate) mod html;
@@ -87,6 +88,10 @@ pub fn process<'input>(
         MarkdownNode::Callout(..) => ast::callout::Callout::process(ctx),
         MarkdownNode::Footnote(..) => ast::footnote::Footnote::process(ctx),
         MarkdownNode::Math(..) => ast::math::BlockMath::process(ctx),
+        MarkdownNode::DefinitionList(..) => ast::definition_list::DefinitionList::process(ctx),
+        MarkdownNode::DefinitionDetails(..) => {
+            ast::definition_list::DefinitionDetails::process(ctx)
+        }
         MarkdownNode::Paragraph => {
             if ctx.line.is_blank_to_end() {
                 BlockProcessing::Unprocessed
@@ -102,7 +107,10 @@ pub fn after(id: usize, parser: &mut Parser, location: u32) {
     // For container blocks like List and ListItem, adjust end location
     // to not include the trailing newline of the last line
     let adjusted_location = match &parser.tree[id].body {
-        MarkdownNode::List(..) | MarkdownNode::ListItem(..) => {
+        MarkdownNode::List(..)
+        | MarkdownNode::ListItem(..)
+        | MarkdownNode::DefinitionList(..)
+        | MarkdownNode::DefinitionDetails(..) => {
             // Use the end of the last child if available
             if let Some(last_child) = parser.tree.get_last_child(id) {
                 parser.tree[last_child].span.end
@@ -136,6 +144,7 @@ pub fn after(id: usize, parser: &mut Parser, location: u32) {
         MarkdownNode::Callout(..) => ast::callout::Callout::after(id, parser),
         MarkdownNode::Footnote(..) => ast::footnote::Footnote::after(id, parser),
         MarkdownNode::Math(..) => ast::math::BlockMath::after(id, parser),
+        MarkdownNode::DefinitionList(..) => ast::definition_list::DefinitionList::after(id, parser),
         _ => (),
     }
 }
@@ -161,7 +170,7 @@ static LINE_HEAD: [u16; 256] = {
         d += 1;
     }
     t[b'|' as usize] = 1 << 8; // Table
-    t[b':' as usize] = 1 << 8;
+    t[b':' as usize] = (1 << 8) | (1 << 12); // Table, DefinitionDetails
     t[b'[' as usize] = 1 << 9; // Footnote
     t[b'$' as usize] = 1 << 11; // BlockMath
     t
@@ -209,6 +218,7 @@ pub fn matcher<'input>(
     try_matcher!(9, ast::footnote::Footnote::before);
     try_matcher!(10, ast::code::IndentedCode::before);
     try_matcher!(11, ast::math::BlockMath::before);
+    try_matcher!(12, ast::definition_list::DefinitionDetails::before);
     line.resume(&snapshot);
     BlockMatching::Unmatched
 }
@@ -0,0 +1,266 @@
+use crate::ast::{MarkdownNode, definition_list};
+use crate::blocks::list::subtree_end_offset;
+use crate::blocks::{BeforeCtx, BlockMatching, BlockProcessing, BlockStrategy, ProcessCtx};
+use crate::parser::Parser;
+use crate::span::Span;
+
+/// 标记后的空白宽度（tab 按 4 列计）
+fn spaces_after_marker(line: &Span) -> usize {
+    let mut count = 0usize;
+    let mut pos = 0;
+    loop {
+        match line.get(pos) {
+            Some(b' ') => count += 1,
+            Some(b'\t') => count += 4,
+            _ => return count,
+        }
+        pos += 1;
+    }
+}
+
+/// 原始字节层面的 `: definition` 判定，供向后窥视使用
+fn is_marker_bytes(bytes: &[u8]) -> bool {
+    let indent = bytes.iter().take_while(|&&b| b == b' ').count();
+    if indent > 3 || bytes.get(indent) != Some(&b':') {
+        return false;
+    }
+    matches!(bytes.get(indent + 1), Some(b' ' | b'\t'))
+        && bytes[indent + 2..]
+            .iter()
+            .any(|&b| !matches!(b, b' ' | b'\t'))
+}
+
+/// 向后窥视下一物理行：去掉与当前行相同的容器前缀后，是否为定义标记行
+fn next_line_is_marker(line: &Span) -> bool {
+    let source = line.source_slice();
+    let prefix = &source[line.start()..line.cursor()];
+    let mut pos = line.end();
+    match source.get(pos) {
+        Some(b'\r') => {
+            pos += 1;
+            if source.get(pos) == Some(&b'\n') {
+                pos += 1;
+            }
+        }
+        Some(b'\n') => pos += 1,
+        _ => return false,
+    }
+    let Some(rest) = source[pos..].strip_prefix(prefix) else {
+        return false;
+    };
+    let eol = memchr::memchr2(b'\n', b'\r', rest).unwrap_or(rest.len());
+    is_marker_bytes(&rest[..eol])
+}
+
+impl definition_list::DefinitionDetails {
+    /// 将段落逐行改写为 DefinitionTerm；段首的引用定义行保留在原段落中
+    fn convert_paragraph(parser: &mut Parser, paragraph_id: usize, location: u32) -> bool {
+        let Some(spans) = parser.inlines.get(paragraph_id) else {
+            return false;
+        };
+        let ref_lines = spans
+            .iter()
+            .take_while(|span| crate::inlines::is_link_reference_line(span))
+            .count();
+        if ref_lines == spans.len() {
+            return false;
+        }
+        let Some(spans) = parser.inlines.remove(paragraph_id) else {
+            return false;
+        };
+        let list =
+            MarkdownNode::DefinitionList(Box::new(definition_list::DefinitionList { tight: true }));
+        if ref_lines == 0 {
+            parser.replace_block(list, location);
+        } else {
+            let paragraph_end = spans[ref_lines - 1].end() as u32;
+            parser
+                .inlines
+                .insert(paragraph_id, spans[..ref_lines].iter().copied().collect());
+            parser.tree[paragraph_id].span.end = paragraph_end;
+            parser.finalize(paragraph_id, paragraph_end);
+            parser.append_block(list, spans[ref_lines].cursor_or_end() as u32);
+        }
+        for span in spans.into_iter().skip(ref_lines) {
+            let term =
+                parser.append_block(MarkdownNode::DefinitionTerm, span.cursor_or_end() as u32);
+            let end = span.end() as u32;
+            parser.append_inline(term, span);
+            parser.finalize(term, end);
+        }
+        true
+    }
+}
+
+impl BlockStrategy for definition_list::DefinitionDetails {
+    fn before(
+        BeforeCtx {
+            line,
+            parser,
+            container,
+        }: BeforeCtx,
+    ) -> BlockMatching {
+        if !parser.options.definition_list || line.is_indented() {
+            return BlockMatching::Unmatched;
+        }
+        let location = line.cursor_or_end() as u32;
+        let marker_offset = line.indent_spaces();
+        line.skip_indent();
+        if !line.consume(b':') {
+            return BlockMatching::Unmatched;
+        }
+        let spaces = spaces_after_marker(line);
+        // 标记后须有空白，且同一行必须带有定义内容
+        if spaces == 0 || line.only_space_to_end() {
+            return BlockMatching::Unmatched;
+        }
+        match &parser.tree[container].body {
+            MarkdownNode::Paragraph => {
+                if !Self::convert_paragraph(parser, container, location) {
+                    return BlockMatching::Unmatched;
+                }
+            }
+            MarkdownNode::DefinitionList(..) => parser.close_unmatched_blocks(),
+            _ => return BlockMatching::Unmatched,
+        }
+        // 与列表项一致：超过 4 个空白时只有 1 个属于标记
+        let padding = if spaces > 4 { 1 } else { spaces };
+        line.skip_spaces(padding);
+        line.re_find_indent();
+        parser.append_block(
+            MarkdownNode::DefinitionDetails(Box::new(definition_list::DefinitionDetails {
+                padding: padding + 1,
+                marker_offset,
+            })),
+            location,
+        );
+        BlockMatching::MatchedContainer
+    }
+
+    fn process(ProcessCtx { line, parser, id }: ProcessCtx) -> BlockProcessing {
+        let required_indent = match &parser.tree[id].body {
+            MarkdownNode::DefinitionDetails(details) => details.marker_offset + details.padding,
+            _ => return BlockProcessing::Unprocessed,
+        };
+        if line.is_blank_to_end() {
+            if parser.tree.get_first_child(id).is_none() {
+                return BlockProcessing::Unprocessed;
+            }
+            line.advance_next_nonspace();
+            return BlockProcessing::Further;
+        }
+        if line.indent_spaces() >= required_indent {
+            line.skip_spaces(required_indent);
+            line.re_find_indent();
+            return BlockProcessing::Further;
+        }
+        BlockProcessing::Unprocessed
+    }
+}
+
+impl BlockStrategy for definition_list::DefinitionList {
+    fn before(_ctx: BeforeCtx) -> BlockMatching {
+        BlockMatching::Unmatched
+    }
+
+    fn process(ProcessCtx { line, parser, id }: ProcessCtx) -> BlockProcessing {
+        if line.is_blank_to_end() || line.is_indented() {
+            return BlockProcessing::Further;
+        }
+        // 满足缩进要求的行交给仍打开的 details 作为续行
+        if let Some(last) = parser
+            .tree
+            .get_last_child(id)
+            .filter(|&last| parser.tree[last].processing)
+            && let MarkdownNode::DefinitionDetails(details) = &parser.tree[last].body
+            && line.indent_spaces() >= details.marker_offset + details.padding
+        {
+            return BlockProcessing::Further;
+        }
+        let rest = &line.source_slice()[line.cursor()..line.end()];
+        if is_marker_bytes(rest) {
+            return BlockProcessing::Further;
+        }
+        if !next_line_is_marker(line) {
+            return BlockProcessing::Unprocessed;
+        }
+        // 新的 term：先关闭上一个 details 及其内部仍打开的块
+        while parser.curr_proc_node != id {
+            let node_id = parser.curr_proc_node;
+            parser.finalize(node_id, parser.last_offset);
+        }
+        line.advance_next_nonspace();
+        let term = parser.append_block(MarkdownNode::DefinitionTerm, line.cursor_or_end() as u32);
+        let end = line.end() as u32;
+        parser.append_inline(term, line.slice(0, line.len()));
+        parser.finalize(term, end);
+        line.skip_to_end();
+        BlockProcessing::Processed
+    }
+
+    fn after(id: usize, parser: &mut Parser) {
+        let source = parser.scanner.source();
+        let separated = |prev: usize, next: usize| -> bool {
+            let prev_end = subtree_end_offset(parser, prev) as usize;
+            let next_start = parser.tree[next].span.start as usize;
+            prev_end < next_start
+                && memchr::memchr_iter(b'\n', &source[prev_end..next_start]).count() > 1
+        };
+        // 空行出现在 details 之前或 details 内部的块之间时为松散列表；
+        // 空行分隔的下一组 term 不影响松紧
+        let mut tight = true;
+        let mut child = parser.tree.get_first_child(id);
+        while let Some(curr) = child.filter(|_| tight) {
+            let next = parser.tree.get_next(curr);
+            if let Some(next) = next
+                && matches!(parser.tree[next].body, MarkdownNode::DefinitionDetails(..))
+                && separated(curr, next)
+            {
+                tight = false;
+            }
+            let mut inner = parser.tree.get_first_child(curr);
+            while let Some(inner_curr) = inner.filter(|_| tight) {
+                inner = parser.tree.get_next(inner_curr);
+                if let Some(inner_next) = inner
+                    && separated(inner_curr, inner_next)
+                {
+                    tight = false;
+                }
+            }
+            child = next;
+        }
+        if let MarkdownNode::DefinitionList(list) = &mut parser.tree[id].body {
+            list.tight = tight;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_marker_bytes, next_line_is_marker};
+    use crate::scanner::Scanner;
+    use crate::span::Span;
+
+    #[test]
+    fn marker_requires_space_and_content() {
+        assert!(is_marker_bytes(b": definition"));
+        assert!(is_marker_bytes(b"   :\tdefinition"));
+        assert!(!is_marker_bytes(b":definition"));
+        assert!(!is_marker_bytes(b":   "));
+        assert!(!is_marker_bytes(b"    : indented"));
+        assert!(!is_marker_bytes(b":-- | --:"));
+    }
+
+    #[test]
+    fn lookahead_strips_container_prefix() {
+        let source = "> Term\n> : definition\nTerm\r\n: definition";
+        let mut scanner = Scanner::new(source);
+        let mut first = Span::extract(&mut scanner).unwrap();
+        first.skip(2);
+        assert!(next_line_is_marker(&first));
+        let second = Span::extract(&mut scanner).unwrap();
+        assert!(!next_line_is_marker(&second));
+        let third = Span::extract(&mut scanner).unwrap();
+        assert!(next_line_is_marker(&third));
+    }
+}
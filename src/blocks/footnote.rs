@@ -32,6 +32,10 @@ impl BlockStrategy for footnote::Footnote {
                 .slice(line.indent_len() + 2, label_end)
                 .as_str()
                 .to_string();
+            let marker = crate::ast::text::SourceSpan::new(
+                (line.cursor() + line.indent_len()) as u32,
+                (line.cursor() + label_end + 2) as u32,
+            );
             line.skip(label_end + 2);
             parser.close_unmatched_blocks();
             let idx = parser.append_block(
@@ -41,6 +45,10 @@ impl BlockStrategy for footnote::Footnote {
                 })),
                 location,
             );
+            parser
+                .link_records
+                .footnote_definitions
+                .push((label.clone(), marker));
             parser.footnotes.entry(label).or_insert(idx);
             return BlockMatching::MatchedContainer;
         }
@@ -1,3 +1,4 @@
+use crate::ast::syntax::NodeSyntax;
 use crate::ast::{MarkdownNode, heading};
 use crate::blocks::{BeforeCtx, BlockMatching, BlockProcessing, BlockStrategy, ProcessCtx};
 use crate::span::Span;
@@ -114,6 +115,10 @@ impl BlockStrategy for heading::ATXHeading {
                 location,
             );
             parser.append_inline(idx, line.slice(start, end));
+            let closing = (end..line.len())
+                .filter(|&i| line.get(i) == Some(b'#'))
+                .count();
+            parser.record_syntax(idx, NodeSyntax::AtxHeading { closing });
             line.skip_to_end();
             BlockMatching::MatchedLeaf
         } else {
@@ -1,3 +1,4 @@
+use crate::ast::syntax::NodeSyntax;
 use crate::ast::{MarkdownNode, list};
 use crate::blocks::{BeforeCtx, BlockMatching, BlockProcessing, BlockStrategy, ProcessCtx};
 use crate::parser::Parser;
@@ -20,6 +21,7 @@ impl BlockStrategy for list::ListItem {
         // Record the marker offset BEFORE skipping indent
         let marker_offset = line.indent_spaces();
         line.skip_indent();
+        let marker_start = line.cursor();
 
         let first_byte = match line.next_byte() {
             Some(b) => b,
@@ -83,6 +85,7 @@ impl BlockStrategy for list::ListItem {
             }
             _ => return BlockMatching::Unmatched,
         };
+        let marker_end = line.cursor();
         // Count spaces after marker
         let spaces_after_marker = {
             let mut count = 0usize;
@@ -316,7 +319,16 @@ impl BlockStrategy for list::ListItem {
                 parser.tree.set_parent(new_list_idx, last_item_idx);
             }
         }
-        parser.append_block(MarkdownNode::ListItem(Box::new(cur_item)), location);
+        let item = parser.append_block(MarkdownNode::ListItem(Box::new(cur_item)), location);
+        if parser.options.source_fidelity {
+            let marker = &line.source_slice()[marker_start..marker_end];
+            parser.record_syntax(
+                item,
+                NodeSyntax::ListItem {
+                    marker: String::from_utf8_lossy(marker).into_owned(),
+                },
+            );
+        }
         BlockMatching::MatchedContainer
     }
 
@@ -420,13 +432,16 @@ impl BlockStrategy for list::List {
     }
 }
 
-fn subtree_end_offset(parser: &Parser<'_>, idx: usize) -> u32 {
+pub(super) fn subtree_end_offset(parser: &Parser<'_>, idx: usize) -> u32 {
     // For container nodes (List, ListItem), don't use their own end
     // because it's set during finalization and may not reflect actual content end.
     // Instead, only use end from leaf nodes or recurse into children.
     let own_end = if matches!(
         parser.tree[idx].body,
-        MarkdownNode::List(..) | MarkdownNode::ListItem(..)
+        MarkdownNode::List(..)
+            | MarkdownNode::ListItem(..)
+            | MarkdownNode::DefinitionList(..)
+            | MarkdownNode::DefinitionDetails(..)
     ) {
         parser.tree[idx].span.start
     } else {
@@ -0,0 +1,499 @@
+//! Lossless concrete syntax tree.
+//!
+//! The AST keeps the meaning of a document and the byte span of each node, but
+//! not the bytes between them: block quote prefixes, list markers,
+//! indentation, blank lines, fence lines, and delimiter runs. [`Document::cst`]
+//! recovers them from the gaps between node spans, so that every byte of the
+//! source belongs to exactly one [`CstToken`]:
+//!
+//! ```
+//! use ptdgrp_markdown::{Parser, TokenKind};
+//!
+//! let source = "> - **bold**\n>\n> ```\n> code\n> ```\n";
+//! let document = Parser::new(source).parse()?;
+//! let cst = document.cst();
+//!
+//! let text = cst.tokens().map(|token| &source[token.span.start as usize..token.span.end as usize]);
+//! assert_eq!(text.collect::<String>(), source);
+//!
+//! let kinds = cst.tokens().map(|token| token.kind).take(6).collect::<Vec<_>>();
+//! assert_eq!(
+//!     kinds,
+//!     [
+//!         TokenKind::BlockQuoteMarker,
+//!         TokenKind::Indent,
+//!         TokenKind::ListMarker,
+//!         TokenKind::Whitespace,
+//!         TokenKind::DelimiterRun,
+//!         TokenKind::Text,
+//!     ]
+//! );
+//! # Ok::<(), ptdgrp_markdown::ParseError>(())
+//! ```
+
+use serde::Serialize;
+
+use crate::ast::MarkdownNode;
+use crate::ast::code::Code;
+use crate::ast::frontmatter::FrontMatterFormat;
+use crate::ast::math::Math;
+use crate::ast::text::SourceSpan;
+use crate::document::Document;
+
+/// The category of a [`CstToken`].
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenKind {
+    /// Content of a leaf node: text, code, math, HTML, or frontmatter.
+    Text,
+    /// Spaces and tabs inside a line.
+    Whitespace,
+    /// Spaces and tabs in the prefix of a line, before any content.
+    Indent,
+    /// `\n`, `\r\n`, or `\r`.
+    LineEnding,
+    /// A line holding only whitespace, with its line ending.
+    BlankLine,
+    /// A `>` prefix of a block quote or callout.
+    BlockQuoteMarker,
+    /// A bullet or ordered list marker, such as `-` or `1.`.
+    ListMarker,
+    /// A task list checkbox, such as `[ ]` or `[x]`.
+    TaskMarker,
+    /// An ATX `#` sequence or a setext underline.
+    HeadingMarker,
+    /// The opening or closing line of fenced code, block math, or frontmatter.
+    Fence,
+    /// Emphasis-like delimiters, and the backticks or dollar signs around
+    /// inline code and math.
+    DelimiterRun,
+    /// Any other markup, such as link brackets and destinations, table pipes,
+    /// thematic breaks, or consumed definitions.
+    Syntax,
+}
+
+/// A leaf of the concrete syntax tree.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CstToken {
+    /// Token category.
+    pub kind: TokenKind,
+    /// Non-empty source range of the token.
+    pub span: SourceSpan,
+}
+
+/// A child of a [`CstNode`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum CstElement {
+    /// A nested node.
+    Node(CstNode),
+    /// A token.
+    Token(CstToken),
+}
+
+/// An AST node together with all the source bytes it owns.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CstNode {
+    /// ID of the AST node in [`Document::tree`].
+    pub node_id: usize,
+    /// Source range covered by `children`. Unlike the AST span, it includes
+    /// the container prefixes and markers of the node.
+    pub span: SourceSpan,
+    /// Nodes and tokens in source order, without gaps.
+    pub children: Vec<CstElement>,
+}
+
+impl CstNode {
+    /// Returns the tokens under this node in source order.
+    ///
+    /// The token spans are contiguous and together cover [`CstNode::span`].
+    pub fn tokens(&self) -> impl Iterator<Item = &CstToken> {
+        let mut stack = vec![self.children.iter()];
+        std::iter::from_fn(move || {
+            loop {
+                let element = stack.last_mut()?.next();
+                match element {
+                    Some(CstElement::Token(token)) => return Some(token),
+                    Some(CstElement::Node(node)) => stack.push(node.children.iter()),
+                    None => {
+                        stack.pop();
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl Document<'_> {
+    /// Builds a lossless concrete syntax tree of this document.
+    ///
+    /// The root covers the whole source. Every AST node that owns source
+    /// bytes gets a [`CstNode`]; footnote definitions stay where they were
+    /// written instead of moving to the generated footnote list, and nodes
+    /// without source, such as footnote back-references, are left out.
+    /// Link reference definitions become [`TokenKind::Syntax`] unless kept as
+    /// nodes with [`crate::ParserOptions::enabled_link_reference_definitions`].
+    /// Expanded transclusions are a single token, since their content comes
+    /// from another note.
+    pub fn cst(&self) -> CstNode {
+        let source = self.source().as_bytes();
+        let mut builder = Builder {
+            document: self,
+            source,
+            hulls: vec![None; self.tree.node_slots_len()],
+        };
+        builder.hull(0);
+        builder.node(0, 0, source.len() as u32, 0)
+    }
+}
+
+struct Builder<'a, 'source> {
+    document: &'a Document<'source>,
+    source: &'a [u8],
+    /// 节点及其后代覆盖的区间；不占源码的节点为 `None`
+    hulls: Vec<Option<(u32, u32)>>,
+}
+
+impl Builder<'_, '_> {
+    /// 子节点按源码位置展开：生成的脚注列表透明，其中的定义回到原位置
+    fn children(&self, id: usize) -> Vec<usize> {
+        let tree = &self.document.tree;
+        let mut children = Vec::new();
+        let mut child = tree.get_first_child(id);
+        while let Some(it) = child {
+            if matches!(tree[it].body, MarkdownNode::FootnoteList) {
+                children.extend(self.children(it));
+            } else {
+                children.push(it);
+            }
+            child = tree.get_next(it);
+        }
+        children
+    }
+
+    /// 后序计算各节点的覆盖区间：HTML 块等节点的子节点可能超出其自身区间
+    fn hull(&mut self, id: usize) -> Option<(u32, u32)> {
+        let node = &self.document.tree[id];
+        let mut hull = (!node.span.is_empty()).then_some((node.span.start, node.span.end));
+        if !matches!(node.body, MarkdownNode::Transclusion(..)) {
+            for child in self.children(id) {
+                if let Some((start, end)) = self.hull(child) {
+                    hull = Some(hull.map_or((start, end), |(s, e)| (s.min(start), e.max(end))));
+                }
+            }
+        }
+        self.hulls[id] = hull;
+        hull
+    }
+
+    /// 构建覆盖 `[start, end)` 的节点；子节点按起点排序并裁剪到未覆盖的部分，
+    /// 其余字节作为 token，保证逐字节无损
+    fn node(&self, id: usize, start: u32, end: u32, quotes: usize) -> CstNode {
+        let body = &self.document.tree[id].body;
+        let quotes =
+            quotes + matches!(body, MarkdownNode::BlockQuote | MarkdownNode::Callout(..)) as usize;
+        let mut lexer = Lexer {
+            source: self.source,
+            tokens: Vec::new(),
+            quotes,
+        };
+        let mut elements = Vec::new();
+        let children = if matches!(body, MarkdownNode::Transclusion(..)) {
+            Vec::new()
+        } else {
+            let mut children = self
+                .children(id)
+                .into_iter()
+                .filter_map(|child| Some((child, self.hulls[child]?)))
+                .collect::<Vec<_>>();
+            children.sort_by_key(|&(_, (start, _))| start);
+            children
+        };
+        let mut cursor = start;
+        if children.is_empty() {
+            lexer.leaf(body, start, end);
+        } else {
+            for (child, (child_start, child_end)) in children {
+                let child_start = child_start.max(cursor);
+                let child_end = child_end.min(end);
+                if child_start >= child_end {
+                    continue;
+                }
+                lexer.gap(body, cursor, child_start, cursor == start);
+                elements.extend(lexer.tokens.drain(..).map(CstElement::Token));
+                elements.push(CstElement::Node(self.node(
+                    child,
+                    child_start,
+                    child_end,
+                    quotes,
+                )));
+                cursor = child_end;
+            }
+            lexer.gap(body, cursor, end, cursor == start);
+        }
+        elements.extend(lexer.tokens.into_iter().map(CstElement::Token));
+        CstNode {
+            node_id: id,
+            span: SourceSpan::new(start, end),
+            children: elements,
+        }
+    }
+}
+
+struct Lexer<'a> {
+    source: &'a [u8],
+    tokens: Vec<CstToken>,
+    /// 祖先中的块引用与 callout 层数；为 0 时 `>` 不是前缀
+    quotes: usize,
+}
+
+impl Lexer<'_> {
+    fn push(&mut self, kind: TokenKind, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+        // 相邻的同类空白合并为一个 token
+        if let Some(last) = self.tokens.last_mut()
+            && last.kind == kind
+            && last.span.end as usize == start
+            && matches!(kind, TokenKind::Whitespace | TokenKind::Indent)
+        {
+            last.span.end = end as u32;
+            return;
+        }
+        self.tokens.push(CstToken {
+            kind,
+            span: SourceSpan::new(start as u32, end as u32),
+        });
+    }
+
+    fn line_ending_len(&self, at: usize, end: usize) -> usize {
+        match self.source[at] {
+            b'\r' if at + 1 < end && self.source[at + 1] == b'\n' => 2,
+            b'\n' | b'\r' => 1,
+            _ => 0,
+        }
+    }
+
+    fn at_line_start(&self, at: usize) -> bool {
+        at == 0 || matches!(self.source[at - 1], b'\n' | b'\r')
+    }
+
+    /// 节点之间的字节：只含容器前缀、标记与空白
+    fn gap(&mut self, parent: &MarkdownNode, start: u32, end: u32, leading: bool) {
+        let (start, end) = (start as usize, end as usize);
+        let source = self.source;
+        let mut prefix = self.at_line_start(start)
+            || source[..start]
+                .iter()
+                .rev()
+                .take_while(|&&b| !matches!(b, b'\n' | b'\r'))
+                .all(|&b| matches!(b, b' ' | b'\t' | b'>'));
+        // 列表项的首个标记，其后可跟任务框
+        let mut list_marker = leading && matches!(parent, MarkdownNode::ListItem(..));
+        let mut task_marker = false;
+        let mut at = start;
+        while at < end {
+            if self.at_line_start(at) {
+                let blank = source[at..end]
+                    .iter()
+                    .position(|&b| !matches!(b, b' ' | b'\t'))
+                    .map(|offset| at + offset);
+                if let Some(ending) = blank.filter(|&it| matches!(source[it], b'\n' | b'\r')) {
+                    let next = ending + self.line_ending_len(ending, end);
+                    self.push(TokenKind::BlankLine, at, next);
+                    at = next;
+                    continue;
+                }
+            }
+            let line_ending = self.line_ending_len(at, end);
+            if line_ending > 0 {
+                self.push(TokenKind::LineEnding, at, at + line_ending);
+                at += line_ending;
+                prefix = true;
+                continue;
+            }
+            let byte = source[at];
+            if matches!(byte, b' ' | b'\t') {
+                let next = self.scan(at, end, |b| matches!(b, b' ' | b'\t'));
+                let kind = if prefix {
+                    TokenKind::Indent
+                } else {
+                    TokenKind::Whitespace
+                };
+                self.push(kind, at, next);
+                at = next;
+                continue;
+            }
+            if prefix && byte == b'>' && self.quotes > 0 {
+                self.push(TokenKind::BlockQuoteMarker, at, at + 1);
+                at += 1;
+                continue;
+            }
+            prefix = false;
+            if task_marker
+                && byte == b'['
+                && at + 3 <= end
+                && source[at + 2] == b']'
+                && matches!(source[at + 1], b' ' | b'x' | b'X')
+            {
+                task_marker = false;
+                self.push(TokenKind::TaskMarker, at, at + 3);
+                at += 3;
+                continue;
+            }
+            let next = self.scan(at, end, |b| !matches!(b, b' ' | b'\t' | b'\n' | b'\r'));
+            let kind = match parent {
+                MarkdownNode::ListItem(..) if list_marker => {
+                    list_marker = false;
+                    task_marker = true;
+                    TokenKind::ListMarker
+                }
+                MarkdownNode::Heading(..)
+                    if source[at..next]
+                        .iter()
+                        .all(|&b| matches!(b, b'#' | b'=' | b'-')) =>
+                {
+                    TokenKind::HeadingMarker
+                }
+                MarkdownNode::Emphasis
+                | MarkdownNode::Strong
+                | MarkdownNode::Strikethrough
+                | MarkdownNode::Highlighting
+                | MarkdownNode::Superscript
+                | MarkdownNode::Subscript
+                | MarkdownNode::Insert
+                | MarkdownNode::Spoiler => TokenKind::DelimiterRun,
+                MarkdownNode::Code(code) => match code.as_ref() {
+                    Code::Inline(..) => TokenKind::DelimiterRun,
+                    Code::Fenced(..) => TokenKind::Fence,
+                    Code::Indented(..) => TokenKind::Syntax,
+                },
+                MarkdownNode::Math(math) => match math.as_ref() {
+                    Math::Inline(..) => TokenKind::DelimiterRun,
+                    Math::Block(..) => TokenKind::Fence,
+                },
+                _ => TokenKind::Syntax,
+            };
+            self.push(kind, at, next);
+            at = next;
+        }
+    }
+
+    fn scan(&self, start: usize, end: usize, pred: impl Fn(u8) -> bool) -> usize {
+        self.source[start..end]
+            .iter()
+            .position(|&b| !pred(b))
+            .map_or(end, |offset| start + offset)
+    }
+
+    /// 无子节点的节点：按行切分，续行先剥离到节点起始列的容器前缀
+    fn leaf(&mut self, body: &MarkdownNode, start: u32, end: u32) {
+        match body {
+            MarkdownNode::SoftBreak
+            | MarkdownNode::HardBreak
+            | MarkdownNode::ThematicBreak
+            | MarkdownNode::LinkReferenceDefinition(..)
+            | MarkdownNode::Link(..)
+            | MarkdownNode::Image(..)
+            | MarkdownNode::Embed(..) => return self.gap(body, start, end, true),
+            _ => {}
+        }
+        let (start, end) = (start as usize, end as usize);
+        let fenced = match body {
+            MarkdownNode::Code(code) => matches!(code.as_ref(), Code::Fenced(..)),
+            MarkdownNode::Math(math) => matches!(math.as_ref(), Math::Block(..)),
+            MarkdownNode::FrontMatter(frontmatter) => {
+                frontmatter.format != FrontMatterFormat::Json
+                    || self.source[start..end].starts_with(b";;;")
+            }
+            _ => false,
+        };
+        let column = self.column(start);
+        let mut lines = Vec::new();
+        let mut at = start;
+        while at < end {
+            let content_start = if at == start {
+                at
+            } else {
+                self.strip_prefix(at, end, column)
+            };
+            let content_end = self.scan(content_start, end, |b| !matches!(b, b'\n' | b'\r'));
+            lines.push((content_start, content_end));
+            at = content_end
+                + if content_end < end {
+                    self.line_ending_len(content_end, end)
+                } else {
+                    0
+                };
+            self.push(TokenKind::LineEnding, content_end, at);
+        }
+        // 先按行记录内容再按位置插回，保证 token 有序
+        let mut contents = Vec::new();
+        for (index, &(line_start, line_end)) in lines.iter().enumerate() {
+            let last = index + 1 == lines.len();
+            if fenced
+                && (index == 0 || (last && self.is_closing_fence(lines[0].0, line_start, line_end)))
+            {
+                contents.push((TokenKind::Fence, line_start, line_end));
+            } else {
+                contents.push((TokenKind::Text, line_start, line_end));
+            }
+        }
+        for (kind, start, end) in contents {
+            self.push(kind, start, end);
+        }
+        self.tokens.sort_by_key(|token| token.span.start);
+    }
+
+    /// 剥离续行的容器前缀（缩进与 `>`），至多到节点起始列
+    fn strip_prefix(&mut self, start: usize, end: usize, column: usize) -> usize {
+        let mut at = start;
+        let mut width = 0;
+        while at < end && width < column {
+            match self.source[at] {
+                b' ' => {
+                    self.push(TokenKind::Indent, at, at + 1);
+                    width += 1;
+                }
+                b'\t' => {
+                    self.push(TokenKind::Indent, at, at + 1);
+                    width += 4 - width % 4;
+                }
+                b'>' if self.quotes > 0 => {
+                    self.push(TokenKind::BlockQuoteMarker, at, at + 1);
+                    width += 1;
+                }
+                _ => break,
+            }
+            at += 1;
+        }
+        at
+    }
+
+    /// `at` 所在行从行首到 `at` 的列数，制表符按 4 列对齐
+    fn column(&self, at: usize) -> usize {
+        let line_start = self.source[..at]
+            .iter()
+            .rposition(|&b| matches!(b, b'\n' | b'\r'))
+            .map_or(0, |it| it + 1);
+        self.source[line_start..at]
+            .iter()
+            .filter(|&&b| b & 0xC0 != 0x80)
+            .fold(0, |width, &b| {
+                if b == b'\t' {
+                    width + 4 - width % 4
+                } else {
+                    width + 1
+                }
+            })
+    }
+
+    /// 末行是否为与开栏同字符、且不短于开栏的闭合栏
+    fn is_closing_fence(&self, open: usize, start: usize, end: usize) -> bool {
+        let marker = self.source[open];
+        let open_len = self.scan(open, end.max(open), |b| b == marker) - open;
+        let line = self.source[start..end].trim_ascii();
+        !line.is_empty() && line.len() >= open_len && line.iter().all(|&b| b == marker)
+    }
+}
@@ -1,5 +1,9 @@
-use crate::{Tree, location::Location, node::Node};
-use rustc_hash::FxHashSet;
+use crate::{
+    Tree,
+    location::{ColumnEncoding, Location, NodeLocation},
+    node::Node,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::{
     fmt::{Debug, Formatter},
     ops::Deref,
@@ -53,9 +57,18 @@ pub struct Document<'source> {
     pub(crate) source: SourceText<'source>,
     /// Arena-backed Markdown syntax tree.
     pub tree: Tree<Node>,
-    /// Tags discovered while parsing. Iteration order is unspecified.
+    /// Lowercased tags discovered while parsing. Iteration order is unspecified.
+    ///
+    /// With [`crate::ParserOptions::enabled_ofm`], this includes the frontmatter
+    /// `tags` property as well as inline `#tag`s.
     pub tags: FxHashSet<String>,
+    pub(crate) abbreviations: Vec<crate::ast::abbreviation::AbbreviationDefinition>,
+    pub(crate) frontmatter_diagnostics: Vec<crate::ast::frontmatter::FrontMatterDiagnostic>,
+    pub(crate) link_records: crate::link_check::LinkRecords,
+    pub(crate) syntax: FxHashMap<usize, crate::ast::syntax::NodeSyntax>,
     pub(crate) line_starts: std::sync::OnceLock<Vec<u32>>,
+    pub(crate) utf16_line_starts: std::sync::OnceLock<Vec<u32>>,
+    pub(crate) node_index: std::sync::OnceLock<crate::interval::IntervalIndex>,
 }
 impl<'source> Document<'source> {
     /// Returns the original Markdown source.
@@ -67,25 +80,358 @@ impl<'source> Document<'source> {
     ///
     /// Columns count Unicode scalar values, with a tab counting as one column.
     /// Offsets past the end are clamped. The line index is built lazily on the
-    /// first call.
+    /// first call. Use [`Document::location_at_with`] for UTF-16 or byte
+    /// columns.
     pub fn location_at(&self, offset: usize) -> Location {
+        self.location_at_with(offset, ColumnEncoding::Utf32)
+    }
+    /// Converts a byte offset into a one-based [`Location`] whose column is
+    /// counted in `encoding` units.
+    pub fn location_at_with(&self, offset: usize, encoding: ColumnEncoding) -> Location {
         let src = self.source.as_str();
-        let starts = self
-            .line_starts
-            .get_or_init(|| Location::build_line_starts(src));
+        let starts = self.line_starts();
         let offset = offset.min(src.len());
         let line_idx = starts
             .partition_point(|&s| (s as usize) <= offset)
             .saturating_sub(1);
         let line_start = starts.get(line_idx).copied().unwrap_or(0) as usize;
-        let column = 1 + crate::span::count_chars(src.as_bytes(), line_start, offset) as u64;
+        let column = 1 + encoding.width(src.as_bytes(), line_start, offset) as u64;
         Location::new(line_idx as u64 + 1, column)
     }
+    /// Converts many byte offsets at once; the result is in the order of
+    /// `offsets`.
+    ///
+    /// Offsets are visited in ascending order, so each line is measured at
+    /// most once instead of once per offset.
+    pub fn locations_at(&self, offsets: &[usize], encoding: ColumnEncoding) -> Vec<Location> {
+        let src = self.source.as_str();
+        let starts = self.line_starts();
+        let mut order = (0..offsets.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| offsets[i]);
+        let mut locations = vec![Location::default(); offsets.len()];
+        // 当前行下标、已量到的字节位置与对应列
+        let mut line_idx = 0;
+        let mut measured = 0;
+        let mut column = 1;
+        for i in order {
+            let offset = offsets[i].min(src.len());
+            while starts
+                .get(line_idx + 1)
+                .is_some_and(|&next| next as usize <= offset)
+            {
+                line_idx += 1;
+                measured = starts[line_idx] as usize;
+                column = 1;
+            }
+            column += encoding.width(src.as_bytes(), measured, offset) as u64;
+            measured = offset;
+            locations[i] = Location::new(line_idx as u64 + 1, column);
+        }
+        locations
+    }
+    /// Returns the start and end [`Location`] of every node, root first and
+    /// then in document order.
+    pub fn node_locations(&self, encoding: ColumnEncoding) -> Vec<NodeLocation> {
+        let ids = std::iter::once(0)
+            .chain(self.tree.descendants(0))
+            .collect::<Vec<_>>();
+        let offsets = ids
+            .iter()
+            .flat_map(|&id| {
+                let span = self.tree[id].span;
+                [span.start as usize, span.end as usize]
+            })
+            .collect::<Vec<_>>();
+        let locations = self.locations_at(&offsets, encoding);
+        ids.into_iter()
+            .zip(locations.chunks_exact(2))
+            .map(|(node_id, pair)| NodeLocation {
+                node_id,
+                start: pair[0],
+                end: pair[1],
+            })
+            .collect()
+    }
+    /// Converts a one-based [`Location`] back into a byte offset; the inverse
+    /// of [`Document::location_at`].
+    ///
+    /// A line past the end maps to the end of the source, and a column past
+    /// the end of its line maps to the line ending.
+    pub fn offset_at(&self, location: Location) -> usize {
+        self.offset_at_with(location, ColumnEncoding::Utf32)
+    }
+    /// Converts a one-based [`Location`] whose column is counted in `encoding`
+    /// units back into a byte offset.
+    ///
+    /// A column inside a character, such as between the two UTF-16 units of
+    /// an emoji, maps to the start of that character.
+    pub fn offset_at_with(&self, location: Location, encoding: ColumnEncoding) -> usize {
+        let line_idx = location.line.saturating_sub(1) as usize;
+        if line_idx >= self.line_starts().len() {
+            return self.source().len();
+        }
+        self.offset_in_line(
+            line_idx,
+            location.column.saturating_sub(1) as usize,
+            encoding,
+        )
+    }
+    /// Converts a byte offset into a UTF-16 offset from the start of the
+    /// source, as used by JavaScript strings and CodeMirror positions.
+    pub fn utf16_offset(&self, offset: usize) -> usize {
+        let offset = offset.min(self.source().len());
+        let starts = self.line_starts();
+        let line_idx = starts
+            .partition_point(|&s| (s as usize) <= offset)
+            .saturating_sub(1);
+        self.utf16_line_starts()[line_idx] as usize
+            + ColumnEncoding::Utf16.width(
+                self.source().as_bytes(),
+                starts[line_idx] as usize,
+                offset,
+            )
+    }
+    /// Converts a UTF-16 offset from the start of the source back into a byte
+    /// offset. An offset between the two units of a surrogate pair maps to
+    /// the start of the character.
+    pub fn offset_from_utf16(&self, utf16_offset: usize) -> usize {
+        let utf16_starts = self.utf16_line_starts();
+        let line_idx = utf16_starts
+            .partition_point(|&s| (s as usize) <= utf16_offset)
+            .saturating_sub(1);
+        self.offset_in_line(
+            line_idx,
+            utf16_offset - utf16_starts[line_idx] as usize,
+            ColumnEncoding::Utf16,
+        )
+    }
+    /// 行内第 `units` 个编码单元对应的字节偏移，超出行尾时停在换行符
+    fn offset_in_line(&self, line_idx: usize, units: usize, encoding: ColumnEncoding) -> usize {
+        let src = self.source.as_str();
+        let starts = self.line_starts();
+        let line_start = starts[line_idx] as usize;
+        let line_end = starts
+            .get(line_idx + 1)
+            .map_or(src.len(), |&next| next as usize - 1);
+        let mut width = 0;
+        for (offset, ch) in src[line_start..line_end].char_indices() {
+            width += encoding.char_width(ch);
+            if width > units {
+                return line_start + offset;
+            }
+        }
+        line_end
+    }
+    fn line_starts(&self) -> &[u32] {
+        self.line_starts
+            .get_or_init(|| Location::build_line_starts(self.source.as_str()))
+    }
+    /// 每行行首的 UTF-16 偏移
+    fn utf16_line_starts(&self) -> &[u32] {
+        self.utf16_line_starts.get_or_init(|| {
+            let src = self.source().as_bytes();
+            let starts = self.line_starts();
+            let mut utf16 = Vec::with_capacity(starts.len());
+            let mut total = 0;
+            utf16.push(0);
+            for pair in starts.windows(2) {
+                total += ColumnEncoding::Utf16.width(src, pair[0] as usize, pair[1] as usize);
+                utf16.push(total as u32);
+            }
+            utf16
+        })
+    }
+    /// Returns the deepest node whose span contains the byte `offset`.
+    ///
+    /// Spans are half-open, so a node does not contain its end offset. The
+    /// root covers the whole source and is returned when no other node
+    /// contains `offset`; `None` means `offset` is past the end. Queries use an
+    /// interval index built lazily on the first call, so mutate
+    /// [`Document::tree`] before querying, not after.
+    pub fn node_at(&self, offset: usize) -> Option<usize> {
+        self.containing(offset).last()
+    }
+    /// Returns the nodes whose spans overlap the byte range `start..end`, in
+    /// document order.
+    ///
+    /// An empty range selects the nodes containing `start`. See
+    /// [`Document::node_at`] for how spans are indexed.
+    pub fn nodes_in_range(&self, start: usize, end: usize) -> Vec<usize> {
+        if start >= self.source().len() {
+            return Vec::new();
+        }
+        self.node_index()
+            .overlapping(start as u32, end.min(self.source().len()) as u32)
+    }
+    /// Returns the deepest block-level node whose span contains the byte
+    /// `offset`, such as the paragraph, heading, or list item under a cursor.
+    ///
+    /// Falls back to the root like [`Document::node_at`].
+    pub fn enclosing_block(&self, offset: usize) -> Option<usize> {
+        self.containing(offset)
+            .filter(|&id| self.tree[id].body.is_block_level())
+            .last()
+    }
+    fn containing(&self, offset: usize) -> impl Iterator<Item = usize> + '_ {
+        let index = self.node_index();
+        let offset = (offset < self.source().len()).then_some(offset as u32);
+        offset
+            .into_iter()
+            .flat_map(|offset| index.containing(offset))
+    }
+    fn node_index(&self) -> &crate::interval::IntervalIndex {
+        self.node_index.get_or_init(|| {
+            crate::interval::IntervalIndex::build(&self.tree, self.source().len() as u32)
+        })
+    }
+    /// Returns the abbreviation definitions in document order.
+    ///
+    /// Definitions are only collected when
+    /// [`crate::ParserOptions::enabled_abbreviation`] is set. When a label is
+    /// defined more than once, occurrences use the first definition.
+    pub fn abbreviations(&self) -> &[crate::ast::abbreviation::AbbreviationDefinition] {
+        &self.abbreviations
+    }
+    /// Returns the link reference definitions, keyed by normalized label.
+    ///
+    /// Normalized labels are case-folded with internal whitespace collapsed,
+    /// so `[Foo  Bar]` is keyed as `foo bar`. When a label is defined more
+    /// than once, the first definition wins, as it does for the links that
+    /// use it. Definitions are collected regardless of
+    /// [`crate::ParserOptions::enabled_link_reference_definitions`].
+    pub fn link_definitions(
+        &self,
+    ) -> std::collections::BTreeMap<&str, &crate::ast::link::LinkReferenceDefinition> {
+        let mut definitions = std::collections::BTreeMap::new();
+        for (label, definition) in &self.link_records.definitions {
+            definitions.entry(label.as_str()).or_insert(definition);
+        }
+        definitions
+    }
+    /// Returns the definition that the reference link or image `node_id`
+    /// resolved to, such as `[text][label]`, `[label][]` or `[label]`.
+    ///
+    /// Returns `None` for inline links and for any other node.
+    pub fn resolved_link_definition(
+        &self,
+        node_id: usize,
+    ) -> Option<&crate::ast::link::LinkReferenceDefinition> {
+        let label = self.link_records.references.get(&node_id)?;
+        self.link_records
+            .definitions
+            .iter()
+            .find(|(key, _)| key == label)
+            .map(|(_, definition)| definition)
+    }
+    /// Returns how `node_id` was written, such as the emphasis marker or the
+    /// form of a link.
+    ///
+    /// Only recorded with [`crate::ParserOptions::enabled_source_fidelity`],
+    /// for links and images, emphasis, inline code, hard breaks, ATX headings,
+    /// and list items. Returns `None` for other nodes.
+    pub fn node_syntax(&self, node_id: usize) -> Option<&crate::ast::syntax::NodeSyntax> {
+        self.syntax.get(&node_id)
+    }
+    /// Returns the parsed frontmatter, if the document starts with one.
+    pub fn frontmatter(&self) -> Option<&crate::ast::frontmatter::FrontMatter> {
+        let first = self.tree.get_first_child(0)?;
+        match &self.tree[first].body {
+            crate::MarkdownNode::FrontMatter(frontmatter) => Some(frontmatter),
+            _ => None,
+        }
+    }
+    /// Returns the Obsidian `aliases` of this note, used as extra wikilink
+    /// targets. Empty when there is no frontmatter.
+    pub fn aliases(&self) -> Vec<String> {
+        self.frontmatter()
+            .map(|frontmatter| frontmatter.aliases())
+            .unwrap_or_default()
+    }
+    /// Returns the Obsidian `cssclasses` of this note. Empty when there is no
+    /// frontmatter.
+    pub fn cssclasses(&self) -> Vec<String> {
+        self.frontmatter()
+            .map(|frontmatter| frontmatter.cssclasses())
+            .unwrap_or_default()
+    }
+    /// Returns diagnostics for a fenced frontmatter block that failed to parse.
+    ///
+    /// When this is non-empty, [`Document::frontmatter`] returns `None` and the
+    /// block is parsed as ordinary Markdown.
+    pub fn frontmatter_diagnostics(&self) -> &[crate::ast::frontmatter::FrontMatterDiagnostic] {
+        &self.frontmatter_diagnostics
+    }
+    /// Validates the frontmatter against `schema`.
+    ///
+    /// A document without frontmatter reports every required key as missing,
+    /// with an empty span at the start of the document. Use
+    /// [`Document::location_at`] to turn error spans into line and column.
+    pub fn validate_frontmatter(
+        &self,
+        schema: &crate::ast::frontmatter::schema::FrontMatterSchema,
+    ) -> Vec<crate::ast::frontmatter::schema::FrontMatterValidationError> {
+        match self.frontmatter() {
+            Some(frontmatter) => schema.validate(frontmatter),
+            None => schema.validate_with(None, crate::ast::text::SourceSpan::new(0, 0)),
+        }
+    }
+    /// Starts editing the frontmatter. See [`crate::frontmatter::edit::FrontMatterEdit`].
+    ///
+    /// Fails when a fenced frontmatter block is present but could not be parsed.
+    pub fn edit_frontmatter(
+        &self,
+    ) -> Result<
+        crate::ast::frontmatter::edit::FrontMatterEdit<'_>,
+        crate::ast::frontmatter::edit::FrontMatterEditError,
+    > {
+        if !self.frontmatter_diagnostics.is_empty() {
+            return Err(crate::ast::frontmatter::edit::FrontMatterEditError::InvalidFrontMatter);
+        }
+        let block = self
+            .tree
+            .get_first_child(0)
+            .map_or(crate::ast::text::SourceSpan::new(0, 0), |first| {
+                self.tree[first].span
+            });
+        Ok(crate::ast::frontmatter::edit::FrontMatterEdit::new(
+            self.source(),
+            self.frontmatter(),
+            block,
+        ))
+    }
     /// Resolves a source-backed or owned text value to display text.
     #[inline]
     pub fn text<'doc>(&'doc self, text: &'doc crate::ast::text::TextRef) -> &'doc str {
         text.resolve(self.source.as_str())
     }
+    /// Returns the text of `node_id` and its descendants with formatting
+    /// dropped, trimmed. For `## **Setup** guide` this is `Setup guide`.
+    pub fn plain_text(&self, node_id: usize) -> String {
+        let mut out = String::new();
+        for id in self.tree.descendants(node_id) {
+            if let crate::MarkdownNode::Text(text) = &self.tree[id].body {
+                out.push_str(self.text(text));
+            }
+        }
+        out.trim().to_string()
+    }
+}
+impl Document<'_> {
+    /// 换下借用的源码，便于调用方以自有源码重新组装（`..document`）
+    pub(crate) fn detach_source(self) -> Document<'static> {
+        Document {
+            source: SourceText::default(),
+            tree: self.tree,
+            tags: self.tags,
+            abbreviations: self.abbreviations,
+            frontmatter_diagnostics: self.frontmatter_diagnostics,
+            link_records: self.link_records,
+            syntax: self.syntax,
+            line_starts: self.line_starts,
+            utf16_line_starts: self.utf16_line_starts,
+            node_index: self.node_index,
+        }
+    }
 }
 impl Deref for Document<'_> {
     type Target = Tree<Node>;
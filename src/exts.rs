@@ -1,2 +1,4 @@
 pub mod frontmatter;
+pub mod json;
+pub mod toml;
 pub mod yaml;
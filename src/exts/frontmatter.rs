@@ -1,4 +1,10 @@
-use crate::exts::yaml::{YamlMap, parse_yaml};
+use crate::Location;
+use crate::ast::frontmatter::{
+    FrontMatter, FrontMatterDiagnostic, FrontMatterFormat, FrontMatterSpan,
+};
+use crate::exts::json::{parse_json_object, parse_json_spanned};
+use crate::exts::toml::parse_toml_spanned;
+use crate::exts::yaml::{YamlError, YamlMap, parse_yaml_spanned, source_span};
 use crate::parser::Parser;
 
 /// 跳过 \r\n 或 \n 换行符
@@ -12,7 +18,7 @@ fn skip_newline(scanner: &mut crate::scanner::Scanner) {
     }
 }
 
-/// 检查是否是有效的 frontmatter 标记行（--- 或 +++）
+/// 检查是否是有效的 frontmatter 标记行（---、+++ 或 ;;;）
 #[inline]
 fn is_marker_line(scanner: &mut crate::scanner::Scanner, marker: u8) -> bool {
     // 快速路径：检查第一个字节
@@ -40,14 +46,127 @@ fn is_marker_line(scanner: &mut crate::scanner::Scanner, marker: u8) -> bool {
     is_valid
 }
 
-pub fn parse(parser: &mut Parser) -> Option<YamlMap> {
+type Parsed = Option<(YamlMap, Vec<FrontMatterSpan>)>;
+
+/// 按围栏标记解析 frontmatter 正文；`+++` 中不是合法 TOML 时回退为 YAML，
+/// 以兼容此前用 `+++` 包裹 YAML 的文档
+///
+/// 空文档返回 `Ok(None)`；语法错误返回格式与错误。`+++` 两种解析都失败时，
+/// 报告解析得更远的一方
+fn parse_fenced(
+    marker: u8,
+    text: &str,
+) -> Result<Option<FrontMatter>, (FrontMatterFormat, YamlError)> {
+    let with = |format: FrontMatterFormat, result: Result<Parsed, YamlError>| {
+        result
+            .map(|parsed| {
+                parsed.map(|(data, spans)| FrontMatter {
+                    format,
+                    data,
+                    spans,
+                })
+            })
+            .map_err(|error| (format, error))
+    };
+    match marker {
+        b'-' => with(FrontMatterFormat::Yaml, parse_yaml_spanned(text)),
+        b'+' => match with(FrontMatterFormat::Toml, parse_toml_spanned(text)) {
+            Ok(Some(frontmatter)) => Ok(Some(frontmatter)),
+            toml => match (
+                toml,
+                with(FrontMatterFormat::Yaml, parse_yaml_spanned(text)),
+            ) {
+                (Err(toml), Err(yaml)) if toml.1.offset > yaml.1.offset => Err(toml),
+                (_, yaml) => yaml,
+            },
+        },
+        _ => with(FrontMatterFormat::Json, parse_json_spanned(text)),
+    }
+}
+
+/// 将相对 frontmatter 正文的区间平移为文档偏移
+fn shift_spans(frontmatter: &mut FrontMatter, base: usize) {
+    let shift = |span: &mut crate::ast::text::SourceSpan| {
+        *span = source_span(span.start as usize + base, span.end as usize + base);
+    };
+    for span in &mut frontmatter.spans {
+        if let Some(key) = &mut span.key {
+            shift(key);
+        }
+        shift(&mut span.value);
+    }
+}
+
+/// 由文档偏移构造诊断：区间延伸到该行末尾，列按 Unicode 标量计数
+fn diagnostic(
+    source: &str,
+    format: FrontMatterFormat,
+    error: YamlError,
+    base: usize,
+) -> FrontMatterDiagnostic {
+    let offset = (base + error.offset).min(source.len());
+    let bytes = source.as_bytes();
+    let line_start = bytes[..offset]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+    let line_end = bytes[offset..]
+        .iter()
+        .position(|&b| b == b'\n' || b == b'\r')
+        .map_or(source.len(), |i| offset + i);
+    let line = 1 + bytes[..line_start].iter().filter(|&&b| b == b'\n').count();
+    let column = 1 + crate::span::count_chars(bytes, line_start, offset);
+    FrontMatterDiagnostic {
+        format,
+        message: error.reason,
+        span: source_span(offset, line_end),
+        location: Location::new(line as u64, column as u64),
+    }
+}
+
+/// Hugo 风格：文档以 JSON 对象开头，对象结束后本行只允许空白
+fn parse_leading_json(parser: &mut Parser) -> Option<FrontMatter> {
+    let start = parser.scanner.pos();
+    let rest = parser
+        .scanner
+        .slice(start, start + parser.scanner.remaining());
+    let (data, end, spans) = parse_json_object(rest).ok()?;
+    if data.is_empty() {
+        return None;
+    }
+    parser.scanner.set_pos(start + end);
+    parser.scanner.skip_spaces();
+    if !matches!(parser.scanner.peek(), None | Some(b'\n') | Some(b'\r')) {
+        return None;
+    }
+    skip_newline(&mut parser.scanner);
+    let mut frontmatter = FrontMatter {
+        format: FrontMatterFormat::Json,
+        data,
+        spans,
+    };
+    shift_spans(&mut frontmatter, start);
+    Some(frontmatter)
+}
+
+/// 解析文档开头的 frontmatter。围栏完整但正文有语法错误时，
+/// 将诊断写入 `parser.frontmatter_diagnostics` 并按普通 Markdown 处理
+pub fn parse(parser: &mut Parser) -> Option<FrontMatter> {
     let snapshot = parser.scanner.snapshot();
 
-    // 快速检查：第一个字节必须是 - 或 +
+    // 快速检查：第一个字节必须是 -、+、;，启用开头 JSON 时也可以是 {
     let first_byte = parser.scanner.peek()?;
     let marker = match first_byte {
         b'-' => b'-',
         b'+' => b'+',
+        b';' => b';',
+        b'{' if parser.options.leading_json_frontmatter => {
+            let frontmatter = parse_leading_json(parser);
+            if frontmatter.is_none() {
+                parser.scanner.resume(&snapshot);
+            }
+            return frontmatter;
+        }
         _ => return None,
     };
 
@@ -78,15 +197,28 @@ pub fn parse(parser: &mut Parser) -> Option<YamlMap> {
             parser.scanner.skip_spaces();
             skip_newline(&mut parser.scanner);
 
-            // 提取并解析 YAML。若不是有效 YAML frontmatter，回滚并按普通 Markdown 处理。
-            let yaml_text = parser.scanner.slice(content_start, content_end);
-            return match parse_yaml(yaml_text) {
-                Some(map) => Some(map),
-                None => {
-                    parser.scanner.resume(&snapshot);
+            // 提取并解析正文。若不是有效 frontmatter，回滚并按普通 Markdown 处理。
+            let text = parser.scanner.slice(content_start, content_end);
+            let frontmatter = match parse_fenced(marker, text) {
+                Ok(frontmatter) => frontmatter.map(|mut frontmatter| {
+                    shift_spans(&mut frontmatter, content_start);
+                    frontmatter
+                }),
+                Err((format, error)) => {
+                    let source = parser.scanner.source_str();
+                    parser.frontmatter_diagnostics.push(diagnostic(
+                        source,
+                        format,
+                        error,
+                        content_start,
+                    ));
                     None
                 }
             };
+            if frontmatter.is_none() {
+                parser.scanner.resume(&snapshot);
+            }
+            return frontmatter;
         }
 
         // 跳到下一行
@@ -108,7 +240,7 @@ pub fn parse(parser: &mut Parser) -> Option<YamlMap> {
 mod tests {
     use super::*;
     use crate::MarkdownNode;
-    use crate::exts::yaml::YamlValue;
+    use crate::ast::frontmatter::YamlValue;
     use crate::parser::ParserOptions;
 
     #[test]
@@ -131,16 +263,19 @@ Hello world        "#
         } else {
             panic!("Failed to get frontmatter node")
         };
-        assert_eq!(frontmatter.get("external"), Some(&YamlValue::Bool(false)));
-        assert_eq!(frontmatter.get("draft"), Some(&YamlValue::Bool(true)));
         assert_eq!(
-            frontmatter.get("description"),
+            frontmatter.data.get("external"),
+            Some(&YamlValue::Bool(false))
+        );
+        assert_eq!(frontmatter.data.get("draft"), Some(&YamlValue::Bool(true)));
+        assert_eq!(
+            frontmatter.data.get("description"),
             Some(&YamlValue::String(
                 "It's a beautiful world out there.".to_string()
             ))
         );
         assert_eq!(
-            frontmatter.get("date"),
+            frontmatter.data.get("date"),
             Some(&YamlValue::String("2022-11-05".to_string()))
         );
         let ast = parser.parse().unwrap();
@@ -183,11 +318,11 @@ Content"#,
             panic!("Failed to get frontmatter node")
         };
         assert_eq!(
-            frontmatter.get("title"),
+            frontmatter.data.get("title"),
             Some(&YamlValue::String("Test".to_string()))
         );
         assert_eq!(
-            frontmatter.get("note"),
+            frontmatter.data.get("note"),
             Some(&YamlValue::String("-- not a marker".to_string()))
         );
     }
@@ -207,11 +342,178 @@ Content"#,
             panic!("Failed to get frontmatter node")
         };
         assert_eq!(
-            frontmatter.get("title"),
+            frontmatter.data.get("title"),
             Some(&YamlValue::String("Test".to_string()))
         );
     }
 
+    fn frontmatter_of(input: &str) -> Option<FrontMatter> {
+        let options = ParserOptions::default().enabled_leading_json_frontmatter();
+        let mut parser = Parser::new_with_options(input, options);
+        parser.parse_frontmatter().unwrap();
+        match &parser.tree[parser.tree.get_first_child(0)?].body {
+            MarkdownNode::FrontMatter(frontmatter) => Some(frontmatter.as_ref().clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_plus_marker_yaml_fallback_format() {
+        let frontmatter = frontmatter_of("+++\ntitle: Test\n+++\nContent").unwrap();
+        assert_eq!(frontmatter.format, FrontMatterFormat::Yaml);
+    }
+
+    #[test]
+    fn test_toml_frontmatter() {
+        let input =
+            "+++\ntitle = \"Test\"\ntags = [\"a\", \"b\"]\n[params]\ntoc = true\n+++\nContent";
+        let frontmatter = frontmatter_of(input).unwrap();
+        assert_eq!(frontmatter.format, FrontMatterFormat::Toml);
+        assert_eq!(frontmatter.data.get_str("title"), Some("Test"));
+        assert_eq!(
+            frontmatter.data.get_path("params.toc"),
+            Some(&YamlValue::Bool(true))
+        );
+        let ast = Parser::new(input).parse().unwrap();
+        assert_eq!(ast.to_html(), "<p>Content</p>");
+    }
+
+    #[test]
+    fn test_json_frontmatter() {
+        let fenced = frontmatter_of(";;;\n{\"title\": \"Test\", \"n\": 1}\n;;;\nContent").unwrap();
+        assert_eq!(fenced.format, FrontMatterFormat::Json);
+        assert_eq!(fenced.data.get("n"), Some(&YamlValue::Integer(1)));
+
+        let input = "{\n  \"title\": \"Test\",\n  \"tags\": [\"a\"]\n}\n\nContent";
+        let leading = frontmatter_of(input).unwrap();
+        assert_eq!(leading.format, FrontMatterFormat::Json);
+        assert_eq!(
+            leading.data.get_path("tags.0"),
+            Some(&YamlValue::String("a".into()))
+        );
+        let options = ParserOptions::default().enabled_leading_json_frontmatter();
+        let ast = Parser::new_with_options(input, options).parse().unwrap();
+        assert_eq!(ast.to_html(), "<p>Content</p>");
+
+        // 默认不识别开头的 JSON 对象，文本原样保留
+        let ast = Parser::new("{\"a\": 1}\n\nHello").parse().unwrap();
+        assert!(ast.frontmatter().is_none());
+        assert_eq!(ast.to_html(), "<p>{&quot;a&quot;: 1}</p>\n<p>Hello</p>");
+    }
+
+    #[test]
+    fn test_leading_brace_that_is_not_json() {
+        for input in [
+            "{not json}\nContent",
+            "{\"a\": 1} trailing text",
+            "{}\nContent",
+        ] {
+            assert_eq!(frontmatter_of(input), None, "{input:?}");
+        }
+        let options = ParserOptions::default().enabled_leading_json_frontmatter();
+        let ast = Parser::new_with_options("{\"a\": 1} trailing", options)
+            .parse()
+            .unwrap();
+        assert_eq!(ast.to_html(), "<p>{&quot;a&quot;: 1} trailing</p>");
+    }
+
+    #[test]
+    fn test_entry_spans() {
+        let input = "---\ntitle: Hello # note\ntags: [a, \"b\"]\nauthor:\n  name: Ann\naliases:\n  - one\n---\n";
+        let document = Parser::new(input).parse().unwrap();
+        let frontmatter = document.frontmatter().unwrap();
+        let text = |path: &str| {
+            let span = frontmatter.span_of(path).unwrap();
+            (
+                span.key
+                    .map(|key| &input[key.start as usize..key.end as usize]),
+                &input[span.value.start as usize..span.value.end as usize],
+            )
+        };
+        assert_eq!(text("title"), (Some("title"), "Hello"));
+        assert_eq!(text("tags"), (Some("tags"), "[a, \"b\"]"));
+        assert_eq!(text("tags.1"), (None, "\"b\""));
+        assert_eq!(text("author.name"), (Some("name"), "Ann"));
+        assert_eq!(text("aliases.0"), (None, "one"));
+        assert_eq!(
+            frontmatter
+                .spans
+                .iter()
+                .map(|it| it.path.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "title",
+                "tags",
+                "tags.0",
+                "tags.1",
+                "author",
+                "author.name",
+                "aliases",
+                "aliases.0"
+            ]
+        );
+
+        let input = "+++\ntitle = \"Hi\"\n[params]\nlist = [1, 2]\n+++\n";
+        let frontmatter = frontmatter_of(input).unwrap();
+        let span = frontmatter.span_of("params.list.1").unwrap();
+        assert_eq!(
+            &input[span.value.start as usize..span.value.end as usize],
+            "2"
+        );
+        let span = frontmatter.span_of("title").unwrap();
+        assert_eq!(
+            &input[span.value.start as usize..span.value.end as usize],
+            "\"Hi\""
+        );
+
+        let input = "{\"a\": {\"b\": [true]}}\nbody";
+        let frontmatter = frontmatter_of(input).unwrap();
+        let span = frontmatter.span_of("a.b.0").unwrap();
+        assert_eq!(
+            &input[span.value.start as usize..span.value.end as usize],
+            "true"
+        );
+    }
+
+    #[test]
+    fn test_frontmatter_diagnostics() {
+        let diagnostics = |input: &str| {
+            let document = Parser::new(input).parse().unwrap();
+            assert!(document.frontmatter().is_none());
+            document.frontmatter_diagnostics().to_vec()
+        };
+        let found = diagnostics("---\ntitle: a\ntitle: b\n---\nbody");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].format, FrontMatterFormat::Yaml);
+        assert_eq!(found[0].message, "duplicate mapping key");
+        assert_eq!(found[0].location, crate::Location::new(3, 1));
+        assert_eq!(found[0].span, crate::ast::text::SourceSpan::new(13, 21));
+
+        let found = diagnostics("---\ntitle: \"标题\nnext: 1\n---\n");
+        assert_eq!(found[0].message, "unterminated quoted string");
+        assert_eq!(found[0].location, crate::Location::new(2, 8));
+
+        let found = diagnostics("---\na:\n    b: 1\n  c: 2\n---\n");
+        assert_eq!(found[0].location.line, 4);
+
+        let found = diagnostics("+++\ntitle = \"a\"\ntitle = \"b\"\n+++\n");
+        assert_eq!(found[0].format, FrontMatterFormat::Toml);
+        assert_eq!(found[0].message, "duplicate key");
+        assert_eq!(found[0].location, crate::Location::new(3, 1));
+
+        let found = diagnostics(";;;\n{\"a\": 1,}\n;;;\n");
+        assert_eq!(found[0].format, FrontMatterFormat::Json);
+        assert_eq!(found[0].location, crate::Location::new(2, 9));
+
+        // 未闭合的围栏、空文档与不是 JSON 的 `{` 都不产生诊断
+        for input in ["---\ntitle: a: b\n", "---\n---\n", "{not json}\n"] {
+            let document = Parser::new(input).parse().unwrap();
+            assert!(document.frontmatter_diagnostics().is_empty(), "{input:?}");
+        }
+        let document = Parser::new("---\ntitle: ok\n---\n").parse().unwrap();
+        assert!(document.frontmatter_diagnostics().is_empty());
+    }
+
     #[test]
     fn basic_usage() {}
 
@@ -0,0 +1,438 @@
+use crate::ast::frontmatter::FrontMatterSpan;
+use crate::exts::yaml::{YamlError, YamlMap, YamlValue, finish_spans, source_span};
+
+/// 解析 JSON frontmatter 文本；顶层必须是非空对象，否则返回 `None`
+///
+/// 数字在 `i64` 范围内且没有小数或指数部分时为 `Integer`，其余为 `Float`。
+#[cfg(test)]
+pub fn parse_json(input: &str) -> Option<YamlMap> {
+    parse_json_spanned(input).ok().flatten().map(|(map, _)| map)
+}
+
+/// 解析整段 JSON 并记录每个键与值的区间；空对象返回 `Ok(None)`
+pub(crate) fn parse_json_spanned(
+    input: &str,
+) -> Result<Option<(YamlMap, Vec<FrontMatterSpan>)>, YamlError> {
+    let (map, end, spans) = parse_json_object(input)?;
+    let trailing = end + (input[end..].len() - input[end..].trim_start().len());
+    if trailing < input.len() {
+        return Err(YamlError {
+            offset: trailing,
+            reason: "unexpected content after the JSON object".to_string(),
+        });
+    }
+    Ok((!map.is_empty()).then_some((map, spans)))
+}
+
+/// 从 `input` 开头解析一个 JSON 对象，返回对象、其结束的字节偏移与条目区间；
+/// 对象之后的内容不检查，用于文档开头以 `{` 起始的 frontmatter
+pub(crate) fn parse_json_object(
+    input: &str,
+) -> Result<(YamlMap, usize, Vec<FrontMatterSpan>), YamlError> {
+    let mut parser = JsonParser {
+        src: input,
+        bytes: input.as_bytes(),
+        pos: 0,
+        depth: 0,
+        path: Vec::new(),
+        spans: Vec::new(),
+    };
+    parser.skip_whitespace();
+    if parser.peek() != Some(b'{') {
+        return parser.error("expected a JSON object");
+    }
+    match parser.parse_object()? {
+        YamlValue::Map(map) => Ok((map, parser.pos, finish_spans(parser.spans))),
+        _ => unreachable!("parse_object always returns a map"),
+    }
+}
+
+/// 写出字符串字面量
+pub(crate) fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            ch if ch.is_control() => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
+/// 以单行形式写出值；JSON 无法表示 NaN 与无穷大，遇到时返回 `false`
+pub(crate) fn write_value(value: &YamlValue, out: &mut String) -> bool {
+    match value {
+        YamlValue::String(s) => write_string(s, out),
+        YamlValue::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        YamlValue::Integer(i) => out.push_str(&i.to_string()),
+        YamlValue::Float(f) if !f.is_finite() => return false,
+        YamlValue::Float(f) => out.push_str(&format!("{f:?}")),
+        YamlValue::Null => out.push_str("null"),
+        YamlValue::List(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                if !write_value(item, out) {
+                    return false;
+                }
+            }
+            out.push(']');
+        }
+        YamlValue::Map(map) => {
+            out.push('{');
+            for (i, (key, value)) in map.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_string(key, out);
+                out.push_str(": ");
+                if !write_value(value, out) {
+                    return false;
+                }
+            }
+            out.push('}');
+        }
+    }
+    true
+}
+
+type JsonResult<T> = Result<T, YamlError>;
+
+/// 数组与对象的嵌套层数上限
+const MAX_DEPTH: usize = 256;
+
+/// RFC 8259 JSON 解析器；重复键视为错误
+struct JsonParser<'a> {
+    src: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+    /// 当前条目的路径
+    path: Vec<String>,
+    spans: Vec<FrontMatterSpan>,
+}
+
+impl JsonParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn error<T>(&self, reason: &str) -> JsonResult<T> {
+        self.error_at(self.pos, reason)
+    }
+
+    fn error_at<T>(&self, offset: usize, reason: &str) -> JsonResult<T> {
+        Err(YamlError {
+            offset,
+            reason: reason.to_string(),
+        })
+    }
+
+    fn record(&mut self, key: Option<(usize, usize)>, value: (usize, usize)) {
+        self.spans.push(FrontMatterSpan {
+            path: self.path.join("."),
+            key: key.map(|(start, end)| source_span(start, end)),
+            value: source_span(value.0, value.1),
+        });
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn enter(&mut self) -> JsonResult<()> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return self.error("nesting depth limit exceeded");
+        }
+        Ok(())
+    }
+
+    fn parse_value(&mut self) -> JsonResult<YamlValue> {
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => self.parse_string().map(YamlValue::String),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(_) => {
+                for (literal, value) in [
+                    ("true", YamlValue::Bool(true)),
+                    ("false", YamlValue::Bool(false)),
+                    ("null", YamlValue::Null),
+                ] {
+                    if self.src[self.pos..].starts_with(literal) {
+                        self.pos += literal.len();
+                        return Ok(value);
+                    }
+                }
+                self.error("expected a JSON value")
+            }
+            None => self.error("unexpected end of JSON input"),
+        }
+    }
+
+    /// cursor 位于 `{`
+    fn parse_object(&mut self) -> JsonResult<YamlValue> {
+        self.enter()?;
+        let start = self.pos;
+        self.pos += 1;
+        let mut map = YamlMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            self.depth -= 1;
+            return Ok(YamlValue::Map(map));
+        }
+        loop {
+            self.skip_whitespace();
+            let key_offset = self.pos;
+            if self.peek() != Some(b'"') {
+                return self.error("expected a string key");
+            }
+            let key = self.parse_string()?;
+            let key_end = self.pos;
+            self.skip_whitespace();
+            if self.peek() != Some(b':') {
+                return self.error("expected ':' after an object key");
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            if map.contains_key(&key) {
+                return self.error_at(key_offset, "duplicate object key");
+            }
+            self.path.push(key.clone());
+            let value_start = self.pos;
+            let value = self.parse_value()?;
+            self.record(Some((key_offset, key_end)), (value_start, self.pos));
+            self.path.pop();
+            map.insert(key, value);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    self.depth -= 1;
+                    return Ok(YamlValue::Map(map));
+                }
+                None => return self.error_at(start, "unterminated object"),
+                _ => return self.error("expected ',' or '}'"),
+            }
+        }
+    }
+
+    /// cursor 位于 `[`
+    fn parse_array(&mut self) -> JsonResult<YamlValue> {
+        self.enter()?;
+        let start = self.pos;
+        self.pos += 1;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            self.depth -= 1;
+            return Ok(YamlValue::List(items));
+        }
+        loop {
+            self.skip_whitespace();
+            self.path.push(items.len().to_string());
+            let item_start = self.pos;
+            items.push(self.parse_value()?);
+            self.record(None, (item_start, self.pos));
+            self.path.pop();
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    self.depth -= 1;
+                    return Ok(YamlValue::List(items));
+                }
+                None => return self.error_at(start, "unterminated array"),
+                _ => return self.error("expected ',' or ']'"),
+            }
+        }
+    }
+
+    /// cursor 位于 `"`
+    fn parse_string(&mut self) -> JsonResult<String> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let run = self.pos;
+            while self
+                .peek()
+                .is_some_and(|b| b != b'"' && b != b'\\' && b >= 0x20)
+            {
+                self.pos += 1;
+            }
+            out.push_str(&self.src[run..self.pos]);
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                Some(b'\\') => self.parse_escape(&mut out)?,
+                Some(_) => return self.error("control characters must be escaped"),
+                None => return self.error_at(start, "unterminated string"),
+            }
+        }
+    }
+
+    /// cursor 位于 `\\`
+    fn parse_escape(&mut self, out: &mut String) -> JsonResult<()> {
+        let escape = self.pos;
+        self.pos += 1;
+        let ch = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{08}',
+            Some(b'f') => '\u{0C}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.pos += 1;
+                let high = self.parse_hex4(escape)?;
+                let code = if (0xD800..0xDC00).contains(&high) {
+                    // UTF-16 代理对
+                    if !self.src[self.pos..].starts_with("\\u") {
+                        return self.error_at(escape, "unpaired surrogate in escape sequence");
+                    }
+                    self.pos += 2;
+                    let low = self.parse_hex4(escape)?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return self.error_at(escape, "unpaired surrogate in escape sequence");
+                    }
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                } else {
+                    high
+                };
+                let Some(ch) = char::from_u32(code) else {
+                    return self.error_at(escape, "unpaired surrogate in escape sequence");
+                };
+                out.push(ch);
+                return Ok(());
+            }
+            _ => return self.error_at(escape, "invalid escape sequence"),
+        };
+        self.pos += 1;
+        out.push(ch);
+        Ok(())
+    }
+
+    fn parse_hex4(&mut self, escape: usize) -> JsonResult<u32> {
+        let code = self
+            .src
+            .get(self.pos..self.pos + 4)
+            .filter(|hex| hex.bytes().all(|it| it.is_ascii_hexdigit()))
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+        let Some(code) = code else {
+            return self.error_at(escape, "invalid escape sequence");
+        };
+        self.pos += 4;
+        Ok(code)
+    }
+
+    /// `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][-+]?[0-9]+)?`
+    fn parse_number(&mut self) -> JsonResult<YamlValue> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let digits = |parser: &mut Self| {
+            let from = parser.pos;
+            while parser.peek().is_some_and(|b| b.is_ascii_digit()) {
+                parser.pos += 1;
+            }
+            parser.pos - from
+        };
+        let integer_start = self.pos;
+        let integer = digits(self);
+        if integer == 0 || (integer > 1 && self.bytes[integer_start] == b'0') {
+            return self.error_at(start, "invalid number");
+        }
+        let mut is_float = false;
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            is_float = true;
+            if digits(self) == 0 {
+                return self.error_at(start, "invalid number");
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.pos += 1;
+            is_float = true;
+            if matches!(self.peek(), Some(b'-' | b'+')) {
+                self.pos += 1;
+            }
+            if digits(self) == 0 {
+                return self.error_at(start, "invalid number");
+            }
+        }
+        let text = &self.src[start..self.pos];
+        if !is_float && let Ok(value) = text.parse() {
+            return Ok(YamlValue::Integer(value));
+        }
+        match text.parse() {
+            Ok(value) => Ok(YamlValue::Float(value)),
+            Err(_) => self.error_at(start, "invalid number"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_values_in_order() {
+        let map = parse_json(
+            r#"{"title": "Hi é 😀", "n": 3, "x": -1.5e2, "big": 18446744073709551616,
+                "tags": ["a", true, null], "params": {"z": 1, "a": {}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            map.keys().collect::<Vec<_>>(),
+            ["title", "n", "x", "big", "tags", "params"]
+        );
+        assert_eq!(map.get_str("title"), Some("Hi é 😀"));
+        assert_eq!(map.get("n"), Some(&YamlValue::Integer(3)));
+        assert_eq!(map.get("x"), Some(&YamlValue::Float(-150.0)));
+        assert_eq!(
+            map.get("big"),
+            Some(&YamlValue::Float(18446744073709551616.0))
+        );
+        assert_eq!(map.get_path("tags.2"), Some(&YamlValue::Null));
+        assert_eq!(
+            map.get_path("params")
+                .and_then(YamlValue::as_map)
+                .map(|it| it.keys().collect::<Vec<_>>()),
+            Some(vec!["z", "a"])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        let reason = |input: &str| parse_json_object(input).unwrap_err().reason;
+        assert_eq!(reason(r#"{"a": 1, "a": 2}"#), "duplicate object key");
+        assert_eq!(reason(r#"{"a": 01}"#), "invalid number");
+        assert_eq!(reason(r#"{"a": [1, 2}"#), "expected ',' or ']'");
+        assert_eq!(reason(r#"{a: 1}"#), "expected a string key");
+        assert_eq!(reason(r#"{"a": "\x"}"#), "invalid escape sequence");
+        assert_eq!(reason("[1]"), "expected a JSON object");
+        assert_eq!(parse_json("{}"), None);
+        assert_eq!(parse_json(r#"{"a": 1} trailing"#), None);
+        assert_eq!(parse_json_object(r#"{"a": 1}rest"#).unwrap().1, 8);
+    }
+}
@@ -0,0 +1,944 @@
+use rustc_hash::FxHashSet;
+
+use crate::ast::frontmatter::FrontMatterSpan;
+use crate::exts::yaml::{YamlError, YamlMap, YamlValue, finish_spans, source_span};
+
+/// 解析 `+++` frontmatter 中的 TOML 文本；结果为空表或语法错误时返回 `None`
+///
+/// 值映射到与 YAML 相同的 [`YamlValue`] 模型：表为 `Map`，数组为 `List`，
+/// 日期时间保留源文本作为 `String`。
+#[cfg(test)]
+pub fn parse_toml(input: &str) -> Option<YamlMap> {
+    parse_toml_spanned(input).ok().flatten().map(|(map, _)| map)
+}
+
+/// 解析并记录每个键与值的区间（相对 `input` 的字节偏移）；空文档返回 `Ok(None)`
+pub(crate) fn parse_toml_spanned(
+    input: &str,
+) -> Result<Option<(YamlMap, Vec<FrontMatterSpan>)>, YamlError> {
+    let mut parser = TomlParser::new(input);
+    let map = parser.parse_document()?;
+    Ok((!map.is_empty()).then(|| (map, finish_spans(parser.spans))))
+}
+
+type TomlResult<T> = Result<T, YamlError>;
+
+/// 数组与内联表的嵌套层数上限
+const MAX_DEPTH: usize = 256;
+
+/// 表在文档树中的具体位置；数组表的元素以下标表示
+#[derive(Clone)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// 点分路径中的各段，数组表元素使用下标
+fn path_strings(path: &[Segment]) -> Vec<String> {
+    path.iter()
+        .map(|segment| match segment {
+            Segment::Key(key) => key.clone(),
+            Segment::Index(index) => index.to_string(),
+        })
+        .collect()
+}
+
+/// 规范化路径，用于记录已定义与不可扩展的表
+fn canonical(path: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in path {
+        match segment {
+            Segment::Key(key) => {
+                out.push('\u{0}');
+                out.push_str(key);
+            }
+            Segment::Index(index) => {
+                out.push('\u{1}');
+                out.push_str(&index.to_string());
+            }
+        }
+    }
+    out
+}
+
+/// TOML 1.0 解析器
+struct TomlParser<'a> {
+    src: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+    /// 通过 `[table]` 头显式定义过的表
+    defined: FxHashSet<String>,
+    /// 由 `key = value` 整体给出的内联表与静态数组，之后不可再扩展
+    frozen: FxHashSet<String>,
+    /// 当前条目的路径
+    path: Vec<String>,
+    spans: Vec<FrontMatterSpan>,
+}
+
+#[inline]
+fn is_bare_key(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'-'
+}
+
+impl<'a> TomlParser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            src,
+            bytes: src.as_bytes(),
+            pos: 0,
+            depth: 0,
+            defined: FxHashSet::default(),
+            frozen: FxHashSet::default(),
+            path: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.pos + offset).copied()
+    }
+
+    fn starts_with(&self, needle: &str) -> bool {
+        self.src[self.pos..].starts_with(needle)
+    }
+
+    fn error<T>(&self, reason: &str) -> TomlResult<T> {
+        self.error_at(self.pos, reason)
+    }
+
+    fn error_at<T>(&self, offset: usize, reason: &str) -> TomlResult<T> {
+        Err(YamlError {
+            offset,
+            reason: reason.to_string(),
+        })
+    }
+
+    fn skip_blanks(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        if self.peek() == Some(b'#') {
+            while !matches!(self.peek(), None | Some(b'\n' | b'\r')) {
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn skip_break(&mut self) -> bool {
+        match self.peek() {
+            Some(b'\n') => {
+                self.pos += 1;
+                true
+            }
+            Some(b'\r') if self.peek_at(1) == Some(b'\n') => {
+                self.pos += 2;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// 跳过空白、换行与注释（数组内部与表达式之间）
+    fn skip_trivia(&mut self) {
+        loop {
+            self.skip_blanks();
+            self.skip_comment();
+            if !self.skip_break() {
+                return;
+            }
+        }
+    }
+
+    /// 表达式结束后本行只允许空白与注释
+    fn finish_line(&mut self) -> TomlResult<()> {
+        self.skip_blanks();
+        self.skip_comment();
+        if self.peek().is_none() || self.skip_break() {
+            Ok(())
+        } else {
+            self.error("expected a newline after the expression")
+        }
+    }
+
+    fn parse_document(&mut self) -> TomlResult<YamlMap> {
+        if self.src.starts_with('\u{feff}') {
+            self.pos = 3;
+        }
+        let mut root = YamlMap::new();
+        let mut current: Vec<Segment> = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => return Ok(root),
+                Some(b'[') => {
+                    let start = self.pos;
+                    let array = self.peek_at(1) == Some(b'[');
+                    self.pos += 1 + usize::from(array);
+                    self.skip_blanks();
+                    let (keys, key_span) = self.parse_key()?;
+                    let close = if array { "]]" } else { "]" };
+                    if !self.starts_with(close) {
+                        return self.error("expected the end of a table header");
+                    }
+                    self.pos += close.len();
+                    let header_end = self.pos;
+                    self.finish_line()?;
+                    current = self.open_table(&mut root, keys, array, start)?;
+                    self.path = path_strings(&current);
+                    self.record(Some(key_span), (start, header_end));
+                }
+                Some(_) => {
+                    let start = self.pos;
+                    let (keys, key_span) = self.parse_key()?;
+                    if self.peek() != Some(b'=') {
+                        return self.error("expected '=' after a key");
+                    }
+                    self.pos += 1;
+                    self.skip_blanks();
+                    self.path = path_strings(&current);
+                    self.path.extend(keys.iter().cloned());
+                    let value_start = self.pos;
+                    let value = self.parse_value()?;
+                    self.record(Some(key_span), (value_start, self.pos));
+                    self.finish_line()?;
+                    let mut path = current.clone();
+                    let table = Self::navigate(&mut root, &current);
+                    self.insert_dotted(table, &mut path, keys, value, start)?;
+                }
+            }
+        }
+    }
+
+    /// 沿具体路径进入已存在的表
+    fn navigate<'m>(root: &'m mut YamlMap, path: &[Segment]) -> &'m mut YamlMap {
+        let mut table = root;
+        let mut index = 0;
+        while index < path.len() {
+            let value = match &path[index] {
+                Segment::Key(key) => table.get_mut(key),
+                Segment::Index(_) => None,
+            };
+            table = match value {
+                Some(YamlValue::Map(map)) => map,
+                Some(YamlValue::List(list)) => {
+                    index += 1;
+                    let Some(Segment::Index(item)) = path.get(index) else {
+                        unreachable!("array table path must end with an index")
+                    };
+                    match &mut list[*item] {
+                        YamlValue::Map(map) => map,
+                        _ => unreachable!("array table elements are tables"),
+                    }
+                }
+                _ => unreachable!("table path must point to an existing table"),
+            };
+            index += 1;
+        }
+        table
+    }
+
+    /// 处理 `[a.b]` 与 `[[a.b]]` 表头，返回新的当前表路径
+    fn open_table(
+        &mut self,
+        root: &mut YamlMap,
+        keys: Vec<String>,
+        array: bool,
+        offset: usize,
+    ) -> TomlResult<Vec<Segment>> {
+        let mut path = Vec::new();
+        let mut table = root;
+        let last = keys.len() - 1;
+        for (index, key) in keys.into_iter().enumerate() {
+            path.push(Segment::Key(key.clone()));
+            if self.frozen.contains(&canonical(&path)) {
+                return self.error_at(offset, "cannot extend an inline table or static array");
+            }
+            if index == last {
+                if array {
+                    let YamlValue::List(list) =
+                        table.get_or_insert(key, YamlValue::List(Vec::new()))
+                    else {
+                        return self.error_at(offset, "duplicate key");
+                    };
+                    list.push(YamlValue::Map(YamlMap::new()));
+                    path.push(Segment::Index(list.len() - 1));
+                } else {
+                    let slot = table.get_or_insert(key, YamlValue::Map(YamlMap::new()));
+                    // 隐式创建的父表可以之后再显式定义一次
+                    if !matches!(slot, YamlValue::Map(_)) || !self.defined.insert(canonical(&path))
+                    {
+                        return self.error_at(offset, "duplicate table");
+                    }
+                }
+                return Ok(path);
+            }
+            let slot = table.get_or_insert(key, YamlValue::Map(YamlMap::new()));
+            table = match slot {
+                YamlValue::Map(map) => map,
+                YamlValue::List(list) => match list.len().checked_sub(1) {
+                    Some(last) if matches!(list[last], YamlValue::Map(_)) => {
+                        path.push(Segment::Index(last));
+                        let YamlValue::Map(map) = &mut list[last] else {
+                            unreachable!()
+                        };
+                        map
+                    }
+                    _ => return self.error_at(offset, "key is not a table"),
+                },
+                _ => return self.error_at(offset, "key is not a table"),
+            };
+        }
+        unreachable!("keys are never empty")
+    }
+
+    /// 在当前表中插入 `a.b.c = value`，中间键按需创建子表
+    fn insert_dotted(
+        &mut self,
+        table: &mut YamlMap,
+        path: &mut Vec<Segment>,
+        keys: Vec<String>,
+        value: YamlValue,
+        offset: usize,
+    ) -> TomlResult<()> {
+        let mut table = table;
+        let last = keys.len() - 1;
+        for (index, key) in keys.into_iter().enumerate() {
+            path.push(Segment::Key(key.clone()));
+            if index == last {
+                if table.contains_key(&key) {
+                    return self.error_at(offset, "duplicate key");
+                }
+                if matches!(value, YamlValue::Map(_) | YamlValue::List(_)) {
+                    self.frozen.insert(canonical(path));
+                }
+                table.insert(key, value);
+                return Ok(());
+            }
+            if self.frozen.contains(&canonical(path)) || self.defined.contains(&canonical(path)) {
+                return self.error_at(offset, "cannot extend a table with dotted keys");
+            }
+            table = match table.get_or_insert(key, YamlValue::Map(YamlMap::new())) {
+                YamlValue::Map(map) => map,
+                _ => return self.error_at(offset, "key is not a table"),
+            };
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, key: Option<(usize, usize)>, value: (usize, usize)) {
+        self.spans.push(FrontMatterSpan {
+            path: self.path.join("."),
+            key: key.map(|(start, end)| source_span(start, end)),
+            value: source_span(value.0, value.1),
+        });
+    }
+
+    /// 解析裸键、引号键及其点分组合，返回各段与末段的区间
+    fn parse_key(&mut self) -> TomlResult<(Vec<String>, (usize, usize))> {
+        let mut keys = Vec::new();
+        loop {
+            let start = self.pos;
+            let key = match self.peek() {
+                Some(b'"') if !self.starts_with("\"\"\"") => self.parse_basic_string()?,
+                Some(b'\'') if !self.starts_with("'''") => self.parse_literal_string()?,
+                Some(b) if is_bare_key(b) => {
+                    while self.peek().is_some_and(is_bare_key) {
+                        self.pos += 1;
+                    }
+                    self.src[start..self.pos].to_string()
+                }
+                _ => return self.error("expected a key"),
+            };
+            keys.push(key);
+            let end = self.pos;
+            self.skip_blanks();
+            if self.peek() != Some(b'.') {
+                return Ok((keys, (start, end)));
+            }
+            self.pos += 1;
+            self.skip_blanks();
+        }
+    }
+
+    fn parse_value(&mut self) -> TomlResult<YamlValue> {
+        match self.peek() {
+            Some(b'"') if self.starts_with("\"\"\"") => {
+                self.parse_multiline_basic_string().map(YamlValue::String)
+            }
+            Some(b'"') => self.parse_basic_string().map(YamlValue::String),
+            Some(b'\'') if self.starts_with("'''") => {
+                self.parse_multiline_literal_string().map(YamlValue::String)
+            }
+            Some(b'\'') => self.parse_literal_string().map(YamlValue::String),
+            Some(b'[') => self.parse_array(),
+            Some(b'{') => self.parse_inline_table(),
+            Some(_) => self.parse_scalar(),
+            None => self.error("expected a value"),
+        }
+    }
+
+    fn enter(&mut self) -> TomlResult<()> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return self.error("nesting depth limit exceeded");
+        }
+        Ok(())
+    }
+
+    fn parse_array(&mut self) -> TomlResult<YamlValue> {
+        self.enter()?;
+        let start = self.pos;
+        self.pos += 1;
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                Some(b']') => {
+                    self.pos += 1;
+                    self.depth -= 1;
+                    return Ok(YamlValue::List(items));
+                }
+                None => return self.error_at(start, "unterminated array"),
+                _ => {}
+            }
+            self.path.push(items.len().to_string());
+            let item_start = self.pos;
+            items.push(self.parse_value()?);
+            self.record(None, (item_start, self.pos));
+            self.path.pop();
+            self.skip_trivia();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {}
+                None => return self.error_at(start, "unterminated array"),
+                _ => return self.error("expected ',' or ']'"),
+            }
+        }
+    }
+
+    fn parse_inline_table(&mut self) -> TomlResult<YamlValue> {
+        self.enter()?;
+        let start = self.pos;
+        self.pos += 1;
+        let mut table = YamlMap::new();
+        // 内联表内部的点分键只需判重，借用独立的路径与状态
+        let mut nested = TomlParser::new(self.src);
+        self.skip_blanks();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            self.depth -= 1;
+            return Ok(YamlValue::Map(table));
+        }
+        loop {
+            self.skip_blanks();
+            let key_offset = self.pos;
+            let (keys, key_span) = self.parse_key()?;
+            if self.peek() != Some(b'=') {
+                return self.error("expected '=' after a key");
+            }
+            self.pos += 1;
+            self.skip_blanks();
+            let depth = self.path.len();
+            self.path.extend(keys.iter().cloned());
+            let value_start = self.pos;
+            let value = self.parse_value()?;
+            self.record(Some(key_span), (value_start, self.pos));
+            self.path.truncate(depth);
+            nested.insert_dotted(&mut table, &mut Vec::new(), keys, value, key_offset)?;
+            self.skip_blanks();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    self.depth -= 1;
+                    return Ok(YamlValue::Map(table));
+                }
+                None | Some(b'\n' | b'\r') => {
+                    return self.error_at(start, "unterminated inline table");
+                }
+                _ => return self.error("expected ',' or '}'"),
+            }
+        }
+    }
+
+    fn parse_basic_string(&mut self) -> TomlResult<String> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let run = self.pos;
+            while self
+                .peek()
+                .is_some_and(|b| !matches!(b, b'"' | b'\\' | b'\n' | b'\r'))
+            {
+                self.pos += 1;
+            }
+            out.push_str(&self.src[run..self.pos]);
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                Some(b'\\') => self.parse_escape(&mut out)?,
+                _ => return self.error_at(start, "unterminated string"),
+            }
+        }
+    }
+
+    fn parse_multiline_basic_string(&mut self) -> TomlResult<String> {
+        let start = self.pos;
+        self.pos += 3;
+        // 紧跟开始定界符的换行被忽略
+        self.skip_break();
+        let mut out = String::new();
+        loop {
+            let run = self.pos;
+            while self.peek().is_some_and(|b| !matches!(b, b'"' | b'\\')) {
+                self.pos += 1;
+            }
+            out.push_str(&self.src[run..self.pos]);
+            match self.peek() {
+                Some(b'"') if self.starts_with("\"\"\"") => {
+                    // 结束定界符前最多可再包含两个引号
+                    let quotes = self.bytes[self.pos..]
+                        .iter()
+                        .take_while(|&&b| b == b'"')
+                        .count();
+                    if quotes > 5 {
+                        return self.error("too many quotes in a multi-line string");
+                    }
+                    out.push_str(&"\"".repeat(quotes - 3));
+                    self.pos += quotes;
+                    return Ok(out);
+                }
+                Some(b'"') => {
+                    out.push('"');
+                    self.pos += 1;
+                }
+                Some(b'\\') => {
+                    // 行尾反斜杠：去除换行及下一个非空白字符前的全部空白
+                    let mut i = self.pos + 1;
+                    while matches!(self.bytes.get(i), Some(b' ' | b'\t')) {
+                        i += 1;
+                    }
+                    if matches!(self.bytes.get(i), Some(b'\n' | b'\r')) {
+                        self.pos = i;
+                        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+                            self.pos += 1;
+                        }
+                    } else {
+                        self.parse_escape(&mut out)?;
+                    }
+                }
+                _ => return self.error_at(start, "unterminated multi-line string"),
+            }
+        }
+    }
+
+    /// cursor 位于 `\\`
+    fn parse_escape(&mut self, out: &mut String) -> TomlResult<()> {
+        let escape = self.pos;
+        self.pos += 1;
+        let ch = match self.peek() {
+            Some(b'b') => '\u{08}',
+            Some(b't') => '\t',
+            Some(b'n') => '\n',
+            Some(b'f') => '\u{0C}',
+            Some(b'r') => '\r',
+            Some(b'e') => '\u{1B}',
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b @ (b'u' | b'U')) => {
+                let len = if b == b'u' { 4 } else { 8 };
+                let code = self
+                    .src
+                    .get(self.pos + 1..self.pos + 1 + len)
+                    .filter(|hex| hex.bytes().all(|it| it.is_ascii_hexdigit()))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(char::from_u32);
+                let Some(ch) = code else {
+                    return self.error_at(escape, "invalid escape sequence");
+                };
+                self.pos += len;
+                ch
+            }
+            _ => return self.error_at(escape, "invalid escape sequence"),
+        };
+        self.pos += 1;
+        out.push(ch);
+        Ok(())
+    }
+
+    fn parse_literal_string(&mut self) -> TomlResult<String> {
+        let start = self.pos;
+        self.pos += 1;
+        while self
+            .peek()
+            .is_some_and(|b| !matches!(b, b'\'' | b'\n' | b'\r'))
+        {
+            self.pos += 1;
+        }
+        if self.peek() != Some(b'\'') {
+            return self.error_at(start, "unterminated string");
+        }
+        self.pos += 1;
+        Ok(self.src[start + 1..self.pos - 1].to_string())
+    }
+
+    fn parse_multiline_literal_string(&mut self) -> TomlResult<String> {
+        let start = self.pos;
+        self.pos += 3;
+        self.skip_break();
+        let content = self.pos;
+        let Some(end) = self.src[content..].find("'''") else {
+            return self.error_at(start, "unterminated multi-line string");
+        };
+        let mut end = content + end;
+        let quotes = self.bytes[end..]
+            .iter()
+            .take_while(|&&b| b == b'\'')
+            .count();
+        if quotes > 5 {
+            return self.error_at(end, "too many quotes in a multi-line string");
+        }
+        end += quotes - 3;
+        self.pos = end + 3;
+        Ok(self.src[content..end].to_string())
+    }
+
+    /// 布尔、数字与日期时间
+    fn parse_scalar(&mut self) -> TomlResult<YamlValue> {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| {
+            b.is_ascii_alphanumeric() || matches!(b, b'_' | b'+' | b'-' | b'.' | b':')
+        }) {
+            self.pos += 1;
+        }
+        // `1979-05-27 07:32:00`：日期与时间之间允许单个空格
+        if self.pos - start == 10
+            && self.peek() == Some(b' ')
+            && self.peek_at(1).is_some_and(|b| b.is_ascii_digit())
+            && self.peek_at(3) == Some(b':')
+            && is_date(&self.src[start..self.pos])
+        {
+            self.pos += 1;
+            self.parse_scalar_tail();
+        }
+        let token = &self.src[start..self.pos];
+        let value = match token {
+            "true" => YamlValue::Bool(true),
+            "false" => YamlValue::Bool(false),
+            "inf" | "+inf" => YamlValue::Float(f64::INFINITY),
+            "-inf" => YamlValue::Float(f64::NEG_INFINITY),
+            "nan" | "+nan" | "-nan" => YamlValue::Float(f64::NAN),
+            _ if is_datetime(token) => YamlValue::String(token.to_string()),
+            _ => match parse_number(token) {
+                Some(value) => value,
+                None => return self.error_at(start, "invalid value"),
+            },
+        };
+        Ok(value)
+    }
+
+    fn parse_scalar_tail(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.' | b':'))
+        {
+            self.pos += 1;
+        }
+    }
+}
+
+fn is_date(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() == 10
+        && b[4] == b'-'
+        && b[7] == b'-'
+        && b.iter()
+            .enumerate()
+            .all(|(i, c)| i == 4 || i == 7 || c.is_ascii_digit())
+}
+
+fn is_time(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() >= 8
+        && b[2] == b':'
+        && b[5] == b':'
+        && b[..8]
+            .iter()
+            .enumerate()
+            .all(|(i, c)| i == 2 || i == 5 || c.is_ascii_digit())
+}
+
+/// 偏移日期时间、本地日期时间、本地日期与本地时间，按源文本保留
+fn is_datetime(s: &str) -> bool {
+    if is_time(s) {
+        return true;
+    }
+    if s.len() < 10 || !is_date(&s[..10]) {
+        return false;
+    }
+    match s.as_bytes().get(10) {
+        None => true,
+        Some(b'T' | b't' | b' ') => is_time(&s[11..]),
+        _ => false,
+    }
+}
+
+/// 写出键；不是裸键时使用基本字符串
+pub(crate) fn write_key(key: &str, out: &mut String) {
+    if !key.is_empty() && key.bytes().all(is_bare_key) {
+        out.push_str(key);
+    } else {
+        write_string(key, out);
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            ch if ch.is_control() => out.push_str(&format!("\\u{:04X}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
+/// 以单行形式写出值，表写为内联表；日期时间形式的字符串按 TOML 日期时间写出。
+/// TOML 没有 null，遇到时返回 `false`
+pub(crate) fn write_value(value: &YamlValue, out: &mut String) -> bool {
+    match value {
+        YamlValue::String(s) if is_datetime(s) => out.push_str(s),
+        YamlValue::String(s) => write_string(s, out),
+        YamlValue::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        YamlValue::Integer(i) => out.push_str(&i.to_string()),
+        YamlValue::Float(f) if f.is_nan() => out.push_str("nan"),
+        YamlValue::Float(f) if f.is_infinite() => {
+            out.push_str(if *f > 0.0 { "inf" } else { "-inf" })
+        }
+        YamlValue::Float(f) => out.push_str(&format!("{f:?}")),
+        YamlValue::Null => return false,
+        YamlValue::List(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                if !write_value(item, out) {
+                    return false;
+                }
+            }
+            out.push(']');
+        }
+        YamlValue::Map(map) => {
+            out.push('{');
+            for (i, (key, value)) in map.iter().enumerate() {
+                out.push_str(if i > 0 { ", " } else { " " });
+                write_key(key, out);
+                out.push_str(" = ");
+                if !write_value(value, out) {
+                    return false;
+                }
+            }
+            out.push_str(if map.is_empty() { "}" } else { " }" });
+        }
+    }
+    true
+}
+
+/// 下划线只能出现在两个数字之间
+fn strip_underscores(digits: &str, radix: u32) -> Option<String> {
+    let bytes = digits.as_bytes();
+    let valid = !bytes.is_empty()
+        && bytes.iter().enumerate().all(|(i, &b)| {
+            if b == b'_' {
+                i > 0
+                    && i + 1 < bytes.len()
+                    && (bytes[i - 1] as char).is_digit(radix)
+                    && (bytes[i + 1] as char).is_digit(radix)
+            } else {
+                (b as char).is_digit(radix)
+            }
+        });
+    valid.then(|| digits.replace('_', ""))
+}
+
+fn parse_number(token: &str) -> Option<YamlValue> {
+    for (prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+        if let Some(digits) = token.strip_prefix(prefix) {
+            let digits = strip_underscores(digits, radix)?;
+            return i64::from_str_radix(&digits, radix)
+                .ok()
+                .map(YamlValue::Integer);
+        }
+    }
+    let unsigned = token.strip_prefix(['+', '-']).unwrap_or(token);
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+        None => (unsigned, None),
+    };
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+    let integer = strip_underscores(integer, 10)?;
+    // 不允许前导零
+    if integer.len() > 1 && integer.starts_with('0') {
+        return None;
+    }
+    let sign = if token.starts_with('-') { "-" } else { "" };
+    if fraction.is_none() && exponent.is_none() {
+        return format!("{sign}{integer}")
+            .parse()
+            .ok()
+            .map(YamlValue::Integer);
+    }
+    let mut text = format!("{sign}{integer}");
+    if let Some(fraction) = fraction {
+        text.push('.');
+        text.push_str(&strip_underscores(fraction, 10)?);
+    }
+    if let Some(exponent) = exponent {
+        let (exp_sign, digits) = match exponent.strip_prefix(['+', '-']) {
+            Some(digits) => (&exponent[..1], digits),
+            None => ("", exponent),
+        };
+        text.push('e');
+        text.push_str(exp_sign);
+        text.push_str(&strip_underscores(digits, 10)?);
+    }
+    text.parse().ok().map(YamlValue::Float)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(input: &str) -> String {
+        TomlParser::new(input).parse_document().unwrap_err().reason
+    }
+
+    #[test]
+    fn parses_hugo_frontmatter() {
+        let map = parse_toml(
+            r#"
+title = "Hello \"TOML\" é"  # comment
+date = 2024-01-02T03:04:05Z
+lastmod = 1979-05-27 07:32:00
+draft = false
+weight = 1_000
+ratio = 6.02e23
+tags = [
+  "rust",   # trailing comment
+  'literal\path',
+]
+author.name = "Ada"
+[params]
+summary = """
+Line one \
+  continued"""
+raw = '''
+keep \n'''
+[params.social]
+links = { github = "ada", site.url = "https://ada.dev" }
+[[menu.main]]
+name = "Home"
+[[menu.main]]
+name = "Blog"
+[menu.main.extra]
+hidden = true
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            map.keys().collect::<Vec<_>>(),
+            [
+                "title", "date", "lastmod", "draft", "weight", "ratio", "tags", "author", "params",
+                "menu"
+            ]
+        );
+        assert_eq!(map.get_str("title"), Some("Hello \"TOML\" é"));
+        assert_eq!(map.get_str("date"), Some("2024-01-02T03:04:05Z"));
+        assert_eq!(map.get_str("lastmod"), Some("1979-05-27 07:32:00"));
+        assert_eq!(map.get("weight"), Some(&YamlValue::Integer(1000)));
+        assert_eq!(map.get("ratio"), Some(&YamlValue::Float(6.02e23)));
+        assert_eq!(
+            map.get_path("tags.1").and_then(YamlValue::as_str),
+            Some("literal\\path")
+        );
+        assert_eq!(
+            map.get_path("author.name").and_then(YamlValue::as_str),
+            Some("Ada")
+        );
+        assert_eq!(
+            map.get_path("params.summary").and_then(YamlValue::as_str),
+            Some("Line one continued")
+        );
+        assert_eq!(
+            map.get_path("params.raw").and_then(YamlValue::as_str),
+            Some("keep \\n")
+        );
+        assert_eq!(
+            map.get_path("params.social.links.site.url")
+                .and_then(YamlValue::as_str),
+            Some("https://ada.dev")
+        );
+        assert_eq!(
+            map.get_path("menu.main.1.name").and_then(YamlValue::as_str),
+            Some("Blog")
+        );
+        assert_eq!(
+            map.get_path("menu.main.1.extra.hidden"),
+            Some(&YamlValue::Bool(true))
+        );
+    }
+
+    #[test]
+    fn parses_numbers() {
+        let map = parse_toml("a = 0xff\nb = 0o17\nc = 0b101\nd = -inf\ne = +12\nf = -0.5").unwrap();
+        assert_eq!(map.get("a"), Some(&YamlValue::Integer(255)));
+        assert_eq!(map.get("b"), Some(&YamlValue::Integer(15)));
+        assert_eq!(map.get("c"), Some(&YamlValue::Integer(5)));
+        assert_eq!(map.get("d"), Some(&YamlValue::Float(f64::NEG_INFINITY)));
+        assert_eq!(map.get("e"), Some(&YamlValue::Integer(12)));
+        assert_eq!(map.get("f"), Some(&YamlValue::Float(-0.5)));
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        assert_eq!(error("a = 1\na = 2"), "duplicate key");
+        assert_eq!(error("[a]\n[a]"), "duplicate table");
+        assert_eq!(
+            error("a = {x = 1}\n[a]"),
+            "cannot extend an inline table or static array"
+        );
+        assert_eq!(
+            error("a = [1]\n[[a]]"),
+            "cannot extend an inline table or static array"
+        );
+        assert_eq!(error("a = 01"), "invalid value");
+        assert_eq!(error("a = 1_"), "invalid value");
+        assert_eq!(error("a = \"open"), "unterminated string");
+        assert_eq!(
+            error("a = 1 b = 2"),
+            "expected a newline after the expression"
+        );
+        assert_eq!(error("title: Test"), "expected '=' after a key");
+        assert_eq!(parse_toml("# only a comment\n"), None);
+    }
+}
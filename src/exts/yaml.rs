@@ -1,4 +1,8 @@
 use rustc_hash::FxHashMap;
+
+use crate::ast::frontmatter::FrontMatterSpan;
+use crate::ast::text::SourceSpan;
+use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
 
 /// Frontmatter 值类型
@@ -9,6 +13,8 @@ pub enum YamlValue {
     Integer(i64),
     Float(f64),
     List(Vec<YamlValue>),
+    /// 嵌套映射，保持源文本中的键顺序
+    Map(YamlMap),
     Null,
 }
 
@@ -24,128 +30,1651 @@ impl Serialize for YamlValue {
             YamlValue::Integer(i) => serializer.serialize_i64(*i),
             YamlValue::Float(f) => serializer.serialize_f64(*f),
             YamlValue::List(list) => list.serialize(serializer),
+            YamlValue::Map(map) => map.serialize(serializer),
             YamlValue::Null => serializer.serialize_none(),
         }
     }
 }
 
-/// Frontmatter 键值对映射
-pub type YamlMap = FxHashMap<String, YamlValue>;
+impl YamlValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            YamlValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            YamlValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            YamlValue::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+    /// 整数同样按浮点数返回
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            YamlValue::Float(f) => Some(*f),
+            YamlValue::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+    pub fn as_list(&self) -> Option<&[YamlValue]> {
+        match self {
+            YamlValue::List(list) => Some(list),
+            _ => None,
+        }
+    }
+    pub fn as_map(&self) -> Option<&YamlMap> {
+        match self {
+            YamlValue::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+    pub fn is_null(&self) -> bool {
+        matches!(self, YamlValue::Null)
+    }
+    /// 按 `.` 分隔的路径查找嵌套值，列表段使用下标，如 `author.links.0`
+    pub fn get_path(&self, path: &str) -> Option<&YamlValue> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        path.split('.')
+            .try_fold(self, |value, segment| match value {
+                YamlValue::Map(map) => map.get(segment),
+                YamlValue::List(list) => list.get(segment.parse::<usize>().ok()?),
+                _ => None,
+            })
+    }
+}
+
+impl From<&str> for YamlValue {
+    fn from(value: &str) -> Self {
+        YamlValue::String(value.to_string())
+    }
+}
+
+impl From<String> for YamlValue {
+    fn from(value: String) -> Self {
+        YamlValue::String(value)
+    }
+}
+
+impl From<bool> for YamlValue {
+    fn from(value: bool) -> Self {
+        YamlValue::Bool(value)
+    }
+}
+
+impl From<i64> for YamlValue {
+    fn from(value: i64) -> Self {
+        YamlValue::Integer(value)
+    }
+}
+
+impl From<f64> for YamlValue {
+    fn from(value: f64) -> Self {
+        YamlValue::Float(value)
+    }
+}
+
+impl<T: Into<YamlValue>> From<Vec<T>> for YamlValue {
+    fn from(value: Vec<T>) -> Self {
+        YamlValue::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<YamlMap> for YamlValue {
+    fn from(value: YamlMap) -> Self {
+        YamlValue::Map(value)
+    }
+}
+
+/// Frontmatter 键值对映射，按源文本顺序保存键
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct YamlMap {
+    entries: Vec<(String, YamlValue)>,
+}
+
+impl YamlMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    pub fn get(&self, key: &str) -> Option<&YamlValue> {
+        self.entries
+            .iter()
+            .find(|(it, _)| it == key)
+            .map(|(_, value)| value)
+    }
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut YamlValue> {
+        self.entries
+            .iter_mut()
+            .find(|(it, _)| it == key)
+            .map(|(_, value)| value)
+    }
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.entries.iter().any(|(it, _)| it == key)
+    }
+    /// 已存在的键原位替换并返回旧值，否则追加到末尾
+    pub fn insert(&mut self, key: impl Into<String>, value: YamlValue) -> Option<YamlValue> {
+        let key = key.into();
+        match self.get_mut(&key) {
+            Some(slot) => Some(std::mem::replace(slot, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+    /// 键不存在时先追加 `default`，返回该键的值
+    pub(crate) fn get_or_insert(&mut self, key: String, default: YamlValue) -> &mut YamlValue {
+        let index = match self.entries.iter().position(|(it, _)| *it == key) {
+            Some(index) => index,
+            None => {
+                self.entries.push((key, default));
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[index].1
+    }
+    /// 移除键并保持其余键的顺序
+    pub fn remove(&mut self, key: &str) -> Option<YamlValue> {
+        let index = self.entries.iter().position(|(it, _)| it == key)?;
+        Some(self.entries.remove(index).1)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &YamlValue)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(key, _)| key.as_str())
+    }
+    pub fn values(&self) -> impl Iterator<Item = &YamlValue> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+    /// 字符串值；键不存在或不是字符串时返回 `None`
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(YamlValue::as_str)
+    }
+    /// 列表值；键不存在或不是列表时返回 `None`
+    pub fn get_list(&self, key: &str) -> Option<&[YamlValue]> {
+        self.get(key).and_then(YamlValue::as_list)
+    }
+    /// 按 `.` 分隔的路径查找嵌套值，列表段使用下标，如 `author.links.0`
+    pub fn get_path(&self, path: &str) -> Option<&YamlValue> {
+        let (head, rest) = match path.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (path, None),
+        };
+        let value = self.get(head)?;
+        match rest {
+            Some(rest) => value.get_path(rest),
+            None => Some(value),
+        }
+    }
+}
+
+impl Serialize for YamlMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.entries.len()))?;
+        for (key, value) in &self.entries {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl FromIterator<(String, YamlValue)> for YamlMap {
+    fn from_iter<T: IntoIterator<Item = (String, YamlValue)>>(iter: T) -> Self {
+        let mut map = YamlMap::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+impl<'a> IntoIterator for &'a YamlMap {
+    type Item = (&'a str, &'a YamlValue);
+    type IntoIter = std::iter::Map<
+        std::slice::Iter<'a, (String, YamlValue)>,
+        fn(&'a (String, YamlValue)) -> (&'a str, &'a YamlValue),
+    >;
 
-/// 解析 frontmatter YAML 文本为键值对映射
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+impl std::ops::Index<&str> for YamlMap {
+    type Output = YamlValue;
+
+    fn index(&self, key: &str) -> &YamlValue {
+        self.get(key).expect("key not found in YamlMap")
+    }
+}
+
+/// 解析 frontmatter YAML 文本为键值对映射；顶层不是非空映射或语法错误时返回 `None`
+#[cfg(test)]
 pub fn parse_yaml(input: &str) -> Option<YamlMap> {
-    let mut map = FxHashMap::default();
-    let lines: Vec<&str> = input.lines().collect();
-    let mut i = 0;
+    parse_yaml_spanned(input).ok().flatten().map(|(map, _)| map)
+}
 
-    while i < lines.len() {
-        let line = lines[i].trim();
+/// 解析并记录每个键与值的区间（相对 `input` 的字节偏移）；
+/// 空文档返回 `Ok(None)`，顶层不是映射时报错
+pub(crate) fn parse_yaml_spanned(
+    input: &str,
+) -> Result<Option<(YamlMap, Vec<FrontMatterSpan>)>, YamlError> {
+    let mut parser = YamlParser::new(input);
+    match parser.parse_document()? {
+        YamlValue::Map(map) if map.is_empty() => Ok(None),
+        YamlValue::Map(map) => Ok(Some((map, finish_spans(parser.spans)))),
+        YamlValue::Null => Ok(None),
+        _ => parser.error_at(parser.root, "expected a mapping at the document root"),
+    }
+}
 
-        // Skip empty lines and comments
-        if line.is_empty() || line.starts_with('#') {
-            i += 1;
-            continue;
+/// 按源文本顺序排列：父条目在前，子条目随后
+pub(crate) fn finish_spans(mut spans: Vec<FrontMatterSpan>) -> Vec<FrontMatterSpan> {
+    spans.sort_by_key(|it| it.key.unwrap_or(it.value).start);
+    spans
+}
+
+pub(crate) fn source_span(start: usize, end: usize) -> SourceSpan {
+    SourceSpan::new(start as u32, end as u32)
+}
+
+/// 语法错误，`offset` 为源文本字节偏移；TOML 与 JSON 解析器共用
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct YamlError {
+    pub(crate) offset: usize,
+    pub(crate) reason: String,
+}
+
+type YamlResult<T> = Result<T, YamlError>;
+
+/// 嵌套层数上限，防止恶意输入耗尽栈空间
+const MAX_DEPTH: usize = 256;
+/// 别名展开的累计节点上限，防止 "billion laughs" 式膨胀
+const MAX_ALIAS_NODES: usize = 100_000;
+
+/// 节点在块上下文中的位置，决定是否允许块集合以及紧凑序列
+#[derive(Clone, Copy, PartialEq)]
+enum Place {
+    /// 独占一行（或位于文档开头）
+    Line,
+    /// 紧跟在 `- ` 或 `? ` 之后
+    Entry,
+    /// 紧跟在 `key: ` 之后
+    Value,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Chomping {
+    Clip,
+    Strip,
+    Keep,
+}
+
+/// 单遍递归下降的 YAML 1.2 解析器（core schema）
+struct YamlParser<'a> {
+    src: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+    alias_nodes: usize,
+    anchors: FxHashMap<&'a str, YamlValue>,
+    /// 根节点首字节
+    root: usize,
+    /// 最近一个节点内容的结束位置（不含其后的空白与注释）
+    last_end: usize,
+    /// 当前条目的路径
+    path: Vec<String>,
+    spans: Vec<FrontMatterSpan>,
+}
+
+#[inline]
+fn is_blank(b: u8) -> bool {
+    b == b' ' || b == b'\t'
+}
+
+#[inline]
+fn is_ws_or_end(b: Option<u8>) -> bool {
+    matches!(b, None | Some(b' ' | b'\t' | b'\n' | b'\r'))
+}
+
+#[inline]
+fn is_flow_indicator(b: u8) -> bool {
+    matches!(b, b',' | b'[' | b']' | b'{' | b'}')
+}
+
+impl<'a> YamlParser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            src,
+            bytes: src.as_bytes(),
+            pos: 0,
+            depth: 0,
+            alias_nodes: 0,
+            anchors: FxHashMap::default(),
+            root: 0,
+            last_end: 0,
+            path: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.pos + offset).copied()
+    }
+
+    fn error<T>(&self, reason: &str) -> YamlResult<T> {
+        self.error_at(self.pos, reason)
+    }
+
+    fn error_at<T>(&self, offset: usize, reason: &str) -> YamlResult<T> {
+        Err(YamlError {
+            offset,
+            reason: reason.to_string(),
+        })
+    }
+
+    fn line_start(&self, pos: usize) -> usize {
+        self.bytes[..pos]
+            .iter()
+            .rposition(|&b| b == b'\n' || b == b'\r')
+            .map_or(0, |it| it + 1)
+    }
+
+    /// 当前字节列（缩进只允许空格，因此与缩进宽度一致）
+    fn column(&self) -> usize {
+        self.pos - self.line_start(self.pos)
+    }
+
+    fn at_line_end(&self) -> bool {
+        matches!(self.peek(), None | Some(b'\n' | b'\r'))
+    }
+
+    fn at_comment_or_line_end(&self) -> bool {
+        self.at_line_end() || self.peek() == Some(b'#')
+    }
+
+    fn skip_blanks(&mut self) {
+        while self.peek().is_some_and(is_blank) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_to_line_end(&mut self) {
+        while !self.at_line_end() {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_break(&mut self) {
+        if self.peek() == Some(b'\r') {
+            self.pos += 1;
+        }
+        if self.peek() == Some(b'\n') {
+            self.pos += 1;
+        }
+    }
+
+    /// 节点结束后本行只允许空白与注释
+    fn finish_line(&mut self) -> YamlResult<()> {
+        let before = self.pos;
+        self.skip_blanks();
+        match self.peek() {
+            Some(b'#') if self.pos > before || self.pos == self.line_start(self.pos) => {
+                self.skip_to_line_end();
+                Ok(())
+            }
+            Some(b':') => self.error("mapping values are not allowed in this context"),
+            _ if self.at_line_end() => Ok(()),
+            _ => self.error("unexpected content after value"),
+        }
+    }
+
+    /// 跳过空行与纯注释行，停在下一内容行的首个非空白字节并返回其缩进
+    fn next_content_line(&mut self) -> YamlResult<Option<usize>> {
+        loop {
+            self.skip_blanks();
+            match self.peek() {
+                None => return Ok(None),
+                Some(b'#') => self.skip_to_line_end(),
+                Some(b'\n' | b'\r') => self.skip_break(),
+                Some(_) => {
+                    let start = self.line_start(self.pos);
+                    if let Some(tab) = self.bytes[start..self.pos].iter().position(|&b| b == b'\t')
+                    {
+                        return self.error_at(start + tab, "tabs are not allowed for indentation");
+                    }
+                    return Ok(Some(self.pos - start));
+                }
+            }
         }
+    }
+
+    fn starts_sequence_entry(&self) -> bool {
+        self.peek() == Some(b'-') && is_ws_or_end(self.peek_at(1))
+    }
+
+    fn starts_explicit_key(&self) -> bool {
+        self.peek() == Some(b'?') && is_ws_or_end(self.peek_at(1))
+    }
+
+    fn record(&mut self, key: Option<(usize, usize)>, value: (usize, usize)) {
+        self.spans.push(FrontMatterSpan {
+            path: self.path.join("."),
+            key: key.map(|(start, end)| source_span(start, end)),
+            value: source_span(value.0, value.1),
+        });
+    }
+
+    /// 解析位于行尾之后的嵌套值，返回值及其区间；没有值时区间为 `empty` 处的空区间
+    fn parse_nested_value(
+        &mut self,
+        parent_indent: isize,
+        compact_sequence: bool,
+        empty: usize,
+    ) -> YamlResult<(YamlValue, (usize, usize))> {
+        self.skip_to_line_end();
+        // 先定位到下一内容行以取得值的起点；parse_nested_node 会再次调用，结果一致
+        self.next_content_line()?;
+        let start = self.pos;
+        let value = self.parse_nested_node(parent_indent, compact_sequence)?;
+        let range = if self.pos == start {
+            (empty, empty)
+        } else {
+            (start, self.last_end)
+        };
+        Ok((value, range))
+    }
+
+    fn parse_document(&mut self) -> YamlResult<YamlValue> {
+        if self.src.starts_with('\u{feff}') {
+            self.pos = 3;
+        }
+        if self.next_content_line()?.is_none() {
+            return Ok(YamlValue::Null);
+        }
+        self.root = self.pos;
+        let value = self.parse_block_node(-1, Place::Line)?;
+        if self.next_content_line()?.is_some() {
+            return self.error("unexpected content after the document root");
+        }
+        Ok(value)
+    }
 
-        // Parse key-value pair
-        if let Some(colon_pos) = line.find(':') {
-            let key = line[..colon_pos].trim().to_string();
-            let value_part = line[colon_pos + 1..].trim();
+    fn enter(&mut self) -> YamlResult<()> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return self.error("nesting depth limit exceeded");
+        }
+        Ok(())
+    }
+
+    /// 解析块上下文节点：cursor 位于节点首字节
+    fn parse_block_node(&mut self, parent_indent: isize, place: Place) -> YamlResult<YamlValue> {
+        self.enter()?;
+        let (anchor, tag) = self.parse_properties()?;
+        let has_properties = anchor.is_some() || tag.is_some();
+        let value = if has_properties && self.at_comment_or_line_end() {
+            // 属性独占一行，节点内容位于后续行
+            self.last_end = self.src[..self.pos].trim_end_matches([' ', '\t']).len();
+            self.skip_to_line_end();
+            self.parse_nested_node(parent_indent, place == Place::Value)?
+        } else if place != Place::Value && self.starts_sequence_entry() {
+            self.parse_block_sequence(self.column())?
+        } else if place != Place::Value
+            && (self.starts_explicit_key() || self.scan_implicit_key().is_some())
+        {
+            self.parse_block_mapping(self.column())?
+        } else {
+            self.parse_inline_node(parent_indent, tag)?
+        };
+        self.check_collection_tag(&value, tag)?;
+        self.depth -= 1;
+        Ok(self.register_anchor(anchor, value))
+    }
+
+    /// 节点位于后续行：缩进大于 `parent_indent` 的块节点；映射值还允许同缩进的紧凑序列
+    fn parse_nested_node(
+        &mut self,
+        parent_indent: isize,
+        compact_sequence: bool,
+    ) -> YamlResult<YamlValue> {
+        match self.next_content_line()? {
+            Some(indent) if indent as isize > parent_indent => {
+                self.parse_block_node(parent_indent, Place::Line)
+            }
+            Some(indent)
+                if compact_sequence
+                    && indent as isize == parent_indent
+                    && self.starts_sequence_entry() =>
+            {
+                self.parse_block_sequence(indent)
+            }
+            _ => Ok(YamlValue::Null),
+        }
+    }
+
+    fn parse_block_sequence(&mut self, indent: usize) -> YamlResult<YamlValue> {
+        let mut items = Vec::new();
+        loop {
+            // 跳过 `-`
+            self.pos += 1;
+            self.skip_blanks();
+            self.path.push(items.len().to_string());
+            let (item, range) = if self.at_comment_or_line_end() {
+                self.parse_nested_value(indent as isize, false, self.pos)?
+            } else {
+                let start = self.pos;
+                let item = self.parse_block_node(indent as isize, Place::Entry)?;
+                (item, (start, self.last_end))
+            };
+            self.record(None, range);
+            self.path.pop();
+            items.push(item);
+            match self.next_content_line()? {
+                Some(next) if next == indent && self.starts_sequence_entry() => {}
+                Some(next) if next > indent => {
+                    return self.error("bad indentation of a sequence entry");
+                }
+                _ => break,
+            }
+        }
+        Ok(YamlValue::List(items))
+    }
 
-            let value = if value_part.is_empty() {
-                // Check if next lines are indented list items
-                if i + 1 < lines.len() && lines[i + 1].trim_start().starts_with('-') {
-                    let (list_value, consumed) = parse_indented_list(&lines[i + 1..]);
-                    i += consumed;
-                    list_value
+    fn parse_block_mapping(&mut self, indent: usize) -> YamlResult<YamlValue> {
+        let mut map = YamlMap::new();
+        loop {
+            let key_offset = self.pos;
+            let (key, value) = if self.starts_explicit_key() {
+                self.pos += 1;
+                self.skip_blanks();
+                let (key, key_range) = if self.at_comment_or_line_end() {
+                    self.parse_nested_value(indent as isize, false, self.pos)?
                 } else {
-                    YamlValue::Null
+                    let start = self.pos;
+                    let key = self.parse_block_node(indent as isize, Place::Entry)?;
+                    (key, (start, self.last_end))
+                };
+                let key = self.key_string(key, key_offset)?;
+                self.check_duplicate(&map, &key, key_offset)?;
+                self.path.push(key);
+                let (value, value_range) = match self.next_content_line()? {
+                    Some(next)
+                        if next == indent
+                            && self.peek() == Some(b':')
+                            && is_ws_or_end(self.peek_at(1)) =>
+                    {
+                        self.pos += 1;
+                        self.parse_map_value(indent)?
+                    }
+                    _ => (YamlValue::Null, (key_range.1, key_range.1)),
+                };
+                self.record(Some(key_range), value_range);
+                (self.path.pop().unwrap_or_default(), value)
+            } else {
+                let Some(colon) = self.scan_implicit_key() else {
+                    return self.error("could not find expected ':'");
+                };
+                let (key, key_end) = self.parse_implicit_key(colon)?;
+                self.check_duplicate(&map, &key, key_offset)?;
+                self.pos = colon + 1;
+                self.path.push(key);
+                let (value, value_range) = self.parse_map_value(indent)?;
+                self.record(Some((key_offset, key_end)), value_range);
+                (self.path.pop().unwrap_or_default(), value)
+            };
+            map.insert(key, value);
+            match self.next_content_line()? {
+                Some(next) if next == indent => {}
+                Some(next) if next > indent => {
+                    return self.error("bad indentation of a mapping entry");
+                }
+                _ => break,
+            }
+        }
+        Ok(YamlValue::Map(map))
+    }
+
+    fn check_duplicate(&self, map: &YamlMap, key: &str, offset: usize) -> YamlResult<()> {
+        if map.contains_key(key) {
+            return self.error_at(offset, "duplicate mapping key");
+        }
+        Ok(())
+    }
+
+    /// cursor 位于 `:` 之后；返回值及其区间
+    fn parse_map_value(&mut self, indent: usize) -> YamlResult<(YamlValue, (usize, usize))> {
+        let colon_end = self.pos;
+        self.skip_blanks();
+        if self.at_comment_or_line_end() {
+            self.parse_nested_value(indent as isize, true, colon_end)
+        } else {
+            let start = self.pos;
+            let value = self.parse_block_node(indent as isize, Place::Value)?;
+            Ok((value, (start, self.last_end)))
+        }
+    }
+
+    /// 在当前行查找隐式键后的 `:`，返回其位置
+    fn scan_implicit_key(&self) -> Option<usize> {
+        let mut i = self.pos;
+        match self.bytes.get(i)? {
+            quote @ (b'"' | b'\'') => {
+                i = self.scan_quoted_on_line(i, *quote)?;
+                while self.bytes.get(i).copied().is_some_and(is_blank) {
+                    i += 1;
+                }
+                return (self.bytes.get(i) == Some(&b':')
+                    && is_ws_or_end(self.bytes.get(i + 1).copied()))
+                .then_some(i);
+            }
+            b'-' | b'?' | b':' if is_ws_or_end(self.bytes.get(i + 1).copied()) => return None,
+            b',' | b'[' | b']' | b'{' | b'}' | b'#' | b'&' | b'*' | b'!' | b'|' | b'>' | b'%'
+            | b'@' | b'`' => return None,
+            _ => {}
+        }
+        while let Some(&b) = self.bytes.get(i) {
+            match b {
+                b'\n' | b'\r' => return None,
+                b':' if is_ws_or_end(self.bytes.get(i + 1).copied()) => return Some(i),
+                b'#' if is_blank(self.bytes[i - 1]) => return None,
+                _ => i += 1,
+            }
+        }
+        None
+    }
+
+    /// 返回同一行内引号字符串结束引号之后的位置
+    fn scan_quoted_on_line(&self, start: usize, quote: u8) -> Option<usize> {
+        let mut i = start + 1;
+        while let Some(&b) = self.bytes.get(i) {
+            match b {
+                b'\n' | b'\r' => return None,
+                b'\\' if quote == b'"' => i += 2,
+                b'\'' if quote == b'\'' && self.bytes.get(i + 1) == Some(&b'\'') => i += 2,
+                _ if b == quote => return Some(i + 1),
+                _ => i += 1,
+            }
+        }
+        None
+    }
+
+    /// 返回键文本及其在源文本中的结束位置
+    fn parse_implicit_key(&mut self, colon: usize) -> YamlResult<(String, usize)> {
+        let key = match self.peek() {
+            Some(b'"') => self.parse_double_quoted()?,
+            Some(b'\'') => self.parse_single_quoted()?,
+            _ => {
+                let key = self.src[self.pos..colon].trim_end_matches([' ', '\t']);
+                return Ok((key.to_string(), self.pos + key.len()));
+            }
+        };
+        Ok((key, self.pos))
+    }
+
+    fn key_string(&self, key: YamlValue, offset: usize) -> YamlResult<String> {
+        Ok(match key {
+            YamlValue::String(s) => s,
+            YamlValue::Bool(b) => b.to_string(),
+            YamlValue::Integer(i) => i.to_string(),
+            YamlValue::Float(f) => f.to_string(),
+            YamlValue::Null => String::new(),
+            YamlValue::List(_) | YamlValue::Map(_) => {
+                return self.error_at(offset, "complex mapping keys are not supported");
+            }
+        })
+    }
+
+    /// 解析 `&anchor` 与 `!tag` 属性，返回后 cursor 位于节点内容（或行尾）
+    fn parse_properties(&mut self) -> YamlResult<(Option<&'a str>, Option<&'a str>)> {
+        let mut anchor = None;
+        let mut tag = None;
+        loop {
+            match self.peek() {
+                Some(b'&') if anchor.is_none() => {
+                    self.pos += 1;
+                    let name = self.scan_name();
+                    if name.is_empty() {
+                        return self.error("expected an anchor name");
+                    }
+                    anchor = Some(name);
                 }
+                Some(b'!') if tag.is_none() => tag = Some(self.scan_name()),
+                _ => return Ok((anchor, tag)),
+            }
+            if !is_ws_or_end(self.peek()) && !self.peek().is_some_and(is_flow_indicator) {
+                return self.error("expected whitespace after a node property");
+            }
+            self.skip_blanks();
+        }
+    }
+
+    /// 锚点、别名与标签名：直到空白或流指示符
+    fn scan_name(&mut self) -> &'a str {
+        let start = self.pos;
+        while !is_ws_or_end(self.peek()) && !self.peek().is_some_and(is_flow_indicator) {
+            self.pos += 1;
+        }
+        &self.src[start..self.pos]
+    }
+
+    fn register_anchor(&mut self, anchor: Option<&'a str>, value: YamlValue) -> YamlValue {
+        if let Some(anchor) = anchor {
+            self.anchors.insert(anchor, value.clone());
+        }
+        value
+    }
+
+    fn parse_alias(&mut self) -> YamlResult<YamlValue> {
+        let start = self.pos;
+        self.pos += 1;
+        let name = self.scan_name();
+        let Some(value) = self.anchors.get(name) else {
+            return self.error_at(start, "undefined alias");
+        };
+        self.alias_nodes += count_nodes(value, MAX_ALIAS_NODES);
+        if self.alias_nodes > MAX_ALIAS_NODES {
+            return self.error_at(start, "alias expansion limit exceeded");
+        }
+        Ok(value.clone())
+    }
+
+    fn check_collection_tag(&self, value: &YamlValue, tag: Option<&str>) -> YamlResult<()> {
+        let Some(tag) = tag.map(normalize_tag) else {
+            return Ok(());
+        };
+        let matched = match value {
+            YamlValue::List(_) => !matches!(tag, "str" | "int" | "float" | "bool" | "null" | "map"),
+            YamlValue::Map(_) => !matches!(tag, "str" | "int" | "float" | "bool" | "null" | "seq"),
+            _ => true,
+        };
+        if matched {
+            Ok(())
+        } else {
+            self.error("tag does not match the node kind")
+        }
+    }
+
+    /// 块上下文中位于同一行的标量、流集合或别名
+    fn parse_inline_node(
+        &mut self,
+        parent_indent: isize,
+        tag: Option<&str>,
+    ) -> YamlResult<YamlValue> {
+        let start = self.pos;
+        let value = match self.peek() {
+            Some(b'*') => {
+                let value = self.parse_alias()?;
+                self.last_end = self.pos;
+                self.finish_line()?;
+                return Ok(value);
+            }
+            Some(b'[' | b'{') => {
+                let value = self.parse_flow_collection()?;
+                self.last_end = self.pos;
+                self.finish_line()?;
+                return Ok(value);
+            }
+            Some(b'|' | b'>') => {
+                let text = self.parse_block_scalar(parent_indent)?;
+                return self.resolve_scalar(text, false, tag, start);
+            }
+            Some(b'"') => {
+                let text = self.parse_double_quoted()?;
+                self.last_end = self.pos;
+                self.finish_line()?;
+                return self.resolve_scalar(text, false, tag, start);
+            }
+            Some(b'\'') => {
+                let text = self.parse_single_quoted()?;
+                self.last_end = self.pos;
+                self.finish_line()?;
+                return self.resolve_scalar(text, false, tag, start);
+            }
+            Some(b'-') if self.starts_sequence_entry() => {
+                return self.error("block sequence entries are not allowed in this context");
+            }
+            Some(b'?') if self.starts_explicit_key() => {
+                return self.error("explicit keys are not allowed in this context");
+            }
+            Some(b',' | b']' | b'}' | b'%' | b'@' | b'`') => {
+                return self.error("found character that cannot start any token");
+            }
+            _ => self.parse_plain_block(parent_indent)?,
+        };
+        self.last_end = self.pos;
+        self.finish_line()?;
+        self.resolve_scalar(value, true, tag, start)
+    }
+
+    /// 块上下文的多行纯量：续行缩进需大于 `parent_indent`，行间按折叠规则合并
+    fn parse_plain_block(&mut self, parent_indent: isize) -> YamlResult<String> {
+        let mut out = String::new();
+        loop {
+            let start = self.pos;
+            let mut end = start;
+            while let Some(b) = self.peek() {
+                match b {
+                    b'\n' | b'\r' => break,
+                    b':' if is_ws_or_end(self.peek_at(1)) => break,
+                    b'#' if self.pos > start && is_blank(self.bytes[self.pos - 1]) => break,
+                    b' ' | b'\t' => self.pos += 1,
+                    _ => {
+                        self.pos += 1;
+                        end = self.pos;
+                    }
+                }
+            }
+            out.push_str(&self.src[start..end]);
+            self.pos = end;
+            // 被 `:` 或注释截断时不再续行
+            self.skip_blanks();
+            if !self.at_line_end() {
+                self.pos = end;
+                break;
+            }
+            let line_end = end;
+            let mut breaks = 0;
+            let continued = loop {
+                if self.peek().is_none() {
+                    break false;
+                }
+                self.skip_break();
+                breaks += 1;
+                self.skip_blanks();
+                if !self.at_line_end() {
+                    break (self.column() as isize) > parent_indent
+                        && self.peek() != Some(b'#')
+                        && !self.starts_document_marker();
+                }
+            };
+            if !continued {
+                self.pos = line_end;
+                break;
+            }
+            fold_breaks(&mut out, breaks - 1);
+        }
+        Ok(out)
+    }
+
+    fn starts_document_marker(&self) -> bool {
+        self.column() == 0
+            && (self.src[self.pos..].starts_with("---") || self.src[self.pos..].starts_with("..."))
+            && is_ws_or_end(self.peek_at(3))
+    }
+
+    fn parse_double_quoted(&mut self) -> YamlResult<String> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let run_start = self.pos;
+            while self
+                .peek()
+                .is_some_and(|b| !matches!(b, b'"' | b'\\' | b'\n' | b'\r'))
+            {
+                self.pos += 1;
+            }
+            out.push_str(&self.src[run_start..self.pos]);
+            match self.peek() {
+                None => return self.error_at(start, "unterminated double-quoted string"),
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    if self.at_line_end() {
+                        // 转义换行：直接拼接下一行，忽略其前导空白
+                        self.skip_break();
+                        self.skip_blanks();
+                        continue;
+                    }
+                    self.parse_escape(&mut out)?;
+                }
+                Some(_) => self.fold_quoted_lines(&mut out, start)?,
+            }
+        }
+    }
+
+    fn parse_escape(&mut self, out: &mut String) -> YamlResult<()> {
+        let escape = self.pos - 1;
+        let Some(b) = self.peek() else {
+            return self.error_at(escape, "invalid escape sequence");
+        };
+        self.pos += 1;
+        let ch = match b {
+            b'0' => '\0',
+            b'a' => '\u{07}',
+            b'b' => '\u{08}',
+            b't' | b'\t' => '\t',
+            b'n' => '\n',
+            b'v' => '\u{0B}',
+            b'f' => '\u{0C}',
+            b'r' => '\r',
+            b'e' => '\u{1B}',
+            b' ' => ' ',
+            b'"' => '"',
+            b'/' => '/',
+            b'\\' => '\\',
+            b'N' => '\u{85}',
+            b'_' => '\u{A0}',
+            b'L' => '\u{2028}',
+            b'P' => '\u{2029}',
+            b'x' | b'u' | b'U' => {
+                let len = match b {
+                    b'x' => 2,
+                    b'u' => 4,
+                    _ => 8,
+                };
+                let code = self
+                    .src
+                    .get(self.pos..self.pos + len)
+                    .filter(|hex| hex.bytes().all(|it| it.is_ascii_hexdigit()))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(char::from_u32);
+                let Some(ch) = code else {
+                    return self.error_at(escape, "invalid escape sequence");
+                };
+                self.pos += len;
+                ch
+            }
+            _ => return self.error_at(escape, "invalid escape sequence"),
+        };
+        out.push(ch);
+        Ok(())
+    }
+
+    fn parse_single_quoted(&mut self) -> YamlResult<String> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let run_start = self.pos;
+            while self
+                .peek()
+                .is_some_and(|b| !matches!(b, b'\'' | b'\n' | b'\r'))
+            {
+                self.pos += 1;
+            }
+            out.push_str(&self.src[run_start..self.pos]);
+            match self.peek() {
+                None => return self.error_at(start, "unterminated single-quoted string"),
+                Some(b'\'') if self.peek_at(1) == Some(b'\'') => {
+                    out.push('\'');
+                    self.pos += 2;
+                }
+                Some(b'\'') => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                Some(_) => self.fold_quoted_lines(&mut out, start)?,
+            }
+        }
+    }
+
+    /// 引号字符串跨行：去除行尾空白与续行前导空白，单个换行折叠为空格
+    fn fold_quoted_lines(&mut self, out: &mut String, start: usize) -> YamlResult<()> {
+        out.truncate(out.trim_end_matches([' ', '\t']).len());
+        let mut breaks = 0;
+        while self.at_line_end() {
+            if self.peek().is_none() {
+                return self.error_at(start, "unterminated quoted string");
+            }
+            self.skip_break();
+            breaks += 1;
+            self.skip_blanks();
+        }
+        fold_breaks(out, breaks - 1);
+        Ok(())
+    }
+
+    /// `|` 与 `>` 块标量；结束后 cursor 位于下一行行首
+    fn parse_block_scalar(&mut self, parent_indent: isize) -> YamlResult<String> {
+        let literal = self.peek() == Some(b'|');
+        self.pos += 1;
+        let mut chomping = None;
+        let mut explicit_indent = None;
+        for _ in 0..2 {
+            match self.peek() {
+                Some(b'-') if chomping.is_none() => chomping = Some(Chomping::Strip),
+                Some(b'+') if chomping.is_none() => chomping = Some(Chomping::Keep),
+                Some(b @ b'1'..=b'9') if explicit_indent.is_none() => {
+                    explicit_indent = Some((b - b'0') as usize)
+                }
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        let chomping = chomping.unwrap_or(Chomping::Clip);
+        if !is_ws_or_end(self.peek()) {
+            return self.error("invalid block scalar header");
+        }
+        self.finish_line()?;
+        self.skip_break();
+
+        let base = parent_indent.max(0) as usize;
+        let indent = match explicit_indent {
+            Some(indent) => base + indent,
+            None => self.detect_block_indent(parent_indent),
+        };
+
+        let mut lines: Vec<&str> = Vec::new();
+        let mut ends_with_break = false;
+        self.last_end = self.pos;
+        while self.peek().is_some() && indent > 0 {
+            let line_start = self.pos;
+            let mut line_end = line_start;
+            while line_end < self.bytes.len() && !matches!(self.bytes[line_end], b'\n' | b'\r') {
+                line_end += 1;
+            }
+            let line = &self.src[line_start..line_end];
+            let spaces = line.bytes().take_while(|&b| b == b' ').count();
+            if spaces < indent && !line.trim_start_matches(' ').is_empty() {
+                break;
+            }
+            lines.push(line.get(indent..).unwrap_or(""));
+            if !line.trim_start_matches(' ').is_empty() {
+                self.last_end = line_end;
+            }
+            self.pos = line_end;
+            ends_with_break = !self.at_line_end() || self.peek().is_some();
+            self.skip_break();
+        }
+
+        let content_len = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .map_or(0, |it| it + 1);
+        let trailing = lines.len() - content_len;
+        let mut out = String::new();
+        if literal {
+            for (index, line) in lines[..content_len].iter().enumerate() {
+                if index > 0 {
+                    out.push('\n');
+                }
+                out.push_str(line);
+            }
+        } else {
+            fold_block_lines(&mut out, &lines[..content_len]);
+        }
+        match chomping {
+            Chomping::Strip => {}
+            Chomping::Clip => {
+                if content_len > 0 && (ends_with_break || trailing > 0) {
+                    out.push('\n');
+                }
+            }
+            Chomping::Keep => {
+                if content_len > 0 && (ends_with_break || trailing > 0) {
+                    out.push('\n');
+                }
+                for _ in 0..trailing.saturating_sub(usize::from(!ends_with_break)) {
+                    out.push('\n');
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// 自动检测块标量缩进：首个非空行的缩进，需大于父节点缩进
+    fn detect_block_indent(&self, parent_indent: isize) -> usize {
+        let mut i = self.pos;
+        loop {
+            let spaces = self.bytes[i..].iter().take_while(|&&b| b == b' ').count();
+            i += spaces;
+            match self.bytes.get(i) {
+                None => return 0,
+                Some(b'\n' | b'\r') => {
+                    if self.bytes[i] == b'\r' && self.bytes.get(i + 1) == Some(&b'\n') {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                Some(_) if spaces as isize > parent_indent => return spaces,
+                Some(_) => return 0,
+            }
+        }
+    }
+
+    fn skip_flow_space(&mut self) {
+        loop {
+            let before = self.pos;
+            self.skip_blanks();
+            match self.peek() {
+                Some(b'\n' | b'\r') => self.skip_break(),
+                Some(b'#')
+                    if self.pos > before
+                        || self.pos == self.line_start(self.pos)
+                        || self.bytes[self.pos - 1].is_ascii_whitespace() =>
+                {
+                    self.skip_to_line_end()
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn parse_flow_collection(&mut self) -> YamlResult<YamlValue> {
+        self.enter()?;
+        let value = if self.peek() == Some(b'[') {
+            self.parse_flow_sequence()?
+        } else {
+            self.parse_flow_mapping()?
+        };
+        self.depth -= 1;
+        Ok(value)
+    }
+
+    fn parse_flow_sequence(&mut self) -> YamlResult<YamlValue> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut items = Vec::new();
+        loop {
+            self.skip_flow_space();
+            match self.peek() {
+                None => return self.error_at(start, "unterminated flow sequence"),
+                Some(b']') => {
+                    self.pos += 1;
+                    self.last_end = self.pos;
+                    return Ok(YamlValue::List(items));
+                }
+                _ => {}
+            }
+            let entry_offset = self.pos;
+            self.path.push(items.len().to_string());
+            let node = self.parse_flow_node()?;
+            let node_end = self.last_end;
+            self.skip_flow_space();
+            // 单键值对 `[key: value]`
+            let item = if self.peek() == Some(b':') {
+                self.pos += 1;
+                let key = self.key_string(node, entry_offset)?;
+                self.path.push(key);
+                let (value, value_range) = self.parse_flow_value()?;
+                self.record(Some((entry_offset, node_end)), value_range);
+                let key = self.path.pop().unwrap_or_default();
+                let mut map = YamlMap::new();
+                map.insert(key, value);
+                YamlValue::Map(map)
             } else {
-                parse_value(value_part)
+                node
             };
+            self.record(None, (entry_offset, self.last_end));
+            self.path.pop();
+            items.push(item);
+            self.skip_flow_space();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {}
+                None => return self.error_at(start, "unterminated flow sequence"),
+                _ => return self.error("expected ',' or ']'"),
+            }
+        }
+    }
 
+    fn parse_flow_mapping(&mut self) -> YamlResult<YamlValue> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut map = YamlMap::new();
+        loop {
+            self.skip_flow_space();
+            match self.peek() {
+                None => return self.error_at(start, "unterminated flow mapping"),
+                Some(b'}') => {
+                    self.pos += 1;
+                    self.last_end = self.pos;
+                    return Ok(YamlValue::Map(map));
+                }
+                _ => {}
+            }
+            let key_offset = self.pos;
+            if self.starts_explicit_key() {
+                self.pos += 1;
+                self.skip_flow_space();
+            }
+            let key = match self.peek() {
+                Some(b'[' | b'{' | b'*' | b'"' | b'\'' | b'&' | b'!') => {
+                    let node = self.parse_flow_node()?;
+                    self.key_string(node, key_offset)?
+                }
+                _ => self.parse_plain_flow(),
+            };
+            let key_end = self.pos;
+            self.check_duplicate(&map, &key, key_offset)?;
+            self.skip_flow_space();
+            self.path.push(key);
+            let (value, value_range) = if self.peek() == Some(b':') {
+                self.pos += 1;
+                self.parse_flow_value()?
+            } else {
+                (YamlValue::Null, (key_end, key_end))
+            };
+            self.record(Some((key_offset, key_end)), value_range);
+            let key = self.path.pop().unwrap_or_default();
             map.insert(key, value);
+            self.skip_flow_space();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {}
+                None => return self.error_at(start, "unterminated flow mapping"),
+                _ => return self.error("expected ',' or '}'"),
+            }
         }
+    }
 
-        i += 1;
+    /// 流上下文中 `:` 之后的值，可为空；返回值及其区间
+    fn parse_flow_value(&mut self) -> YamlResult<(YamlValue, (usize, usize))> {
+        let colon_end = self.pos;
+        self.skip_flow_space();
+        match self.peek() {
+            Some(b',' | b']' | b'}') | None => Ok((YamlValue::Null, (colon_end, colon_end))),
+            _ => {
+                let start = self.pos;
+                let value = self.parse_flow_node()?;
+                Ok((value, (start, self.last_end)))
+            }
+        }
+    }
+
+    fn parse_flow_node(&mut self) -> YamlResult<YamlValue> {
+        let (anchor, tag) = self.parse_properties()?;
+        self.skip_flow_space();
+        let start = self.pos;
+        let value = match self.peek() {
+            Some(b'[' | b'{') => self.parse_flow_collection()?,
+            Some(b'*') => self.parse_alias()?,
+            Some(b'"') => {
+                let text = self.parse_double_quoted()?;
+                self.resolve_scalar(text, false, tag, start)?
+            }
+            Some(b'\'') => {
+                let text = self.parse_single_quoted()?;
+                self.resolve_scalar(text, false, tag, start)?
+            }
+            Some(b',' | b']' | b'}' | b':') | None if anchor.is_some() || tag.is_some() => {
+                self.resolve_scalar(String::new(), true, tag, start)?
+            }
+            Some(b',' | b']' | b'}' | b'#' | b'|' | b'>' | b'%' | b'@' | b'`') | None => {
+                return self.error("found character that cannot start any token");
+            }
+            _ => {
+                let text = self.parse_plain_flow();
+                if text.is_empty() {
+                    return self.error("found character that cannot start any token");
+                }
+                self.resolve_scalar(text, true, tag, start)?
+            }
+        };
+        self.last_end = self.pos;
+        self.check_collection_tag(&value, tag)?;
+        Ok(self.register_anchor(anchor, value))
+    }
+
+    /// 流上下文的纯量，遇到流指示符、`: ` 或注释结束，可跨行折叠
+    fn parse_plain_flow(&mut self) -> String {
+        let mut out = String::new();
+        loop {
+            let start = self.pos;
+            let mut end = start;
+            while let Some(b) = self.peek() {
+                match b {
+                    b'\n' | b'\r' => break,
+                    b':' if is_ws_or_end(self.peek_at(1))
+                        || self.peek_at(1).is_some_and(is_flow_indicator) =>
+                    {
+                        break;
+                    }
+                    b'#' if self.pos > start && is_blank(self.bytes[self.pos - 1]) => break,
+                    _ if is_flow_indicator(b) => break,
+                    b' ' | b'\t' => self.pos += 1,
+                    _ => {
+                        self.pos += 1;
+                        end = self.pos;
+                    }
+                }
+            }
+            out.push_str(&self.src[start..end]);
+            if !self.at_line_end() {
+                self.pos = end;
+                return out;
+            }
+            let line_end = end;
+            let mut breaks = 0;
+            while self.at_line_end() && self.peek().is_some() {
+                self.skip_break();
+                breaks += 1;
+                self.skip_blanks();
+            }
+            match self.peek() {
+                Some(b) if !is_flow_indicator(b) && b != b'#' && b != b':' && !out.is_empty() => {
+                    fold_breaks(&mut out, breaks - 1);
+                }
+                _ => {
+                    self.pos = line_end;
+                    return out;
+                }
+            }
+        }
     }
 
-    if map.is_empty() { None } else { Some(map) }
+    fn resolve_scalar(
+        &self,
+        text: String,
+        plain: bool,
+        tag: Option<&str>,
+        offset: usize,
+    ) -> YamlResult<YamlValue> {
+        let value = match tag.map(normalize_tag) {
+            None if plain => resolve_plain(text),
+            None | Some("str" | "!") => YamlValue::String(text),
+            Some("null") => match resolve_plain(text) {
+                YamlValue::Null => YamlValue::Null,
+                _ => return self.error_at(offset, "invalid !!null value"),
+            },
+            Some("bool") => match resolve_plain(text) {
+                value @ YamlValue::Bool(_) => value,
+                _ => return self.error_at(offset, "invalid !!bool value"),
+            },
+            Some("int") => match parse_int(&text) {
+                Some(value) => YamlValue::Integer(value),
+                None => return self.error_at(offset, "invalid !!int value"),
+            },
+            Some("float") => match resolve_plain(text) {
+                YamlValue::Float(value) => YamlValue::Float(value),
+                YamlValue::Integer(value) => YamlValue::Float(value as f64),
+                _ => return self.error_at(offset, "invalid !!float value"),
+            },
+            Some("seq" | "map") => {
+                return self.error_at(offset, "tag does not match the node kind");
+            }
+            // 未知标签按无标签处理
+            Some(_) if plain => resolve_plain(text),
+            Some(_) => YamlValue::String(text),
+        };
+        Ok(value)
+    }
 }
 
-/// 解析缩进列表
-fn parse_indented_list(lines: &[&str]) -> (YamlValue, usize) {
-    let mut items = Vec::new();
-    let mut consumed = 0;
+/// 将 `!!str`、`!<tag:yaml.org,2002:str>` 统一为 `str`；其余标签原样返回
+fn normalize_tag(tag: &str) -> &str {
+    tag.strip_prefix("!!")
+        .or_else(|| {
+            tag.strip_prefix("!<tag:yaml.org,2002:")
+                .and_then(|it| it.strip_suffix('>'))
+        })
+        .unwrap_or(tag)
+}
 
+/// 行折叠：`extra` 个空行保留为换行，否则单个换行折叠为空格
+fn fold_breaks(out: &mut String, extra: usize) {
+    if extra == 0 {
+        out.push(' ');
+    } else {
+        for _ in 0..extra {
+            out.push('\n');
+        }
+    }
+}
+
+/// `>` 块标量的折叠：普通行之间以空格连接，空行保留为换行，更深缩进的行保留换行
+fn fold_block_lines(out: &mut String, lines: &[&str]) {
+    let is_more_indented = |line: &str| line.starts_with([' ', '\t']);
+    let mut previous: Option<&str> = None;
+    let mut empty = 0;
     for line in lines {
-        let trimmed = line.trim();
-        if trimmed.starts_with('-') {
-            let item_text = trimmed[1..].trim();
-            items.push(parse_value(item_text));
-            consumed += 1;
-        } else if trimmed.is_empty() {
-            consumed += 1;
+        if line.is_empty() {
+            empty += 1;
             continue;
-        } else {
-            break;
         }
+        match previous {
+            Some(previous) if is_more_indented(previous) || is_more_indented(line) => {
+                for _ in 0..=empty {
+                    out.push('\n');
+                }
+            }
+            Some(_) => fold_breaks(out, empty),
+            None => {
+                for _ in 0..empty {
+                    out.push('\n');
+                }
+            }
+        }
+        out.push_str(line);
+        previous = Some(line);
+        empty = 0;
     }
+}
 
-    (YamlValue::List(items), consumed)
+/// 统计节点数量，超过 `limit` 后提前返回
+fn count_nodes(value: &YamlValue, limit: usize) -> usize {
+    let mut count = 1;
+    let children: Box<dyn Iterator<Item = &YamlValue>> = match value {
+        YamlValue::List(list) => Box::new(list.iter()),
+        YamlValue::Map(map) => Box::new(map.values()),
+        _ => return count,
+    };
+    for child in children {
+        count += count_nodes(child, limit - count.min(limit));
+        if count > limit {
+            break;
+        }
+    }
+    count
 }
 
-/// 解析单个值
-fn parse_value(s: &str) -> YamlValue {
-    let s = s.trim();
+/// YAML 1.2 core schema 的纯量解析
+fn resolve_plain(text: String) -> YamlValue {
+    match text.as_str() {
+        "" | "~" | "null" | "Null" | "NULL" => YamlValue::Null,
+        "true" | "True" | "TRUE" => YamlValue::Bool(true),
+        "false" | "False" | "FALSE" => YamlValue::Bool(false),
+        ".inf" | ".Inf" | ".INF" | "+.inf" | "+.Inf" | "+.INF" => YamlValue::Float(f64::INFINITY),
+        "-.inf" | "-.Inf" | "-.INF" => YamlValue::Float(f64::NEG_INFINITY),
+        ".nan" | ".NaN" | ".NAN" => YamlValue::Float(f64::NAN),
+        s => {
+            if let Some(value) = parse_int(s) {
+                YamlValue::Integer(value)
+            } else if let Some(value) = parse_float(s) {
+                YamlValue::Float(value)
+            } else {
+                YamlValue::String(text)
+            }
+        }
+    }
+}
 
-    // Null
-    if s.is_empty() || s == "null" || s == "~" {
-        return YamlValue::Null;
+fn parse_int(s: &str) -> Option<i64> {
+    let radix = |digits: &str, radix: u32| {
+        (!digits.is_empty() && digits.chars().all(|it| it.is_digit(radix)))
+            .then(|| i64::from_str_radix(digits, radix).ok())
+            .flatten()
+    };
+    if let Some(hex) = s.strip_prefix("0x") {
+        return radix(hex, 16);
     }
+    if let Some(octal) = s.strip_prefix("0o") {
+        return radix(octal, 8);
+    }
+    let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+    radix(digits, 10).and_then(|_| s.parse().ok())
+}
 
-    // Boolean
-    if s == "true" || s == "True" || s == "TRUE" {
-        return YamlValue::Bool(true);
+/// `[-+]?(\.[0-9]+|[0-9]+(\.[0-9]*)?)([eE][-+]?[0-9]+)?`
+fn parse_float(s: &str) -> Option<f64> {
+    let bytes = s.as_bytes();
+    let mut i = usize::from(matches!(bytes.first(), Some(b'-' | b'+')));
+    let digits = |i: &mut usize| {
+        let start = *i;
+        while bytes.get(*i).is_some_and(u8::is_ascii_digit) {
+            *i += 1;
+        }
+        *i - start
+    };
+    let integer = digits(&mut i);
+    let fraction = if bytes.get(i) == Some(&b'.') {
+        i += 1;
+        Some(digits(&mut i))
+    } else {
+        None
+    };
+    if integer == 0 && fraction.is_none_or(|it| it == 0) {
+        return None;
     }
-    if s == "false" || s == "False" || s == "FALSE" {
-        return YamlValue::Bool(false);
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'-' | b'+')) {
+            i += 1;
+        }
+        if digits(&mut i) == 0 {
+            return None;
+        }
     }
+    (i == bytes.len()).then(|| s.parse().ok()).flatten()
+}
 
-    // Quoted string
-    if (s.starts_with('"') && s.ends_with('"') && s.len() >= 2)
-        || (s.starts_with('\'') && s.ends_with('\'') && s.len() >= 2)
-    {
-        return YamlValue::String(s[1..s.len() - 1].to_string());
+/// 能否作为不加引号的纯量写出：解析回来仍是同一字符串，且在流式集合中也安全
+fn is_plain_safe(s: &str) -> bool {
+    let Some(first) = s.bytes().next() else {
+        return false;
+    };
+    s.trim() == s
+        && !b"-?:,[]{}#&*!|>'\"%@`".contains(&first)
+        && !s.contains(": ")
+        && !s.contains(" #")
+        && !s.ends_with(':')
+        && !s
+            .bytes()
+            .any(|b| is_flow_indicator(b) || b.is_ascii_control())
+        && matches!(resolve_plain(s.to_string()), YamlValue::String(_))
+}
+
+/// 写出映射键，必要时加双引号
+pub(crate) fn write_key(key: &str, out: &mut String) {
+    if is_plain_safe(key) {
+        out.push_str(key);
+    } else {
+        write_quoted(key, false, out);
     }
+}
 
-    // Inline list [item1, item2]
-    if s.starts_with('[') && s.ends_with(']') {
-        let inner = &s[1..s.len() - 1].trim();
-        if inner.is_empty() {
-            return YamlValue::List(Vec::new());
+/// 写出带引号的字符串；单引号无法表示换行等控制字符，此时改用双引号
+pub(crate) fn write_quoted(s: &str, single: bool, out: &mut String) {
+    if single && !s.chars().any(char::is_control) {
+        out.push('\'');
+        out.push_str(&s.replace('\'', "''"));
+        out.push('\'');
+        return;
+    }
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            ch if ch.is_control() => out.push_str(&format!("\\u{:04X}", ch as u32)),
+            ch => out.push(ch),
         }
-        let items: Vec<YamlValue> = inner
-            .split(',')
-            .map(|item| parse_value(item.trim()))
-            .collect();
-        return YamlValue::List(items);
     }
+    out.push('"');
+}
 
-    // Number (integer or float)
-    if let Ok(i) = s.parse::<i64>() {
-        return YamlValue::Integer(i);
-    }
-    if let Ok(f) = s.parse::<f64>() {
-        return YamlValue::Float(f);
+/// 以单行流式写出值
+pub(crate) fn write_flow(value: &YamlValue, out: &mut String) {
+    match value {
+        YamlValue::String(s) if is_plain_safe(s) => out.push_str(s),
+        YamlValue::String(s) => write_quoted(s, false, out),
+        YamlValue::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        YamlValue::Integer(i) => out.push_str(&i.to_string()),
+        YamlValue::Float(f) if f.is_nan() => out.push_str(".nan"),
+        YamlValue::Float(f) if f.is_infinite() => {
+            out.push_str(if *f > 0.0 { ".inf" } else { "-.inf" })
+        }
+        // `{:?}` 保留小数点，避免整数值的浮点数被解析为整数
+        YamlValue::Float(f) => out.push_str(&format!("{f:?}")),
+        YamlValue::Null => out.push_str("null"),
+        YamlValue::List(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_flow(item, out);
+            }
+            out.push(']');
+        }
+        YamlValue::Map(map) => {
+            out.push('{');
+            for (i, (key, value)) in map.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_key(key, out);
+                out.push_str(": ");
+                write_flow(value, out);
+            }
+            out.push('}');
+        }
     }
+}
 
-    // Default to string
-    YamlValue::String(s.to_string())
+/// 非空集合以块式写出，每行以 `\n` 加 `indent` 个空格开头；其余值为 ` ` 加流式
+///
+/// 用于 `key:` 之后；序列项中的集合仍写为流式
+pub(crate) fn write_block(value: &YamlValue, indent: usize, out: &mut String) {
+    match value {
+        YamlValue::List(items) if !items.is_empty() => {
+            for item in items {
+                out.push('\n');
+                out.push_str(&" ".repeat(indent));
+                out.push_str("- ");
+                write_flow(item, out);
+            }
+        }
+        YamlValue::Map(map) if !map.is_empty() => {
+            for (key, value) in map {
+                out.push('\n');
+                out.push_str(&" ".repeat(indent));
+                write_key(key, out);
+                out.push(':');
+                write_block(value, indent + 2, out);
+            }
+        }
+        value => {
+            out.push(' ');
+            write_flow(value, out);
+        }
+    }
 }
 
-/// 将 YamlValue 序列化为 YAML 文本（用于往返测试）
 #[cfg(test)]
 pub fn serialize_yaml(map: &YamlMap) -> String {
     let mut result = String::new();
@@ -164,20 +1693,13 @@ pub fn serialize_yaml(map: &YamlMap) -> String {
 
     result
 }
-
 #[cfg(test)]
 fn serialize_value(value: &YamlValue, output: &mut String, indent: usize) {
     match value {
         YamlValue::String(s) => {
-            // Quote strings that contain special characters or look like other types
-            let needs_quotes = s.is_empty()
-                || s.trim() != s  // Has leading/trailing whitespace
-                || s.trim().is_empty()  // Only whitespace
-                || s == "true"
-                || s == "false"
-                || s == "null"
-                || s.parse::<i64>().is_ok()
-                || s.parse::<f64>().is_ok()
+            // Quote strings that contain special characters or resolve to other types
+            let needs_quotes = s.trim() != s  // Has leading/trailing whitespace
+                || !matches!(resolve_plain(s.clone()), YamlValue::String(_))
                 || s.contains(':')
                 || s.contains('#');
 
@@ -191,8 +1713,21 @@ fn serialize_value(value: &YamlValue, output: &mut String, indent: usize) {
         }
         YamlValue::Bool(b) => output.push_str(if *b { "true" } else { "false" }),
         YamlValue::Integer(i) => output.push_str(&i.to_string()),
-        YamlValue::Float(f) => output.push_str(&f.to_string()),
+        // `{:?}` 保留小数点，避免整数值的浮点数被解析为整数
+        YamlValue::Float(f) => output.push_str(&format!("{f:?}")),
         YamlValue::Null => output.push_str("null"),
+        YamlValue::Map(map) => {
+            if map.is_empty() {
+                output.push_str("{}");
+            }
+            for (key, value) in map {
+                output.push('\n');
+                output.push_str(&"  ".repeat(indent + 1));
+                output.push_str(key);
+                output.push_str(": ");
+                serialize_value(value, output, indent + 1);
+            }
+        }
         YamlValue::List(items) => {
             if items.is_empty() {
                 output.push_str("[]");
@@ -249,7 +1784,7 @@ mod tests {
             values in prop::collection::vec(yaml_value_strategy(), 1..5)
         ) {
             // Create a YamlMap from generated keys and values
-            let mut map = FxHashMap::default();
+            let mut map = YamlMap::default();
             for (key, value) in keys.into_iter().zip(values.into_iter()) {
                 map.insert(key, value);
             }
@@ -411,7 +1946,7 @@ mod tests {
 
     #[test]
     fn test_serialize_simple() {
-        let mut map = FxHashMap::default();
+        let mut map = YamlMap::default();
         map.insert("title".to_string(), YamlValue::String("Test".to_string()));
         map.insert("count".to_string(), YamlValue::Integer(42));
 
@@ -422,7 +1957,7 @@ mod tests {
 
     #[test]
     fn test_roundtrip() {
-        let mut map = FxHashMap::default();
+        let mut map = YamlMap::default();
         map.insert("title".to_string(), YamlValue::String("Hello".to_string()));
         map.insert("draft".to_string(), YamlValue::Bool(true));
         map.insert("count".to_string(), YamlValue::Integer(42));
@@ -437,4 +1972,152 @@ mod tests {
         assert_eq!(parsed.get("draft"), Some(&YamlValue::Bool(true)));
         assert_eq!(parsed.get("count"), Some(&YamlValue::Integer(42)));
     }
+
+    fn string(s: &str) -> YamlValue {
+        YamlValue::String(s.to_string())
+    }
+
+    #[test]
+    fn test_parse_nested_map_preserves_order() {
+        let input = "title: Post\nauthor:\n  name: Ada\n  links:\n    - https://a.dev\n    - https://b.dev\nzeta: 1\nalpha: 2";
+        let result = parse_yaml(input).unwrap();
+        assert_eq!(
+            result.keys().collect::<Vec<_>>(),
+            ["title", "author", "zeta", "alpha"]
+        );
+        assert_eq!(
+            result.get_path("author.name").and_then(YamlValue::as_str),
+            Some("Ada")
+        );
+        assert_eq!(
+            result.get_path("author.links.1"),
+            Some(&string("https://b.dev"))
+        );
+        assert_eq!(result.get_path("author.missing"), None);
+        assert_eq!(result.get_path("title.0"), None);
+    }
+
+    #[test]
+    fn test_parse_compact_sequences() {
+        let input = "items:\n- name: a\n  done: true\n- name: b\nmatrix:\n  - - 1\n    - 2";
+        let result = parse_yaml(input).unwrap();
+        assert_eq!(
+            result.get_path("items.0.done"),
+            Some(&YamlValue::Bool(true))
+        );
+        assert_eq!(result.get_path("items.1.name"), Some(&string("b")));
+        assert_eq!(result.get_path("matrix.0.1"), Some(&YamlValue::Integer(2)));
+    }
+
+    #[test]
+    fn test_parse_block_scalars() {
+        let input = "literal: |\n  line one\n    indented\n\n  line three\nfolded: >-\n  a\n  b\n\n  c\nkeep: |+\n  x\n\nlast: end";
+        let result = parse_yaml(input).unwrap();
+        assert_eq!(
+            result.get_str("literal"),
+            Some("line one\n  indented\n\nline three\n")
+        );
+        assert_eq!(result.get_str("folded"), Some("a b\nc"));
+        assert_eq!(result.get_str("keep"), Some("x\n\n"));
+        assert_eq!(result.get_str("last"), Some("end"));
+    }
+
+    #[test]
+    fn test_parse_multiline_flow_and_plain() {
+        let input = "tags: [\n  rust,   # comment\n  \"yaml\",\n  {k: v},\n]\nmeta: {a: 1, b: [x, y]}\ndesc: first\n  second\n\n  third";
+        let result = parse_yaml(input).unwrap();
+        let tags = result.get_list("tags").unwrap();
+        assert_eq!(tags[..2], [string("rust"), string("yaml")]);
+        assert_eq!(tags[2].get_path("k"), Some(&string("v")));
+        assert_eq!(result.get_path("meta.b.1"), Some(&string("y")));
+        assert_eq!(result.get_str("desc"), Some("first second\nthird"));
+    }
+
+    #[test]
+    fn test_parse_quoted_keys_and_scalars() {
+        let input =
+            "\"key: one\": 1\n'it''s': 'a ''b'''\nesc: \"tab\\tand \\u00e9\"\nfold: \"a\n  b\"";
+        let result = parse_yaml(input).unwrap();
+        assert_eq!(result.get("key: one"), Some(&YamlValue::Integer(1)));
+        assert_eq!(result.get_str("it's"), Some("a 'b'"));
+        assert_eq!(result.get_str("esc"), Some("tab\tand é"));
+        assert_eq!(result.get_str("fold"), Some("a b"));
+    }
+
+    #[test]
+    fn test_parse_comments_after_values() {
+        let input = "# leading\ntitle: Hello # trailing\nurl: http://x.dev/#frag\ncount: 3 # n\n";
+        let result = parse_yaml(input).unwrap();
+        assert_eq!(result.get_str("title"), Some("Hello"));
+        assert_eq!(result.get_str("url"), Some("http://x.dev/#frag"));
+        assert_eq!(result.get("count"), Some(&YamlValue::Integer(3)));
+    }
+
+    #[test]
+    fn test_parse_anchors_aliases_and_tags() {
+        let input = "base: &base\n  lang: en\ncopy: *base\nversion: !!str 1.0\nhex: 0x1F\nnone: ~\ninf: -.inf";
+        let result = parse_yaml(input).unwrap();
+        assert_eq!(result.get("copy"), result.get("base"));
+        assert_eq!(result.get_str("version"), Some("1.0"));
+        assert_eq!(result.get("hex"), Some(&YamlValue::Integer(31)));
+        assert!(result.get("none").is_some_and(YamlValue::is_null));
+        assert_eq!(
+            result.get("inf").and_then(YamlValue::as_f64),
+            Some(f64::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn test_parse_crlf_line_endings() {
+        let result = parse_yaml("a: 1\r\nb:\r\n  - x\r\n").unwrap();
+        assert_eq!(result.get_path("b.0"), Some(&string("x")));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| YamlParser::new(input).parse_document().unwrap_err();
+        assert_eq!(error("a: 1\na: 2").reason, "duplicate mapping key");
+        assert_eq!(error("a: 1\na: 2").offset, 5);
+        assert_eq!(error("a: *missing").reason, "undefined alias");
+        assert_eq!(error("a: [1, 2").reason, "unterminated flow sequence");
+        assert_eq!(
+            error("a:\n\tb: 1").reason,
+            "tabs are not allowed for indentation"
+        );
+        assert_eq!(
+            error("a: b: c").reason,
+            "mapping values are not allowed in this context"
+        );
+        assert_eq!(parse_yaml("- a\n- b"), None);
+        assert_eq!(parse_yaml("just text"), None);
+    }
+
+    #[test]
+    fn test_alias_expansion_limit() {
+        let mut input = String::from("a0: &a0 [x, x, x, x, x, x, x, x, x, x]\n");
+        for i in 1..10 {
+            let prev = format!("*a{}", i - 1);
+            input.push_str(&format!("a{i}: &a{i} [{}]\n", vec![prev; 10].join(", ")));
+        }
+        assert_eq!(
+            YamlParser::new(&input).parse_document().unwrap_err().reason,
+            "alias expansion limit exceeded"
+        );
+    }
+
+    #[test]
+    fn test_map_api() {
+        let mut map: YamlMap = [("b".to_string(), YamlValue::Integer(1))]
+            .into_iter()
+            .collect();
+        map.insert("a", YamlValue::Integer(2));
+        assert_eq!(
+            map.insert("b", YamlValue::Integer(3)),
+            Some(YamlValue::Integer(1))
+        );
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["b", "a"]);
+        assert_eq!(map.remove("b"), Some(YamlValue::Integer(3)));
+        assert!(!map.contains_key("b"));
+        assert_eq!(map.len(), 1);
+    }
 }
@@ -1,6 +1,7 @@
 use crate::parser::Parser;
 use crate::span::MergedSpan;
 
+mod abbreviation;
 pub(crate) mod bracket;
 mod code;
 mod comment;
@@ -13,11 +14,13 @@ mod link;
 mod link_reference;
 mod math;
 mod newline;
+mod ruby;
 mod tag;
 #[cfg(test)]
 mod tests;
 mod text;
 
+pub(crate) use abbreviation::{process_abbreviation_definitions, wrap_abbreviations};
 pub(crate) use footnote::process_footnote_list;
 pub(crate) use link_reference::is_link_reference_line;
 pub(crate) use link_reference::process_link_reference;
@@ -79,15 +82,28 @@ pub(super) fn process<'input>(
         special_table[b'\'' as usize] = true;
         special_table[b'"' as usize] = true;
     }
-    if ctx.parser.options.jsx_like_component {
+    if ctx.parser.options.jsx_like_component || ctx.parser.options.ruby {
         special_table[b'{' as usize] = true;
     }
+    if ctx.parser.options.superscript {
+        special_table[b'^' as usize] = true;
+    }
+    if ctx.parser.options.subscript {
+        special_table[b'~' as usize] = true;
+    }
+    if ctx.parser.options.insert {
+        special_table[b'+' as usize] = true;
+    }
+    if ctx.parser.options.spoiler {
+        special_table[b'|' as usize] = true;
+    }
 
     let gate_flags = GateFlags {
         ofm: ctx.parser.options.obsidian_flavored,
         non_default: !ctx.parser.options.default_flavored,
         gfm_autolink: ctx.parser.options.github_flavored
             && ctx.parser.options.gfm_extended_autolink,
+        superscript: ctx.parser.options.superscript,
     };
 
     // 累积连续文本的字节偏移范围，避免逐字符 to_string() 堆分配
@@ -120,21 +136,30 @@ pub(super) fn process<'input>(
             // Code
             b'`' => code::process(&mut ctx),
             // Emphasis, Strong emphasis
-            b'*' | b'_' => delimiter::before(&mut ctx, false, false),
+            b'*' | b'_' => delimiter::before(&mut ctx),
             // Smart punctuation quotes
-            b'\'' | b'"' if ctx.parser.options.smart_punctuation => {
-                delimiter::before(&mut ctx, false, false)
-            }
-            // Strikethrough(GFM)
-            b'~' if ctx.parser.options.github_flavored || ctx.parser.options.obsidian_flavored => {
-                delimiter::before(&mut ctx, true, false)
+            b'\'' | b'"' if ctx.parser.options.smart_punctuation => delimiter::before(&mut ctx),
+            // Strikethrough(GFM), Subscript
+            b'~' if ctx.parser.options.github_flavored
+                || ctx.parser.options.obsidian_flavored
+                || ctx.parser.options.subscript =>
+            {
+                delimiter::before(&mut ctx)
             }
             // Highlight(OFM)
-            b'=' if ctx.parser.options.obsidian_flavored => {
-                delimiter::before(&mut ctx, false, true)
-            }
-            // Link or Wikilink
-            b'[' => {
+            b'=' if ctx.parser.options.obsidian_flavored => delimiter::before(&mut ctx),
+            // Insert
+            b'+' if ctx.parser.options.insert => delimiter::before(&mut ctx),
+            // Spoiler
+            b'|' if ctx.parser.options.spoiler => delimiter::before(&mut ctx),
+            // Link or Wikilink, Ruby
+            b'[' => 'multi: {
+                if ctx.parser.options.ruby {
+                    if ruby::process_bracketed(&mut ctx) {
+                        break 'multi true;
+                    }
+                    ctx.line.resume(&snapshot);
+                }
                 if let Some(current_span) = ctx.line.current_span() {
                     if current_span.get(1) == Some(b'[') && ctx.parser.options.obsidian_flavored {
                         link::process_wikilink(&mut ctx)
@@ -186,12 +211,27 @@ pub(super) fn process<'input>(
             {
                 link::process_gfm_autolink(&mut ctx)
             }
-            b'{' if ctx.parser.options.jsx_like_component => html::process(&mut ctx),
+            // Ruby, JSX-like component
+            b'{' => 'multi: {
+                if ctx.parser.options.ruby {
+                    if ruby::process_braced(&mut ctx) {
+                        break 'multi true;
+                    }
+                    ctx.line.resume(&snapshot);
+                }
+                ctx.parser.options.jsx_like_component && html::process(&mut ctx)
+            }
             // Inline math ($)
             0x24 if !ctx.parser.options.default_flavored => math::process(&mut ctx),
-            // Inline footnote / block id (OFM) (^)
-            b'^' if ctx.parser.options.obsidian_flavored => {
-                footnote::process_inline(&mut ctx) || link::process_block_id(&mut ctx)
+            // Inline footnote / block id (OFM), Superscript (^)
+            b'^' => 'multi: {
+                if ctx.parser.options.obsidian_flavored {
+                    if footnote::process_inline(&mut ctx) || link::process_block_id(&mut ctx) {
+                        break 'multi true;
+                    }
+                    ctx.line.resume(&snapshot);
+                }
+                ctx.parser.options.superscript && delimiter::before(&mut ctx)
             }
             // Emoji (:)
             b':' if !ctx.parser.options.default_flavored => emoji::process(&mut ctx),
@@ -233,6 +273,7 @@ struct GateFlags {
     ofm: bool,
     non_default: bool,
     gfm_autolink: bool,
+    superscript: bool,
 }
 
 /// 扫描环内的 1 字节前瞻门控：返回 `false` 表示该特殊字节不可能开启任何
@@ -248,7 +289,9 @@ fn scan_gate(b: u8, next: u8, prev: Option<u8>, f: GateFlags) -> bool {
         b'h' | b'H' if f.gfm_autolink => matches!(next, b't' | b'T'),
         b'w' | b'W' if f.gfm_autolink => matches!(next, b'w' | b'W'),
         b'=' if f.ofm => next == b'=',
-        b'^' if f.ofm => {
+        b'+' => next == b'+',
+        b'|' => next == b'|',
+        b'^' if f.ofm && !f.superscript => {
             matches!(next, b'[' | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-')
         }
         b'#' if f.ofm => {
@@ -302,8 +345,10 @@ fn should_try_special(ctx: &ProcessCtx, byte: u8) -> bool {
         }
         // OFM highlight delimiter is exactly "==".
         b'=' if ctx.parser.options.obsidian_flavored => ctx.line.get(1) == Some(b'='),
+        // Insert / spoiler delimiters are exactly "++" / "||".
+        b'+' | b'|' => ctx.line.get(1) == Some(byte),
         // Inline footnote starts with ^[, block id starts with ^[A-Za-z0-9-].
-        b'^' if ctx.parser.options.obsidian_flavored => {
+        b'^' if ctx.parser.options.obsidian_flavored && !ctx.parser.options.superscript => {
             matches!(
                 ctx.line.get(1),
                 Some(b'[' | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-')
@@ -0,0 +1,297 @@
+use crate::ast::MarkdownNode;
+use crate::ast::abbreviation::{Abbreviation, AbbreviationDefinition};
+use crate::ast::text::{SourceSpan, TextRef};
+use crate::node::Node;
+use crate::parser::Parser;
+use crate::span::Span;
+
+/// 解析 `*[label]: title` 定义行（Markdown Extra 语法，`]` 与 `:` 之间允许一个空格）
+fn scan_definition(line: &Span) -> Option<AbbreviationDefinition> {
+    let source = line.source_slice();
+    let bytes = &source[line.cursor()..line.end()];
+    let indent = bytes.iter().take_while(|&&b| b == b' ').count();
+    if indent > 3 {
+        return None;
+    }
+    let rest = bytes[indent..].strip_prefix(b"*[")?;
+    let close = memchr::memchr(b']', rest)?;
+    let label = rest[..close].trim_ascii();
+    if label.is_empty() {
+        return None;
+    }
+    let rest = &rest[close + 1..];
+    let rest = rest.strip_prefix(b" ").unwrap_or(rest);
+    let title = rest.strip_prefix(b":")?.trim_ascii();
+    // 切分点均为 ASCII 字节，UTF-8 边界保持有效
+    let to_string = |it: &[u8]| String::from_utf8_lossy(it).into_owned();
+    Some(AbbreviationDefinition {
+        label: to_string(label),
+        title: to_string(title),
+        span: SourceSpan::new(line.cursor() as u32, line.end() as u32),
+    })
+}
+
+/// 从段落中移除缩写定义行并收集到 `Parser::abbreviations`；
+/// 与 PHP Markdown Extra 一致，定义行可出现在段落任意位置。
+pub(crate) fn process_abbreviation_definitions(parser: &mut Parser, node_id: usize) {
+    if !matches!(parser.tree[node_id].body, MarkdownNode::Paragraph) {
+        return;
+    }
+    if !parser
+        .inlines
+        .get(node_id)
+        .is_some_and(|spans| spans.iter().any(|span| scan_definition(span).is_some()))
+    {
+        return;
+    }
+    let Some(spans) = parser.inlines.remove(node_id) else {
+        return;
+    };
+    let mut remains = crate::pending::PendingSegments::new();
+    for span in spans {
+        match scan_definition(&span) {
+            Some(definition) => parser.abbreviations.push(definition),
+            None => remains.push(span),
+        }
+    }
+    let (Some(first), Some(last)) = (remains.first(), remains.last()) else {
+        parser.tree.remove(node_id);
+        return;
+    };
+    parser.tree[node_id].span = SourceSpan::new(first.cursor() as u32, last.end() as u32);
+    parser.inlines.insert(node_id, remains);
+}
+
+/// 按标签长度降序排列、同名仅保留首个定义的匹配表
+struct AbbreviationTable<'a> {
+    entries: Vec<&'a AbbreviationDefinition>,
+    first_bytes: [bool; 256],
+}
+
+impl<'a> AbbreviationTable<'a> {
+    fn new(definitions: &'a [AbbreviationDefinition]) -> Self {
+        let mut entries: Vec<&AbbreviationDefinition> = Vec::with_capacity(definitions.len());
+        let mut first_bytes = [false; 256];
+        for definition in definitions {
+            if entries.iter().any(|it| it.label == definition.label) {
+                continue;
+            }
+            first_bytes[definition.label.as_bytes()[0] as usize] = true;
+            entries.push(definition);
+        }
+        entries.sort_by_key(|it| std::cmp::Reverse(it.label.len()));
+        Self {
+            entries,
+            first_bytes,
+        }
+    }
+
+    /// 返回 text 中全部整词命中：(起始字节, 结束字节, 条目下标)
+    fn find_all(&self, text: &str) -> Vec<(usize, usize, usize)> {
+        let mut found = Vec::new();
+        let bytes = text.as_bytes();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            if self.first_bytes[bytes[pos] as usize]
+                && text.is_char_boundary(pos)
+                && text[..pos].chars().next_back().is_none_or(is_boundary)
+            {
+                let hit = self.entries.iter().position(|entry| {
+                    text[pos..].starts_with(entry.label.as_str())
+                        && text[pos + entry.label.len()..]
+                            .chars()
+                            .next()
+                            .is_none_or(is_boundary)
+                });
+                if let Some(index) = hit {
+                    let end = pos + self.entries[index].label.len();
+                    found.push((pos, end, index));
+                    pos = end;
+                    continue;
+                }
+            }
+            pos += 1;
+        }
+        found
+    }
+}
+
+/// 整词边界：非字母数字；CJK 字符之间没有空格，同样视为边界
+fn is_boundary(ch: char) -> bool {
+    !(ch.is_alphanumeric() || ch == '_') || crate::utils::cjk::is_cjk(ch)
+}
+
+/// 在 `id` 物化完成后，把其后代文本中的缩写包裹为 `Abbreviation` 节点
+pub(crate) fn wrap_abbreviations(parser: &mut Parser, id: usize) {
+    let definitions = std::mem::take(&mut parser.abbreviations);
+    let table = AbbreviationTable::new(&definitions);
+    let mut texts = Vec::new();
+    collect_text_nodes(parser, id, &mut texts);
+    for node in texts {
+        wrap_text_node(parser, &table, node);
+    }
+    parser.abbreviations = definitions;
+}
+
+fn collect_text_nodes(parser: &Parser, id: usize, out: &mut Vec<usize>) {
+    let mut child = parser.tree.get_first_child(id);
+    while let Some(idx) = child {
+        match &parser.tree[idx].body {
+            MarkdownNode::Text(..) => out.push(idx),
+            MarkdownNode::Code(..)
+            | MarkdownNode::Html(..)
+            | MarkdownNode::Math(..)
+            | MarkdownNode::Image(..)
+            | MarkdownNode::Embed(..)
+            | MarkdownNode::Abbreviation(..) => {}
+            _ => collect_text_nodes(parser, idx, out),
+        }
+        child = parser.tree.get_next(idx);
+    }
+}
+
+fn wrap_text_node(parser: &mut Parser, table: &AbbreviationTable, node: usize) {
+    let source = parser.scanner.source_str();
+    let MarkdownNode::Text(text) = &parser.tree[node].body else {
+        return;
+    };
+    let text = text.clone();
+    let resolved = text.resolve(source);
+    let hits = table.find_all(resolved);
+    if hits.is_empty() {
+        return;
+    }
+    let node_span = parser.tree[node].span;
+    // 命中位置映射回源码：Source 直接平移；Owned（经转义、智能标点等改写）
+    // 在源码区间内重新匹配，命中序列一致时才采用，否则退化为整个节点区间
+    let source_hits: Option<Vec<(u32, u32)>> = match &text {
+        TextRef::Source(span) => Some(
+            hits.iter()
+                .map(|&(start, end, _)| (span.start + start as u32, span.start + end as u32))
+                .collect(),
+        ),
+        TextRef::Owned(_) => {
+            let raw = source
+                .get(node_span.start as usize..node_span.end as usize)
+                .unwrap_or("");
+            let raw_hits = table.find_all(raw);
+            (raw_hits.len() == hits.len() && raw_hits.iter().zip(&hits).all(|(a, b)| a.2 == b.2))
+                .then(|| {
+                    raw_hits
+                        .iter()
+                        .map(|&(start, end, _)| {
+                            (
+                                node_span.start + start as u32,
+                                node_span.end.min(node_span.start + end as u32),
+                            )
+                        })
+                        .collect()
+                })
+        }
+    };
+    let location = |index: usize| -> (u32, u32) {
+        source_hits
+            .as_ref()
+            .map(|it| it[index])
+            .unwrap_or((node_span.start, node_span.end))
+    };
+
+    let piece = |start: usize, end: usize| -> TextRef {
+        match &text {
+            TextRef::Source(span) => TextRef::Source(SourceSpan::new(
+                span.start + start as u32,
+                span.start + end as u32,
+            )),
+            TextRef::Owned(owned) => TextRef::Owned(owned[start..end].to_string()),
+        }
+    };
+    // 原节点保留首个命中前的文本，其后依次插入 Abbreviation 与剩余文本
+    let (first_start, _, _) = hits[0];
+    parser.tree[node].body = MarkdownNode::Text(piece(0, first_start));
+    parser.tree[node].span.end = location(0).0.max(node_span.start);
+    let mut prev = node;
+    for (index, &(start, end, entry)) in hits.iter().enumerate() {
+        let (loc_start, loc_end) = location(index);
+        let abbreviation = MarkdownNode::Abbreviation(Box::new(Abbreviation {
+            title: table.entries[entry].title.clone(),
+        }));
+        let abbr_id = insert_node_after(parser, prev, abbreviation, (loc_start, loc_end));
+        let child = Node::new(MarkdownNode::Text(piece(start, end)), loc_start);
+        let child_id = parser.tree.append_child(abbr_id, child);
+        parser.tree[child_id].span.end = loc_end;
+        parser.mark_as_processed(child_id);
+        prev = abbr_id;
+        let next_start = hits.get(index + 1).map_or(resolved.len(), |it| it.0);
+        if next_start > end {
+            let tail_end = if index + 1 < hits.len() {
+                location(index + 1).0
+            } else {
+                node_span.end
+            };
+            let tail = MarkdownNode::Text(piece(end, next_start));
+            prev = insert_node_after(parser, prev, tail, (loc_end, tail_end.max(loc_end)));
+        }
+    }
+    if first_start == 0 {
+        parser.tree.remove(node);
+    }
+}
+
+fn insert_node_after(
+    parser: &mut Parser,
+    sibling: usize,
+    body: MarkdownNode,
+    location: (u32, u32),
+) -> usize {
+    let id = parser
+        .tree
+        .insert_after(sibling, Node::new(body, location.0));
+    parser.tree[id].span.end = location.1;
+    parser.mark_as_processed(id);
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AbbreviationTable, scan_definition};
+    use crate::scanner::Scanner;
+    use crate::span::Span;
+
+    fn definition(line: &str) -> Option<(String, String)> {
+        let mut scanner = Scanner::new(line);
+        let span = Span::extract(&mut scanner)?;
+        scan_definition(&span).map(|it| (it.label, it.title))
+    }
+
+    #[test]
+    fn scans_definition_lines() {
+        assert_eq!(
+            definition("*[HTML]: Hyper Text Markup Language"),
+            Some(("HTML".into(), "Hyper Text Markup Language".into()))
+        );
+        assert_eq!(
+            definition("   *[W3C Group] : World Wide Web"),
+            Some(("W3C Group".into(), "World Wide Web".into()))
+        );
+        assert_eq!(definition("*[TBD]:"), Some(("TBD".into(), "".into())));
+        assert_eq!(definition("    *[HTML]: indented"), None);
+        assert_eq!(definition("*[]: empty"), None);
+        assert_eq!(definition("*[HTML] missing colon"), None);
+    }
+
+    #[test]
+    fn matches_whole_words_longest_first() {
+        let mut scanner = Scanner::new("*[HTML]: a\n*[HTML5]: b\n*[HTML]: c");
+        let definitions: Vec<_> = std::iter::from_fn(|| Span::extract(&mut scanner))
+            .filter_map(|span| scan_definition(&span))
+            .collect();
+        let table = AbbreviationTable::new(&definitions);
+        assert_eq!(table.entries.len(), 2);
+        let hits = table.find_all("HTML5 and HTML, not XHTML or HTMLs; 用HTML写");
+        let labels: Vec<_> = hits
+            .iter()
+            .map(|&(_, _, entry)| table.entries[entry].title.as_str())
+            .collect();
+        assert_eq!(labels, ["b", "a", "a"]);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::ast::syntax::{LinkSyntax, NodeSyntax};
+use crate::ast::text::SourceSpan;
 use crate::ast::{self, MarkdownNode};
 use crate::inlines::ProcessCtx;
 use crate::utils;
@@ -125,6 +127,7 @@ pub(super) fn process(ctx: &mut ProcessCtx) -> bool {
         None => return false,
     };
     let is_image = parser.bracket_store[opener_idx].is_image();
+    let close_end = current_span.cursor();
     // OFM/GFM 语义：bracket 自身内容是已定义脚注标签时优先解析为脚注引用，
     // 不再把它当作后随 `[...]`/`(...)` 形式的链接文本（`[^a][^b]` 是两个引用，
     // `[^a](t)` 是引用 + 字面括号）。未定义标签保持原有引用链接回退。
@@ -148,17 +151,30 @@ pub(super) fn process(ctx: &mut ProcessCtx) -> bool {
         let start_location = parser.tree[opener_inl].span.start;
         // 与旧行为保持一致：image 分支优先（`![^x][^y]` 边缘按图片处理）
         let node = if is_image {
-            let (url, title) = match scanned {
-                super::link::ScannedLink::Resource { url, title } => (url, title),
-                super::link::ScannedLink::Footnote { label } => {
-                    (crate::ast::text::TextRef::Owned(label), None)
-                }
+            let (url, title, reference, form) = match scanned {
+                super::link::ScannedLink::Resource {
+                    url,
+                    title,
+                    reference,
+                    form,
+                } => (url, title, reference, form),
+                super::link::ScannedLink::Footnote { label } => (
+                    crate::ast::text::TextRef::Owned(label),
+                    None,
+                    None,
+                    LinkSyntax::Shortcut,
+                ),
             };
             let size = parser.bracket_store[opener_idx].image_size.take();
-            parser.append_free_node(
+            let node = parser.append_free_node(
                 MarkdownNode::Image(Box::new(ast::image::Image { url, title, size })),
                 start_location,
-            )
+            );
+            if let Some(reference) = reference {
+                parser.link_records.references.insert(node, reference);
+            }
+            parser.record_syntax(node, NodeSyntax::Image { form });
+            node
         } else if let super::link::ScannedLink::Footnote { label } = &scanned {
             let label = label.clone();
             // index/ref_count 此处为临时值；parse_footnote_list 会按源码位置统一最终化
@@ -185,16 +201,27 @@ pub(super) fn process(ctx: &mut ProcessCtx) -> bool {
             parser.footnote_ref_nodes.push((node, label));
             node
         } else {
-            let super::link::ScannedLink::Resource { url, title } = scanned else {
+            let super::link::ScannedLink::Resource {
+                url,
+                title,
+                reference,
+                form,
+            } = scanned
+            else {
                 unreachable!()
             };
-            parser.append_free_node(
+            let node = parser.append_free_node(
                 MarkdownNode::Link(Box::new(ast::link::Link::Default(ast::link::DefaultLink {
                     url,
                     title,
                 }))),
                 start_location,
-            )
+            );
+            if let Some(reference) = reference {
+                parser.link_records.references.insert(node, reference);
+            }
+            parser.record_syntax(node, NodeSyntax::Link { form });
+            node
         };
         let mut temp = parser.tree.get_next(opener_inl);
         while let Some(item) = temp {
@@ -221,11 +248,59 @@ pub(super) fn process(ctx: &mut ProcessCtx) -> bool {
         }
         true
     } else {
+        record_unresolved(parser, opener_idx, current_span, close_end);
         remove_brackets(&parser.bracket_store, brackets);
         false
     };
 }
 
+/// 记录未能解析的完整/折叠引用链接与未定义的脚注引用，供链接检查使用。
+/// 捷径形式 `[text]` 常为普通方括号文本，不作记录。
+fn record_unresolved(
+    parser: &mut crate::parser::Parser,
+    opener_idx: usize,
+    span: &crate::span::Span,
+    close_end: usize, // 刚跳过的 ']' 之后
+) {
+    let opener = &parser.bracket_store[opener_idx];
+    let start = parser.tree[opener.node].span.start;
+    let content_start = opener.index + usize::from(opener.is_image());
+    let source = span.source_slice();
+    let Some(content) = source
+        .get(content_start + 1..close_end - 1)
+        .and_then(|it| std::str::from_utf8(it).ok())
+    else {
+        return;
+    };
+    let after = span.slice_from_abs(close_end, span.end());
+    let records = &mut parser.link_records;
+    match super::link::scan_link_label(&after) {
+        Some((size, label)) => {
+            let label = match &label[1..label.len() - 1] {
+                "" => content,
+                label => label,
+            };
+            if !label.starts_with('^') && !label.trim().is_empty() {
+                records.undefined_references.push((
+                    label.to_string(),
+                    SourceSpan::new(start, (close_end + size) as u32),
+                ));
+            }
+        }
+        None if !opener.is_image() => {
+            if let Some(label) = content.strip_prefix('^')
+                && !label.is_empty()
+                && !label.contains(|ch: char| ch.is_whitespace() || matches!(ch, '[' | ']' | '\\'))
+            {
+                records
+                    .undefined_footnotes
+                    .push((label.to_string(), SourceSpan::new(start, close_end as u32)));
+            }
+        }
+        None => {}
+    }
+}
+
 /// bracket 自身内容（`[` 与已消费的 `]` 之间）为 `^` + 已定义脚注标签时返回该标签。
 fn own_footnote_label(
     open_index: usize, // '[' 的绝对字节偏移
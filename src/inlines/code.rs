@@ -1,3 +1,4 @@
+use crate::ast::syntax::NodeSyntax;
 use crate::ast::{MarkdownNode, code};
 use crate::inlines::ProcessCtx;
 use memchr::memchr;
@@ -63,6 +64,12 @@ pub(super) fn process(
         MarkdownNode::Code(Box::new(code::Code::Inline(code::InlineCode {}))),
         (code_start_location, code_end_location),
     );
+    parser.record_syntax(
+        parent,
+        NodeSyntax::Code {
+            backticks: marker_length,
+        },
+    );
     let in_table = matches!(
         parser.tree[*id].body,
         MarkdownNode::TableHeadCol | MarkdownNode::TableDataCol
@@ -1,5 +1,7 @@
 use crate::ast::MarkdownNode;
+use crate::ast::syntax::NodeSyntax;
 use crate::inlines::ProcessCtx;
+use crate::parser::ParserOptions;
 use crate::span::MergedSpan;
 
 /// delimiter 工作区条目（P2）：以 `Parser::delimiter_store` 中的索引成链，
@@ -187,6 +189,7 @@ fn is_cjk_sequence_before(source: &[u8], pos: usize) -> bool {
 pub(super) fn scan_delimiters(
     line: &mut MergedSpan,
     cjk_friendly: bool,
+    intraword_tilde: bool,
 ) -> (u8, usize, bool, bool) {
     let start = line.cursor();
     let Some(initial_byte) = line.peek() else {
@@ -259,6 +262,8 @@ pub(super) fn scan_delimiters(
         )
     };
     let (left, right) = match initial_byte {
+        // 下标 `~` 与 `*` 一样允许词内使用（`H~2~O`）
+        b'~' if intraword_tilde && length == 1 => (left_flanking, right_flanking),
         b'_' | b'~' | b'=' => (
             left_flanking && (!right_flanking || before_is_unicode_punctuation_sequence),
             right_flanking && (!left_flanking || after_is_unicode_punctuation_sequence),
@@ -296,6 +301,28 @@ fn is_multibyte_punctuation_before(source: &[u8], pos: usize) -> bool {
     }
 }
 
+/// 按标记字节与连续长度判断该 run 能否进入 delimiter 链
+///
+/// 启用下标时单个 `~` 归下标，删除线只接受 `~~`；
+/// 未启用下标时保持 GFM 行为，`~` 与 `~~` 均为删除线。
+fn accepts_run(options: &ParserOptions, byte: u8, length: usize) -> bool {
+    let strikethrough = options.github_flavored || options.obsidian_flavored;
+    match byte {
+        b'*' | b'_' => true,
+        b'\'' | b'"' => options.smart_punctuation,
+        b'~' => match length {
+            1 => options.subscript || strikethrough,
+            2 => strikethrough,
+            _ => false,
+        },
+        b'=' => options.obsidian_flavored && length == 2,
+        b'^' => options.superscript && length == 1,
+        b'+' => options.insert && length == 2,
+        b'|' => options.spoiler && length == 2,
+        _ => false,
+    }
+}
+
 pub(super) fn before(
     ProcessCtx {
         line,
@@ -304,13 +331,15 @@ pub(super) fn before(
         delimiters,
         ..
     }: &mut ProcessCtx,
-    enabled_gfm_strikethrough: bool,
-    enabled_ofm_highlight: bool,
 ) -> bool {
     if line.peek().is_none() {
         return false;
     }
-    let scan_result = scan_delimiters(line, parser.options.cjk_friendly_delimiters);
+    let scan_result = scan_delimiters(
+        line,
+        parser.options.cjk_friendly_delimiters,
+        parser.options.subscript,
+    );
     let start = line.cursor();
     let (text, locations) = {
         // P1b：标记本身就是连续源码切片，保存区间不分配 String
@@ -339,9 +368,7 @@ pub(super) fn before(
     let node = parser.append_to(*id, MarkdownNode::Text(text), locations);
     parser.mark_as_processed(node);
     if (scan_result.2 || scan_result.3)
-        && (parser.options.smart_punctuation || !matches!(scan_result.0, b'\'' | b'"'))
-        && (!enabled_gfm_strikethrough || scan_result.1 == 1 || scan_result.1 == 2)
-        && (!enabled_ofm_highlight || scan_result.1 == 2)
+        && accepts_run(&parser.options, scan_result.0, scan_result.1)
     {
         let store = &mut parser.delimiter_store;
         let idx = store.len();
@@ -369,7 +396,9 @@ pub(super) fn process(
     }: &mut ProcessCtx,
     stack_bottom: usize,
 ) {
-    let mut openers_bottom = [stack_bottom; 21];
+    let mut openers_bottom = [stack_bottom; 27];
+    // 启用下标时 `~` 与 `~~` 是两种语法，开闭标记长度必须一致
+    let exact_tilde = parser.options.subscript;
     // 定位 position >= stack_bottom 的最底部 delimiter 作为起始 closer
     let mut candidate = *delimiters;
     let mut closer: Option<usize> = None;
@@ -409,6 +438,9 @@ pub(super) fn process(
             b'*' => 8 + (if closer_can_open { 3 } else { 0 }) + (closer_length % 3),
             b'~' => 14 + if closer_can_open { 2 } else { 0 } + closer_length,
             b'=' => 19 + if closer_can_open { 1 } else { 0 },
+            b'^' => 21 + if closer_can_open { 1 } else { 0 },
+            b'+' => 23 + if closer_can_open { 1 } else { 0 },
+            b'|' => 25 + if closer_can_open { 1 } else { 0 },
             _ => panic!("Invalid delimiter byte {}", closer_byte as char),
         };
         let mut opener = closer_prev;
@@ -420,7 +452,8 @@ pub(super) fn process(
             let odd_match = (closer_can_open || od.can_close)
                 && closer_length % 3 != 0
                 && (od.length + closer_length) % 3 == 0;
-            if od.can_open && od.delimiter_byte == closer_byte && !odd_match {
+            let length_match = !(exact_tilde && closer_byte == b'~') || od.length == closer_length;
+            if od.can_open && od.delimiter_byte == closer_byte && !odd_match && length_match {
                 opener_found = true;
                 break;
             }
@@ -428,7 +461,7 @@ pub(super) fn process(
         }
         let old_closer = closer;
         match closer_byte {
-            b'*' | b'_' | b'~' | b'=' => {
+            b'*' | b'_' | b'~' | b'=' | b'^' | b'+' | b'|' => {
                 if let Some(opener_idx) = opener.filter(|_| opener_found) {
                     let opener_inl = parser.delimiter_store[opener_idx].node;
                     let closer_inl = parser.delimiter_store[closer_idx].node;
@@ -463,10 +496,11 @@ pub(super) fn process(
                         opener_char_nums = text.len(source);
                         parser.tree[opener_inl].span.end -= used_delimiter_nums as u32;
                     }
+                    // 闭合方从紧贴内容的一侧（开头）消耗分隔符
                     if let MarkdownNode::Text(text) = &mut parser.tree[closer_inl].body {
-                        text.truncate(text.len(source) - used_delimiter_nums, source);
+                        text.truncate_front(used_delimiter_nums, source);
                         closer_char_nums = text.len(source);
-                        parser.tree[closer_inl].span.end -= used_delimiter_nums as u32;
+                        parser.tree[closer_inl].span.start += used_delimiter_nums as u32;
                     }
                     let start_location = parser.tree[opener_inl].span.end;
                     let node = match closer_byte {
@@ -477,14 +511,30 @@ pub(super) fn process(
                                 parser.append_free_node(MarkdownNode::Strong, start_location)
                             }
                         }
+                        b'~' if exact_tilde && used_delimiter_nums == 1 => {
+                            parser.append_free_node(MarkdownNode::Subscript, start_location)
+                        }
                         b'~' => {
                             parser.append_free_node(MarkdownNode::Strikethrough, start_location)
                         }
                         b'=' => parser.append_free_node(MarkdownNode::Highlighting, start_location),
+                        b'^' => parser.append_free_node(MarkdownNode::Superscript, start_location),
+                        b'+' => parser.append_free_node(MarkdownNode::Insert, start_location),
+                        b'|' => parser.append_free_node(MarkdownNode::Spoiler, start_location),
                         _ => panic!("Invalid delimiter byte {}", closer_byte as char),
                     };
-                    parser.tree[node].span.end =
-                        parser.tree[closer_inl].span.end + used_delimiter_nums as u32;
+                    parser.tree[node].span.end = parser.tree[closer_inl].span.start;
+                    if matches!(closer_byte, b'*' | b'_') {
+                        let marker = closer_byte as char;
+                        parser.record_syntax(
+                            node,
+                            if used_delimiter_nums == 1 {
+                                NodeSyntax::Emphasis { marker }
+                            } else {
+                                NodeSyntax::Strong { marker }
+                            },
+                        );
+                    }
                     let mut temp = parser.tree.get_next(opener_inl);
                     while let Some(item) = temp.filter(|it| it != &closer_inl) {
                         let next = parser.tree.get_next(item);
@@ -1,4 +1,5 @@
 use crate::ast::MarkdownNode;
+use crate::ast::emoji::Emoji;
 use crate::inlines::ProcessCtx;
 
 #[inline]
@@ -52,21 +53,64 @@ pub(super) fn process(
     };
     let emoji_name =
         unsafe { std::str::from_utf8_unchecked(&line.source_slice()[scan_start..end_pos]) };
-    let end_location = (end_pos + 1) as u32;
+    let Some((builtin, value)) = resolve(&parser.options.emoji_aliases, emoji_name) else {
+        // 未知 shortcode 保持为普通文本
+        return false;
+    };
+    // 紧随其后的 `:skin-tone-N:` 修饰（gemoji/Slack 约定，N 为 2~6）
+    let mut end_location = end_pos + 1;
+    let mut value = value;
+    let mut skin_tone = None;
+    if let Some(builtin) = builtin {
+        let rest = &line.source_slice()[end_location..line.end()];
+        if let Some(&[tone @ b'2'..=b'6', b':', ..]) = rest.strip_prefix(b":skin-tone-".as_slice())
+            && let Some(toned) =
+                crate::utils::emoji::lookup_emoji_skin_tone(builtin.as_bytes(), tone - b'0')
+        {
+            value = toned.to_string();
+            skin_tone = Some(tone - b'0');
+            end_location += b":skin-tone-N:".len();
+        }
+    }
     parser.append_to(
         *id,
-        MarkdownNode::Emoji(emoji_name.to_string()),
-        (start_location, end_location),
+        MarkdownNode::Emoji(Box::new(Emoji {
+            name: emoji_name.to_string(),
+            value,
+            skin_tone,
+        })),
+        (start_location, end_location as u32),
     );
-    // 跳过 emoji 名称 + 结尾的 ':'
-    // cursor 当前在 scan_start，需要跳到 end_pos + 1
-    let skip_count = end_pos + 1 - line.cursor();
+    // cursor 当前在 scan_start，跳过名称、结尾的 ':' 与肤色修饰
+    let skip_count = end_location - line.cursor();
     line.skip(skip_count);
     true
 }
 
+/// 解析 shortcode：自定义别名优先，别名值写作 `:name:` 时指向内置 shortcode。
+/// 返回可接受肤色修饰的内置名称与解析值。
+fn resolve<'a>(
+    aliases: &'a rustc_hash::FxHashMap<String, String>,
+    name: &'a str,
+) -> Option<(Option<&'a str>, String)> {
+    let builtin = match aliases.get(name) {
+        Some(alias) => match alias
+            .strip_prefix(':')
+            .and_then(|it| it.strip_suffix(':'))
+            .filter(|it| !it.is_empty())
+        {
+            Some(target) => target,
+            None => return Some((None, alias.clone())),
+        },
+        None => name,
+    };
+    crate::utils::emoji::lookup_emoji(builtin.as_bytes())
+        .map(|value| (Some(builtin), value.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::MarkdownNode;
     use crate::parser::{Parser, ParserOptions};
 
     #[test]
@@ -94,4 +138,60 @@ mod tests {
         let ast = Parser::new(":狗头:").parse().unwrap();
         assert_eq!(ast.to_html(), "<p>:狗头:</p>");
     }
+
+    #[test]
+    fn resolves_shortcodes_and_keeps_unknown_literal() {
+        let ast = Parser::new_with_options(
+            ":smile: :+1: :no_such_emoji: 10:30:45",
+            ParserOptions::default().enabled_gfm(),
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(
+            ast.to_html(),
+            "<p>\u{1F604} \u{1F44D} :no_such_emoji: 10:30:45</p>"
+        );
+    }
+
+    #[test]
+    fn applies_skin_tone_modifier() {
+        let ast = Parser::new_with_options(
+            ":wave::skin-tone-4: :smile::skin-tone-4: :wave::skin-tone-7:",
+            ParserOptions::default().enabled_gfm(),
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(
+            ast.to_html(),
+            "<p>\u{1F44B}\u{1F3FD} \u{1F604}:skin-tone-4: \u{1F44B}:skin-tone-7:</p>"
+        );
+        let MarkdownNode::Emoji(emoji) = &ast[2].body else {
+            panic!("expected emoji, got {:?}", ast[2].body);
+        };
+        assert_eq!(emoji.name, "wave");
+        assert_eq!(emoji.skin_tone, Some(4));
+        assert_eq!((ast[2].span.start, ast[2].span.end), (0, 19));
+    }
+
+    #[test]
+    fn custom_aliases_take_precedence() {
+        let ast = Parser::new_with_options(
+            ":狗头: :lgtm::skin-tone-2: :smile:",
+            ParserOptions::default().enabled_gfm().with_emoji_aliases([
+                ("狗头", "\u{1F436}"),
+                ("lgtm", ":+1:"),
+                ("smile", "<3"),
+            ]),
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(ast.to_html(), "<p>\u{1F436} \u{1F44D}\u{1F3FB} &lt;3</p>");
+        let MarkdownNode::Emoji(emoji) = &ast[2].body else {
+            panic!("expected emoji, got {:?}", ast[2].body);
+        };
+        assert_eq!(
+            (emoji.name.as_str(), emoji.value.as_str()),
+            ("狗头", "\u{1F436}")
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::ast::syntax::{LinkSyntax, NodeSyntax};
 use crate::ast::{MarkdownNode, embed, link, reference::Reference};
 use crate::inlines::ProcessCtx;
 use crate::inlines::bracket::Bracket;
@@ -12,6 +13,9 @@ pub(crate) enum ScannedLink {
     Resource {
         url: crate::ast::text::TextRef,
         title: Option<crate::ast::text::TextRef>,
+        /// 引用链接解析到的定义（规范化标签）；内联链接为 `None`
+        reference: Option<String>,
+        form: crate::ast::syntax::LinkSyntax,
     },
     Footnote {
         label: String,
@@ -148,6 +152,8 @@ pub(crate) fn scan_link_or_image(
     };
     let mut is_footnote_link = false;
     let mut footnote_label: Option<String> = None;
+    let mut reference = None;
+    let mut form = LinkSyntax::Inline;
     // 如果上一个块未匹配，尝试解析 link label
     'scan_link_label: {
         if matched {
@@ -159,6 +165,7 @@ pub(crate) fn scan_link_or_image(
                 line.skip(size);
                 // If label is empty [], use the opener content as reference
                 if label == "[]" {
+                    form = LinkSyntax::Collapsed;
                     let mut opener_idx = opener.index;
                     // For images, skip the '!' character
                     if opener.is_image() {
@@ -168,10 +175,12 @@ pub(crate) fn scan_link_or_image(
                     let s = line.slice_from_abs(opener_idx, cur);
                     s.to_string()
                 } else {
+                    form = LinkSyntax::Full;
                     label
                 }
             }
             None if !opener.bracket_after => {
+                form = LinkSyntax::Shortcut;
                 // 从 opener 的 index 到当前 cursor 的内容
                 let mut opener_idx = opener.index;
                 // For images, skip the '!' character
@@ -197,6 +206,7 @@ pub(crate) fn scan_link_or_image(
                 url = Some(crate::ast::text::TextRef::Owned(_link.clone()));
                 title = _title.clone().map(crate::ast::text::TextRef::Owned);
                 matched = true;
+                reference = Some(ref_label);
             }
         }
     }
@@ -206,7 +216,12 @@ pub(crate) fn scan_link_or_image(
     if is_footnote_link {
         return footnote_label.map(|label| ScannedLink::Footnote { label });
     }
-    url.map(|url| ScannedLink::Resource { url, title })
+    url.map(|url| ScannedLink::Resource {
+        url,
+        title,
+        reference,
+        form,
+    })
 }
 
 pub(super) fn scan_link_url<'input>(line: &Span<'input>) -> Option<(usize, Span<'input>)> {
@@ -947,6 +962,12 @@ pub(super) fn process_autolink(
             )),
             (start_location, end_location),
         );
+        parser.record_syntax(
+            node,
+            NodeSyntax::Link {
+                form: LinkSyntax::Autolink,
+            },
+        );
         let locations = (link_span.cursor_or_end() as u32, link_span.end() as u32);
         parser.append_text_to_owned(node, link_str, locations);
         true
@@ -973,6 +994,12 @@ pub(super) fn process_autolink(
             )),
             (start_location, end_location),
         );
+        parser.record_syntax(
+            node,
+            NodeSyntax::Link {
+                form: LinkSyntax::Autolink,
+            },
+        );
         let mut locations = (link_span.cursor_or_end() as u32, link_span.end() as u32);
         if escaped_esc {
             locations.1 += 1;
@@ -1015,6 +1042,12 @@ pub(super) fn process_gfm_autolink(
         )),
         (start_location, end_location),
     );
+    parser.record_syntax(
+        node,
+        NodeSyntax::Link {
+            form: LinkSyntax::ExtendedAutolink,
+        },
+    );
     parser.append_text_to_owned(node, text, (start_location, end_location));
     true
 }
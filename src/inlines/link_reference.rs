@@ -1,10 +1,21 @@
 use crate::ast::MarkdownNode;
+use crate::ast::link::LinkReferenceDefinition;
+use crate::ast::text::SourceSpan;
 use crate::inlines::link;
 use crate::inlines::link::scan_link_title;
+use crate::node::Node;
 use crate::parser::Parser;
 use crate::span::Span;
 use crate::utils;
 
+/// `scan_link_reference` 的结果：规范化标签用于查找，其余字段按原样保留
+struct ScannedDefinition {
+    key: String,
+    label: String,
+    url: String,
+    title: Option<String>,
+}
+
 pub(crate) fn process_link_reference(parser: &mut Parser, node_id: usize) {
     match &parser.tree[node_id].body {
         MarkdownNode::Paragraph => (),
@@ -27,14 +38,13 @@ pub(crate) fn process_link_reference(parser: &mut Parser, node_id: usize) {
         }
         _ => return,
     };
+    let mut definitions = Vec::new();
     loop {
         let snapshot = line.snapshot();
+        let start = line.cursor();
         match scan_link_reference(&mut line) {
-            Some((ref_label, url, title)) => {
-                parser
-                    .link_refs
-                    .entry(ref_label)
-                    .or_insert((url, title.map(utils::entities::unescape_string)));
+            Some(scanned) => {
+                definitions.push(define(parser, scanned, &line, start));
                 continue;
             }
             _ => {
@@ -43,9 +53,11 @@ pub(crate) fn process_link_reference(parser: &mut Parser, node_id: usize) {
         }
         break;
     }
+    keep_definitions(parser, node_id, definitions);
     if line.is_end() {
         parser.tree.remove(node_id);
     } else {
+        parser.tree[node_id].span.start = line.cursor() as u32;
         parser.inlines.insert(node_id, smallvec::smallvec![line]);
     }
 }
@@ -60,44 +72,103 @@ pub(crate) fn process_setext_heading_link_reference(parser: &mut Parser, node_id
     let Some(spans) = parser.inlines.remove(node_id) else {
         return;
     };
-    let mut consumed = 0usize;
+    let mut definitions = Vec::new();
     for span in spans.iter() {
         let mut line = span.clone();
-        let Some((ref_label, url, title)) = scan_link_reference(&mut line) else {
+        let Some(scanned) = scan_link_reference(&mut line) else {
             break;
         };
         if !line.is_end() {
             break;
         }
-        parser
-            .link_refs
-            .entry(ref_label)
-            .or_insert((url, title.map(utils::entities::unescape_string)));
-        consumed += 1;
+        definitions.push(define(parser, scanned, &line, span.cursor()));
     }
+    let consumed = definitions.len();
     if consumed == 0 {
         parser.inlines.insert(node_id, spans);
         return;
     }
+    keep_definitions(parser, node_id, definitions);
     let remains = spans
         .into_iter()
         .skip(consumed)
         .collect::<crate::pending::PendingSegments>();
-    if !remains.is_empty() {
+    if let Some(first) = remains.first() {
+        parser.tree[node_id].span.start = first.cursor() as u32;
         parser.inlines.insert(node_id, remains);
     }
 }
 
+/// 登记定义（首个定义生效），并记录每个定义（含重复定义）供链接检查使用；
+/// 定义区间不含末尾换行与空白
+fn define(
+    parser: &mut Parser,
+    scanned: ScannedDefinition,
+    line: &Span,
+    start: usize,
+) -> LinkReferenceDefinition {
+    let source = line.source_slice();
+    let mut end = line.cursor().min(source.len());
+    while end > start && source[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    let ScannedDefinition {
+        key,
+        label,
+        url,
+        title,
+    } = scanned;
+    let definition = LinkReferenceDefinition {
+        label,
+        url,
+        title: title.map(utils::entities::unescape_string),
+        span: SourceSpan::new(start as u32, end as u32),
+    };
+    parser
+        .link_refs
+        .entry(key.clone())
+        .or_insert_with(|| (definition.url.clone(), definition.title.clone()));
+    parser
+        .link_records
+        .definitions
+        .push((key, definition.clone()));
+    definition
+}
+
+/// 启用 `link_reference_definitions` 时，把定义作为节点插入到 `node_id` 之前
+fn keep_definitions(
+    parser: &mut Parser,
+    node_id: usize,
+    definitions: Vec<LinkReferenceDefinition>,
+) {
+    if !parser.options.link_reference_definitions {
+        return;
+    }
+    for definition in definitions {
+        let span = definition.span;
+        let id = parser.tree.insert_before(
+            node_id,
+            Node::new(
+                MarkdownNode::LinkReferenceDefinition(Box::new(definition)),
+                span.start,
+            ),
+        );
+        parser.tree[id].span.end = span.end;
+        parser.mark_as_processed(id);
+    }
+}
+
 pub(crate) fn is_link_reference_line(span: &Span) -> bool {
     let mut line = span.clone();
     scan_link_reference(&mut line).is_some() && line.is_end()
 }
 
-fn scan_link_reference(line: &mut Span) -> Option<(String, String, Option<String>)> {
-    let ref_label = match link::scan_link_label(line) {
+fn scan_link_reference(line: &mut Span) -> Option<ScannedDefinition> {
+    let (ref_label, label) = match link::scan_link_label(line) {
         Some((size, label)) => {
             line.skip(size);
-            link::normalize_reference(label)
+            let written = label[1..label.len() - 1].to_string();
+            (link::normalize_reference(label), written)
         }
         _ => return None,
     };
@@ -163,7 +234,12 @@ fn scan_link_reference(line: &mut Span) -> Option<(String, String, Option<String
         return None;
     }
     skip_spaces_and_line_ending(line);
-    Some((ref_label, url, title))
+    Some(ScannedDefinition {
+        key: ref_label,
+        label,
+        url,
+        title,
+    })
 }
 
 fn only_space_to_eol(line: &Span) -> bool {
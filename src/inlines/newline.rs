@@ -1,4 +1,5 @@
 use crate::ast::MarkdownNode;
+use crate::ast::syntax::{HardBreakSyntax, NodeSyntax};
 use crate::inlines::ProcessCtx;
 use crate::inlines::link;
 
@@ -28,7 +29,8 @@ pub(super) fn process(ctx: &mut ProcessCtx) -> bool {
     {
         let resolved = text.resolve(source);
         if resolved.ends_with(' ') {
-            let node = if resolved.ends_with("  ") {
+            let hard = resolved.ends_with("  ");
+            let node = if hard {
                 MarkdownNode::HardBreak
             } else {
                 MarkdownNode::SoftBreak
@@ -37,7 +39,7 @@ pub(super) fn process(ctx: &mut ProcessCtx) -> bool {
             let offset = resolved.len() - trimmed_len;
             text.truncate(trimmed_len, source);
             ctx.parser.tree[child_idx].span.end -= offset as u32;
-            ctx.parser.append_to(
+            let node = ctx.parser.append_to(
                 ctx.id,
                 node,
                 (
@@ -45,6 +47,14 @@ pub(super) fn process(ctx: &mut ProcessCtx) -> bool {
                     ctx.line.char_end_offset() as u32,
                 ),
             );
+            if hard {
+                ctx.parser.record_syntax(
+                    node,
+                    NodeSyntax::HardBreak {
+                        form: HardBreakSyntax::Spaces,
+                    },
+                );
+            }
             ctx.line.next_byte();
             skip_leading_continuation_ws(ctx);
             return true;
@@ -82,11 +92,17 @@ pub(super) fn process_backslash(
     // 检查 backslash 后面是否是换行符
     if line.validate_with(1, |b| b == b'\n' || b == b'\r') {
         let end_location = (line.cursor() + 2) as u32;
-        parser.append_to(
+        let node = parser.append_to(
             *id,
             MarkdownNode::HardBreak,
             (line.cursor_or_end() as u32, end_location),
         );
+        parser.record_syntax(
+            node,
+            NodeSyntax::HardBreak {
+                form: HardBreakSyntax::Backslash,
+            },
+        );
         line.skip(2);
         skip_leading_continuation_ws(&mut ProcessCtx {
             id: *id,
@@ -0,0 +1,131 @@
+use crate::ast::MarkdownNode;
+use crate::ast::ruby::{Ruby, RubyAnnotation};
+use crate::inlines::ProcessCtx;
+
+/// `{base|annotation}`：cursor 位于 `{`
+pub(super) fn process_braced(ctx: &mut ProcessCtx) -> bool {
+    let bytes = current_bytes(ctx);
+    let Some(close) = find_unescaped(bytes, 1, b'}', b'{') else {
+        return false;
+    };
+    let mut parts = split_unescaped(&bytes[1..close], b'|');
+    if parts.len() < 2 {
+        return false;
+    }
+    let base = parts.remove(0);
+    emit(ctx, base, &parts, close + 1)
+}
+
+/// `[base]^(annotation)`：cursor 位于 `[`
+pub(super) fn process_bracketed(ctx: &mut ProcessCtx) -> bool {
+    let bytes = current_bytes(ctx);
+    let Some(close) = find_unescaped(bytes, 1, b']', b'[') else {
+        return false;
+    };
+    if bytes.get(close + 1..close + 3) != Some(b"^(") {
+        return false;
+    }
+    let Some(end) = find_unescaped(bytes, close + 3, b')', b'(') else {
+        return false;
+    };
+    let parts = split_unescaped(&bytes[close + 3..end], b'|');
+    emit(ctx, &bytes[1..close], &parts, end + 1)
+}
+
+/// 当前 Span 从 cursor 起的剩余字节；ruby 不跨行
+fn current_bytes<'input>(ctx: &ProcessCtx<'_, 'input>) -> &'input [u8] {
+    &ctx.line.source_slice()[ctx.line.cursor()..ctx.line.end()]
+}
+
+/// 从 `from` 起查找未转义的 `close`；遇到未转义的 `reject`（嵌套）则放弃
+fn find_unescaped(bytes: &[u8], from: usize, close: u8, reject: u8) -> Option<usize> {
+    let mut i = from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b if b == close => return Some(i),
+            b if b == reject => return None,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn split_unescaped(bytes: &[u8], separator: u8) -> Vec<&[u8]> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b if b == separator => {
+                parts.push(&bytes[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    parts.push(&bytes[start.min(bytes.len())..]);
+    parts
+}
+
+/// 去除反斜杠转义；首尾为空白或内容为空时返回 None，
+/// 避免把 `{x | x > 0}` 这类普通文本误识别为 ruby
+fn unescape(bytes: &[u8]) -> Option<String> {
+    if bytes.is_empty()
+        || bytes[0].is_ascii_whitespace()
+        || bytes[bytes.len() - 1].is_ascii_whitespace()
+    {
+        return None;
+    }
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && bytes.get(i + 1).is_some_and(u8::is_ascii_punctuation) {
+            i += 1;
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8(out).ok()
+}
+
+fn emit(ctx: &mut ProcessCtx, base: &[u8], annotations: &[&[u8]], consumed: usize) -> bool {
+    let Some(base) = unescape(base) else {
+        return false;
+    };
+    let Some(texts) = annotations
+        .iter()
+        .map(|it| unescape(it))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+    // 单个注音覆盖整个 base；多个注音须与 base 字符数一致，逐字标注
+    let annotations = if texts.len() == 1 {
+        vec![RubyAnnotation {
+            base: base.clone(),
+            text: texts.into_iter().next().unwrap_or_default(),
+        }]
+    } else if texts.len() == base.chars().count() {
+        base.chars()
+            .zip(texts)
+            .map(|(ch, text)| RubyAnnotation {
+                base: ch.to_string(),
+                text,
+            })
+            .collect()
+    } else {
+        return false;
+    };
+    let start = ctx.line.cursor() as u32;
+    ctx.parser.append_to(
+        ctx.id,
+        MarkdownNode::Ruby(Box::new(Ruby { base, annotations })),
+        (start, start + consumed as u32),
+    );
+    ctx.line.skip(consumed);
+    true
+}
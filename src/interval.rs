@@ -0,0 +1,160 @@
+//! 节点区间的静态索引（隐式区间树），供按偏移与范围查询节点。
+//!
+//! 条目按 `(start, depth)` 排序后原地组织成隐式平衡二叉树：下标 `i` 的层级
+//! 为其二进制末尾连续 1 的个数，每个条目额外记录子树内的最大 `end`。
+//! 重叠查询为 `O(log n + k)`，不依赖区间严格嵌套（HTML 块的子节点可能超出
+//! 父节点区间）。
+
+use crate::ast::MarkdownNode;
+use crate::node::Node;
+use crate::tree::Tree;
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    start: u32,
+    end: u32,
+    /// 以该条目为根的子树中最大的 `end`
+    max: u32,
+    depth: u32,
+    id: usize,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct IntervalIndex {
+    entries: Vec<Entry>,
+    max_level: usize,
+}
+
+impl IntervalIndex {
+    /// 收集占有源码的节点：跳过空区间、生成的脚注列表本身与嵌入笔记的内容
+    pub(crate) fn build(tree: &Tree<Node>, source_len: u32) -> Self {
+        let mut entries = vec![Entry {
+            start: 0,
+            end: source_len,
+            max: source_len,
+            depth: 0,
+            id: 0,
+        }];
+        let mut stack = vec![(0usize, 0u32)];
+        while let Some((id, depth)) = stack.pop() {
+            if matches!(tree[id].body, MarkdownNode::Transclusion(..)) {
+                continue;
+            }
+            let mut child = tree.get_last_child(id);
+            while let Some(it) = child {
+                let node = &tree[it];
+                let child_depth = if matches!(node.body, MarkdownNode::FootnoteList) {
+                    depth
+                } else {
+                    if !node.span.is_empty() {
+                        entries.push(Entry {
+                            start: node.span.start,
+                            end: node.span.end,
+                            max: node.span.end,
+                            depth: depth + 1,
+                            id: it,
+                        });
+                    }
+                    depth + 1
+                };
+                stack.push((it, child_depth));
+                child = tree.get_prev(it);
+            }
+        }
+        entries.sort_by_key(|entry| (entry.start, entry.depth));
+        let max_level = index(&mut entries);
+        IntervalIndex { entries, max_level }
+    }
+
+    /// 与 `[start, end)` 重叠的条目，按 `(start, depth)` 顺序返回下标
+    fn overlap(&self, start: u32, end: u32) -> Vec<usize> {
+        let entries = &self.entries;
+        let n = entries.len();
+        let mut found = Vec::new();
+        if n == 0 {
+            return found;
+        }
+        // (节点下标, 层级, 左子树是否已处理)
+        let mut stack = vec![((1usize << self.max_level) - 1, self.max_level, false)];
+        while let Some((x, k, left_done)) = stack.pop() {
+            if k <= 3 {
+                // 小子树直接线性扫描
+                let i0 = x >> k << k;
+                let i1 = (i0 + (1 << (k + 1)) - 1).min(n);
+                for (i, entry) in entries.iter().enumerate().take(i1).skip(i0) {
+                    if entry.start >= end {
+                        break;
+                    }
+                    if start < entry.end {
+                        found.push(i);
+                    }
+                }
+            } else if !left_done {
+                stack.push((x, k, true));
+                // 左子节点可能越界（树按满二叉树编号）
+                let y = x - (1 << (k - 1));
+                if y >= n || entries[y].max > start {
+                    stack.push((y, k - 1, false));
+                }
+            } else if x < n && entries[x].start < end {
+                if start < entries[x].end {
+                    found.push(x);
+                }
+                stack.push((x + (1 << (k - 1)), k - 1, false));
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+
+    /// 覆盖 `offset` 的节点 ID，由浅到深
+    pub(crate) fn containing(&self, offset: u32) -> impl Iterator<Item = usize> + '_ {
+        let mut found = self.overlap(offset, offset + 1);
+        found.sort_by_key(|&i| self.entries[i].depth);
+        found.into_iter().map(|i| self.entries[i].id)
+    }
+
+    /// 与 `[start, end)` 重叠的节点 ID，按文档顺序
+    pub(crate) fn overlapping(&self, start: u32, end: u32) -> Vec<usize> {
+        self.overlap(start, end.max(start + 1))
+            .into_iter()
+            .map(|i| self.entries[i].id)
+            .collect()
+    }
+}
+
+/// 自底向上计算各层内部节点的子树最大 `end`，返回树的最高层级
+fn index(entries: &mut [Entry]) -> usize {
+    let n = entries.len();
+    if n == 0 {
+        return 0;
+    }
+    let mut last_i = 0;
+    let mut last = 0;
+    for i in (0..n).step_by(2) {
+        last_i = i;
+        entries[i].max = entries[i].end;
+        last = entries[i].max;
+    }
+    let mut k = 1;
+    while 1 << k <= n {
+        let x = 1 << (k - 1);
+        let i0 = (x << 1) - 1;
+        let step = x << 2;
+        for i in (i0..n).step_by(step) {
+            let left = entries[i - x].max;
+            let right = if i + x < n { entries[i + x].max } else { last };
+            entries[i].max = entries[i].end.max(left).max(right);
+        }
+        last_i = if (last_i >> k) & 1 == 1 {
+            last_i - x
+        } else {
+            last_i + x
+        };
+        if last_i < n && entries[last_i].max > last {
+            last = entries[last_i].max;
+        }
+        k += 1;
+    }
+    k - 1
+}
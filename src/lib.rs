@@ -116,25 +116,42 @@
 
 pub mod ast;
 mod blocks;
+pub mod cst;
 mod document;
 mod exts;
 mod inlines;
+mod interval;
+pub mod link_check;
+pub mod lint;
 mod location;
 mod node;
 pub mod parser;
 mod pending;
 mod render;
+pub mod resolve;
 pub(crate) mod scanner;
 pub mod selective;
 mod semantic;
 pub(crate) mod span;
+pub mod statistics;
+pub mod tags;
+pub mod transclude;
 pub mod tree;
 mod utils;
+pub mod vault;
 
 pub use ast::*;
+pub use cst::*;
 pub use document::*;
+pub use link_check::*;
+pub use lint::*;
 pub use location::*;
 pub use node::*;
 pub use parser::*;
+pub use resolve::*;
 pub use selective::*;
+pub use statistics::*;
+pub use tags::*;
+pub use transclude::*;
 pub use tree::*;
+pub use vault::*;
@@ -0,0 +1,267 @@
+//! Link validation for a single document.
+//!
+//! CommonMark turns a reference link with an unknown label into plain text,
+//! and drops footnote definitions that are never referenced. The parser keeps
+//! a record of both, and [`Document::check_links`] reports them together with
+//! other broken links:
+//!
+//! ```
+//! use ptdgrp_markdown::{LinkDiagnosticKind, Parser, ParserOptions};
+//!
+//! let source = "See [docs][guide] and [[#Setup]].\n\n[guide]: https://\n[guide]: /other";
+//! let document = Parser::new_with_options(source, ParserOptions::default().enabled_ofm())
+//!     .parse()?;
+//! let kinds = document
+//!     .check_links()
+//!     .into_iter()
+//!     .map(|diagnostic| diagnostic.kind)
+//!     .collect::<Vec<_>>();
+//!
+//! assert_eq!(
+//!     kinds,
+//!     [
+//!         LinkDiagnosticKind::MalformedUrl,
+//!         LinkDiagnosticKind::MissingTarget,
+//!         LinkDiagnosticKind::DuplicateDefinition,
+//!     ]
+//! );
+//! # Ok::<(), ptdgrp_markdown::ParseError>(())
+//! ```
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use crate::ast::MarkdownNode;
+use crate::ast::link::{Link, LinkReferenceDefinition};
+use crate::ast::reference::Reference;
+use crate::ast::text::SourceSpan;
+use crate::document::Document;
+use crate::location::Location;
+use crate::utils;
+
+/// The category of a [`LinkDiagnostic`].
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkDiagnosticKind {
+    /// A full or collapsed reference link, such as `[text][label]` or
+    /// `[label][]`, whose label has no definition.
+    UndefinedReference,
+    /// A link reference definition whose label was already defined. The first
+    /// definition wins.
+    DuplicateDefinition,
+    /// A footnote reference `[^label]` without a definition.
+    UndefinedFootnote,
+    /// A footnote definition that is never referenced.
+    UnusedFootnote,
+    /// A wikilink or embed to a heading or block of the same note that does
+    /// not exist, such as `[[#Missing]]` or `![[#^missing]]`.
+    MissingTarget,
+    /// A link or image destination that cannot be a valid URL.
+    MalformedUrl,
+}
+
+/// A broken link found by [`Document::check_links`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LinkDiagnostic {
+    /// Error category.
+    pub kind: LinkDiagnosticKind,
+    /// The label, reference, or URL at fault, as written.
+    pub target: String,
+    /// Human-readable description, e.g. ``undefined link reference `guide` ``.
+    pub message: String,
+    /// Source range of the link, reference, or definition.
+    pub span: SourceSpan,
+    /// One-based position of `span.start`.
+    pub location: Location,
+}
+
+/// 解析过程中留下的链接记录；未定义的引用与未使用的脚注定义不会出现在树中
+#[derive(Debug, Default, Clone)]
+pub(crate) struct LinkRecords {
+    /// 链接引用定义（文档序，含被忽略的重复定义）：规范化标签与定义
+    pub(crate) definitions: Vec<(String, LinkReferenceDefinition)>,
+    /// 解析成功的完整/折叠/捷径引用链接与图片：节点 ID 到规范化标签
+    pub(crate) references: FxHashMap<usize, String>,
+    /// 标签未定义的完整/折叠引用链接：标签原文与 `[text][label]` 区间
+    pub(crate) undefined_references: Vec<(String, SourceSpan)>,
+    /// 块级脚注定义：标签与 `[^label]:` 标记区间
+    pub(crate) footnote_definitions: Vec<(String, SourceSpan)>,
+    /// 没有定义的脚注引用：标签与 `[^label]` 区间
+    pub(crate) undefined_footnotes: Vec<(String, SourceSpan)>,
+}
+
+impl Document<'_> {
+    /// Reports broken links in this document, ordered by position.
+    ///
+    /// Only the current note is checked: wikilinks to other notes are left to
+    /// [`crate::Vault::unresolved`]. Reference links and footnotes are
+    /// reported from what the parser saw, so documents produced by selective
+    /// parsing only report the materialized blocks.
+    pub fn check_links(&self) -> Vec<LinkDiagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut report = |kind, target: &str, message: String, span: SourceSpan| {
+            diagnostics.push(LinkDiagnostic {
+                kind,
+                target: target.to_string(),
+                message,
+                span,
+                location: self.location_at(span.start as usize),
+            });
+        };
+        let records = &self.link_records;
+
+        let mut first_definitions = FxHashMap::default();
+        for (label, definition) in &records.definitions {
+            let Some(first) = first_definitions.get(label) else {
+                first_definitions.insert(label, definition.span);
+                continue;
+            };
+            let line = self.location_at(first.start as usize).line;
+            report(
+                LinkDiagnosticKind::DuplicateDefinition,
+                &definition.label,
+                format!("link reference `{label}` is already defined on line {line}"),
+                definition.span,
+            );
+        }
+        for (label, span) in &records.undefined_references {
+            report(
+                LinkDiagnosticKind::UndefinedReference,
+                label,
+                format!("undefined link reference `{label}`"),
+                *span,
+            );
+        }
+        for (label, span) in &records.undefined_footnotes {
+            report(
+                LinkDiagnosticKind::UndefinedFootnote,
+                label,
+                format!("footnote `{label}` is not defined"),
+                *span,
+            );
+        }
+
+        let mut used_footnotes = FxHashSet::default();
+        for id in self.tree.descendants(0) {
+            let span = self.tree[id].span;
+            match &self.tree[id].body {
+                MarkdownNode::Link(link) => match link.as_ref() {
+                    Link::Footnote(footnote) => {
+                        used_footnotes.insert(footnote.footnote_label.as_str());
+                    }
+                    Link::Default(link) => {
+                        let url = self.text(&link.url);
+                        if let Some(reason) = malformed_url(url) {
+                            report(
+                                LinkDiagnosticKind::MalformedUrl,
+                                url,
+                                format!("malformed URL `{url}`: {reason}"),
+                                span,
+                            );
+                        }
+                    }
+                    Link::Wikilink(wikilink) => {
+                        if self.is_missing_target(&wikilink.path, &wikilink.reference) {
+                            report(
+                                LinkDiagnosticKind::MissingTarget,
+                                &wikilink.path,
+                                format!("`{}` does not exist in this note", wikilink.path),
+                                span,
+                            );
+                        }
+                    }
+                    Link::FootnoteBackref(_) => {}
+                },
+                MarkdownNode::Embed(embed)
+                    if self.is_missing_target(&embed.path, &embed.reference) =>
+                {
+                    report(
+                        LinkDiagnosticKind::MissingTarget,
+                        &embed.path,
+                        format!("`{}` does not exist in this note", embed.path),
+                        span,
+                    );
+                }
+                MarkdownNode::Image(image) => {
+                    let url = self.text(&image.url);
+                    if let Some(reason) = malformed_url(url) {
+                        report(
+                            LinkDiagnosticKind::MalformedUrl,
+                            url,
+                            format!("malformed URL `{url}`: {reason}"),
+                            span,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        for (label, span) in &records.footnote_definitions {
+            if !used_footnotes.contains(utils::percent_encode::encode(label, true).as_str()) {
+                report(
+                    LinkDiagnosticKind::UnusedFootnote,
+                    label,
+                    format!("footnote `{label}` is never referenced"),
+                    *span,
+                );
+            }
+        }
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+        diagnostics
+    }
+
+    /// 当前笔记内的 `[[#Heading]]`/`[[#^id]]` 是否指向不存在的标题或块
+    fn is_missing_target(&self, path: &str, reference: &Option<Reference>) -> bool {
+        match (path.strip_prefix('#'), reference) {
+            (Some(fragment), None) => crate::vault::parse_fragment(fragment)
+                .is_some_and(|reference| self.find_reference(&reference).is_none()),
+            _ => false,
+        }
+    }
+}
+
+/// 明显无法构成 URL 的目标；相对路径与未知协议只做最基本的检查。
+/// CommonMark 允许空目标（`[x]()`），不报告
+fn malformed_url(url: &str) -> Option<&'static str> {
+    let (scheme, rest) = url
+        .split_once(':')
+        .filter(|(scheme, _)| !scheme.contains(['/', '?', '#']))?;
+    if !scheme.starts_with(|ch: char| ch.is_ascii_alphabetic())
+        || !scheme
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.'))
+    {
+        return Some("invalid scheme");
+    }
+    match scheme.to_ascii_lowercase().as_str() {
+        "http" | "https" | "ftp" | "ftps" | "ws" | "wss" => {
+            let Some(rest) = rest.strip_prefix("//") else {
+                return Some("missing `//` after the scheme");
+            };
+            let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+            let host = authority.rsplit_once('@').map_or(authority, |it| it.1);
+            let (host, port) = match host.strip_prefix('[') {
+                Some(ipv6) => match ipv6.split_once(']') {
+                    Some((_, port)) => ("[]", port.strip_prefix(':')),
+                    None => return Some("unterminated IPv6 address"),
+                },
+                None => match host.rsplit_once(':') {
+                    Some((host, port)) => (host, Some(port)),
+                    None => (host, None),
+                },
+            };
+            if host.is_empty() {
+                return Some("missing host");
+            }
+            if host.split('.').any(str::is_empty) && host != "[]" {
+                return Some("empty host label");
+            }
+            if port.is_some_and(|port| !port.chars().all(|ch| ch.is_ascii_digit())) {
+                return Some("invalid port");
+            }
+            None
+        }
+        "mailto" if !rest.contains('@') => Some("missing `@` in the address"),
+        _ => None,
+    }
+}
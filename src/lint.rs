@@ -0,0 +1,644 @@
+//! Rule-based Markdown linting with markdownlint rule IDs.
+//!
+//! Rules run on the parsed AST, so they see exactly the dialect the document
+//! was parsed with: an OFM `#tag` is not a heading, and a `[[wikilink]]` is
+//! not a bare URL. Warnings carry a source span and, where the rule has a
+//! safe rewrite, a [`LintFix`]:
+//!
+//! ```
+//! use ptdgrp_markdown::{LintConfig, LintRule, Parser, apply_lint_fixes};
+//!
+//! let source = "# Title\n\n### Skipped\n\n* one\n- two  \t\n";
+//! let document = Parser::new(source).parse()?;
+//! let warnings = document.lint(&LintConfig::default());
+//! let rules = warnings.iter().map(|it| it.rule.id()).collect::<Vec<_>>();
+//!
+//! assert_eq!(rules, ["MD001", "MD004", "MD009", "MD010"]);
+//! assert_eq!(
+//!     apply_lint_fixes(source, &warnings),
+//!     "# Title\n\n### Skipped\n\n* one\n* two\n"
+//! );
+//! assert!(document.lint(&LintConfig::default().disabled(LintRule::UlStyle)).len() == 3);
+//! # Ok::<(), ptdgrp_markdown::ParseError>(())
+//! ```
+
+use std::ops::Range;
+
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+
+use crate::ast::MarkdownNode;
+use crate::ast::code::Code;
+use crate::ast::html::Html;
+use crate::ast::link::Link;
+use crate::ast::math::Math;
+use crate::ast::text::{SourceSpan, TextRef};
+use crate::document::Document;
+use crate::location::Location;
+
+/// A lint rule, identified by its markdownlint ID.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    /// `MD001`: heading levels should only increase by one level at a time.
+    #[serde(rename = "MD001")]
+    HeadingIncrement,
+    /// `MD004`: bullet list markers should match the first one used.
+    #[serde(rename = "MD004")]
+    UlStyle,
+    /// `MD009`: lines should not end with whitespace, except a two-space
+    /// hard break.
+    #[serde(rename = "MD009")]
+    NoTrailingSpaces,
+    /// `MD010`: lines should not contain hard tabs. The fix expands tabs to
+    /// the next multiple of four columns; tabs after a block quote marker
+    /// have no fix.
+    #[serde(rename = "MD010")]
+    NoHardTabs,
+    /// `MD012`: there should be no more than one consecutive blank line.
+    #[serde(rename = "MD012")]
+    NoMultipleBlanks,
+    /// `MD013`: lines should not exceed [`LintConfig::with_line_length`].
+    #[serde(rename = "MD013")]
+    LineLength,
+    /// `MD024`: headings should not repeat the text of an earlier heading.
+    #[serde(rename = "MD024")]
+    NoDuplicateHeading,
+    /// `MD034`: URLs should be wrapped in angle brackets or a link.
+    #[serde(rename = "MD034")]
+    NoBareUrls,
+    /// `MD040`: fenced code blocks should declare a language.
+    #[serde(rename = "MD040")]
+    FencedCodeLanguage,
+    /// `MD047`: the file should end with a single newline character.
+    #[serde(rename = "MD047")]
+    SingleTrailingNewline,
+    /// `MD049`: emphasis should use the same marker as the first one.
+    #[serde(rename = "MD049")]
+    EmphasisStyle,
+    /// `MD050`: strong emphasis should use the same marker as the first one.
+    #[serde(rename = "MD050")]
+    StrongStyle,
+}
+
+impl LintRule {
+    /// Every rule, in ID order.
+    pub const ALL: [LintRule; 12] = [
+        LintRule::HeadingIncrement,
+        LintRule::UlStyle,
+        LintRule::NoTrailingSpaces,
+        LintRule::NoHardTabs,
+        LintRule::NoMultipleBlanks,
+        LintRule::LineLength,
+        LintRule::NoDuplicateHeading,
+        LintRule::NoBareUrls,
+        LintRule::FencedCodeLanguage,
+        LintRule::SingleTrailingNewline,
+        LintRule::EmphasisStyle,
+        LintRule::StrongStyle,
+    ];
+
+    /// Returns the markdownlint ID, e.g. `MD013`.
+    pub fn id(self) -> &'static str {
+        self.names().0
+    }
+
+    /// Returns the markdownlint alias, e.g. `line-length`.
+    pub fn alias(self) -> &'static str {
+        self.names().1
+    }
+
+    /// Looks a rule up by ID or alias, ignoring ASCII case.
+    pub fn from_name(name: &str) -> Option<LintRule> {
+        LintRule::ALL.into_iter().find(|rule| {
+            rule.id().eq_ignore_ascii_case(name) || rule.alias().eq_ignore_ascii_case(name)
+        })
+    }
+
+    fn names(self) -> (&'static str, &'static str) {
+        match self {
+            LintRule::HeadingIncrement => ("MD001", "heading-increment"),
+            LintRule::UlStyle => ("MD004", "ul-style"),
+            LintRule::NoTrailingSpaces => ("MD009", "no-trailing-spaces"),
+            LintRule::NoHardTabs => ("MD010", "no-hard-tabs"),
+            LintRule::NoMultipleBlanks => ("MD012", "no-multiple-blanks"),
+            LintRule::LineLength => ("MD013", "line-length"),
+            LintRule::NoDuplicateHeading => ("MD024", "no-duplicate-heading"),
+            LintRule::NoBareUrls => ("MD034", "no-bare-urls"),
+            LintRule::FencedCodeLanguage => ("MD040", "fenced-code-language"),
+            LintRule::SingleTrailingNewline => ("MD047", "single-trailing-newline"),
+            LintRule::EmphasisStyle => ("MD049", "emphasis-style"),
+            LintRule::StrongStyle => ("MD050", "strong-style"),
+        }
+    }
+}
+
+/// Which rules [`Document::lint`] runs, and their settings.
+///
+/// All rules are enabled by default, with an 80 character line length.
+#[derive(Debug, Clone)]
+pub struct LintConfig {
+    disabled: FxHashSet<LintRule>,
+    line_length: usize,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            disabled: FxHashSet::default(),
+            line_length: 80,
+        }
+    }
+}
+
+impl LintConfig {
+    /// Disables `rule`.
+    pub fn disabled(mut self, rule: LintRule) -> Self {
+        self.disabled.insert(rule);
+        self
+    }
+    /// Enables `rule` again.
+    pub fn enabled(mut self, rule: LintRule) -> Self {
+        self.disabled.remove(&rule);
+        self
+    }
+    /// Sets the maximum line length checked by [`LintRule::LineLength`].
+    pub fn with_line_length(self, line_length: usize) -> Self {
+        Self {
+            line_length,
+            ..self
+        }
+    }
+    /// Applies a markdownlint-style setting: a rule ID or alias, or `default`
+    /// for every rule. Returns `false` for an unknown name.
+    pub fn set(&mut self, name: &str, enabled: bool) -> bool {
+        let rules = if name.eq_ignore_ascii_case("default") {
+            LintRule::ALL.to_vec()
+        } else {
+            match LintRule::from_name(name) {
+                Some(rule) => vec![rule],
+                None => return false,
+            }
+        };
+        for rule in rules {
+            if enabled {
+                self.disabled.remove(&rule);
+            } else {
+                self.disabled.insert(rule);
+            }
+        }
+        true
+    }
+    /// Returns whether `rule` runs.
+    pub fn is_enabled(&self, rule: LintRule) -> bool {
+        !self.disabled.contains(&rule)
+    }
+}
+
+/// A source edit that resolves a [`LintWarning`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LintFix {
+    /// Source range to replace. Empty for an insertion.
+    pub span: SourceSpan,
+    /// Replacement text.
+    pub replacement: String,
+}
+
+/// A rule violation found by [`Document::lint`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+    /// The violated rule.
+    pub rule: LintRule,
+    /// Human-readable description, e.g. `expected h2, found h3`.
+    pub message: String,
+    /// Source range of the violation.
+    pub span: SourceSpan,
+    /// One-based position of `span.start`.
+    pub location: Location,
+    /// Automatic fix, when the rule has a safe rewrite.
+    pub fix: Option<LintFix>,
+}
+
+/// Applies the fixes of `warnings` to `source`.
+///
+/// Fixes are applied in source order. A fix that overlaps an earlier one is
+/// skipped, so linting the result again reports what is left.
+pub fn apply_lint_fixes(source: &str, warnings: &[LintWarning]) -> String {
+    let mut fixes = warnings
+        .iter()
+        .filter_map(|warning| warning.fix.as_ref())
+        .collect::<Vec<_>>();
+    fixes.sort_by_key(|fix| (fix.span.start, fix.span.end));
+    let mut out = String::with_capacity(source.len());
+    let mut cursor = 0;
+    for fix in fixes {
+        let (start, end) = (fix.span.start as usize, fix.span.end as usize);
+        if start < cursor || end > source.len() {
+            continue;
+        }
+        out.push_str(&source[cursor..start]);
+        out.push_str(&fix.replacement);
+        cursor = end;
+    }
+    out.push_str(&source[cursor..]);
+    out
+}
+
+impl Document<'_> {
+    /// Checks this document against the rules enabled in `config`.
+    ///
+    /// Warnings are ordered by position. Line-based rules skip frontmatter,
+    /// and all rules except [`LintRule::NoHardTabs`] and
+    /// [`LintRule::LineLength`] skip code, math, and HTML blocks.
+    pub fn lint(&self, config: &LintConfig) -> Vec<LintWarning> {
+        let mut lint = Lint {
+            document: self,
+            config,
+            warnings: Vec::new(),
+        };
+        lint.check_nodes();
+        lint.check_lines();
+        let mut warnings = lint.warnings;
+        warnings.sort_by_key(|warning| (warning.span.start, warning.rule.id()));
+        warnings
+    }
+}
+
+struct Lint<'a, 'source> {
+    document: &'a Document<'source>,
+    config: &'a LintConfig,
+    warnings: Vec<LintWarning>,
+}
+
+impl Lint<'_, '_> {
+    fn report(&mut self, rule: LintRule, message: String, span: SourceSpan, fix: Option<LintFix>) {
+        if !self.config.is_enabled(rule) {
+            return;
+        }
+        self.warnings.push(LintWarning {
+            rule,
+            message,
+            span,
+            location: self.document.location_at(span.start as usize),
+            fix,
+        });
+    }
+
+    fn check_nodes(&mut self) {
+        let document = self.document;
+        let tree = &document.tree;
+        let source = document.source();
+        let mut previous_level = None;
+        let mut headings = FxHashSet::default();
+        let mut bullet = None;
+        let mut emphasis = None;
+        let mut strong = None;
+        for id in tree.descendants(0) {
+            let span = tree[id].span;
+            let (start, end) = (span.start as usize, span.end as usize);
+            match &tree[id].body {
+                MarkdownNode::Heading(heading) => {
+                    let level = *heading.level() as u8;
+                    if let Some(previous) = previous_level
+                        && level > previous + 1
+                    {
+                        self.report(
+                            LintRule::HeadingIncrement,
+                            format!("expected h{}, found h{level}", previous + 1),
+                            span,
+                            None,
+                        );
+                    }
+                    previous_level = Some(level);
+                    let text = self.document.plain_text(id);
+                    if !headings.insert(text.clone()) {
+                        self.report(
+                            LintRule::NoDuplicateHeading,
+                            format!("duplicate heading `{text}`"),
+                            span,
+                            None,
+                        );
+                    }
+                }
+                MarkdownNode::ListItem(_) => {
+                    let Some(&marker) = source.as_bytes().get(start) else {
+                        continue;
+                    };
+                    if !matches!(marker, b'-' | b'+' | b'*') {
+                        continue;
+                    }
+                    let expected = *bullet.get_or_insert(marker);
+                    if marker != expected {
+                        self.report(
+                            LintRule::UlStyle,
+                            format!(
+                                "expected `{}` list marker, found `{}`",
+                                expected as char, marker as char
+                            ),
+                            SourceSpan::new(span.start, span.start + 1),
+                            Some(LintFix {
+                                span: SourceSpan::new(span.start, span.start + 1),
+                                replacement: (expected as char).to_string(),
+                            }),
+                        );
+                    }
+                }
+                MarkdownNode::Emphasis | MarkdownNode::Strong => {
+                    let strong_node = matches!(tree[id].body, MarkdownNode::Strong);
+                    let width = if strong_node { 2 } else { 1 };
+                    let Some(&marker) = source.as_bytes().get(start) else {
+                        continue;
+                    };
+                    if !matches!(marker, b'*' | b'_') || end < start + 2 * width {
+                        continue;
+                    }
+                    let (rule, style) = if strong_node {
+                        (LintRule::StrongStyle, &mut strong)
+                    } else {
+                        (LintRule::EmphasisStyle, &mut emphasis)
+                    };
+                    let expected = *style.get_or_insert(marker);
+                    if marker == expected {
+                        continue;
+                    }
+                    // 词内的 `_` 不构成强调，改为 `_` 可能破坏语义，不提供修复
+                    let intraword = source[..start]
+                        .chars()
+                        .next_back()
+                        .is_some_and(char::is_alphanumeric)
+                        || source[end..]
+                            .chars()
+                            .next()
+                            .is_some_and(char::is_alphanumeric);
+                    let fix = (expected == b'*' || !intraword).then(|| {
+                        let delimiter = (expected as char).to_string().repeat(width);
+                        LintFix {
+                            span,
+                            replacement: format!(
+                                "{delimiter}{}{delimiter}",
+                                &source[start + width..end - width]
+                            ),
+                        }
+                    });
+                    self.report(
+                        rule,
+                        format!(
+                            "expected `{}` style, found `{}`",
+                            (expected as char).to_string().repeat(width),
+                            (marker as char).to_string().repeat(width)
+                        ),
+                        span,
+                        fix,
+                    );
+                }
+                MarkdownNode::Code(code) => {
+                    if let Code::Fenced(fenced) = code.as_ref()
+                        && fenced.language.as_deref().is_none_or(str::is_empty)
+                    {
+                        self.report(
+                            LintRule::FencedCodeLanguage,
+                            "fenced code block has no language".to_string(),
+                            span,
+                            None,
+                        );
+                    }
+                }
+                MarkdownNode::Text(TextRef::Source(text)) if !self.in_link_or_code(id) => {
+                    for url in bare_urls(document.text(&TextRef::Source(*text))) {
+                        let url_span = SourceSpan::new(
+                            text.start + url.start as u32,
+                            text.start + url.end as u32,
+                        );
+                        let url = &source[url_span.start as usize..url_span.end as usize];
+                        self.report(
+                            LintRule::NoBareUrls,
+                            format!("bare URL `{url}`"),
+                            url_span,
+                            Some(LintFix {
+                                span: url_span,
+                                replacement: format!("<{url}>"),
+                            }),
+                        );
+                    }
+                }
+                MarkdownNode::Link(link) => {
+                    // GFM 扩展自动链接：源码即 URL 本身，未使用 `<>` 包裹
+                    if let Link::Default(link) = link.as_ref()
+                        && !source[start..end].starts_with(['<', '['])
+                        && source[start..end] == *document.text(&link.url)
+                    {
+                        let url = &source[start..end];
+                        self.report(
+                            LintRule::NoBareUrls,
+                            format!("bare URL `{url}`"),
+                            span,
+                            Some(LintFix {
+                                span,
+                                replacement: format!("<{url}>"),
+                            }),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn check_lines(&mut self) {
+        let document = self.document;
+        let source = document.source();
+        let mut raw = Vec::new();
+        let mut frontmatter = None;
+        for id in document.tree.descendants(0) {
+            let span = document.tree[id].span;
+            let range = span.start as usize..span.end as usize;
+            match &document.tree[id].body {
+                MarkdownNode::FrontMatter(_) => frontmatter = Some(range),
+                MarkdownNode::Code(code) if !matches!(code.as_ref(), Code::Inline(_)) => {
+                    raw.push(range)
+                }
+                MarkdownNode::Math(math) if matches!(math.as_ref(), Math::Block(_)) => {
+                    raw.push(range)
+                }
+                MarkdownNode::Html(html) if matches!(html.as_ref(), Html::Block(_)) => {
+                    raw.push(range)
+                }
+                _ => {}
+            }
+        }
+        let inside = |ranges: &[Range<usize>], offset: usize| {
+            ranges
+                .iter()
+                .any(|range| range.start <= offset && offset < range.end)
+        };
+
+        let mut offset = 0;
+        let mut blank_run = 0;
+        for line in source.split_inclusive('\n') {
+            let start = offset;
+            offset += line.len();
+            let content = line.trim_end_matches(['\n', '\r']);
+            let end = start + content.len();
+            if frontmatter
+                .as_ref()
+                .is_some_and(|range| range.start <= start && start < range.end)
+            {
+                continue;
+            }
+            let in_raw = inside(&raw, start);
+            let span = |from: usize, to: usize| SourceSpan::new(from as u32, to as u32);
+
+            if content.trim().is_empty() && !in_raw {
+                blank_run += 1;
+                if blank_run > 1 {
+                    self.report(
+                        LintRule::NoMultipleBlanks,
+                        format!("{blank_run} consecutive blank lines"),
+                        span(start, start),
+                        Some(LintFix {
+                            span: span(start, offset),
+                            replacement: String::new(),
+                        }),
+                    );
+                    continue;
+                }
+            } else {
+                blank_run = 0;
+            }
+
+            let trimmed = content.trim_end_matches([' ', '\t']);
+            let trailing = &content[trimmed.len()..];
+            // 两个空格构成硬换行，按 markdownlint 的默认值允许
+            let hard_break = trailing == "  " && !trimmed.is_empty();
+            if !trailing.is_empty() && !in_raw && !hard_break {
+                self.report(
+                    LintRule::NoTrailingSpaces,
+                    format!("{} trailing whitespace characters", trailing.len()),
+                    span(start + trimmed.len(), end),
+                    Some(LintFix {
+                        span: span(start + trimmed.len(), end),
+                        replacement: String::new(),
+                    }),
+                );
+            }
+
+            if let (Some(first), Some(last)) = (content.find('\t'), content.rfind('\t')) {
+                // 与 MD009 的修复区间重叠时，由重新检查完成剩余修复
+                // 引用标记后的制表符与其后可选空格的列宽相关，展开可能改变块结构，不提供修复
+                let prefix = &content[..first];
+                let quoted =
+                    prefix.contains('>') && prefix.chars().all(|ch| matches!(ch, '>' | ' '));
+                self.report(
+                    LintRule::NoHardTabs,
+                    "hard tab".to_string(),
+                    span(start + first, start + last + 1),
+                    (!quoted).then(|| LintFix {
+                        span: span(start + first, start + last + 1),
+                        replacement: expand_tabs(content, first, last),
+                    }),
+                );
+            }
+
+            let limit = self.config.line_length;
+            if let Some((cut, _)) = content.char_indices().nth(limit)
+                && content[cut..].contains(char::is_whitespace)
+            {
+                self.report(
+                    LintRule::LineLength,
+                    format!(
+                        "line is {} characters long, expected at most {limit}",
+                        content.chars().count()
+                    ),
+                    span(start + cut, end),
+                    None,
+                );
+            }
+        }
+
+        if !source.is_empty() && !source.ends_with('\n') {
+            let end = source.len() as u32;
+            self.report(
+                LintRule::SingleTrailingNewline,
+                "file does not end with a newline".to_string(),
+                SourceSpan::new(end, end),
+                Some(LintFix {
+                    span: SourceSpan::new(end, end),
+                    replacement: "\n".to_string(),
+                }),
+            );
+        }
+    }
+
+    fn in_link_or_code(&self, node_id: usize) -> bool {
+        let tree = &self.document.tree;
+        let mut id = node_id;
+        while id != 0 {
+            id = tree.get_parent(id);
+            if matches!(
+                tree[id].body,
+                MarkdownNode::Link(_)
+                    | MarkdownNode::Image(_)
+                    | MarkdownNode::Code(_)
+                    | MarkdownNode::Html(_)
+            ) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// 把 `content[first..=last]` 中的制表符展开到下一个 4 的倍数列，
+/// 与 CommonMark 的制表位一致，缩进代码块与列表嵌套保持不变
+fn expand_tabs(content: &str, first: usize, last: usize) -> String {
+    let mut column = content[..first].chars().count();
+    let mut out = String::new();
+    for ch in content[first..=last].chars() {
+        if ch == '\t' {
+            let width = 4 - column % 4;
+            out.extend(std::iter::repeat_n(' ', width));
+            column += width;
+        } else {
+            out.push(ch);
+            column += 1;
+        }
+    }
+    out
+}
+
+/// 文本中的 `http://`/`https://` URL，去掉句末标点
+fn bare_urls(text: &str) -> Vec<Range<usize>> {
+    let mut urls = Vec::new();
+    let mut from = 0;
+    while let Some(found) = text[from..].find("http") {
+        let start = from + found;
+        let rest = &text[start..];
+        let preceded = text[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|ch| ch.is_alphanumeric() || matches!(ch, '<' | '/'));
+        if preceded || !(rest.starts_with("http://") || rest.starts_with("https://")) {
+            from = start + 4;
+            continue;
+        }
+        let len = rest
+            .find(|ch: char| ch.is_whitespace() || matches!(ch, '<' | '>' | '"' | '`'))
+            .unwrap_or(rest.len());
+        // 末尾的 `)` 仅在括号不配对时去掉，`https://example.com/a_(b)` 保持完整
+        let mut url = &rest[..len];
+        loop {
+            url = url.trim_end_matches(['.', ',', ':', ';', '!', '?', '\'']);
+            match url.strip_suffix(')') {
+                Some(trimmed) if url.matches(')').count() > url.matches('(').count() => {
+                    url = trimmed;
+                }
+                _ => break,
+            }
+        }
+        let scheme = if rest.starts_with("https") { 8 } else { 7 };
+        if url.len() > scheme {
+            urls.push(start..start + url.len());
+        }
+        from = start + len.max(4);
+    }
+    urls
+}
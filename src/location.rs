@@ -41,3 +41,80 @@ impl Location {
         starts
     }
 }
+
+/// How [`Location::column`] counts characters.
+///
+/// The names follow the LSP `PositionEncodingKind` values.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColumnEncoding {
+    /// UTF-8 bytes.
+    #[serde(rename = "utf-8")]
+    Utf8,
+    /// UTF-16 code units, as used by JavaScript strings, CodeMirror, and the
+    /// default LSP position encoding. Emoji and astral CJK characters count
+    /// as two.
+    #[serde(rename = "utf-16")]
+    Utf16,
+    /// Unicode scalar values. This is the encoding of
+    /// [`crate::Document::location_at`].
+    #[default]
+    #[serde(rename = "utf-32")]
+    Utf32,
+}
+
+impl ColumnEncoding {
+    /// Looks up an encoding by its LSP name: `utf-8`, `utf-16`, or `utf-32`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "utf-8" => Some(ColumnEncoding::Utf8),
+            "utf-16" => Some(ColumnEncoding::Utf16),
+            "utf-32" => Some(ColumnEncoding::Utf32),
+            _ => None,
+        }
+    }
+
+    /// Returns the LSP name of this encoding.
+    pub fn name(self) -> &'static str {
+        match self {
+            ColumnEncoding::Utf8 => "utf-8",
+            ColumnEncoding::Utf16 => "utf-16",
+            ColumnEncoding::Utf32 => "utf-32",
+        }
+    }
+
+    /// `source[start..end]` 的宽度；按字节计算，区间端点无需落在字符边界。
+    /// UTF-16 在标量值计数上为每个四字节序列（辅助平面字符）多计一个单元。
+    pub(crate) fn width(self, source: &[u8], start: usize, end: usize) -> usize {
+        match self {
+            ColumnEncoding::Utf8 => end.saturating_sub(start),
+            ColumnEncoding::Utf16 => {
+                crate::span::count_chars(source, start, end)
+                    + source[start.min(end)..end]
+                        .iter()
+                        .filter(|&&b| b >= 0xF0)
+                        .count()
+            }
+            ColumnEncoding::Utf32 => crate::span::count_chars(source, start, end),
+        }
+    }
+
+    pub(crate) fn char_width(self, ch: char) -> usize {
+        match self {
+            ColumnEncoding::Utf8 => ch.len_utf8(),
+            ColumnEncoding::Utf16 => ch.len_utf16(),
+            ColumnEncoding::Utf32 => 1,
+        }
+    }
+}
+
+/// Start and end [`Location`] of a node, returned by
+/// [`crate::Document::node_locations`].
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeLocation {
+    /// Node ID in [`crate::Document::tree`].
+    pub node_id: usize,
+    /// Location of the span start.
+    pub start: Location,
+    /// Location of the span end.
+    pub end: Location,
+}
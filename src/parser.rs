@@ -24,6 +24,7 @@ pub struct ParserPhaseSnapshot {
     pub(crate) scanner_snapshot: ScannerSnapshot,
     pub(crate) options: ParserOptions,
     pub(crate) text_len: usize,
+    pub(crate) frontmatter_diagnostics: Vec<crate::ast::frontmatter::FrontMatterDiagnostic>,
 }
 
 /// Errors that can stop parsing before a document is produced.
@@ -74,7 +75,19 @@ pub struct ParserOptions {
     pub(crate) smart_punctuation: bool,
     pub(crate) normalize_chinese_punctuation: bool,
     pub(crate) cjk_friendly_delimiters: bool,
+    pub(crate) definition_list: bool,
+    pub(crate) superscript: bool,
+    pub(crate) subscript: bool,
+    pub(crate) insert: bool,
+    pub(crate) spoiler: bool,
+    pub(crate) abbreviation: bool,
+    pub(crate) ruby: bool,
+    pub(crate) link_reference_definitions: bool,
+    pub(crate) source_fidelity: bool,
+    pub(crate) leading_json_frontmatter: bool,
     pub(crate) cjk_nouns: FxHashSet<String>,
+    /// 自定义 emoji shortcode（不含 `:`）到替换值的映射，优先于内置表
+    pub(crate) emoji_aliases: FxHashMap<String, String>,
     /// 启用从 frontmatter 提取 cjk nouns 并指定字段名称
     pub(crate) cjk_nouns_from_frontmatter: Option<String>,
     pub(crate) max_input_bytes: Option<usize>,
@@ -145,6 +158,97 @@ impl ParserOptions {
             ..self
         }
     }
+    /// Enables definition lists (`Term` followed by `: definition` lines).
+    pub fn enabled_definition_list(self) -> Self {
+        Self {
+            definition_list: true,
+            ..self
+        }
+    }
+    /// Enables `^superscript^` inline formatting.
+    pub fn enabled_superscript(self) -> Self {
+        Self {
+            superscript: true,
+            ..self
+        }
+    }
+    /// Enables `~subscript~` inline formatting.
+    ///
+    /// When combined with GFM, a single `~` pair becomes subscript and only
+    /// `~~` pairs become strikethrough.
+    pub fn enabled_subscript(self) -> Self {
+        Self {
+            subscript: true,
+            ..self
+        }
+    }
+    /// Enables `++inserted text++` inline formatting.
+    pub fn enabled_insert(self) -> Self {
+        Self {
+            insert: true,
+            ..self
+        }
+    }
+    /// Enables Discord-style `||spoiler||` inline formatting.
+    pub fn enabled_spoiler(self) -> Self {
+        Self {
+            spoiler: true,
+            ..self
+        }
+    }
+    /// Enables Markdown Extra abbreviations (`*[HTML]: Hyper Text Markup Language`).
+    ///
+    /// Definitions are removed from the output and every whole-word occurrence
+    /// of a defined abbreviation is wrapped in an `<abbr>` element.
+    pub fn enabled_abbreviation(self) -> Self {
+        Self {
+            abbreviation: true,
+            ..self
+        }
+    }
+    /// Enables ruby annotations: `{漢字|かんじ}` and `[漢字]^(かんじ)`.
+    ///
+    /// Separating the annotation with `|` per base character
+    /// (`{漢字|かん|じ}`) annotates each character individually. Ruby bases are
+    /// never touched by CJK autocorrect spacing.
+    pub fn enabled_ruby(self) -> Self {
+        Self { ruby: true, ..self }
+    }
+    /// Keeps link reference definitions in the tree as
+    /// [`MarkdownNode::LinkReferenceDefinition`] nodes.
+    ///
+    /// By default definitions are consumed while parsing and only remain
+    /// available through [`crate::Document::link_definitions`]. The nodes render
+    /// nothing, so HTML output is unchanged.
+    pub fn enabled_link_reference_definitions(self) -> Self {
+        Self {
+            link_reference_definitions: true,
+            ..self
+        }
+    }
+    /// Records which syntax each node was written with, such as `*` or `_`
+    /// emphasis and inline or reference links.
+    ///
+    /// Read the record with [`crate::Document::node_syntax`]. Nothing is
+    /// recorded unless this is enabled.
+    pub fn enabled_source_fidelity(self) -> Self {
+        Self {
+            source_fidelity: true,
+            ..self
+        }
+    }
+    /// Reads a JSON object at the very start of the document as frontmatter,
+    /// as Hugo does.
+    ///
+    /// The object must end its line. Without this option only `;;;` fenced
+    /// JSON is frontmatter, and a leading object stays document text. Not
+    /// included in [`ParserOptions::enabled_all`].
+    pub fn enabled_leading_json_frontmatter(self) -> Self {
+        Self {
+            leading_json_frontmatter: true,
+            ..self
+        }
+    }
     /// Enables every syntax extension and text-processing option.
     pub fn enabled_all(self) -> Self {
         Self {
@@ -157,6 +261,13 @@ impl ParserOptions {
             smart_punctuation: true,
             normalize_chinese_punctuation: true,
             cjk_friendly_delimiters: true,
+            definition_list: true,
+            superscript: true,
+            subscript: true,
+            insert: true,
+            spoiler: true,
+            abbreviation: true,
+            ruby: true,
             ..self
         }
     }
@@ -184,6 +295,25 @@ impl ParserOptions {
         self.cjk_nouns.extend(nouns.into_iter().map(Into::into));
         self
     }
+    /// Replaces the custom emoji shortcodes resolved before the built-in gemoji table.
+    ///
+    /// Keys are shortcodes without colons. A value written as `:name:` refers to
+    /// a built-in shortcode (and therefore accepts skin tones); any other value
+    /// is used verbatim.
+    pub fn with_emoji_aliases<I, K, V>(mut self, aliases: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.emoji_aliases.clear();
+        self.emoji_aliases.extend(
+            aliases
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into())),
+        );
+        self
+    }
     /// Loads additional CJK proper nouns from a frontmatter field.
     ///
     /// The field may contain a string or a list of strings.
@@ -208,8 +338,16 @@ pub struct Parser<'input> {
     /// （文档顺序条目 + 稠密索引 + 单/双段内联，见 `crate::pending`）
     pub(crate) inlines: crate::pending::PendingInlines<'input>,
     pub(crate) link_refs: FxHashMap<String, (String, Option<String>)>, // HRefLabel, (Url, Option<Title>)
-    pub(crate) footnotes: FxHashMap<String, usize>,                    // label, node_id
-    pub(crate) footnote_refs: FxHashMap<String, (usize, usize)>,       // label, index, ref count
+    /// 缩写定义（文档序）；同名定义以首个为准
+    pub(crate) abbreviations: Vec<crate::ast::abbreviation::AbbreviationDefinition>,
+    /// 围栏完整但无法解析的 frontmatter 的诊断
+    pub(crate) frontmatter_diagnostics: Vec<crate::ast::frontmatter::FrontMatterDiagnostic>,
+    /// 供链接检查使用的定义与未解析引用记录
+    pub(crate) link_records: crate::link_check::LinkRecords,
+    /// 源码保真模式下各节点的书写形式
+    pub(crate) syntax: FxHashMap<usize, crate::ast::syntax::NodeSyntax>,
+    pub(crate) footnotes: FxHashMap<String, usize>, // label, node_id
+    pub(crate) footnote_refs: FxHashMap<String, (usize, usize)>, // label, index, ref count
     pub(crate) doc: usize,
     /// 应等同于 tree.cur()
     pub(crate) curr_proc_node: usize,
@@ -277,6 +415,10 @@ impl<'input> Parser<'input> {
             ),
             options,
             link_refs: FxHashMap::default(),
+            abbreviations: Vec::new(),
+            frontmatter_diagnostics: Vec::new(),
+            link_records: Default::default(),
+            syntax: FxHashMap::default(),
             footnotes: FxHashMap::default(),
             footnote_refs: FxHashMap::default(),
             tags: FxHashSet::default(),
@@ -320,21 +462,12 @@ impl<'input> Parser<'input> {
         source: String,
         options: ParserOptions,
     ) -> Result<Document<'static>, ParseError> {
-        let (tree, tags, line_starts) = {
-            let document = Parser::new_with_options(&source, options).parse()?;
-            let Document {
-                tree,
-                tags,
-                line_starts,
-                ..
-            } = document;
-            (tree, tags, line_starts)
-        };
+        let document = Parser::new_with_options(&source, options)
+            .parse()?
+            .detach_source();
         Ok(Document {
             source: SourceText::Owned(source),
-            tree,
-            tags,
-            line_starts,
+            ..document
         })
     }
     /// Parses owned source while materializing inline content only for selected nodes.
@@ -347,7 +480,7 @@ impl<'input> Parser<'input> {
         options: ParserOptions,
         node_ids: &[usize],
     ) -> Result<Document<'static>, ParseError> {
-        let (tree, tags, line_starts) = {
+        let document = {
             let phase = Parser::new_with_options(&source, options)
                 .run_block_phase(None)?
                 .prepare_semantic_targets()?;
@@ -356,19 +489,11 @@ impl<'input> Parser<'input> {
                 selection.select(id);
             }
             let output = phase.parse_selected_inlines(selection)?;
-            let Document {
-                tree,
-                tags,
-                line_starts,
-                ..
-            } = output.document;
-            (tree, tags, line_starts)
+            output.document.detach_source()
         };
         Ok(Document {
             source: SourceText::Owned(source),
-            tree,
-            tags,
-            line_starts,
+            ..document
         })
     }
     /// Parses only frontmatter and returns an owned partial document plus a snapshot.
@@ -376,23 +501,13 @@ impl<'input> Parser<'input> {
         source: String,
         options: ParserOptions,
     ) -> Result<(Document<'static>, ParserPhaseSnapshot), ParseError> {
-        let (tree, tags, line_starts, snapshot) = {
-            let (document, snapshot) =
-                Parser::new_with_options(&source, options).parse_frontmatter_phase()?;
-            let Document {
-                tree,
-                tags,
-                line_starts,
-                ..
-            } = document;
-            (tree, tags, line_starts, snapshot)
-        };
+        let (document, snapshot) =
+            Parser::new_with_options(&source, options).parse_frontmatter_phase()?;
+        let document = document.detach_source();
         Ok((
             Document {
                 source: SourceText::Owned(source),
-                tree,
-                tags,
-                line_starts,
+                ..document
             },
             snapshot,
         ))
@@ -403,29 +518,16 @@ impl<'input> Parser<'input> {
         snapshot: ParserPhaseSnapshot,
     ) -> Result<Document<'static>, ParseError> {
         let Document {
-            source,
-            tree,
-            tags,
-            line_starts: _,
+            source, tree, tags, ..
         } = document;
         match source {
             SourceText::Owned(source) => {
-                let (tree, tags, line_starts) = {
-                    let parser = Parser::from_phase_snapshot(&source, snapshot, tree, tags)?;
-                    let document = parser.continue_parse()?;
-                    let Document {
-                        tree,
-                        tags,
-                        line_starts,
-                        ..
-                    } = document;
-                    (tree, tags, line_starts)
-                };
+                let document = Parser::from_phase_snapshot(&source, snapshot, tree, tags)?
+                    .continue_parse()?
+                    .detach_source();
                 Ok(Document {
                     source: SourceText::Owned(source),
-                    tree,
-                    tags,
-                    line_starts,
+                    ..document
                 })
             }
             SourceText::Borrowed(source) => {
@@ -486,13 +588,20 @@ impl<'input> Parser<'input> {
             scanner_snapshot: self.scanner.snapshot(),
             options: self.options,
             text_len: self.scanner.source().len(),
+            frontmatter_diagnostics: self.frontmatter_diagnostics.clone(),
         };
         Ok((
             Document {
                 source: SourceText::Borrowed(self.scanner.source_str()),
                 tree: self.tree,
                 tags: self.tags,
+                abbreviations: Vec::new(),
+                frontmatter_diagnostics: self.frontmatter_diagnostics,
+                link_records: Default::default(),
+                syntax: FxHashMap::default(),
                 line_starts: std::sync::OnceLock::new(),
+                utf16_line_starts: std::sync::OnceLock::new(),
+                node_index: std::sync::OnceLock::new(),
             },
             snapshot,
         ))
@@ -519,6 +628,7 @@ impl<'input> Parser<'input> {
         parser.scanner.resume(&snapshot.scanner_snapshot);
         parser.tree = tree;
         parser.tags = tags;
+        parser.frontmatter_diagnostics = snapshot.frontmatter_diagnostics;
         parser.curr_proc_node = parser.doc;
         parser.prev_proc_node = parser.doc;
         parser.last_matched_node = parser.doc;
@@ -565,7 +675,12 @@ impl<'input> Parser<'input> {
     /// [`Parser::parse_frontmatter_phase`] instead.
     pub fn parse_frontmatter(&mut self) -> Result<(), ParseError> {
         if let Some(frontmatter) = exts::frontmatter::parse(self) {
-            self.merge_cjk_nouns_from_frontmatter(&frontmatter);
+            self.merge_cjk_nouns_from_frontmatter(&frontmatter.data);
+            if self.options.obsidian_flavored {
+                // 与行内标签一致，按小写合并
+                self.tags
+                    .extend(frontmatter.tags().iter().map(|tag| tag.to_lowercase()));
+            }
             let idx = self.tree.append_child(
                 self.doc,
                 Node::new(MarkdownNode::FrontMatter(Box::new(frontmatter)), 0),
@@ -768,6 +883,9 @@ impl<'input> Parser<'input> {
             last.trim_end_matches(|b: u8| b == b' ' || b == b'\t');
         }
         inlines::process(idx, self, spans);
+        if !self.abbreviations.is_empty() {
+            inlines::wrap_abbreviations(self, idx);
+        }
         self.normalize_component_children(idx);
     }
     pub fn into_ast(self) -> Document<'input> {
@@ -775,7 +893,13 @@ impl<'input> Parser<'input> {
             source: SourceText::Borrowed(self.scanner.source_str()),
             tree: self.tree,
             tags: self.tags,
+            abbreviations: self.abbreviations,
+            frontmatter_diagnostics: self.frontmatter_diagnostics,
+            link_records: self.link_records,
+            syntax: self.syntax,
             line_starts: std::sync::OnceLock::new(),
+            utf16_line_starts: std::sync::OnceLock::new(),
+            node_index: std::sync::OnceLock::new(),
         }
     }
     fn ensure_limits(&self) -> Result<(), ParseError> {
@@ -819,6 +943,12 @@ impl<'input> Parser<'input> {
         nodes.reserve(self.inlines.len().max(16).saturating_sub(nodes.capacity()));
         self.collect_ref_link_candidates(self.doc, &mut nodes);
         for idx in nodes.iter().copied() {
+            if self.options.abbreviation {
+                inlines::process_abbreviation_definitions(self, idx);
+                if !self.tree.node_exists(idx) {
+                    continue;
+                }
+            }
             match self.tree[idx].body {
                 MarkdownNode::Paragraph => inlines::process_link_reference(self, idx),
                 MarkdownNode::Heading(crate::ast::heading::Heading::SETEXT(_)) => {
@@ -1465,6 +1595,13 @@ impl<'input> Parser<'input> {
     pub(crate) fn take_text_postprocess_flag(&mut self, parent: usize) -> bool {
         self.text_postprocess_parents.remove(&parent)
     }
+    /// 源码保真模式下记录 `idx` 的书写形式
+    #[inline]
+    pub(crate) fn record_syntax(&mut self, idx: usize, syntax: crate::ast::syntax::NodeSyntax) {
+        if self.options.source_fidelity {
+            self.syntax.insert(idx, syntax);
+        }
+    }
     pub(crate) fn mark_as_processed(&mut self, idx: usize) {
         self.tree[idx].processing = false;
     }
@@ -1715,6 +1852,25 @@ Text
         assert_eq!(full.len(), resumed.len());
     }
 
+    #[test]
+    fn snapshot_resume_keeps_frontmatter_diagnostics() {
+        let text = "---\na: 1\na: 2\n---\ncontent";
+        let (deferred_doc, snapshot) = Parser::new(text)
+            .parse_frontmatter_phase()
+            .expect("frontmatter phase should succeed");
+        assert_eq!(deferred_doc.frontmatter_diagnostics().len(), 1);
+        let resumed =
+            Parser::from_phase_snapshot(text, snapshot, deferred_doc.tree, deferred_doc.tags)
+                .expect("snapshot restore should succeed")
+                .continue_parse()
+                .expect("continue parse should succeed");
+        assert_eq!(
+            resumed.frontmatter_diagnostics(),
+            Parser::new(text).parse().unwrap().frontmatter_diagnostics()
+        );
+        assert_eq!(resumed.frontmatter_diagnostics()[0].location.line, 3);
+    }
+
     #[test]
     fn snapshot_resume_rejects_input_length_mismatch() {
         let text = "---\na: 1\n---\ncontent";
@@ -13,12 +13,56 @@ use crate::{ast, utils};
 
 struct HtmlRender<'input> {
     writer: &'input mut String,
-    tree: &'input Tree<Node>,
+    tree: &'input RenderTree<'input>,
     source: &'input str,
 }
 
+/// 渲染视图：跳过不产生输出的链接引用定义节点，使块间换行与未保留定义时一致
+struct RenderTree<'input>(&'input Tree<Node>);
+
+impl RenderTree<'_> {
+    fn skip(
+        &self,
+        mut idx: Option<usize>,
+        step: fn(&Tree<Node>, usize) -> Option<usize>,
+    ) -> Option<usize> {
+        while let Some(id) = idx
+            && matches!(self.0[id].body, MarkdownNode::LinkReferenceDefinition(..))
+        {
+            idx = step(self.0, id);
+        }
+        idx
+    }
+    fn get_first_child(&self, idx: usize) -> Option<usize> {
+        self.skip(self.0.get_first_child(idx), Tree::get_next)
+    }
+    fn get_last_child(&self, idx: usize) -> Option<usize> {
+        self.skip(self.0.get_last_child(idx), Tree::get_prev)
+    }
+    fn get_next(&self, idx: usize) -> Option<usize> {
+        self.skip(self.0.get_next(idx), Tree::get_next)
+    }
+    fn get_prev(&self, idx: usize) -> Option<usize> {
+        self.skip(self.0.get_prev(idx), Tree::get_prev)
+    }
+    fn get_parent(&self, idx: usize) -> usize {
+        self.0.get_parent(idx)
+    }
+}
+
+impl std::ops::Index<usize> for RenderTree<'_> {
+    type Output = Node;
+    fn index(&self, idx: usize) -> &Node {
+        &self.0[idx]
+    }
+}
+
 impl<'input> HtmlRender<'input> {
-    fn new(tree: &'input Tree<Node>, source: &'input str, writer: &'input mut String) -> Self {
+    fn new(
+        tree: &'input RenderTree<'input>,
+        source: &'input str,
+        writer: &'input mut String,
+    ) -> Self {
         Self {
             tree,
             source,
@@ -89,6 +133,24 @@ impl<'input> HtmlRender<'input> {
             MarkdownNode::Strong => self.render_wrapped(idx, "<strong>", "</strong>")?,
             MarkdownNode::Strikethrough => self.render_wrapped(idx, "<del>", "</del>")?,
             MarkdownNode::Highlighting => self.render_wrapped(idx, "<mark>", "</mark>")?,
+            MarkdownNode::Superscript => self.render_wrapped(idx, "<sup>", "</sup>")?,
+            MarkdownNode::Subscript => self.render_wrapped(idx, "<sub>", "</sub>")?,
+            MarkdownNode::Insert => self.render_wrapped(idx, "<ins>", "</ins>")?,
+            MarkdownNode::Spoiler => {
+                self.render_wrapped(idx, "<span class=\"spoiler\">", "</span>")?
+            }
+            MarkdownNode::Abbreviation(abbreviation) => {
+                self.prepare_open(idx);
+                self.writer.push_str("<abbr");
+                Self::push_attr(
+                    self.writer,
+                    "title",
+                    Some(abbreviation.title.as_str()).filter(|title| !title.is_empty()),
+                );
+                self.writer.push('>');
+                self.write_children(idx)?;
+                self.write_close("</abbr>", idx);
+            }
             MarkdownNode::Link(link_box) => match link_box.as_ref() {
                 link::Link::Default(link) => {
                     self.prepare_open(idx);
@@ -203,11 +265,18 @@ impl<'input> HtmlRender<'input> {
                 }
                 self.writer.push_str(" />");
             }
-            MarkdownNode::Emoji(emoji) => {
-                self.writer.push(':');
-                self.writer.push_str(emoji);
-                self.writer.push(':');
+            MarkdownNode::Ruby(ruby) => {
+                self.writer.push_str("<ruby>");
+                for annotation in &ruby.annotations {
+                    self.writer.push_str("<rb>");
+                    Self::push_escaped(self.writer, &annotation.base);
+                    self.writer.push_str("</rb><rt>");
+                    Self::push_escaped(self.writer, &annotation.text);
+                    self.writer.push_str("</rt>");
+                }
+                self.writer.push_str("</ruby>");
             }
+            MarkdownNode::Emoji(emoji) => Self::push_escaped(self.writer, &emoji.value),
             MarkdownNode::Tag(tag) => {
                 write!(
                     self.writer,
@@ -271,7 +340,7 @@ impl<'input> HtmlRender<'input> {
                 self.writer.push_str("<hr />");
                 self.writer.push_str(after);
             }
-            MarkdownNode::FrontMatter(..) => {}
+            MarkdownNode::FrontMatter(..) | MarkdownNode::LinkReferenceDefinition(..) => {}
             MarkdownNode::Math(math) => {
                 self.write_math(math, idx)?;
             }
@@ -281,6 +350,14 @@ impl<'input> HtmlRender<'input> {
             MarkdownNode::Embed(embed) => {
                 self.write_embed(embed)?;
             }
+            MarkdownNode::DefinitionList(list) => {
+                self.write_definition_list(list.tight(), idx)?;
+            }
+            MarkdownNode::DefinitionTerm => unreachable!(),
+            MarkdownNode::DefinitionDetails(_) => unreachable!(),
+            MarkdownNode::Transclusion(transclusion) => {
+                self.write_transclusion(transclusion, idx)?;
+            }
         }
         Ok(())
     }
@@ -756,20 +833,7 @@ impl<'input> HtmlRender<'input> {
         tight: bool,
         task_list: Option<bool>,
     ) -> Result<&'static str, fmt::Error> {
-        let newline = if !tight
-            || self
-                .tree
-                .get_first_child(idx)
-                .map(|idx| {
-                    self.tree[idx].body.is_block_level()
-                        && self.tree[idx].body != MarkdownNode::Paragraph
-                })
-                .unwrap_or(false)
-        {
-            "\n"
-        } else {
-            ""
-        };
+        let newline = self.item_open_newline(idx, tight);
         let task_state = if task_list.is_some() {
             if let MarkdownNode::ListItem(li) = &self.tree[idx].body {
                 if let list::ListItem::Task(item) = li.as_ref() {
@@ -818,6 +882,27 @@ impl<'input> HtmlRender<'input> {
                 }
             }
         }
+        self.write_item_children(idx, tight)?;
+        Ok(newline)
+    }
+    /// `<li>`/`<dd>` 的开标签之后是否需要换行
+    fn item_open_newline(&self, idx: usize, tight: bool) -> &'static str {
+        if !tight
+            || self
+                .tree
+                .get_first_child(idx)
+                .map(|idx| {
+                    self.tree[idx].body.is_block_level()
+                        && self.tree[idx].body != MarkdownNode::Paragraph
+                })
+                .unwrap_or(false)
+        {
+            "\n"
+        } else {
+            ""
+        }
+    }
+    fn write_item_children(&mut self, idx: usize, tight: bool) -> fmt::Result {
         if let Some(first_child) = self.tree.get_first_child(idx) {
             if tight {
                 // In tight lists, skip <p> wrappers for ALL Paragraph children
@@ -837,7 +922,26 @@ impl<'input> HtmlRender<'input> {
                 self.write_html(first_child)?;
             }
         }
-        Ok(newline)
+        Ok(())
+    }
+    fn write_definition_list(&mut self, tight: bool, idx: usize) -> fmt::Result {
+        self.write_open("<dl>\n", idx);
+        let mut next = self.tree.get_first_child(idx);
+        while let Some(child_idx) = next {
+            if self.tree[child_idx].body == MarkdownNode::DefinitionTerm {
+                self.writer.push_str("<dt>");
+                self.write_children(child_idx)?;
+                self.writer.push_str("</dt>\n");
+            } else {
+                let newline = self.item_open_newline(child_idx, tight);
+                write!(self.writer, "<dd>{newline}")?;
+                self.write_item_children(child_idx, tight)?;
+                writeln!(self.writer, "{newline}</dd>")?;
+            }
+            next = self.tree.get_next(child_idx);
+        }
+        self.write_close("</dl>", idx);
+        Ok(())
     }
     fn write_table(&mut self, table: &table::Table, idx: usize) -> fmt::Result {
         writeln!(self.writer, "<table>")?;
@@ -1003,9 +1107,9 @@ impl<'input> HtmlRender<'input> {
         self.writer.push_str("\n</div>\n</div>");
         Ok(())
     }
-    fn write_embed(&mut self, embed: &ast::embed::Embed) -> fmt::Result {
-        let mut src = embed.path.clone();
-        if let Some(reference) = &embed.reference {
+    fn embed_src(path: &str, reference: Option<&ast::reference::Reference>) -> String {
+        let mut src = path.to_string();
+        if let Some(reference) = reference {
             use ast::reference::Reference;
             src.push('#');
             match reference {
@@ -1024,6 +1128,25 @@ impl<'input> HtmlRender<'input> {
                 }
             }
         }
+        src
+    }
+    fn write_transclusion(
+        &mut self,
+        transclusion: &ast::transclusion::Transclusion,
+        idx: usize,
+    ) -> fmt::Result {
+        let src = Self::embed_src(&transclusion.path, transclusion.reference.as_ref());
+        self.prepare_open(idx);
+        self.writer
+            .push_str("<div class=\"internal-embed markdown-embed\" src=\"");
+        Self::push_escaped(self.writer, &src);
+        self.writer.push_str("\">\n");
+        self.write_children(idx)?;
+        self.write_close("\n</div>", idx);
+        Ok(())
+    }
+    fn write_embed(&mut self, embed: &ast::embed::Embed) -> fmt::Result {
+        let mut src = Self::embed_src(&embed.path, embed.reference.as_ref());
         if let Some(attrs) = &embed.attrs {
             if !attrs.is_empty() {
                 if src.contains('#') {
@@ -1106,7 +1229,8 @@ impl Document<'_> {
         // Most Markdown inputs expand modestly when rendered to HTML.
         // Reserve upfront to reduce repeated String growth during write!.
         let mut buffer = String::with_capacity(self.tree.node_slots_len().saturating_mul(32));
-        let _ = HtmlRender::new(&self.tree, self.source(), &mut buffer).write_html(0);
+        let tree = RenderTree(&self.tree);
+        let _ = HtmlRender::new(&tree, self.source(), &mut buffer).write_html(0);
         buffer
     }
 }
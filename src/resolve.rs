@@ -0,0 +1,259 @@
+//! Resolving heading and block references against a target note.
+//!
+//! Wikilinks and embeds carry an optional [`Reference`]. Given the source of
+//! the note they point to, [`Parser::resolve_reference`] finds the node or
+//! heading section the reference denotes. Only the resolved nodes get their
+//! inline content materialized, so resolving one reference in a large note
+//! stays cheap:
+//!
+//! ```
+//! use ptdgrp_markdown::reference::Reference;
+//! use ptdgrp_markdown::{Parser, ParserOptions};
+//!
+//! let note = "# Intro\n\nSkip.\n\n## Install\n\nRun **cargo**.\n\n## API\n\nSkip.";
+//! let reference = Reference::Heading("install".to_string());
+//! let resolved = Parser::new_with_options(note, ParserOptions::default().enabled_ofm())
+//!     .resolve_reference(&reference)?
+//!     .expect("heading not found");
+//!
+//! assert_eq!(resolved.target.nodes.len(), 2);
+//! assert_eq!(resolved.text(), "## Install\n\nRun **cargo**.");
+//! # Ok::<(), ptdgrp_markdown::ParseError>(())
+//! ```
+
+use serde::Serialize;
+
+use crate::ast::MarkdownNode;
+use crate::ast::reference::Reference;
+use crate::ast::text::SourceSpan;
+use crate::document::Document;
+use crate::node::Node;
+use crate::parser::{ParseError, Parser};
+use crate::selective::{InlineSelection, SemanticTarget};
+use crate::tree::Tree;
+
+/// The nodes a [`Reference`] denotes.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceTarget {
+    /// Sibling node IDs in document order. A heading reference yields the
+    /// heading followed by its section, up to the next heading of the same or
+    /// a higher level. A block reference yields the single block.
+    pub nodes: Vec<usize>,
+    /// Source range from the first to the last node.
+    pub span: SourceSpan,
+}
+
+/// A reference resolved by [`Parser::resolve_reference`].
+pub struct ResolvedReference<'source> {
+    /// The target note. Only the resolved nodes, and the headings compared on
+    /// the way, have inline children.
+    pub document: Document<'source>,
+    /// The resolved nodes.
+    pub target: ReferenceTarget,
+}
+
+impl ResolvedReference<'_> {
+    /// Returns the source text of the resolved range.
+    pub fn text(&self) -> &str {
+        let span = self.target.span;
+        &self.document.source()[span.start as usize..span.end as usize]
+    }
+}
+
+impl<'input> Parser<'input> {
+    /// Parses the note and locates `reference` in it.
+    ///
+    /// Headings match Obsidian-style: case-insensitively, ignoring formatting
+    /// and the characters `#|^:%[]`. A [`Reference::MultiHeading`] path such
+    /// as `[[Note#Guide#Linux]]` looks for each heading inside the section of
+    /// the previous one. A block ID on a line of its own refers to the
+    /// preceding block, and an ID on the first paragraph of a list item refers
+    /// to the item. Returns `Ok(None)` when nothing matches.
+    pub fn resolve_reference(
+        self,
+        reference: &Reference,
+    ) -> Result<Option<ResolvedReference<'input>>, ParseError> {
+        let mut phase = self.parse_blocks()?.prepare_semantics()?;
+        let targets = phase.targets.clone();
+        let Some(nodes) = locate(&mut phase.parser, &targets, reference) else {
+            return Ok(None);
+        };
+        let mut selection = InlineSelection::default();
+        for &id in &nodes {
+            selection.select(id);
+        }
+        let document = phase.parse_selected_inlines(selection)?.document;
+        let target = ReferenceTarget {
+            span: range(&document.tree, &nodes),
+            nodes,
+        };
+        Ok(Some(ResolvedReference { document, target }))
+    }
+}
+
+impl Document<'_> {
+    /// Locates `reference` in this fully parsed document, with the same rules
+    /// as [`Parser::resolve_reference`].
+    pub fn find_reference(&self, reference: &Reference) -> Option<ReferenceTarget> {
+        let targets = self
+            .tree
+            .descendants(0)
+            .filter(|&id| {
+                matches!(self.tree[id].body, MarkdownNode::Heading(_)) || self.tree[id].id.is_some()
+            })
+            .collect::<Vec<_>>();
+        let nodes = locate(&mut &*self, &targets, reference)?;
+        Some(ReferenceTarget {
+            span: range(&self.tree, &nodes),
+            nodes,
+        })
+    }
+}
+
+/// 解析器（按需物化）与完整文档共用的目标查找接口
+trait TargetSource {
+    fn tree(&self) -> &Tree<Node>;
+    fn source(&self) -> &str;
+    fn ref_text(&mut self, node_id: usize) -> String;
+}
+
+impl TargetSource for Parser<'_> {
+    fn tree(&self) -> &Tree<Node> {
+        &self.tree
+    }
+
+    fn source(&self) -> &str {
+        self.scanner.source_str()
+    }
+
+    fn ref_text(&mut self, node_id: usize) -> String {
+        SemanticTarget {
+            node_id,
+            parser: self,
+        }
+        .ref_text()
+    }
+}
+
+impl TargetSource for &Document<'_> {
+    fn tree(&self) -> &Tree<Node> {
+        &self.tree
+    }
+
+    fn source(&self) -> &str {
+        Document::source(self)
+    }
+
+    fn ref_text(&mut self, node_id: usize) -> String {
+        self.plain_text(node_id)
+    }
+}
+
+fn locate(
+    source: &mut impl TargetSource,
+    targets: &[usize],
+    reference: &Reference,
+) -> Option<Vec<usize>> {
+    match reference {
+        Reference::BlockId(block_id) => {
+            let &node_id = targets.iter().find(|&&id| {
+                source.tree()[id]
+                    .id
+                    .as_ref()
+                    .is_some_and(|it| it.eq_ignore_ascii_case(block_id))
+            })?;
+            Some(vec![block_owner(source, node_id, block_id)])
+        }
+        Reference::Heading(heading) => {
+            heading_section(source, targets, std::slice::from_ref(heading))
+        }
+        Reference::MultiHeading(path) => heading_section(source, targets, path),
+    }
+}
+
+/// 依次在上一个标题的章节内查找路径中的下一个标题
+fn heading_section(
+    source: &mut impl TargetSource,
+    targets: &[usize],
+    path: &[String],
+) -> Option<Vec<usize>> {
+    let (mut start, mut end) = (0, u32::MAX);
+    let mut section = None;
+    for segment in path {
+        let wanted = normalize_heading(segment);
+        let heading = targets.iter().copied().find(|&id| {
+            let node = &source.tree()[id];
+            matches!(node.body, MarkdownNode::Heading(_))
+                && node.span.start >= start
+                && node.span.start < end
+                && normalize_heading(&source.ref_text(id)) == wanted
+        })?;
+        let nodes = section_nodes(source.tree(), heading);
+        start = source.tree()[heading].span.end;
+        end = range(source.tree(), &nodes).end;
+        section = Some(nodes);
+    }
+    section
+}
+
+/// 标题及其后续兄弟节点，直到同级或更高级的标题
+fn section_nodes(tree: &Tree<Node>, heading: usize) -> Vec<usize> {
+    let level = match &tree[heading].body {
+        MarkdownNode::Heading(heading) => *heading.level() as u8,
+        _ => return vec![heading],
+    };
+    let mut nodes = vec![heading];
+    let mut next = tree.get_next(heading);
+    while let Some(id) = next {
+        if let MarkdownNode::Heading(heading) = &tree[id].body
+            && *heading.level() as u8 <= level
+        {
+            break;
+        }
+        nodes.push(id);
+        next = tree.get_next(id);
+    }
+    nodes
+}
+
+/// 单独成行的 `^id` 指向前一个块；列表项首段的 id 指向整个列表项
+fn block_owner(source: &impl TargetSource, node_id: usize, block_id: &str) -> usize {
+    let tree = source.tree();
+    let span = tree[node_id].span;
+    let text = source.source()[span.start as usize..span.end as usize].trim();
+    if matches!(tree[node_id].body, MarkdownNode::Paragraph)
+        && text.strip_prefix('^') == Some(block_id)
+        && let Some(prev) = tree.get_prev(node_id)
+    {
+        return prev;
+    }
+    let parent = tree.get_parent(node_id);
+    if parent != node_id
+        && matches!(tree[parent].body, MarkdownNode::ListItem(_))
+        && tree.get_first_child(parent) == Some(node_id)
+    {
+        return parent;
+    }
+    node_id
+}
+
+fn range(tree: &Tree<Node>, nodes: &[usize]) -> SourceSpan {
+    match (nodes.first(), nodes.last()) {
+        (Some(&first), Some(&last)) => SourceSpan::new(tree[first].span.start, tree[last].span.end),
+        _ => SourceSpan::new(0, 0),
+    }
+}
+
+/// Obsidian 比较标题时忽略大小写、链接中不能出现的字符与多余空白
+fn normalize_heading(text: &str) -> String {
+    text.chars()
+        .map(|ch| match ch {
+            '#' | '|' | '^' | ':' | '%' | '[' | ']' => ' ',
+            ch => ch,
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
@@ -0,0 +1,300 @@
+//! Word counts, character counts, and reading time.
+//!
+//! Words are counted the way Obsidian's word counter does: every CJK
+//! ideograph or kana is one word, and a run of letters or digits is one word,
+//! so mixed Chinese and English notes get a meaningful total. Counting runs
+//! over the source text with code, math, frontmatter, and HTML left out by
+//! default:
+//!
+//! ```
+//! use ptdgrp_markdown::{Parser, ParserOptions, StatisticsConfig};
+//!
+//! let source = "# 笔记\n\n使用 Rust 编写，don't panic.\n\n```rs\nfn main() {}\n```\n";
+//! let document = Parser::new_with_options(source, ParserOptions::default().enabled_gfm())
+//!     .parse()?;
+//! let statistics = document.statistics();
+//!
+//! // 笔 记 使 用 编 写，加上 Rust、don't、panic
+//! assert_eq!(statistics.words, 9);
+//! assert_eq!(statistics.cjk_words, 6);
+//! assert_eq!(statistics.code_blocks, 1);
+//! assert_eq!(
+//!     document
+//!         .statistics_with(&StatisticsConfig::default().with_code(true))
+//!         .words,
+//!     12
+//! );
+//! # Ok::<(), ptdgrp_markdown::ParseError>(())
+//! ```
+
+use serde::Serialize;
+
+use crate::ast::MarkdownNode;
+use crate::ast::code::Code;
+use crate::ast::html::Html;
+use crate::ast::link::Link;
+use crate::document::Document;
+use crate::utils::cjk::is_cjk;
+
+/// Embed extensions counted as images, the same set the HTML renderer shows
+/// as `<img>`.
+const IMAGE_EXTENSIONS: [&str; 7] = ["png", "jpg", "jpeg", "gif", "webp", "svg", "bmp"];
+
+/// What [`Document::statistics_with`] counts, and the reading speeds used for
+/// [`DocumentStatistics::reading_seconds`].
+///
+/// By default code, math, frontmatter, and HTML are not counted, and reading
+/// time assumes 200 Latin words or 300 CJK characters per minute.
+#[derive(Debug, Clone)]
+pub struct StatisticsConfig {
+    code: bool,
+    math: bool,
+    frontmatter: bool,
+    html: bool,
+    words_per_minute: u32,
+    cjk_characters_per_minute: u32,
+}
+
+impl Default for StatisticsConfig {
+    fn default() -> Self {
+        Self {
+            code: false,
+            math: false,
+            frontmatter: false,
+            html: false,
+            words_per_minute: 200,
+            cjk_characters_per_minute: 300,
+        }
+    }
+}
+
+impl StatisticsConfig {
+    /// Counts the text of inline code and code blocks, including fences.
+    pub fn with_code(self, code: bool) -> Self {
+        Self { code, ..self }
+    }
+    /// Counts inline and block math, including `$` delimiters.
+    pub fn with_math(self, math: bool) -> Self {
+        Self { math, ..self }
+    }
+    /// Counts the frontmatter block as written.
+    pub fn with_frontmatter(self, frontmatter: bool) -> Self {
+        Self {
+            frontmatter,
+            ..self
+        }
+    }
+    /// Counts HTML blocks, comments, and tags. Text between inline tags, as
+    /// in `<b>bold</b>`, is always counted.
+    pub fn with_html(self, html: bool) -> Self {
+        Self { html, ..self }
+    }
+    /// Sets the reading speed for words other than CJK characters.
+    pub fn with_words_per_minute(self, words_per_minute: u32) -> Self {
+        Self {
+            words_per_minute: words_per_minute.max(1),
+            ..self
+        }
+    }
+    /// Sets the reading speed for CJK characters.
+    pub fn with_cjk_characters_per_minute(self, cjk_characters_per_minute: u32) -> Self {
+        Self {
+            cjk_characters_per_minute: cjk_characters_per_minute.max(1),
+            ..self
+        }
+    }
+}
+
+/// Counts returned by [`Document::statistics`].
+///
+/// `words` and the character counts cover the counted source text, markup
+/// characters included, as Obsidian's status bar does. The node counts always
+/// cover the whole document.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DocumentStatistics {
+    /// Words, counting each CJK ideograph or kana as one word.
+    pub words: usize,
+    /// The CJK ideographs and kana among `words`.
+    pub cjk_words: usize,
+    /// Characters (Unicode scalar values), whitespace included.
+    pub characters: usize,
+    /// Characters other than whitespace.
+    pub characters_without_spaces: usize,
+    pub paragraphs: usize,
+    pub headings: usize,
+    /// Inline, reference, autolink, and wikilinks; footnote references are
+    /// not links.
+    pub links: usize,
+    /// Images, including embeds of image files such as `![[photo.png]]`.
+    pub images: usize,
+    /// Fenced and indented code blocks.
+    pub code_blocks: usize,
+    /// Estimated reading time in seconds, rounded up.
+    pub reading_seconds: u64,
+}
+
+impl DocumentStatistics {
+    /// Returns the reading time in whole minutes, rounded up.
+    pub fn reading_minutes(&self) -> u64 {
+        self.reading_seconds.div_ceil(60)
+    }
+}
+
+impl Document<'_> {
+    /// Counts words, characters, and blocks with the default
+    /// [`StatisticsConfig`].
+    pub fn statistics(&self) -> DocumentStatistics {
+        self.statistics_with(&StatisticsConfig::default())
+    }
+
+    /// Counts words, characters, and blocks, leaving out the content `config`
+    /// does not count.
+    pub fn statistics_with(&self, config: &StatisticsConfig) -> DocumentStatistics {
+        let mut statistics = DocumentStatistics::default();
+        let mut skipped = Vec::new();
+        for id in self.tree.descendants(0) {
+            let span = self.tree[id].span;
+            let skip = match &self.tree[id].body {
+                MarkdownNode::Paragraph => {
+                    statistics.paragraphs += 1;
+                    false
+                }
+                MarkdownNode::Heading(_) => {
+                    statistics.headings += 1;
+                    false
+                }
+                MarkdownNode::Link(link) => {
+                    if matches!(link.as_ref(), Link::Default(_) | Link::Wikilink(_)) {
+                        statistics.links += 1;
+                    }
+                    false
+                }
+                MarkdownNode::Image(_) => {
+                    statistics.images += 1;
+                    false
+                }
+                MarkdownNode::Embed(embed) => {
+                    let ext = embed.path.rsplit('.').next().unwrap_or_default();
+                    if IMAGE_EXTENSIONS
+                        .iter()
+                        .any(|it| ext.eq_ignore_ascii_case(it))
+                    {
+                        statistics.images += 1;
+                    }
+                    false
+                }
+                MarkdownNode::Code(code) => {
+                    if !matches!(code.as_ref(), Code::Inline(_)) {
+                        statistics.code_blocks += 1;
+                    }
+                    !config.code
+                }
+                MarkdownNode::Math(_) => !config.math,
+                MarkdownNode::FrontMatter(_) => !config.frontmatter,
+                MarkdownNode::Html(html) => {
+                    // 行内元素的区间只含开始标签，结束标签位于最后一个子节点与下一个兄弟节点之间
+                    if !config.html
+                        && matches!(html.as_ref(), Html::Inline(_))
+                        && let Some(last) = self.tree.get_last_child(id)
+                    {
+                        let next = self.tree.get_next(id).map_or_else(
+                            || self.tree[self.tree.get_parent(id)].span.end,
+                            |next| self.tree[next].span.start,
+                        );
+                        skipped.push((self.tree[last].span.end as usize, next as usize));
+                    }
+                    !config.html
+                }
+                _ => false,
+            };
+            if skip {
+                skipped.push((span.start as usize, span.end as usize));
+            }
+        }
+        skipped.sort_unstable();
+
+        // 跳过区间可能嵌套或重叠（如 HTML 块内的代码），按起点顺序扫描
+        let source = self.source();
+        let mut counter = WordCounter::default();
+        let mut offset = 0;
+        for (start, end) in skipped {
+            if start > offset {
+                counter.count(&source[offset..start]);
+            }
+            offset = offset.max(end);
+        }
+        if offset < source.len() {
+            counter.count(&source[offset..]);
+        }
+
+        statistics.words = counter.words;
+        statistics.cjk_words = counter.cjk_words;
+        statistics.characters = counter.characters;
+        statistics.characters_without_spaces = counter.characters_without_spaces;
+        // 以毫秒累计，避免浮点
+        let latin = (counter.words - counter.cjk_words) as u64;
+        let millis = latin * 60_000 / config.words_per_minute as u64
+            + counter.cjk_words as u64 * 60_000 / config.cjk_characters_per_minute as u64;
+        statistics.reading_seconds = millis.div_ceil(1000);
+        statistics
+    }
+}
+
+#[derive(Default)]
+struct WordCounter {
+    words: usize,
+    cjk_words: usize,
+    characters: usize,
+    characters_without_spaces: usize,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum WordState {
+    Gap,
+    /// 位于词内；`digit` 表示上一个字符是数字
+    Word {
+        digit: bool,
+    },
+    /// 词内连接符之后：`'`、`’`、`-` 连接字母，`.`、`,` 只连接数字（`1,000.5`）
+    Joiner {
+        digits: bool,
+    },
+}
+
+impl WordCounter {
+    /// 每段单独计数，段与段之间视为分隔
+    fn count(&mut self, text: &str) {
+        let mut state = WordState::Gap;
+        for ch in text.chars() {
+            self.characters += 1;
+            if !ch.is_whitespace() {
+                self.characters_without_spaces += 1;
+            }
+            // `is_cjk` 也包含 CJK 标点与全角符号，标点不算词
+            if is_cjk(ch) && ch.is_alphanumeric() {
+                self.words += 1;
+                self.cjk_words += 1;
+                state = WordState::Gap;
+                continue;
+            }
+            state = match (state, ch) {
+                (_, ch) if ch.is_alphanumeric() => {
+                    let continues = match state {
+                        WordState::Gap => false,
+                        WordState::Word { .. } => true,
+                        WordState::Joiner { digits } => !digits || ch.is_numeric(),
+                    };
+                    if !continues {
+                        self.words += 1;
+                    }
+                    WordState::Word {
+                        digit: ch.is_numeric(),
+                    }
+                }
+                (WordState::Word { .. }, '\'' | '’' | '-') => WordState::Joiner { digits: false },
+                (WordState::Word { digit: true }, '.' | ',') => WordState::Joiner { digits: true },
+                _ => WordState::Gap,
+            };
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Hierarchical view of nested tags such as `#project/alpha/design`.
+//!
+//! [`Document::tag_occurrences`] lists every tag of one note with its source
+//! span. A [`TagTree`] collects the occurrences of many notes and groups them
+//! by `/`-separated path segments, the way Obsidian's tag pane does:
+//!
+//! ```
+//! use ptdgrp_markdown::{Parser, ParserOptions, TagTree};
+//!
+//! let options = ParserOptions::default().enabled_ofm();
+//! let alpha = Parser::new_with_options("#Project/alpha #project/beta", options.clone()).parse()?;
+//! let beta = Parser::new_with_options("---\ntags: [project/alpha/design]\n---\n", options).parse()?;
+//!
+//! let mut tree = TagTree::new();
+//! tree.add_document("alpha.md", &alpha);
+//! tree.add_document("beta.md", &beta);
+//!
+//! let project = tree.get("project").unwrap();
+//! assert_eq!(project.name(), "Project");
+//! assert_eq!(project.count(), 3);
+//! assert_eq!(tree.notes_under("project/alpha"), ["alpha.md", "beta.md"]);
+//! # Ok::<(), ptdgrp_markdown::ParseError>(())
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::ast::text::SourceSpan;
+use crate::{Document, MarkdownNode};
+
+/// Where a tag was written.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TagSource {
+    /// An inline `#tag` in the body.
+    Inline,
+    /// The frontmatter `tags` (or legacy `tag`) property.
+    FrontMatter,
+}
+
+/// One tag written in a note.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TagOccurrence {
+    /// Tag text as written, without the leading `#`.
+    pub tag: String,
+    /// Source range of the occurrence. Inline tags include the `#`; a
+    /// frontmatter tag covers its list item, or the whole value when several
+    /// tags share one string.
+    pub span: SourceSpan,
+    /// Where the tag was written.
+    pub source: TagSource,
+}
+
+impl TagOccurrence {
+    /// Returns the non-empty `/`-separated path segments of the tag.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        tag_segments(&self.tag)
+    }
+}
+
+/// A [`TagOccurrence`] together with the note it was found in.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct NoteTag {
+    /// Note identifier passed to [`TagTree::add_document`].
+    pub note: String,
+    /// The occurrence inside that note.
+    pub occurrence: TagOccurrence,
+}
+
+/// Splits a tag into its non-empty `/`-separated segments, ignoring a leading `#`.
+pub fn tag_segments(tag: &str) -> impl Iterator<Item = &str> {
+    tag.trim_start_matches('#')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+}
+
+/// Normalizes a tag the way Obsidian compares tags: without the leading `#`,
+/// without empty segments, and lowercased.
+pub fn normalize_tag(tag: &str) -> String {
+    tag_segments(tag)
+        .collect::<Vec<_>>()
+        .join("/")
+        .to_lowercase()
+}
+
+/// One level of a [`TagTree`].
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagNode {
+    name: String,
+    path: String,
+    occurrences: Vec<NoteTag>,
+    children: BTreeMap<String, TagNode>,
+}
+
+impl TagNode {
+    /// Returns the last path segment, e.g. `design` for `project/alpha/design`.
+    ///
+    /// In a case-insensitive tree the casing of the first occurrence is kept.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the full path of this node, e.g. `project/alpha/design`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the occurrences of exactly this tag, in insertion order.
+    pub fn occurrences(&self) -> &[NoteTag] {
+        &self.occurrences
+    }
+
+    /// Returns the nested tags one level below, ordered by key.
+    pub fn children(&self) -> impl Iterator<Item = &TagNode> {
+        self.children.values()
+    }
+
+    /// Returns the number of occurrences of this tag and all nested tags.
+    pub fn count(&self) -> usize {
+        self.occurrences.len() + self.children.values().map(TagNode::count).sum::<usize>()
+    }
+
+    /// Returns the occurrences of this tag and all nested tags, parents first.
+    pub fn occurrences_under(&self) -> Vec<&NoteTag> {
+        let mut result = Vec::new();
+        self.collect(&mut result);
+        result
+    }
+
+    /// Returns the sorted, deduplicated notes that use this tag or a nested tag.
+    pub fn notes(&self) -> Vec<&str> {
+        let mut notes = self
+            .occurrences_under()
+            .into_iter()
+            .map(|it| it.note.as_str())
+            .collect::<Vec<_>>();
+        notes.sort_unstable();
+        notes.dedup();
+        notes
+    }
+
+    fn collect<'a>(&'a self, result: &mut Vec<&'a NoteTag>) {
+        result.extend(&self.occurrences);
+        for child in self.children.values() {
+            child.collect(result);
+        }
+    }
+
+    /// 删除某篇笔记的出现，并剪掉变空的子节点
+    fn remove_note(&mut self, note: &str) {
+        self.occurrences.retain(|it| it.note != note);
+        self.children.retain(|_, child| {
+            child.remove_note(note);
+            !child.occurrences.is_empty() || !child.children.is_empty()
+        });
+    }
+}
+
+/// Tags of many notes, grouped by nested path.
+///
+/// Lookups are case-insensitive by default, matching Obsidian. A prefix query
+/// such as `project` covers `project` and `project/alpha`, but not `projects`.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagTree {
+    root: TagNode,
+    case_sensitive: bool,
+}
+
+impl TagTree {
+    /// Creates an empty case-insensitive tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treats tags that differ only in case as different tags.
+    pub fn case_sensitive(self) -> Self {
+        Self {
+            case_sensitive: true,
+            ..self
+        }
+    }
+
+    /// Adds every tag of `document`. See [`Document::tag_occurrences`].
+    pub fn add_document(&mut self, note: impl Into<String>, document: &Document<'_>) {
+        let note = note.into();
+        for occurrence in document.tag_occurrences() {
+            self.add(note.clone(), occurrence);
+        }
+    }
+
+    /// Adds one occurrence. Tags without any segment are ignored.
+    pub fn add(&mut self, note: impl Into<String>, occurrence: TagOccurrence) {
+        let case_sensitive = self.case_sensitive;
+        let mut node = &mut self.root;
+        let mut has_segment = false;
+        for segment in tag_segments(&occurrence.tag) {
+            let path = if node.path.is_empty() {
+                segment.to_string()
+            } else {
+                format!("{}/{segment}", node.path)
+            };
+            node = node
+                .children
+                .entry(key(segment, case_sensitive))
+                .or_insert_with(|| TagNode {
+                    name: segment.to_string(),
+                    path,
+                    ..TagNode::default()
+                });
+            has_segment = true;
+        }
+        if has_segment {
+            node.occurrences.push(NoteTag {
+                note: note.into(),
+                occurrence,
+            });
+        }
+    }
+
+    /// Removes every occurrence from `note`, e.g. before re-adding a changed note.
+    pub fn remove_note(&mut self, note: &str) {
+        self.root.remove_note(note);
+    }
+
+    /// Returns the top-level tags, ordered by key.
+    pub fn roots(&self) -> impl Iterator<Item = &TagNode> {
+        self.root.children()
+    }
+
+    /// Returns the node for a tag path such as `project/alpha`.
+    pub fn get(&self, prefix: &str) -> Option<&TagNode> {
+        let mut node = &self.root;
+        let mut has_segment = false;
+        for segment in tag_segments(prefix) {
+            node = node.children.get(&key(segment, self.case_sensitive))?;
+            has_segment = true;
+        }
+        has_segment.then_some(node)
+    }
+
+    /// Returns the occurrences of `prefix` and every tag nested below it.
+    pub fn occurrences_under(&self, prefix: &str) -> Vec<&NoteTag> {
+        self.get(prefix)
+            .map(TagNode::occurrences_under)
+            .unwrap_or_default()
+    }
+
+    /// Returns the sorted notes that use `prefix` or a tag nested below it.
+    pub fn notes_under(&self, prefix: &str) -> Vec<&str> {
+        self.get(prefix).map(TagNode::notes).unwrap_or_default()
+    }
+}
+
+fn key(segment: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        segment.to_string()
+    } else {
+        segment.to_lowercase()
+    }
+}
+
+impl Document<'_> {
+    /// Returns every tag of this note with its source span: frontmatter tags
+    /// first, then inline `#tag`s in document order.
+    ///
+    /// Inline tags are only parsed with [`crate::ParserOptions::enabled_ofm`].
+    pub fn tag_occurrences(&self) -> Vec<TagOccurrence> {
+        let mut result = self
+            .frontmatter()
+            .map(|frontmatter| frontmatter.tag_entries())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(tag, span)| TagOccurrence {
+                tag,
+                span,
+                source: TagSource::FrontMatter,
+            })
+            .collect::<Vec<_>>();
+        for id in self.tree.descendants(0) {
+            if let MarkdownNode::Tag(tag) = &self.tree[id].body {
+                result.push(TagOccurrence {
+                    tag: tag.clone(),
+                    span: self.tree[id].span,
+                    source: TagSource::Inline,
+                });
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,602 @@
+//! Expanding note embeds into the host document.
+//!
+//! [`Transcluder::expand`] replaces `![[Note]]`, `![[Note#Section]]`, and
+//! `![[Note#^block]]` embeds with [`MarkdownNode::Transclusion`] nodes that
+//! contain the referenced content, parsed from sources supplied by a loader:
+//!
+//! ```
+//! use ptdgrp_markdown::{LoadedNote, Parser, ParserOptions, Transcluder};
+//!
+//! let options = ParserOptions::default().enabled_ofm();
+//! let mut document = Parser::new_with_options("Intro\n\n![[Setup#Linux]]", options.clone()).parse()?;
+//!
+//! let issues = Transcluder::new(options).expand(&mut document, "index.md", |path, _from| {
+//!     (path == "Setup").then(|| LoadedNote {
+//!         note: "setup.md".to_string(),
+//!         source: "# Linux\n\nUse apt.\n\n# macOS\n\nUse brew.".to_string(),
+//!     })
+//! })?;
+//!
+//! assert!(issues.is_empty());
+//! assert_eq!(
+//!     document.to_html(),
+//!     "<p>Intro</p>\n<div class=\"internal-embed markdown-embed\" src=\"Setup#Linux\">\n<h1>Linux</h1>\n<p>Use apt.</p>\n</div>"
+//! );
+//! # Ok::<(), ptdgrp_markdown::ParseError>(())
+//! ```
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use crate::ast::MarkdownNode;
+use crate::ast::footnote::Footnote;
+use crate::ast::link::Link;
+use crate::ast::reference::Reference;
+use crate::ast::text::{SourceSpan, TextRef};
+use crate::ast::transclusion::Transclusion;
+use crate::document::Document;
+use crate::node::Node;
+use crate::parser::{ParseError, Parser, ParserOptions};
+use crate::tree::Tree;
+
+/// Default nesting limit of [`Transcluder`].
+pub const DEFAULT_TRANSCLUSION_DEPTH: usize = 4;
+
+/// Attachment extensions that are never loaded as notes.
+const ATTACHMENT_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "webp", "svg", "bmp", "avif", "mp3", "wav", "ogg", "m4a", "flac",
+    "mp4", "webm", "mov", "mkv", "ogv", "pdf",
+];
+
+/// A note returned by the loader passed to [`Transcluder::expand`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedNote {
+    /// Stable identifier of the note, such as its vault path. Used for cycle
+    /// detection and recorded in [`Transclusion::note`].
+    pub note: String,
+    /// Markdown source of the note.
+    pub source: String,
+}
+
+/// Why an embed was left unexpanded.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransclusionIssueKind {
+    /// The loader returned no note for the path.
+    NotFound,
+    /// The note exists but does not contain the heading or block.
+    ReferenceNotFound,
+    /// The note is already being expanded further up the chain.
+    Cycle,
+    /// Expanding would exceed the configured depth.
+    DepthLimit,
+    /// The embed is in a heading or table cell, where block content cannot
+    /// be placed.
+    InlineContext,
+}
+
+/// An embed that [`Transcluder::expand`] left in place.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TransclusionIssue {
+    /// Identifier of the note containing the embed.
+    pub note: String,
+    /// Embed path as written.
+    pub path: String,
+    /// Heading or block reference of the embed, if any.
+    pub reference: Option<Reference>,
+    /// Source range of the embed in `note`.
+    pub span: SourceSpan,
+    /// Why the embed was not expanded.
+    pub kind: TransclusionIssueKind,
+}
+
+/// Expands note embeds with content from a caller-supplied loader.
+///
+/// An embed is expanded when it is the only content of its paragraph, and
+/// also when it shares a line with text: the paragraph is then split around
+/// the embed. Embeds of attachments such as images are left alone.
+///
+/// Embedded notes are parsed with the transcluder's options and expanded
+/// recursively. A note that embeds itself, directly or through other notes,
+/// and embeds nested deeper than the depth limit are reported as issues and
+/// left unexpanded.
+///
+/// Grafted footnotes are appended to the host's footnote list, renamed when
+/// their label is already used, and all footnotes are renumbered in document
+/// order. Grafted block IDs that clash with an ID already in the host get a
+/// `-1`, `-2`, ... suffix, so rendered `id` attributes stay unique.
+#[derive(Debug, Clone)]
+pub struct Transcluder {
+    options: ParserOptions,
+    max_depth: usize,
+}
+
+impl Default for Transcluder {
+    fn default() -> Self {
+        Self::new(ParserOptions::default().enabled_ofm())
+    }
+}
+
+impl Transcluder {
+    /// Creates a transcluder that parses embedded notes with `options`.
+    pub fn new(options: ParserOptions) -> Self {
+        Self {
+            options,
+            max_depth: DEFAULT_TRANSCLUSION_DEPTH,
+        }
+    }
+
+    /// Sets how many levels of nested embeds are expanded. `0` expands nothing.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// Expands the embeds of `document`, whose identifier is `note`.
+    ///
+    /// The loader receives the embed path as written and the identifier of
+    /// the note containing the embed, and returns `None` for a missing note.
+    pub fn expand<L>(
+        &self,
+        document: &mut Document<'_>,
+        note: &str,
+        mut loader: L,
+    ) -> Result<Vec<TransclusionIssue>, ParseError>
+    where
+        L: FnMut(&str, &str) -> Option<LoadedNote>,
+    {
+        let mut expansion = Expansion {
+            transcluder: self,
+            loader: &mut loader,
+            stack: vec![note.to_string()],
+            issues: Vec::new(),
+            footnotes_grafted: false,
+        };
+        let mut scope = Vec::new();
+        let mut next = document.tree.get_first_child(0);
+        while let Some(id) = next {
+            scope.push(id);
+            next = document.tree.get_next(id);
+        }
+        expansion.expand(document, note, &mut scope, 0)?;
+        if expansion.footnotes_grafted {
+            renumber_footnotes(&mut document.tree);
+        }
+        // 树结构已改变，丢弃按旧树建立的节点区间索引
+        document.node_index.take();
+        Ok(expansion.issues)
+    }
+}
+
+struct Expansion<'a, L> {
+    transcluder: &'a Transcluder,
+    loader: &'a mut L,
+    /// 正在展开的笔记链，用于检测循环
+    stack: Vec<String>,
+    issues: Vec<TransclusionIssue>,
+    footnotes_grafted: bool,
+}
+
+impl<L> Expansion<'_, L>
+where
+    L: FnMut(&str, &str) -> Option<LoadedNote>,
+{
+    /// 展开 `scope` 中各块内的嵌入；`scope` 随段落拆分更新，结束时仍覆盖同一段内容
+    fn expand(
+        &mut self,
+        document: &mut Document<'_>,
+        note: &str,
+        scope: &mut Vec<usize>,
+        depth: usize,
+    ) -> Result<(), ParseError> {
+        let embeds = scope
+            .iter()
+            .flat_map(|&root| document.tree.descendants(root))
+            .filter_map(|id| match &document.tree[id].body {
+                MarkdownNode::Embed(embed) if is_note_path(&embed.path) => {
+                    Some((id, embed.as_ref().clone()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (id, embed) in embeds {
+            let mut issue = |kind| {
+                self.issues.push(TransclusionIssue {
+                    note: note.to_string(),
+                    path: embed.path.clone(),
+                    reference: embed.reference.clone(),
+                    span: document.tree[id].span,
+                    kind,
+                })
+            };
+            let paragraph = document.tree.get_parent(id);
+            if !matches!(document.tree[paragraph].body, MarkdownNode::Paragraph) {
+                issue(TransclusionIssueKind::InlineContext);
+                continue;
+            }
+            if depth >= self.transcluder.max_depth {
+                issue(TransclusionIssueKind::DepthLimit);
+                continue;
+            }
+            let Some(loaded) = (self.loader)(&embed.path, note) else {
+                issue(TransclusionIssueKind::NotFound);
+                continue;
+            };
+            if self.stack.contains(&loaded.note) {
+                issue(TransclusionIssueKind::Cycle);
+                continue;
+            }
+            let mut embedded =
+                Parser::new_with_options(&loaded.source, self.transcluder.options.clone())
+                    .parse()?;
+            // 先在未展开的笔记中定位引用，只展开选中的块，未选中部分的嵌入不加载也不报告
+            let (mut nodes, span) = match &embed.reference {
+                Some(reference) => match embedded.find_reference(reference) {
+                    Some(target) => (target.nodes, target.span),
+                    None => {
+                        self.issues.push(TransclusionIssue {
+                            note: note.to_string(),
+                            path: embed.path.clone(),
+                            reference: embed.reference.clone(),
+                            span: document.tree[id].span,
+                            kind: TransclusionIssueKind::ReferenceNotFound,
+                        });
+                        continue;
+                    }
+                },
+                None => {
+                    let nodes = content_nodes(&embedded.tree);
+                    let span = match (nodes.first(), nodes.last()) {
+                        (Some(&first), Some(&last)) => SourceSpan::new(
+                            embedded.tree[first].span.start,
+                            embedded.tree[last].span.end,
+                        ),
+                        _ => SourceSpan::new(0, 0),
+                    };
+                    (nodes, span)
+                }
+            };
+            self.stack.push(loaded.note.clone());
+            let result = self.expand(&mut embedded, &loaded.note, &mut nodes, depth + 1);
+            self.stack.pop();
+            result?;
+            let transclusion = Transclusion {
+                note: loaded.note,
+                path: embed.path,
+                reference: embed.reference,
+                span,
+            };
+            self.footnotes_grafted |= graft(document, id, &embedded, &nodes, transclusion, scope);
+        }
+        Ok(())
+    }
+}
+
+fn is_note_path(path: &str) -> bool {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    !name.rsplit_once('.').is_some_and(|(_, extension)| {
+        ATTACHMENT_EXTENSIONS
+            .iter()
+            .any(|it| it.eq_ignore_ascii_case(extension))
+    })
+}
+
+/// 整篇嵌入：根下除 frontmatter 与脚注列表外的全部块
+fn content_nodes(tree: &Tree<Node>) -> Vec<usize> {
+    let mut nodes = Vec::new();
+    let mut next = tree.get_first_child(0);
+    while let Some(id) = next {
+        if !matches!(
+            tree[id].body,
+            MarkdownNode::FrontMatter(_) | MarkdownNode::FootnoteList
+        ) {
+            nodes.push(id);
+        }
+        next = tree.get_next(id);
+    }
+    nodes
+}
+
+/// 用 `Transclusion` 块替换 `embed`，必要时在其前后拆分段落；返回是否嫁接了脚注。
+/// 所在段落位于 `scope` 中时，以拆分后的块替换它
+fn graft(
+    host: &mut Document<'_>,
+    embed: usize,
+    embedded: &Document<'_>,
+    nodes: &[usize],
+    transclusion: Transclusion,
+    scope: &mut Vec<usize>,
+) -> bool {
+    let mut ids = collect_ids(&host.tree);
+    let mut labels = collect_labels(&host.tree);
+    let source = host.source.as_str();
+    let tree = &mut host.tree;
+    let paragraph = tree.get_parent(embed);
+    let span = tree[embed].span;
+    let mut after = Vec::new();
+    let mut next = tree.get_next(embed);
+    while let Some(id) = next {
+        after.push(id);
+        next = tree.get_next(id);
+    }
+    // 拆分点两侧的换行与纯空白文本随嵌入一起移除
+    let is_gap = |tree: &Tree<Node>, id: usize| match &tree[id].body {
+        MarkdownNode::SoftBreak | MarkdownNode::HardBreak => true,
+        MarkdownNode::Text(text) => text.resolve(source).trim().is_empty(),
+        _ => false,
+    };
+    while let Some(&first) = after.first()
+        && is_gap(tree, first)
+    {
+        after.remove(0);
+        tree.remove(first);
+    }
+    while let Some(prev) = tree.get_prev(embed)
+        && is_gap(tree, prev)
+    {
+        tree.remove(prev);
+    }
+    tree.remove(embed);
+
+    let target = tree.insert_after(
+        paragraph,
+        block(MarkdownNode::Transclusion(Box::new(transclusion)), span),
+    );
+    let paragraph_span = tree[paragraph].span;
+    let mut block_id = tree[paragraph].id.take();
+    let mut blocks = vec![paragraph, target];
+    if let Some(&first) = after.first() {
+        let start = tree[first].span.start;
+        let rest = tree.insert_after(
+            target,
+            block(
+                MarkdownNode::Paragraph,
+                SourceSpan::new(start, paragraph_span.end),
+            ),
+        );
+        for id in after {
+            tree.unlink(id);
+            tree.append_existing(rest, id);
+        }
+        tree[rest].id = block_id.take();
+        blocks.push(rest);
+    }
+    match tree.get_last_child(paragraph) {
+        Some(last) => {
+            tree[paragraph].span.end = tree[last].span.end;
+            tree[paragraph].id = block_id.take();
+        }
+        None => {
+            tree.remove(paragraph);
+            tree[target].id = block_id.take();
+            blocks.remove(0);
+        }
+    }
+    if let Some(index) = scope.iter().position(|&id| id == paragraph) {
+        scope.splice(index..=index, blocks);
+    }
+
+    let mut copied = Vec::new();
+    for &id in nodes {
+        // 单个列表项（块 ID 引用）连同所在列表的外壳一起复制
+        let parent = match &embedded.tree[id].body {
+            MarkdownNode::ListItem(_) => {
+                let list = embedded.tree.get_parent(id);
+                let body = embedded.tree[list].body.clone();
+                tree.append_child(target, block(body, span))
+            }
+            _ => target,
+        };
+        copy_subtree(embedded, id, span, tree, parent, &mut copied);
+    }
+    dedup_ids(tree, &copied, &mut ids);
+    graft_footnotes(tree, &copied, embedded, span, &mut labels)
+}
+
+fn block(body: MarkdownNode, span: SourceSpan) -> Node {
+    Node {
+        body,
+        span,
+        processing: false,
+        id: None,
+    }
+}
+
+/// 复制子树；源码引用的文本转为自有文本，区间一律取嵌入在宿主中的区间
+fn copy_subtree(
+    from: &Document<'_>,
+    id: usize,
+    span: SourceSpan,
+    tree: &mut Tree<Node>,
+    parent: usize,
+    copied: &mut Vec<usize>,
+) -> usize {
+    let node = &from.tree[id];
+    let owned = |text: &TextRef| TextRef::Owned(from.text(text).to_string());
+    let body = match &node.body {
+        MarkdownNode::Text(text) => MarkdownNode::Text(owned(text)),
+        MarkdownNode::Link(link) => match link.as_ref() {
+            Link::Default(link) => {
+                let mut link = link.clone();
+                link.url = owned(&link.url);
+                link.title = link.title.as_ref().map(owned);
+                MarkdownNode::Link(Box::new(Link::Default(link)))
+            }
+            _ => node.body.clone(),
+        },
+        MarkdownNode::Image(image) => {
+            let mut image = image.clone();
+            image.url = owned(&image.url);
+            image.title = image.title.as_ref().map(owned);
+            MarkdownNode::Image(image)
+        }
+        body => body.clone(),
+    };
+    let index = tree.append_child(
+        parent,
+        Node {
+            body,
+            span,
+            processing: false,
+            id: node.id.clone(),
+        },
+    );
+    copied.push(index);
+    let mut child = from.tree.get_first_child(id);
+    while let Some(child_id) = child {
+        copy_subtree(from, child_id, span, tree, index, copied);
+        child = from.tree.get_next(child_id);
+    }
+    index
+}
+
+fn collect_ids(tree: &Tree<Node>) -> FxHashSet<String> {
+    tree.descendants(0)
+        .filter_map(|id| tree[id].id.as_deref().cloned())
+        .collect()
+}
+
+fn collect_labels(tree: &Tree<Node>) -> FxHashSet<String> {
+    tree.descendants(0)
+        .filter_map(|id| match &tree[id].body {
+            MarkdownNode::Footnote(footnote) => Some(footnote.label.clone()),
+            MarkdownNode::Link(link) => match link.as_ref() {
+                Link::Footnote(link) => Some(link.footnote_label.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// 在 `taken` 中占用 `name`，冲突时追加 `-1`、`-2`…
+fn unique(name: &str, taken: &mut FxHashSet<String>) -> String {
+    let mut candidate = name.to_string();
+    let mut suffix = 0;
+    while taken.contains(&candidate) {
+        suffix += 1;
+        candidate = format!("{name}-{suffix}");
+    }
+    taken.insert(candidate.clone());
+    candidate
+}
+
+fn dedup_ids(tree: &mut Tree<Node>, copied: &[usize], taken: &mut FxHashSet<String>) {
+    for &id in copied {
+        if let Some(block_id) = &tree[id].id {
+            let renamed = unique(block_id, taken);
+            tree[id].id = Some(Box::new(renamed));
+        }
+    }
+}
+
+/// 改名嫁接内容中的脚注引用，并把对应定义复制到宿主的脚注列表
+fn graft_footnotes(
+    tree: &mut Tree<Node>,
+    copied: &[usize],
+    embedded: &Document<'_>,
+    span: SourceSpan,
+    taken: &mut FxHashSet<String>,
+) -> bool {
+    // 旧标签 -> (新标签, 嫁接的引用序号)，按首次出现排序
+    let mut renames: Vec<(String, String, FxHashSet<usize>)> = Vec::new();
+    for &id in copied {
+        let MarkdownNode::Link(link) = &mut tree[id].body else {
+            continue;
+        };
+        let Link::Footnote(link) = link.as_mut() else {
+            continue;
+        };
+        let position = match renames
+            .iter()
+            .position(|(old, ..)| *old == link.footnote_label)
+        {
+            Some(position) => position,
+            None => {
+                let renamed = unique(&link.footnote_label, taken);
+                renames.push((link.footnote_label.clone(), renamed, FxHashSet::default()));
+                renames.len() - 1
+            }
+        };
+        renames[position].2.insert(link.ref_count);
+        link.footnote_label = renames[position].1.clone();
+    }
+    if renames.is_empty() {
+        return false;
+    }
+    let Some(source_list) = content_list(&embedded.tree) else {
+        return true;
+    };
+    let list = match content_list(tree) {
+        Some(list) => list,
+        None => tree.append_child(0, block(MarkdownNode::FootnoteList, SourceSpan::new(0, 0))),
+    };
+    for (old, renamed, refs) in &renames {
+        let definition = children(&embedded.tree, source_list).find(|&id| {
+            matches!(&embedded.tree[id].body, MarkdownNode::Footnote(footnote) if footnote.label == *old)
+        });
+        let Some(definition) = definition else {
+            continue;
+        };
+        let mut copied = Vec::new();
+        let index = copy_subtree(embedded, definition, span, tree, list, &mut copied);
+        tree[index].body = MarkdownNode::Footnote(Box::new(Footnote {
+            label: renamed.clone(),
+            ref_count: refs.len(),
+        }));
+        // 只保留指向已嫁接引用的回链
+        for id in copied {
+            let MarkdownNode::Link(link) = &mut tree[id].body else {
+                continue;
+            };
+            let Link::FootnoteBackref(backref) = link.as_mut() else {
+                continue;
+            };
+            if backref.footnote_label != *old {
+                continue;
+            }
+            if refs.contains(&backref.index) {
+                backref.footnote_label = renamed.clone();
+            } else {
+                tree.remove(id);
+            }
+        }
+    }
+    true
+}
+
+fn content_list(tree: &Tree<Node>) -> Option<usize> {
+    children(tree, 0).find(|&id| matches!(tree[id].body, MarkdownNode::FootnoteList))
+}
+
+fn children(tree: &Tree<Node>, parent: usize) -> impl Iterator<Item = usize> + '_ {
+    std::iter::successors(tree.get_first_child(parent), |&id| tree.get_next(id))
+}
+
+/// 按文档中首次引用的顺序重新编号全部脚注，并据此重排脚注列表
+fn renumber_footnotes(tree: &mut Tree<Node>) {
+    let mut numbers = FxHashMap::default();
+    let links = tree.descendants(0).collect::<Vec<_>>();
+    for id in links {
+        if let MarkdownNode::Link(link) = &mut tree[id].body
+            && let Link::Footnote(link) = link.as_mut()
+        {
+            let next = numbers.len() + 1;
+            link.index = *numbers.entry(link.footnote_label.clone()).or_insert(next);
+        }
+    }
+    let Some(list) = content_list(tree) else {
+        return;
+    };
+    let mut definitions = children(tree, list).collect::<Vec<_>>();
+    definitions.sort_by_key(|&id| {
+        match &tree[id].body {
+            MarkdownNode::Footnote(footnote) => numbers.get(&footnote.label).copied(),
+            _ => None,
+        }
+        .unwrap_or(usize::MAX)
+    });
+    for id in definitions {
+        tree.unlink(id);
+        tree.append_existing(list, id);
+    }
+}
@@ -157,6 +157,36 @@ impl<T: Debug> Tree<T> {
         index
     }
 
+    /// Inserts a new node directly after `sibling` and returns its ID.
+    pub fn insert_after(&mut self, sibling: usize, node: T) -> usize {
+        let index = self.create_node_attached(node);
+        let parent = self.nodes[sibling].parent;
+        self.nodes[index].parent = parent;
+        self.nodes[index].prev = link_to(sibling);
+        self.nodes[index].next = self.nodes[sibling].next;
+        match link_get(self.nodes[sibling].next) {
+            Some(next) => self.nodes[next].prev = link_to(index),
+            None => self.nodes[parent].last_child = link_to(index),
+        }
+        self.nodes[sibling].next = link_to(index);
+        index
+    }
+
+    /// Inserts a new node directly before `sibling` and returns its ID.
+    pub fn insert_before(&mut self, sibling: usize, node: T) -> usize {
+        let index = self.create_node_attached(node);
+        let parent = self.nodes[sibling].parent;
+        self.nodes[index].parent = parent;
+        self.nodes[index].next = link_to(sibling);
+        self.nodes[index].prev = self.nodes[sibling].prev;
+        match link_get(self.nodes[sibling].prev) {
+            Some(prev) => self.nodes[prev].next = link_to(index),
+            None => self.nodes[parent].first_child = link_to(index),
+        }
+        self.nodes[sibling].prev = link_to(index);
+        index
+    }
+
     /// Replaces the value at `idx` while preserving its structural links.
     pub fn replace(&mut self, idx: usize, item: T) -> Option<TreeNode<T>> {
         if idx >= self.nodes.len() {
@@ -324,6 +354,15 @@ impl<T: Debug> Tree<T> {
         self.nodes[index].next = link_to(next);
         self.nodes[next].prev = link_to(index);
     }
+    /// Returns the descendants of `index` in document (pre-)order, excluding
+    /// `index` itself.
+    pub fn descendants(&self, index: usize) -> Descendants<'_, T> {
+        Descendants {
+            tree: self,
+            root: index,
+            next: self.get_first_child(index),
+        }
+    }
     /// Returns the previous sibling of `index`.
     pub fn get_prev(&self, index: usize) -> Option<usize> {
         link_get(self.nodes[index].prev)
@@ -411,6 +450,20 @@ impl<T: Debug> Tree<T> {
         self.nodes[idx].next = None;
         self.nodes[idx].prev = None;
     }
+    /// 把已 `unlink` 的节点（连同其子树）重新挂为 `parent` 的最后一个子节点
+    pub(crate) fn append_existing(&mut self, parent: usize, idx: usize) {
+        debug_assert!(self.nodes[idx].next.is_none() && self.nodes[idx].prev.is_none());
+        match link_get(self.nodes[parent].last_child) {
+            Some(last_child) => {
+                self.nodes[last_child].next = link_to(idx);
+                self.nodes[idx].prev = link_to(last_child);
+            }
+            None => self.nodes[parent].first_child = link_to(idx),
+        }
+        self.nodes[parent].last_child = link_to(idx);
+        self.nodes[idx].parent = parent;
+        self.clear_free(idx);
+    }
     /// Returns `true` when the node is detached from the live tree.
     pub fn is_free_node(&self, idx: &usize) -> bool {
         self.free_flags.get(*idx).copied().unwrap_or(false)
@@ -487,9 +540,38 @@ where
     }
 }
 
+/// Pre-order iterator returned by [`Tree::descendants`].
+pub struct Descendants<'a, T> {
+    tree: &'a Tree<T>,
+    root: usize,
+    next: Option<usize>,
+}
+
+impl<T: Debug> Iterator for Descendants<'_, T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let current = self.next?;
+        // 先子节点，再兄弟节点，最后回溯到祖先的下一个兄弟
+        self.next = self.tree.get_first_child(current).or_else(|| {
+            let mut node = current;
+            loop {
+                if node == self.root {
+                    return None;
+                }
+                if let Some(next) = self.tree.get_next(node) {
+                    return Some(next);
+                }
+                node = self.tree.get_parent(node);
+            }
+        });
+        Some(current)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::TreeNode;
+    use super::{Tree, TreeNode};
 
     /// P5 链接压缩后的槽位尺寸上限（压缩前 152 字节，`Option<usize>` 链接 ×4）。
     #[test]
@@ -500,4 +582,53 @@ mod tests {
             std::mem::size_of::<TreeNode<crate::node::Node>>()
         );
     }
+
+    #[test]
+    fn insert_after_links_siblings() {
+        let mut tree = Tree::new();
+        let root = tree.append(0);
+        let first = tree.append_child(root, 1);
+        let last = tree.append_child(root, 3);
+        let middle = tree.insert_after(first, 2);
+        let tail = tree.insert_after(last, 4);
+        assert_eq!(tree.get_next(first), Some(middle));
+        assert_eq!(tree.get_prev(last), Some(middle));
+        assert_eq!(tree.get_next(last), Some(tail));
+        assert_eq!(tree.get_last_child(root), Some(tail));
+        assert_eq!(tree.get_parent(middle), root);
+    }
+
+    #[test]
+    fn insert_before_links_siblings() {
+        let mut tree = Tree::new();
+        let root = tree.append(0);
+        let first = tree.append_child(root, 1);
+        let last = tree.append_child(root, 3);
+        let middle = tree.insert_before(last, 2);
+        let head = tree.insert_before(first, 0);
+        assert_eq!(tree.get_prev(last), Some(middle));
+        assert_eq!(tree.get_next(first), Some(middle));
+        assert_eq!(tree.get_prev(first), Some(head));
+        assert_eq!(tree.get_first_child(root), Some(head));
+        assert_eq!(tree.get_parent(middle), root);
+    }
+
+    #[test]
+    fn descendants_are_pre_order() {
+        let mut tree = Tree::new();
+        let root = tree.append(0);
+        let a = tree.append_child(root, 1);
+        let a1 = tree.append_child(a, 2);
+        tree.append_child(a1, 3);
+        let b = tree.append_child(root, 4);
+        tree.append_child(b, 5);
+        let values = |index| {
+            tree.descendants(index)
+                .map(|it| tree[it])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(root), [1, 2, 3, 4, 5]);
+        assert_eq!(values(a), [2, 3]);
+        assert_eq!(values(a1), [3]);
+    }
 }
@@ -1,5 +1,6 @@
 pub(crate) mod chinese_punctuation;
 pub(crate) mod cjk;
+pub(crate) mod emoji;
 pub(crate) mod entities;
 pub(crate) mod percent_encode;
 mod puncttable;
@@ -0,0 +1,5239 @@
+// https://github.com/github/gemoji/blob/master/db/emoji.json
+// 由 gemoji 数据（经 `emojis` crate 0.8.2 打包）生成，按字节序排序以便二分查找：
+// ```rust
+// for e in emojis::iter() {
+//     for code in e.shortcodes() {
+//         names.push((code, e.as_str()));
+//         if let Some(tones) = e.skin_tones().filter(|it| it.clone().count() == 6) {
+//             skin_tones.push((code, tones.skip(1).map(emojis::Emoji::as_str)));
+//         }
+//     }
+// }
+// names.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+// skin_tones.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+// ```
+
+static EMOJIS: [(&[u8], &str); 1913] = [
+    (b"+1", "\u{1F44D}"),
+    (b"-1", "\u{1F44E}"),
+    (b"100", "\u{1F4AF}"),
+    (b"1234", "\u{1F522}"),
+    (b"1st_place_medal", "\u{1F947}"),
+    (b"2nd_place_medal", "\u{1F948}"),
+    (b"3rd_place_medal", "\u{1F949}"),
+    (b"8ball", "\u{1F3B1}"),
+    (b"a", "\u{1F170}\u{FE0F}"),
+    (b"ab", "\u{1F18E}"),
+    (b"abacus", "\u{1F9EE}"),
+    (b"abc", "\u{1F524}"),
+    (b"abcd", "\u{1F521}"),
+    (b"accept", "\u{1F251}"),
+    (b"accordion", "\u{1FA97}"),
+    (b"adhesive_bandage", "\u{1FA79}"),
+    (b"adult", "\u{1F9D1}"),
+    (b"aerial_tramway", "\u{1F6A1}"),
+    (b"afghanistan", "\u{1F1E6}\u{1F1EB}"),
+    (b"airplane", "\u{2708}\u{FE0F}"),
+    (b"aland_islands", "\u{1F1E6}\u{1F1FD}"),
+    (b"alarm_clock", "\u{23F0}"),
+    (b"albania", "\u{1F1E6}\u{1F1F1}"),
+    (b"alembic", "\u{2697}\u{FE0F}"),
+    (b"algeria", "\u{1F1E9}\u{1F1FF}"),
+    (b"alien", "\u{1F47D}"),
+    (b"ambulance", "\u{1F691}"),
+    (b"american_samoa", "\u{1F1E6}\u{1F1F8}"),
+    (b"amphora", "\u{1F3FA}"),
+    (b"anatomical_heart", "\u{1FAC0}"),
+    (b"anchor", "\u{2693}"),
+    (b"andorra", "\u{1F1E6}\u{1F1E9}"),
+    (b"angel", "\u{1F47C}"),
+    (b"anger", "\u{1F4A2}"),
+    (b"angola", "\u{1F1E6}\u{1F1F4}"),
+    (b"angry", "\u{1F620}"),
+    (b"anguilla", "\u{1F1E6}\u{1F1EE}"),
+    (b"anguished", "\u{1F627}"),
+    (b"ant", "\u{1F41C}"),
+    (b"antarctica", "\u{1F1E6}\u{1F1F6}"),
+    (b"antigua_barbuda", "\u{1F1E6}\u{1F1EC}"),
+    (b"apple", "\u{1F34E}"),
+    (b"aquarius", "\u{2652}"),
+    (b"argentina", "\u{1F1E6}\u{1F1F7}"),
+    (b"aries", "\u{2648}"),
+    (b"armenia", "\u{1F1E6}\u{1F1F2}"),
+    (b"arrow_backward", "\u{25C0}\u{FE0F}"),
+    (b"arrow_double_down", "\u{23EC}"),
+    (b"arrow_double_up", "\u{23EB}"),
+    (b"arrow_down", "\u{2B07}\u{FE0F}"),
+    (b"arrow_down_small", "\u{1F53D}"),
+    (b"arrow_forward", "\u{25B6}\u{FE0F}"),
+    (b"arrow_heading_down", "\u{2935}\u{FE0F}"),
+    (b"arrow_heading_up", "\u{2934}\u{FE0F}"),
+    (b"arrow_left", "\u{2B05}\u{FE0F}"),
+    (b"arrow_lower_left", "\u{2199}\u{FE0F}"),
+    (b"arrow_lower_right", "\u{2198}\u{FE0F}"),
+    (b"arrow_right", "\u{27A1}\u{FE0F}"),
+    (b"arrow_right_hook", "\u{21AA}\u{FE0F}"),
+    (b"arrow_up", "\u{2B06}\u{FE0F}"),
+    (b"arrow_up_down", "\u{2195}\u{FE0F}"),
+    (b"arrow_up_small", "\u{1F53C}"),
+    (b"arrow_upper_left", "\u{2196}\u{FE0F}"),
+    (b"arrow_upper_right", "\u{2197}\u{FE0F}"),
+    (b"arrows_clockwise", "\u{1F503}"),
+    (b"arrows_counterclockwise", "\u{1F504}"),
+    (b"art", "\u{1F3A8}"),
+    (b"articulated_lorry", "\u{1F69B}"),
+    (b"artificial_satellite", "\u{1F6F0}\u{FE0F}"),
+    (b"artist", "\u{1F9D1}\u{200D}\u{1F3A8}"),
+    (b"aruba", "\u{1F1E6}\u{1F1FC}"),
+    (b"ascension_island", "\u{1F1E6}\u{1F1E8}"),
+    (b"asterisk", "\u{002A}\u{FE0F}\u{20E3}"),
+    (b"astonished", "\u{1F632}"),
+    (b"astronaut", "\u{1F9D1}\u{200D}\u{1F680}"),
+    (b"athletic_shoe", "\u{1F45F}"),
+    (b"atm", "\u{1F3E7}"),
+    (b"atom_symbol", "\u{269B}\u{FE0F}"),
+    (b"australia", "\u{1F1E6}\u{1F1FA}"),
+    (b"austria", "\u{1F1E6}\u{1F1F9}"),
+    (b"auto_rickshaw", "\u{1F6FA}"),
+    (b"avocado", "\u{1F951}"),
+    (b"axe", "\u{1FA93}"),
+    (b"azerbaijan", "\u{1F1E6}\u{1F1FF}"),
+    (b"b", "\u{1F171}\u{FE0F}"),
+    (b"baby", "\u{1F476}"),
+    (b"baby_bottle", "\u{1F37C}"),
+    (b"baby_chick", "\u{1F424}"),
+    (b"baby_symbol", "\u{1F6BC}"),
+    (b"back", "\u{1F519}"),
+    (b"bacon", "\u{1F953}"),
+    (b"badger", "\u{1F9A1}"),
+    (b"badminton", "\u{1F3F8}"),
+    (b"bagel", "\u{1F96F}"),
+    (b"baggage_claim", "\u{1F6C4}"),
+    (b"baguette_bread", "\u{1F956}"),
+    (b"bahamas", "\u{1F1E7}\u{1F1F8}"),
+    (b"bahrain", "\u{1F1E7}\u{1F1ED}"),
+    (b"balance_scale", "\u{2696}\u{FE0F}"),
+    (b"bald_man", "\u{1F468}\u{200D}\u{1F9B2}"),
+    (b"bald_woman", "\u{1F469}\u{200D}\u{1F9B2}"),
+    (b"ballet_shoes", "\u{1FA70}"),
+    (b"balloon", "\u{1F388}"),
+    (b"ballot_box", "\u{1F5F3}\u{FE0F}"),
+    (b"ballot_box_with_check", "\u{2611}\u{FE0F}"),
+    (b"bamboo", "\u{1F38D}"),
+    (b"banana", "\u{1F34C}"),
+    (b"bangbang", "\u{203C}\u{FE0F}"),
+    (b"bangladesh", "\u{1F1E7}\u{1F1E9}"),
+    (b"banjo", "\u{1FA95}"),
+    (b"bank", "\u{1F3E6}"),
+    (b"bar_chart", "\u{1F4CA}"),
+    (b"barbados", "\u{1F1E7}\u{1F1E7}"),
+    (b"barber", "\u{1F488}"),
+    (b"baseball", "\u{26BE}"),
+    (b"basket", "\u{1F9FA}"),
+    (b"basketball", "\u{1F3C0}"),
+    (
+        b"basketball_man",
+        "\u{26F9}\u{FE0F}\u{200D}\u{2642}\u{FE0F}",
+    ),
+    (
+        b"basketball_woman",
+        "\u{26F9}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (b"bat", "\u{1F987}"),
+    (b"bath", "\u{1F6C0}"),
+    (b"bathtub", "\u{1F6C1}"),
+    (b"battery", "\u{1F50B}"),
+    (b"beach_umbrella", "\u{1F3D6}\u{FE0F}"),
+    (b"beans", "\u{1FAD8}"),
+    (b"bear", "\u{1F43B}"),
+    (b"bearded_person", "\u{1F9D4}"),
+    (b"beaver", "\u{1F9AB}"),
+    (b"bed", "\u{1F6CF}\u{FE0F}"),
+    (b"bee", "\u{1F41D}"),
+    (b"beer", "\u{1F37A}"),
+    (b"beers", "\u{1F37B}"),
+    (b"beetle", "\u{1FAB2}"),
+    (b"beginner", "\u{1F530}"),
+    (b"belarus", "\u{1F1E7}\u{1F1FE}"),
+    (b"belgium", "\u{1F1E7}\u{1F1EA}"),
+    (b"belize", "\u{1F1E7}\u{1F1FF}"),
+    (b"bell", "\u{1F514}"),
+    (b"bell_pepper", "\u{1FAD1}"),
+    (b"bellhop_bell", "\u{1F6CE}\u{FE0F}"),
+    (b"benin", "\u{1F1E7}\u{1F1EF}"),
+    (b"bento", "\u{1F371}"),
+    (b"bermuda", "\u{1F1E7}\u{1F1F2}"),
+    (b"beverage_box", "\u{1F9C3}"),
+    (b"bhutan", "\u{1F1E7}\u{1F1F9}"),
+    (b"bicyclist", "\u{1F6B4}"),
+    (b"bike", "\u{1F6B2}"),
+    (b"biking_man", "\u{1F6B4}\u{200D}\u{2642}\u{FE0F}"),
+    (b"biking_woman", "\u{1F6B4}\u{200D}\u{2640}\u{FE0F}"),
+    (b"bikini", "\u{1F459}"),
+    (b"billed_cap", "\u{1F9E2}"),
+    (b"biohazard", "\u{2623}\u{FE0F}"),
+    (b"bird", "\u{1F426}"),
+    (b"birthday", "\u{1F382}"),
+    (b"bison", "\u{1F9AC}"),
+    (b"biting_lip", "\u{1FAE6}"),
+    (b"black_bird", "\u{1F426}\u{200D}\u{2B1B}"),
+    (b"black_cat", "\u{1F408}\u{200D}\u{2B1B}"),
+    (b"black_circle", "\u{26AB}"),
+    (b"black_flag", "\u{1F3F4}"),
+    (b"black_heart", "\u{1F5A4}"),
+    (b"black_joker", "\u{1F0CF}"),
+    (b"black_large_square", "\u{2B1B}"),
+    (b"black_medium_small_square", "\u{25FE}"),
+    (b"black_medium_square", "\u{25FC}\u{FE0F}"),
+    (b"black_nib", "\u{2712}\u{FE0F}"),
+    (b"black_small_square", "\u{25AA}\u{FE0F}"),
+    (b"black_square_button", "\u{1F532}"),
+    (b"blond_haired_man", "\u{1F471}\u{200D}\u{2642}\u{FE0F}"),
+    (b"blond_haired_person", "\u{1F471}"),
+    (b"blond_haired_woman", "\u{1F471}\u{200D}\u{2640}\u{FE0F}"),
+    (b"blonde_woman", "\u{1F471}\u{200D}\u{2640}\u{FE0F}"),
+    (b"blossom", "\u{1F33C}"),
+    (b"blowfish", "\u{1F421}"),
+    (b"blue_book", "\u{1F4D8}"),
+    (b"blue_car", "\u{1F699}"),
+    (b"blue_heart", "\u{1F499}"),
+    (b"blue_square", "\u{1F7E6}"),
+    (b"blueberries", "\u{1FAD0}"),
+    (b"blush", "\u{1F60A}"),
+    (b"boar", "\u{1F417}"),
+    (b"boat", "\u{26F5}"),
+    (b"bolivia", "\u{1F1E7}\u{1F1F4}"),
+    (b"bomb", "\u{1F4A3}"),
+    (b"bone", "\u{1F9B4}"),
+    (b"book", "\u{1F4D6}"),
+    (b"bookmark", "\u{1F516}"),
+    (b"bookmark_tabs", "\u{1F4D1}"),
+    (b"books", "\u{1F4DA}"),
+    (b"boom", "\u{1F4A5}"),
+    (b"boomerang", "\u{1FA83}"),
+    (b"boot", "\u{1F462}"),
+    (b"bosnia_herzegovina", "\u{1F1E7}\u{1F1E6}"),
+    (b"botswana", "\u{1F1E7}\u{1F1FC}"),
+    (
+        b"bouncing_ball_man",
+        "\u{26F9}\u{FE0F}\u{200D}\u{2642}\u{FE0F}",
+    ),
+    (b"bouncing_ball_person", "\u{26F9}\u{FE0F}"),
+    (
+        b"bouncing_ball_woman",
+        "\u{26F9}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (b"bouquet", "\u{1F490}"),
+    (b"bouvet_island", "\u{1F1E7}\u{1F1FB}"),
+    (b"bow", "\u{1F647}"),
+    (b"bow_and_arrow", "\u{1F3F9}"),
+    (b"bowing_man", "\u{1F647}\u{200D}\u{2642}\u{FE0F}"),
+    (b"bowing_woman", "\u{1F647}\u{200D}\u{2640}\u{FE0F}"),
+    (b"bowl_with_spoon", "\u{1F963}"),
+    (b"bowling", "\u{1F3B3}"),
+    (b"boxing_glove", "\u{1F94A}"),
+    (b"boy", "\u{1F466}"),
+    (b"brain", "\u{1F9E0}"),
+    (b"brazil", "\u{1F1E7}\u{1F1F7}"),
+    (b"bread", "\u{1F35E}"),
+    (b"breast_feeding", "\u{1F931}"),
+    (b"bricks", "\u{1F9F1}"),
+    (b"bride_with_veil", "\u{1F470}\u{200D}\u{2640}\u{FE0F}"),
+    (b"bridge_at_night", "\u{1F309}"),
+    (b"briefcase", "\u{1F4BC}"),
+    (b"british_indian_ocean_territory", "\u{1F1EE}\u{1F1F4}"),
+    (b"british_virgin_islands", "\u{1F1FB}\u{1F1EC}"),
+    (b"broccoli", "\u{1F966}"),
+    (b"broken_heart", "\u{1F494}"),
+    (b"broom", "\u{1F9F9}"),
+    (b"brown_circle", "\u{1F7E4}"),
+    (b"brown_heart", "\u{1F90E}"),
+    (b"brown_square", "\u{1F7EB}"),
+    (b"brunei", "\u{1F1E7}\u{1F1F3}"),
+    (b"bubble_tea", "\u{1F9CB}"),
+    (b"bubbles", "\u{1FAE7}"),
+    (b"bucket", "\u{1FAA3}"),
+    (b"bug", "\u{1F41B}"),
+    (b"building_construction", "\u{1F3D7}\u{FE0F}"),
+    (b"bulb", "\u{1F4A1}"),
+    (b"bulgaria", "\u{1F1E7}\u{1F1EC}"),
+    (b"bullettrain_front", "\u{1F685}"),
+    (b"bullettrain_side", "\u{1F684}"),
+    (b"burkina_faso", "\u{1F1E7}\u{1F1EB}"),
+    (b"burrito", "\u{1F32F}"),
+    (b"burundi", "\u{1F1E7}\u{1F1EE}"),
+    (b"bus", "\u{1F68C}"),
+    (b"business_suit_levitating", "\u{1F574}\u{FE0F}"),
+    (b"busstop", "\u{1F68F}"),
+    (b"bust_in_silhouette", "\u{1F464}"),
+    (b"busts_in_silhouette", "\u{1F465}"),
+    (b"butter", "\u{1F9C8}"),
+    (b"butterfly", "\u{1F98B}"),
+    (b"cactus", "\u{1F335}"),
+    (b"cake", "\u{1F370}"),
+    (b"calendar", "\u{1F4C6}"),
+    (b"call_me_hand", "\u{1F919}"),
+    (b"calling", "\u{1F4F2}"),
+    (b"cambodia", "\u{1F1F0}\u{1F1ED}"),
+    (b"camel", "\u{1F42B}"),
+    (b"camera", "\u{1F4F7}"),
+    (b"camera_flash", "\u{1F4F8}"),
+    (b"cameroon", "\u{1F1E8}\u{1F1F2}"),
+    (b"camping", "\u{1F3D5}\u{FE0F}"),
+    (b"canada", "\u{1F1E8}\u{1F1E6}"),
+    (b"canary_islands", "\u{1F1EE}\u{1F1E8}"),
+    (b"cancer", "\u{264B}"),
+    (b"candle", "\u{1F56F}\u{FE0F}"),
+    (b"candy", "\u{1F36C}"),
+    (b"canned_food", "\u{1F96B}"),
+    (b"canoe", "\u{1F6F6}"),
+    (b"cape_verde", "\u{1F1E8}\u{1F1FB}"),
+    (b"capital_abcd", "\u{1F520}"),
+    (b"capricorn", "\u{2651}"),
+    (b"car", "\u{1F697}"),
+    (b"card_file_box", "\u{1F5C3}\u{FE0F}"),
+    (b"card_index", "\u{1F4C7}"),
+    (b"card_index_dividers", "\u{1F5C2}\u{FE0F}"),
+    (b"caribbean_netherlands", "\u{1F1E7}\u{1F1F6}"),
+    (b"carousel_horse", "\u{1F3A0}"),
+    (b"carpentry_saw", "\u{1FA9A}"),
+    (b"carrot", "\u{1F955}"),
+    (b"cartwheeling", "\u{1F938}"),
+    (b"cat", "\u{1F431}"),
+    (b"cat2", "\u{1F408}"),
+    (b"cayman_islands", "\u{1F1F0}\u{1F1FE}"),
+    (b"cd", "\u{1F4BF}"),
+    (b"central_african_republic", "\u{1F1E8}\u{1F1EB}"),
+    (b"ceuta_melilla", "\u{1F1EA}\u{1F1E6}"),
+    (b"chad", "\u{1F1F9}\u{1F1E9}"),
+    (b"chains", "\u{26D3}\u{FE0F}"),
+    (b"chair", "\u{1FA91}"),
+    (b"champagne", "\u{1F37E}"),
+    (b"chart", "\u{1F4B9}"),
+    (b"chart_with_downwards_trend", "\u{1F4C9}"),
+    (b"chart_with_upwards_trend", "\u{1F4C8}"),
+    (b"checkered_flag", "\u{1F3C1}"),
+    (b"cheese", "\u{1F9C0}"),
+    (b"cherries", "\u{1F352}"),
+    (b"cherry_blossom", "\u{1F338}"),
+    (b"chess_pawn", "\u{265F}\u{FE0F}"),
+    (b"chestnut", "\u{1F330}"),
+    (b"chicken", "\u{1F414}"),
+    (b"child", "\u{1F9D2}"),
+    (b"children_crossing", "\u{1F6B8}"),
+    (b"chile", "\u{1F1E8}\u{1F1F1}"),
+    (b"chipmunk", "\u{1F43F}\u{FE0F}"),
+    (b"chocolate_bar", "\u{1F36B}"),
+    (b"chopsticks", "\u{1F962}"),
+    (b"christmas_island", "\u{1F1E8}\u{1F1FD}"),
+    (b"christmas_tree", "\u{1F384}"),
+    (b"church", "\u{26EA}"),
+    (b"cinema", "\u{1F3A6}"),
+    (b"circus_tent", "\u{1F3AA}"),
+    (b"city_sunrise", "\u{1F307}"),
+    (b"city_sunset", "\u{1F306}"),
+    (b"cityscape", "\u{1F3D9}\u{FE0F}"),
+    (b"cl", "\u{1F191}"),
+    (b"clamp", "\u{1F5DC}\u{FE0F}"),
+    (b"clap", "\u{1F44F}"),
+    (b"clapper", "\u{1F3AC}"),
+    (b"classical_building", "\u{1F3DB}\u{FE0F}"),
+    (b"climbing", "\u{1F9D7}"),
+    (b"climbing_man", "\u{1F9D7}\u{200D}\u{2642}\u{FE0F}"),
+    (b"climbing_woman", "\u{1F9D7}\u{200D}\u{2640}\u{FE0F}"),
+    (b"clinking_glasses", "\u{1F942}"),
+    (b"clipboard", "\u{1F4CB}"),
+    (b"clipperton_island", "\u{1F1E8}\u{1F1F5}"),
+    (b"clock1", "\u{1F550}"),
+    (b"clock10", "\u{1F559}"),
+    (b"clock1030", "\u{1F565}"),
+    (b"clock11", "\u{1F55A}"),
+    (b"clock1130", "\u{1F566}"),
+    (b"clock12", "\u{1F55B}"),
+    (b"clock1230", "\u{1F567}"),
+    (b"clock130", "\u{1F55C}"),
+    (b"clock2", "\u{1F551}"),
+    (b"clock230", "\u{1F55D}"),
+    (b"clock3", "\u{1F552}"),
+    (b"clock330", "\u{1F55E}"),
+    (b"clock4", "\u{1F553}"),
+    (b"clock430", "\u{1F55F}"),
+    (b"clock5", "\u{1F554}"),
+    (b"clock530", "\u{1F560}"),
+    (b"clock6", "\u{1F555}"),
+    (b"clock630", "\u{1F561}"),
+    (b"clock7", "\u{1F556}"),
+    (b"clock730", "\u{1F562}"),
+    (b"clock8", "\u{1F557}"),
+    (b"clock830", "\u{1F563}"),
+    (b"clock9", "\u{1F558}"),
+    (b"clock930", "\u{1F564}"),
+    (b"closed_book", "\u{1F4D5}"),
+    (b"closed_lock_with_key", "\u{1F510}"),
+    (b"closed_umbrella", "\u{1F302}"),
+    (b"cloud", "\u{2601}\u{FE0F}"),
+    (b"cloud_with_lightning", "\u{1F329}\u{FE0F}"),
+    (b"cloud_with_lightning_and_rain", "\u{26C8}\u{FE0F}"),
+    (b"cloud_with_rain", "\u{1F327}\u{FE0F}"),
+    (b"cloud_with_snow", "\u{1F328}\u{FE0F}"),
+    (b"clown_face", "\u{1F921}"),
+    (b"clubs", "\u{2663}\u{FE0F}"),
+    (b"cn", "\u{1F1E8}\u{1F1F3}"),
+    (b"coat", "\u{1F9E5}"),
+    (b"cockroach", "\u{1FAB3}"),
+    (b"cocktail", "\u{1F378}"),
+    (b"coconut", "\u{1F965}"),
+    (b"cocos_islands", "\u{1F1E8}\u{1F1E8}"),
+    (b"coffee", "\u{2615}"),
+    (b"coffin", "\u{26B0}\u{FE0F}"),
+    (b"coin", "\u{1FA99}"),
+    (b"cold_face", "\u{1F976}"),
+    (b"cold_sweat", "\u{1F630}"),
+    (b"collision", "\u{1F4A5}"),
+    (b"colombia", "\u{1F1E8}\u{1F1F4}"),
+    (b"comet", "\u{2604}\u{FE0F}"),
+    (b"comoros", "\u{1F1F0}\u{1F1F2}"),
+    (b"compass", "\u{1F9ED}"),
+    (b"computer", "\u{1F4BB}"),
+    (b"computer_mouse", "\u{1F5B1}\u{FE0F}"),
+    (b"confetti_ball", "\u{1F38A}"),
+    (b"confounded", "\u{1F616}"),
+    (b"confused", "\u{1F615}"),
+    (b"congo_brazzaville", "\u{1F1E8}\u{1F1EC}"),
+    (b"congo_kinshasa", "\u{1F1E8}\u{1F1E9}"),
+    (b"congratulations", "\u{3297}\u{FE0F}"),
+    (b"construction", "\u{1F6A7}"),
+    (b"construction_worker", "\u{1F477}"),
+    (
+        b"construction_worker_man",
+        "\u{1F477}\u{200D}\u{2642}\u{FE0F}",
+    ),
+    (
+        b"construction_worker_woman",
+        "\u{1F477}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (b"control_knobs", "\u{1F39B}\u{FE0F}"),
+    (b"convenience_store", "\u{1F3EA}"),
+    (b"cook", "\u{1F9D1}\u{200D}\u{1F373}"),
+    (b"cook_islands", "\u{1F1E8}\u{1F1F0}"),
+    (b"cookie", "\u{1F36A}"),
+    (b"cool", "\u{1F192}"),
+    (b"cop", "\u{1F46E}"),
+    (b"copyright", "\u{00A9}\u{FE0F}"),
+    (b"coral", "\u{1FAB8}"),
+    (b"corn", "\u{1F33D}"),
+    (b"costa_rica", "\u{1F1E8}\u{1F1F7}"),
+    (b"cote_divoire", "\u{1F1E8}\u{1F1EE}"),
+    (b"couch_and_lamp", "\u{1F6CB}\u{FE0F}"),
+    (b"couple", "\u{1F46B}"),
+    (b"couple_with_heart", "\u{1F491}"),
+    (
+        b"couple_with_heart_man_man",
+        "\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}",
+    ),
+    (
+        b"couple_with_heart_woman_man",
+        "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}",
+    ),
+    (
+        b"couple_with_heart_woman_woman",
+        "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}",
+    ),
+    (b"couplekiss", "\u{1F48F}"),
+    (
+        b"couplekiss_man_man",
+        "\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}",
+    ),
+    (
+        b"couplekiss_man_woman",
+        "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}",
+    ),
+    (
+        b"couplekiss_woman_woman",
+        "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}",
+    ),
+    (b"cow", "\u{1F42E}"),
+    (b"cow2", "\u{1F404}"),
+    (b"cowboy_hat_face", "\u{1F920}"),
+    (b"crab", "\u{1F980}"),
+    (b"crayon", "\u{1F58D}\u{FE0F}"),
+    (b"credit_card", "\u{1F4B3}"),
+    (b"crescent_moon", "\u{1F319}"),
+    (b"cricket", "\u{1F997}"),
+    (b"cricket_game", "\u{1F3CF}"),
+    (b"croatia", "\u{1F1ED}\u{1F1F7}"),
+    (b"crocodile", "\u{1F40A}"),
+    (b"croissant", "\u{1F950}"),
+    (b"crossed_fingers", "\u{1F91E}"),
+    (b"crossed_flags", "\u{1F38C}"),
+    (b"crossed_swords", "\u{2694}\u{FE0F}"),
+    (b"crown", "\u{1F451}"),
+    (b"crutch", "\u{1FA7C}"),
+    (b"cry", "\u{1F622}"),
+    (b"crying_cat_face", "\u{1F63F}"),
+    (b"crystal_ball", "\u{1F52E}"),
+    (b"cuba", "\u{1F1E8}\u{1F1FA}"),
+    (b"cucumber", "\u{1F952}"),
+    (b"cup_with_straw", "\u{1F964}"),
+    (b"cupcake", "\u{1F9C1}"),
+    (b"cupid", "\u{1F498}"),
+    (b"curacao", "\u{1F1E8}\u{1F1FC}"),
+    (b"curling_stone", "\u{1F94C}"),
+    (b"curly_haired_man", "\u{1F468}\u{200D}\u{1F9B1}"),
+    (b"curly_haired_woman", "\u{1F469}\u{200D}\u{1F9B1}"),
+    (b"curly_loop", "\u{27B0}"),
+    (b"currency_exchange", "\u{1F4B1}"),
+    (b"curry", "\u{1F35B}"),
+    (b"cursing_face", "\u{1F92C}"),
+    (b"custard", "\u{1F36E}"),
+    (b"customs", "\u{1F6C3}"),
+    (b"cut_of_meat", "\u{1F969}"),
+    (b"cyclone", "\u{1F300}"),
+    (b"cyprus", "\u{1F1E8}\u{1F1FE}"),
+    (b"czech_republic", "\u{1F1E8}\u{1F1FF}"),
+    (b"dagger", "\u{1F5E1}\u{FE0F}"),
+    (b"dancer", "\u{1F483}"),
+    (b"dancers", "\u{1F46F}"),
+    (b"dancing_men", "\u{1F46F}\u{200D}\u{2642}\u{FE0F}"),
+    (b"dancing_women", "\u{1F46F}\u{200D}\u{2640}\u{FE0F}"),
+    (b"dango", "\u{1F361}"),
+    (b"dark_sunglasses", "\u{1F576}\u{FE0F}"),
+    (b"dart", "\u{1F3AF}"),
+    (b"dash", "\u{1F4A8}"),
+    (b"date", "\u{1F4C5}"),
+    (b"de", "\u{1F1E9}\u{1F1EA}"),
+    (b"deaf_man", "\u{1F9CF}\u{200D}\u{2642}\u{FE0F}"),
+    (b"deaf_person", "\u{1F9CF}"),
+    (b"deaf_woman", "\u{1F9CF}\u{200D}\u{2640}\u{FE0F}"),
+    (b"deciduous_tree", "\u{1F333}"),
+    (b"deer", "\u{1F98C}"),
+    (b"denmark", "\u{1F1E9}\u{1F1F0}"),
+    (b"department_store", "\u{1F3EC}"),
+    (b"derelict_house", "\u{1F3DA}\u{FE0F}"),
+    (b"desert", "\u{1F3DC}\u{FE0F}"),
+    (b"desert_island", "\u{1F3DD}\u{FE0F}"),
+    (b"desktop_computer", "\u{1F5A5}\u{FE0F}"),
+    (b"detective", "\u{1F575}\u{FE0F}"),
+    (b"diamond_shape_with_a_dot_inside", "\u{1F4A0}"),
+    (b"diamonds", "\u{2666}\u{FE0F}"),
+    (b"diego_garcia", "\u{1F1E9}\u{1F1EC}"),
+    (b"disappointed", "\u{1F61E}"),
+    (b"disappointed_relieved", "\u{1F625}"),
+    (b"disguised_face", "\u{1F978}"),
+    (b"diving_mask", "\u{1F93F}"),
+    (b"diya_lamp", "\u{1FA94}"),
+    (b"dizzy", "\u{1F4AB}"),
+    (b"dizzy_face", "\u{1F635}"),
+    (b"djibouti", "\u{1F1E9}\u{1F1EF}"),
+    (b"dna", "\u{1F9EC}"),
+    (b"do_not_litter", "\u{1F6AF}"),
+    (b"dodo", "\u{1F9A4}"),
+    (b"dog", "\u{1F436}"),
+    (b"dog2", "\u{1F415}"),
+    (b"dollar", "\u{1F4B5}"),
+    (b"dolls", "\u{1F38E}"),
+    (b"dolphin", "\u{1F42C}"),
+    (b"dominica", "\u{1F1E9}\u{1F1F2}"),
+    (b"dominican_republic", "\u{1F1E9}\u{1F1F4}"),
+    (b"donkey", "\u{1FACF}"),
+    (b"door", "\u{1F6AA}"),
+    (b"dotted_line_face", "\u{1FAE5}"),
+    (b"doughnut", "\u{1F369}"),
+    (b"dove", "\u{1F54A}\u{FE0F}"),
+    (b"dragon", "\u{1F409}"),
+    (b"dragon_face", "\u{1F432}"),
+    (b"dress", "\u{1F457}"),
+    (b"dromedary_camel", "\u{1F42A}"),
+    (b"drooling_face", "\u{1F924}"),
+    (b"drop_of_blood", "\u{1FA78}"),
+    (b"droplet", "\u{1F4A7}"),
+    (b"drum", "\u{1F941}"),
+    (b"duck", "\u{1F986}"),
+    (b"dumpling", "\u{1F95F}"),
+    (b"dvd", "\u{1F4C0}"),
+    (b"e-mail", "\u{1F4E7}"),
+    (b"eagle", "\u{1F985}"),
+    (b"ear", "\u{1F442}"),
+    (b"ear_of_rice", "\u{1F33E}"),
+    (b"ear_with_hearing_aid", "\u{1F9BB}"),
+    (b"earth_africa", "\u{1F30D}"),
+    (b"earth_americas", "\u{1F30E}"),
+    (b"earth_asia", "\u{1F30F}"),
+    (b"ecuador", "\u{1F1EA}\u{1F1E8}"),
+    (b"egg", "\u{1F95A}"),
+    (b"eggplant", "\u{1F346}"),
+    (b"egypt", "\u{1F1EA}\u{1F1EC}"),
+    (b"eight", "\u{0038}\u{FE0F}\u{20E3}"),
+    (b"eight_pointed_black_star", "\u{2734}\u{FE0F}"),
+    (b"eight_spoked_asterisk", "\u{2733}\u{FE0F}"),
+    (b"eject_button", "\u{23CF}\u{FE0F}"),
+    (b"el_salvador", "\u{1F1F8}\u{1F1FB}"),
+    (b"electric_plug", "\u{1F50C}"),
+    (b"elephant", "\u{1F418}"),
+    (b"elevator", "\u{1F6D7}"),
+    (b"elf", "\u{1F9DD}"),
+    (b"elf_man", "\u{1F9DD}\u{200D}\u{2642}\u{FE0F}"),
+    (b"elf_woman", "\u{1F9DD}\u{200D}\u{2640}\u{FE0F}"),
+    (b"email", "\u{1F4E7}"),
+    (b"empty_nest", "\u{1FAB9}"),
+    (b"end", "\u{1F51A}"),
+    (
+        b"england",
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+    ),
+    (b"envelope", "\u{2709}\u{FE0F}"),
+    (b"envelope_with_arrow", "\u{1F4E9}"),
+    (b"equatorial_guinea", "\u{1F1EC}\u{1F1F6}"),
+    (b"eritrea", "\u{1F1EA}\u{1F1F7}"),
+    (b"es", "\u{1F1EA}\u{1F1F8}"),
+    (b"estonia", "\u{1F1EA}\u{1F1EA}"),
+    (b"ethiopia", "\u{1F1EA}\u{1F1F9}"),
+    (b"eu", "\u{1F1EA}\u{1F1FA}"),
+    (b"euro", "\u{1F4B6}"),
+    (b"european_castle", "\u{1F3F0}"),
+    (b"european_post_office", "\u{1F3E4}"),
+    (b"european_union", "\u{1F1EA}\u{1F1FA}"),
+    (b"evergreen_tree", "\u{1F332}"),
+    (b"exclamation", "\u{2757}"),
+    (b"exploding_head", "\u{1F92F}"),
+    (b"expressionless", "\u{1F611}"),
+    (b"eye", "\u{1F441}\u{FE0F}"),
+    (
+        b"eye_speech_bubble",
+        "\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}",
+    ),
+    (b"eyeglasses", "\u{1F453}"),
+    (b"eyes", "\u{1F440}"),
+    (b"face_exhaling", "\u{1F62E}\u{200D}\u{1F4A8}"),
+    (b"face_holding_back_tears", "\u{1F979}"),
+    (b"face_in_clouds", "\u{1F636}\u{200D}\u{1F32B}\u{FE0F}"),
+    (b"face_with_diagonal_mouth", "\u{1FAE4}"),
+    (b"face_with_head_bandage", "\u{1F915}"),
+    (b"face_with_open_eyes_and_hand_over_mouth", "\u{1FAE2}"),
+    (b"face_with_peeking_eye", "\u{1FAE3}"),
+    (b"face_with_spiral_eyes", "\u{1F635}\u{200D}\u{1F4AB}"),
+    (b"face_with_thermometer", "\u{1F912}"),
+    (b"facepalm", "\u{1F926}"),
+    (b"facepunch", "\u{1F44A}"),
+    (b"factory", "\u{1F3ED}"),
+    (b"factory_worker", "\u{1F9D1}\u{200D}\u{1F3ED}"),
+    (b"fairy", "\u{1F9DA}"),
+    (b"fairy_man", "\u{1F9DA}\u{200D}\u{2642}\u{FE0F}"),
+    (b"fairy_woman", "\u{1F9DA}\u{200D}\u{2640}\u{FE0F}"),
+    (b"falafel", "\u{1F9C6}"),
+    (b"falkland_islands", "\u{1F1EB}\u{1F1F0}"),
+    (b"fallen_leaf", "\u{1F342}"),
+    (b"family", "\u{1F46A}"),
+    (b"family_man_boy", "\u{1F468}\u{200D}\u{1F466}"),
+    (
+        b"family_man_boy_boy",
+        "\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    ),
+    (b"family_man_girl", "\u{1F468}\u{200D}\u{1F467}"),
+    (
+        b"family_man_girl_boy",
+        "\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_man_girl_girl",
+        "\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    ),
+    (
+        b"family_man_man_boy",
+        "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_man_man_boy_boy",
+        "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_man_man_girl",
+        "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}",
+    ),
+    (
+        b"family_man_man_girl_boy",
+        "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_man_man_girl_girl",
+        "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    ),
+    (
+        b"family_man_woman_boy",
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_man_woman_boy_boy",
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_man_woman_girl",
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+    ),
+    (
+        b"family_man_woman_girl_boy",
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_man_woman_girl_girl",
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    ),
+    (b"family_woman_boy", "\u{1F469}\u{200D}\u{1F466}"),
+    (
+        b"family_woman_boy_boy",
+        "\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    ),
+    (b"family_woman_girl", "\u{1F469}\u{200D}\u{1F467}"),
+    (
+        b"family_woman_girl_boy",
+        "\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_woman_girl_girl",
+        "\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    ),
+    (
+        b"family_woman_woman_boy",
+        "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_woman_woman_boy_boy",
+        "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_woman_woman_girl",
+        "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+    ),
+    (
+        b"family_woman_woman_girl_boy",
+        "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    ),
+    (
+        b"family_woman_woman_girl_girl",
+        "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    ),
+    (b"farmer", "\u{1F9D1}\u{200D}\u{1F33E}"),
+    (b"faroe_islands", "\u{1F1EB}\u{1F1F4}"),
+    (b"fast_forward", "\u{23E9}"),
+    (b"fax", "\u{1F4E0}"),
+    (b"fearful", "\u{1F628}"),
+    (b"feather", "\u{1FAB6}"),
+    (b"feet", "\u{1F43E}"),
+    (
+        b"female_detective",
+        "\u{1F575}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (b"female_sign", "\u{2640}\u{FE0F}"),
+    (b"ferris_wheel", "\u{1F3A1}"),
+    (b"ferry", "\u{26F4}\u{FE0F}"),
+    (b"field_hockey", "\u{1F3D1}"),
+    (b"fiji", "\u{1F1EB}\u{1F1EF}"),
+    (b"file_cabinet", "\u{1F5C4}\u{FE0F}"),
+    (b"file_folder", "\u{1F4C1}"),
+    (b"film_projector", "\u{1F4FD}\u{FE0F}"),
+    (b"film_strip", "\u{1F39E}\u{FE0F}"),
+    (b"finland", "\u{1F1EB}\u{1F1EE}"),
+    (b"fire", "\u{1F525}"),
+    (b"fire_engine", "\u{1F692}"),
+    (b"fire_extinguisher", "\u{1F9EF}"),
+    (b"firecracker", "\u{1F9E8}"),
+    (b"firefighter", "\u{1F9D1}\u{200D}\u{1F692}"),
+    (b"fireworks", "\u{1F386}"),
+    (b"first_quarter_moon", "\u{1F313}"),
+    (b"first_quarter_moon_with_face", "\u{1F31B}"),
+    (b"fish", "\u{1F41F}"),
+    (b"fish_cake", "\u{1F365}"),
+    (b"fishing_pole_and_fish", "\u{1F3A3}"),
+    (b"fist", "\u{270A}"),
+    (b"fist_left", "\u{1F91B}"),
+    (b"fist_oncoming", "\u{1F44A}"),
+    (b"fist_raised", "\u{270A}"),
+    (b"fist_right", "\u{1F91C}"),
+    (b"five", "\u{0035}\u{FE0F}\u{20E3}"),
+    (b"flags", "\u{1F38F}"),
+    (b"flamingo", "\u{1F9A9}"),
+    (b"flashlight", "\u{1F526}"),
+    (b"flat_shoe", "\u{1F97F}"),
+    (b"flatbread", "\u{1FAD3}"),
+    (b"fleur_de_lis", "\u{269C}\u{FE0F}"),
+    (b"flight_arrival", "\u{1F6EC}"),
+    (b"flight_departure", "\u{1F6EB}"),
+    (b"flipper", "\u{1F42C}"),
+    (b"floppy_disk", "\u{1F4BE}"),
+    (b"flower_playing_cards", "\u{1F3B4}"),
+    (b"flushed", "\u{1F633}"),
+    (b"flute", "\u{1FA88}"),
+    (b"fly", "\u{1FAB0}"),
+    (b"flying_disc", "\u{1F94F}"),
+    (b"flying_saucer", "\u{1F6F8}"),
+    (b"fog", "\u{1F32B}\u{FE0F}"),
+    (b"foggy", "\u{1F301}"),
+    (b"folding_hand_fan", "\u{1FAAD}"),
+    (b"fondue", "\u{1FAD5}"),
+    (b"foot", "\u{1F9B6}"),
+    (b"football", "\u{1F3C8}"),
+    (b"footprints", "\u{1F463}"),
+    (b"fork_and_knife", "\u{1F374}"),
+    (b"fortune_cookie", "\u{1F960}"),
+    (b"fountain", "\u{26F2}"),
+    (b"fountain_pen", "\u{1F58B}\u{FE0F}"),
+    (b"four", "\u{0034}\u{FE0F}\u{20E3}"),
+    (b"four_leaf_clover", "\u{1F340}"),
+    (b"fox_face", "\u{1F98A}"),
+    (b"fr", "\u{1F1EB}\u{1F1F7}"),
+    (b"framed_picture", "\u{1F5BC}\u{FE0F}"),
+    (b"free", "\u{1F193}"),
+    (b"french_guiana", "\u{1F1EC}\u{1F1EB}"),
+    (b"french_polynesia", "\u{1F1F5}\u{1F1EB}"),
+    (b"french_southern_territories", "\u{1F1F9}\u{1F1EB}"),
+    (b"fried_egg", "\u{1F373}"),
+    (b"fried_shrimp", "\u{1F364}"),
+    (b"fries", "\u{1F35F}"),
+    (b"frog", "\u{1F438}"),
+    (b"frowning", "\u{1F626}"),
+    (b"frowning_face", "\u{2639}\u{FE0F}"),
+    (b"frowning_man", "\u{1F64D}\u{200D}\u{2642}\u{FE0F}"),
+    (b"frowning_person", "\u{1F64D}"),
+    (b"frowning_woman", "\u{1F64D}\u{200D}\u{2640}\u{FE0F}"),
+    (b"fu", "\u{1F595}"),
+    (b"fuelpump", "\u{26FD}"),
+    (b"full_moon", "\u{1F315}"),
+    (b"full_moon_with_face", "\u{1F31D}"),
+    (b"funeral_urn", "\u{26B1}\u{FE0F}"),
+    (b"gabon", "\u{1F1EC}\u{1F1E6}"),
+    (b"gambia", "\u{1F1EC}\u{1F1F2}"),
+    (b"game_die", "\u{1F3B2}"),
+    (b"garlic", "\u{1F9C4}"),
+    (b"gb", "\u{1F1EC}\u{1F1E7}"),
+    (b"gear", "\u{2699}\u{FE0F}"),
+    (b"gem", "\u{1F48E}"),
+    (b"gemini", "\u{264A}"),
+    (b"genie", "\u{1F9DE}"),
+    (b"genie_man", "\u{1F9DE}\u{200D}\u{2642}\u{FE0F}"),
+    (b"genie_woman", "\u{1F9DE}\u{200D}\u{2640}\u{FE0F}"),
+    (b"georgia", "\u{1F1EC}\u{1F1EA}"),
+    (b"ghana", "\u{1F1EC}\u{1F1ED}"),
+    (b"ghost", "\u{1F47B}"),
+    (b"gibraltar", "\u{1F1EC}\u{1F1EE}"),
+    (b"gift", "\u{1F381}"),
+    (b"gift_heart", "\u{1F49D}"),
+    (b"ginger_root", "\u{1FADA}"),
+    (b"giraffe", "\u{1F992}"),
+    (b"girl", "\u{1F467}"),
+    (b"globe_with_meridians", "\u{1F310}"),
+    (b"gloves", "\u{1F9E4}"),
+    (b"goal_net", "\u{1F945}"),
+    (b"goat", "\u{1F410}"),
+    (b"goggles", "\u{1F97D}"),
+    (b"golf", "\u{26F3}"),
+    (b"golfing", "\u{1F3CC}\u{FE0F}"),
+    (b"golfing_man", "\u{1F3CC}\u{FE0F}\u{200D}\u{2642}\u{FE0F}"),
+    (
+        b"golfing_woman",
+        "\u{1F3CC}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (b"goose", "\u{1FABF}"),
+    (b"gorilla", "\u{1F98D}"),
+    (b"grapes", "\u{1F347}"),
+    (b"greece", "\u{1F1EC}\u{1F1F7}"),
+    (b"green_apple", "\u{1F34F}"),
+    (b"green_book", "\u{1F4D7}"),
+    (b"green_circle", "\u{1F7E2}"),
+    (b"green_heart", "\u{1F49A}"),
+    (b"green_salad", "\u{1F957}"),
+    (b"green_square", "\u{1F7E9}"),
+    (b"greenland", "\u{1F1EC}\u{1F1F1}"),
+    (b"grenada", "\u{1F1EC}\u{1F1E9}"),
+    (b"grey_exclamation", "\u{2755}"),
+    (b"grey_heart", "\u{1FA76}"),
+    (b"grey_question", "\u{2754}"),
+    (b"grimacing", "\u{1F62C}"),
+    (b"grin", "\u{1F601}"),
+    (b"grinning", "\u{1F600}"),
+    (b"guadeloupe", "\u{1F1EC}\u{1F1F5}"),
+    (b"guam", "\u{1F1EC}\u{1F1FA}"),
+    (b"guard", "\u{1F482}"),
+    (b"guardsman", "\u{1F482}\u{200D}\u{2642}\u{FE0F}"),
+    (b"guardswoman", "\u{1F482}\u{200D}\u{2640}\u{FE0F}"),
+    (b"guatemala", "\u{1F1EC}\u{1F1F9}"),
+    (b"guernsey", "\u{1F1EC}\u{1F1EC}"),
+    (b"guide_dog", "\u{1F9AE}"),
+    (b"guinea", "\u{1F1EC}\u{1F1F3}"),
+    (b"guinea_bissau", "\u{1F1EC}\u{1F1FC}"),
+    (b"guitar", "\u{1F3B8}"),
+    (b"gun", "\u{1F52B}"),
+    (b"guyana", "\u{1F1EC}\u{1F1FE}"),
+    (b"hair_pick", "\u{1FAAE}"),
+    (b"haircut", "\u{1F487}"),
+    (b"haircut_man", "\u{1F487}\u{200D}\u{2642}\u{FE0F}"),
+    (b"haircut_woman", "\u{1F487}\u{200D}\u{2640}\u{FE0F}"),
+    (b"haiti", "\u{1F1ED}\u{1F1F9}"),
+    (b"hamburger", "\u{1F354}"),
+    (b"hammer", "\u{1F528}"),
+    (b"hammer_and_pick", "\u{2692}\u{FE0F}"),
+    (b"hammer_and_wrench", "\u{1F6E0}\u{FE0F}"),
+    (b"hamsa", "\u{1FAAC}"),
+    (b"hamster", "\u{1F439}"),
+    (b"hand", "\u{270B}"),
+    (b"hand_over_mouth", "\u{1F92D}"),
+    (b"hand_with_index_finger_and_thumb_crossed", "\u{1FAF0}"),
+    (b"handbag", "\u{1F45C}"),
+    (b"handball_person", "\u{1F93E}"),
+    (b"handshake", "\u{1F91D}"),
+    (b"hankey", "\u{1F4A9}"),
+    (b"hash", "\u{0023}\u{FE0F}\u{20E3}"),
+    (b"hatched_chick", "\u{1F425}"),
+    (b"hatching_chick", "\u{1F423}"),
+    (b"headphones", "\u{1F3A7}"),
+    (b"headstone", "\u{1FAA6}"),
+    (b"health_worker", "\u{1F9D1}\u{200D}\u{2695}\u{FE0F}"),
+    (b"hear_no_evil", "\u{1F649}"),
+    (b"heard_mcdonald_islands", "\u{1F1ED}\u{1F1F2}"),
+    (b"heart", "\u{2764}\u{FE0F}"),
+    (b"heart_decoration", "\u{1F49F}"),
+    (b"heart_eyes", "\u{1F60D}"),
+    (b"heart_eyes_cat", "\u{1F63B}"),
+    (b"heart_hands", "\u{1FAF6}"),
+    (b"heart_on_fire", "\u{2764}\u{FE0F}\u{200D}\u{1F525}"),
+    (b"heartbeat", "\u{1F493}"),
+    (b"heartpulse", "\u{1F497}"),
+    (b"hearts", "\u{2665}\u{FE0F}"),
+    (b"heavy_check_mark", "\u{2714}\u{FE0F}"),
+    (b"heavy_division_sign", "\u{2797}"),
+    (b"heavy_dollar_sign", "\u{1F4B2}"),
+    (b"heavy_equals_sign", "\u{1F7F0}"),
+    (b"heavy_exclamation_mark", "\u{2757}"),
+    (b"heavy_heart_exclamation", "\u{2763}\u{FE0F}"),
+    (b"heavy_minus_sign", "\u{2796}"),
+    (b"heavy_multiplication_x", "\u{2716}\u{FE0F}"),
+    (b"heavy_plus_sign", "\u{2795}"),
+    (b"hedgehog", "\u{1F994}"),
+    (b"helicopter", "\u{1F681}"),
+    (b"herb", "\u{1F33F}"),
+    (b"hibiscus", "\u{1F33A}"),
+    (b"high_brightness", "\u{1F506}"),
+    (b"high_heel", "\u{1F460}"),
+    (b"hiking_boot", "\u{1F97E}"),
+    (b"hindu_temple", "\u{1F6D5}"),
+    (b"hippopotamus", "\u{1F99B}"),
+    (b"hocho", "\u{1F52A}"),
+    (b"hole", "\u{1F573}\u{FE0F}"),
+    (b"honduras", "\u{1F1ED}\u{1F1F3}"),
+    (b"honey_pot", "\u{1F36F}"),
+    (b"honeybee", "\u{1F41D}"),
+    (b"hong_kong", "\u{1F1ED}\u{1F1F0}"),
+    (b"hook", "\u{1FA9D}"),
+    (b"horse", "\u{1F434}"),
+    (b"horse_racing", "\u{1F3C7}"),
+    (b"hospital", "\u{1F3E5}"),
+    (b"hot_face", "\u{1F975}"),
+    (b"hot_pepper", "\u{1F336}\u{FE0F}"),
+    (b"hotdog", "\u{1F32D}"),
+    (b"hotel", "\u{1F3E8}"),
+    (b"hotsprings", "\u{2668}\u{FE0F}"),
+    (b"hourglass", "\u{231B}"),
+    (b"hourglass_flowing_sand", "\u{23F3}"),
+    (b"house", "\u{1F3E0}"),
+    (b"house_with_garden", "\u{1F3E1}"),
+    (b"houses", "\u{1F3D8}\u{FE0F}"),
+    (b"hugs", "\u{1F917}"),
+    (b"hungary", "\u{1F1ED}\u{1F1FA}"),
+    (b"hushed", "\u{1F62F}"),
+    (b"hut", "\u{1F6D6}"),
+    (b"hyacinth", "\u{1FABB}"),
+    (b"ice_cream", "\u{1F368}"),
+    (b"ice_cube", "\u{1F9CA}"),
+    (b"ice_hockey", "\u{1F3D2}"),
+    (b"ice_skate", "\u{26F8}\u{FE0F}"),
+    (b"icecream", "\u{1F366}"),
+    (b"iceland", "\u{1F1EE}\u{1F1F8}"),
+    (b"id", "\u{1F194}"),
+    (b"identification_card", "\u{1FAAA}"),
+    (b"ideograph_advantage", "\u{1F250}"),
+    (b"imp", "\u{1F47F}"),
+    (b"inbox_tray", "\u{1F4E5}"),
+    (b"incoming_envelope", "\u{1F4E8}"),
+    (b"index_pointing_at_the_viewer", "\u{1FAF5}"),
+    (b"india", "\u{1F1EE}\u{1F1F3}"),
+    (b"indonesia", "\u{1F1EE}\u{1F1E9}"),
+    (b"infinity", "\u{267E}\u{FE0F}"),
+    (b"information_desk_person", "\u{1F481}"),
+    (b"information_source", "\u{2139}\u{FE0F}"),
+    (b"innocent", "\u{1F607}"),
+    (b"interrobang", "\u{2049}\u{FE0F}"),
+    (b"iphone", "\u{1F4F1}"),
+    (b"iran", "\u{1F1EE}\u{1F1F7}"),
+    (b"iraq", "\u{1F1EE}\u{1F1F6}"),
+    (b"ireland", "\u{1F1EE}\u{1F1EA}"),
+    (b"isle_of_man", "\u{1F1EE}\u{1F1F2}"),
+    (b"israel", "\u{1F1EE}\u{1F1F1}"),
+    (b"it", "\u{1F1EE}\u{1F1F9}"),
+    (b"izakaya_lantern", "\u{1F3EE}"),
+    (b"jack_o_lantern", "\u{1F383}"),
+    (b"jamaica", "\u{1F1EF}\u{1F1F2}"),
+    (b"japan", "\u{1F5FE}"),
+    (b"japanese_castle", "\u{1F3EF}"),
+    (b"japanese_goblin", "\u{1F47A}"),
+    (b"japanese_ogre", "\u{1F479}"),
+    (b"jar", "\u{1FAD9}"),
+    (b"jeans", "\u{1F456}"),
+    (b"jellyfish", "\u{1FABC}"),
+    (b"jersey", "\u{1F1EF}\u{1F1EA}"),
+    (b"jigsaw", "\u{1F9E9}"),
+    (b"jordan", "\u{1F1EF}\u{1F1F4}"),
+    (b"joy", "\u{1F602}"),
+    (b"joy_cat", "\u{1F639}"),
+    (b"joystick", "\u{1F579}\u{FE0F}"),
+    (b"jp", "\u{1F1EF}\u{1F1F5}"),
+    (b"judge", "\u{1F9D1}\u{200D}\u{2696}\u{FE0F}"),
+    (b"juggling_person", "\u{1F939}"),
+    (b"kaaba", "\u{1F54B}"),
+    (b"kangaroo", "\u{1F998}"),
+    (b"kazakhstan", "\u{1F1F0}\u{1F1FF}"),
+    (b"kenya", "\u{1F1F0}\u{1F1EA}"),
+    (b"key", "\u{1F511}"),
+    (b"keyboard", "\u{2328}\u{FE0F}"),
+    (b"keycap_ten", "\u{1F51F}"),
+    (b"khanda", "\u{1FAAF}"),
+    (b"kick_scooter", "\u{1F6F4}"),
+    (b"kimono", "\u{1F458}"),
+    (b"kiribati", "\u{1F1F0}\u{1F1EE}"),
+    (b"kiss", "\u{1F48B}"),
+    (b"kissing", "\u{1F617}"),
+    (b"kissing_cat", "\u{1F63D}"),
+    (b"kissing_closed_eyes", "\u{1F61A}"),
+    (b"kissing_heart", "\u{1F618}"),
+    (b"kissing_smiling_eyes", "\u{1F619}"),
+    (b"kite", "\u{1FA81}"),
+    (b"kiwi_fruit", "\u{1F95D}"),
+    (b"kneeling_man", "\u{1F9CE}\u{200D}\u{2642}\u{FE0F}"),
+    (b"kneeling_person", "\u{1F9CE}"),
+    (b"kneeling_woman", "\u{1F9CE}\u{200D}\u{2640}\u{FE0F}"),
+    (b"knife", "\u{1F52A}"),
+    (b"knot", "\u{1FAA2}"),
+    (b"koala", "\u{1F428}"),
+    (b"koko", "\u{1F201}"),
+    (b"kosovo", "\u{1F1FD}\u{1F1F0}"),
+    (b"kr", "\u{1F1F0}\u{1F1F7}"),
+    (b"kuwait", "\u{1F1F0}\u{1F1FC}"),
+    (b"kyrgyzstan", "\u{1F1F0}\u{1F1EC}"),
+    (b"lab_coat", "\u{1F97C}"),
+    (b"label", "\u{1F3F7}\u{FE0F}"),
+    (b"lacrosse", "\u{1F94D}"),
+    (b"ladder", "\u{1FA9C}"),
+    (b"lady_beetle", "\u{1F41E}"),
+    (b"lantern", "\u{1F3EE}"),
+    (b"laos", "\u{1F1F1}\u{1F1E6}"),
+    (b"large_blue_circle", "\u{1F535}"),
+    (b"large_blue_diamond", "\u{1F537}"),
+    (b"large_orange_diamond", "\u{1F536}"),
+    (b"last_quarter_moon", "\u{1F317}"),
+    (b"last_quarter_moon_with_face", "\u{1F31C}"),
+    (b"latin_cross", "\u{271D}\u{FE0F}"),
+    (b"latvia", "\u{1F1F1}\u{1F1FB}"),
+    (b"laughing", "\u{1F606}"),
+    (b"leafy_green", "\u{1F96C}"),
+    (b"leaves", "\u{1F343}"),
+    (b"lebanon", "\u{1F1F1}\u{1F1E7}"),
+    (b"ledger", "\u{1F4D2}"),
+    (b"left_luggage", "\u{1F6C5}"),
+    (b"left_right_arrow", "\u{2194}\u{FE0F}"),
+    (b"left_speech_bubble", "\u{1F5E8}\u{FE0F}"),
+    (b"leftwards_arrow_with_hook", "\u{21A9}\u{FE0F}"),
+    (b"leftwards_hand", "\u{1FAF2}"),
+    (b"leftwards_pushing_hand", "\u{1FAF7}"),
+    (b"leg", "\u{1F9B5}"),
+    (b"lemon", "\u{1F34B}"),
+    (b"leo", "\u{264C}"),
+    (b"leopard", "\u{1F406}"),
+    (b"lesotho", "\u{1F1F1}\u{1F1F8}"),
+    (b"level_slider", "\u{1F39A}\u{FE0F}"),
+    (b"liberia", "\u{1F1F1}\u{1F1F7}"),
+    (b"libra", "\u{264E}"),
+    (b"libya", "\u{1F1F1}\u{1F1FE}"),
+    (b"liechtenstein", "\u{1F1F1}\u{1F1EE}"),
+    (b"light_blue_heart", "\u{1FA75}"),
+    (b"light_rail", "\u{1F688}"),
+    (b"link", "\u{1F517}"),
+    (b"lion", "\u{1F981}"),
+    (b"lips", "\u{1F444}"),
+    (b"lipstick", "\u{1F484}"),
+    (b"lithuania", "\u{1F1F1}\u{1F1F9}"),
+    (b"lizard", "\u{1F98E}"),
+    (b"llama", "\u{1F999}"),
+    (b"lobster", "\u{1F99E}"),
+    (b"lock", "\u{1F512}"),
+    (b"lock_with_ink_pen", "\u{1F50F}"),
+    (b"lollipop", "\u{1F36D}"),
+    (b"long_drum", "\u{1FA98}"),
+    (b"loop", "\u{27BF}"),
+    (b"lotion_bottle", "\u{1F9F4}"),
+    (b"lotus", "\u{1FAB7}"),
+    (b"lotus_position", "\u{1F9D8}"),
+    (b"lotus_position_man", "\u{1F9D8}\u{200D}\u{2642}\u{FE0F}"),
+    (b"lotus_position_woman", "\u{1F9D8}\u{200D}\u{2640}\u{FE0F}"),
+    (b"loud_sound", "\u{1F50A}"),
+    (b"loudspeaker", "\u{1F4E2}"),
+    (b"love_hotel", "\u{1F3E9}"),
+    (b"love_letter", "\u{1F48C}"),
+    (b"love_you_gesture", "\u{1F91F}"),
+    (b"low_battery", "\u{1FAAB}"),
+    (b"low_brightness", "\u{1F505}"),
+    (b"luggage", "\u{1F9F3}"),
+    (b"lungs", "\u{1FAC1}"),
+    (b"luxembourg", "\u{1F1F1}\u{1F1FA}"),
+    (b"lying_face", "\u{1F925}"),
+    (b"m", "\u{24C2}\u{FE0F}"),
+    (b"macau", "\u{1F1F2}\u{1F1F4}"),
+    (b"macedonia", "\u{1F1F2}\u{1F1F0}"),
+    (b"madagascar", "\u{1F1F2}\u{1F1EC}"),
+    (b"mag", "\u{1F50D}"),
+    (b"mag_right", "\u{1F50E}"),
+    (b"mage", "\u{1F9D9}"),
+    (b"mage_man", "\u{1F9D9}\u{200D}\u{2642}\u{FE0F}"),
+    (b"mage_woman", "\u{1F9D9}\u{200D}\u{2640}\u{FE0F}"),
+    (b"magic_wand", "\u{1FA84}"),
+    (b"magnet", "\u{1F9F2}"),
+    (b"mahjong", "\u{1F004}"),
+    (b"mailbox", "\u{1F4EB}"),
+    (b"mailbox_closed", "\u{1F4EA}"),
+    (b"mailbox_with_mail", "\u{1F4EC}"),
+    (b"mailbox_with_no_mail", "\u{1F4ED}"),
+    (b"malawi", "\u{1F1F2}\u{1F1FC}"),
+    (b"malaysia", "\u{1F1F2}\u{1F1FE}"),
+    (b"maldives", "\u{1F1F2}\u{1F1FB}"),
+    (
+        b"male_detective",
+        "\u{1F575}\u{FE0F}\u{200D}\u{2642}\u{FE0F}",
+    ),
+    (b"male_sign", "\u{2642}\u{FE0F}"),
+    (b"mali", "\u{1F1F2}\u{1F1F1}"),
+    (b"malta", "\u{1F1F2}\u{1F1F9}"),
+    (b"mammoth", "\u{1F9A3}"),
+    (b"man", "\u{1F468}"),
+    (b"man_artist", "\u{1F468}\u{200D}\u{1F3A8}"),
+    (b"man_astronaut", "\u{1F468}\u{200D}\u{1F680}"),
+    (b"man_beard", "\u{1F9D4}\u{200D}\u{2642}\u{FE0F}"),
+    (b"man_cartwheeling", "\u{1F938}\u{200D}\u{2642}\u{FE0F}"),
+    (b"man_cook", "\u{1F468}\u{200D}\u{1F373}"),
+    (b"man_dancing", "\u{1F57A}"),
+    (b"man_facepalming", "\u{1F926}\u{200D}\u{2642}\u{FE0F}"),
+    (b"man_factory_worker", "\u{1F468}\u{200D}\u{1F3ED}"),
+    (b"man_farmer", "\u{1F468}\u{200D}\u{1F33E}"),
+    (b"man_feeding_baby", "\u{1F468}\u{200D}\u{1F37C}"),
+    (b"man_firefighter", "\u{1F468}\u{200D}\u{1F692}"),
+    (b"man_health_worker", "\u{1F468}\u{200D}\u{2695}\u{FE0F}"),
+    (b"man_in_manual_wheelchair", "\u{1F468}\u{200D}\u{1F9BD}"),
+    (b"man_in_motorized_wheelchair", "\u{1F468}\u{200D}\u{1F9BC}"),
+    (b"man_in_tuxedo", "\u{1F935}\u{200D}\u{2642}\u{FE0F}"),
+    (b"man_judge", "\u{1F468}\u{200D}\u{2696}\u{FE0F}"),
+    (b"man_juggling", "\u{1F939}\u{200D}\u{2642}\u{FE0F}"),
+    (b"man_mechanic", "\u{1F468}\u{200D}\u{1F527}"),
+    (b"man_office_worker", "\u{1F468}\u{200D}\u{1F4BC}"),
+    (b"man_pilot", "\u{1F468}\u{200D}\u{2708}\u{FE0F}"),
+    (b"man_playing_handball", "\u{1F93E}\u{200D}\u{2642}\u{FE0F}"),
+    (
+        b"man_playing_water_polo",
+        "\u{1F93D}\u{200D}\u{2642}\u{FE0F}",
+    ),
+    (b"man_scientist", "\u{1F468}\u{200D}\u{1F52C}"),
+    (b"man_shrugging", "\u{1F937}\u{200D}\u{2642}\u{FE0F}"),
+    (b"man_singer", "\u{1F468}\u{200D}\u{1F3A4}"),
+    (b"man_student", "\u{1F468}\u{200D}\u{1F393}"),
+    (b"man_teacher", "\u{1F468}\u{200D}\u{1F3EB}"),
+    (b"man_technologist", "\u{1F468}\u{200D}\u{1F4BB}"),
+    (b"man_with_gua_pi_mao", "\u{1F472}"),
+    (b"man_with_probing_cane", "\u{1F468}\u{200D}\u{1F9AF}"),
+    (b"man_with_turban", "\u{1F473}\u{200D}\u{2642}\u{FE0F}"),
+    (b"man_with_veil", "\u{1F470}\u{200D}\u{2642}\u{FE0F}"),
+    (b"mandarin", "\u{1F34A}"),
+    (b"mango", "\u{1F96D}"),
+    (b"mans_shoe", "\u{1F45E}"),
+    (b"mantelpiece_clock", "\u{1F570}\u{FE0F}"),
+    (b"manual_wheelchair", "\u{1F9BD}"),
+    (b"maple_leaf", "\u{1F341}"),
+    (b"maracas", "\u{1FA87}"),
+    (b"marshall_islands", "\u{1F1F2}\u{1F1ED}"),
+    (b"martial_arts_uniform", "\u{1F94B}"),
+    (b"martinique", "\u{1F1F2}\u{1F1F6}"),
+    (b"mask", "\u{1F637}"),
+    (b"massage", "\u{1F486}"),
+    (b"massage_man", "\u{1F486}\u{200D}\u{2642}\u{FE0F}"),
+    (b"massage_woman", "\u{1F486}\u{200D}\u{2640}\u{FE0F}"),
+    (b"mate", "\u{1F9C9}"),
+    (b"mauritania", "\u{1F1F2}\u{1F1F7}"),
+    (b"mauritius", "\u{1F1F2}\u{1F1FA}"),
+    (b"mayotte", "\u{1F1FE}\u{1F1F9}"),
+    (b"meat_on_bone", "\u{1F356}"),
+    (b"mechanic", "\u{1F9D1}\u{200D}\u{1F527}"),
+    (b"mechanical_arm", "\u{1F9BE}"),
+    (b"mechanical_leg", "\u{1F9BF}"),
+    (b"medal_military", "\u{1F396}\u{FE0F}"),
+    (b"medal_sports", "\u{1F3C5}"),
+    (b"medical_symbol", "\u{2695}\u{FE0F}"),
+    (b"mega", "\u{1F4E3}"),
+    (b"melon", "\u{1F348}"),
+    (b"melting_face", "\u{1FAE0}"),
+    (b"memo", "\u{1F4DD}"),
+    (b"men_wrestling", "\u{1F93C}\u{200D}\u{2642}\u{FE0F}"),
+    (b"mending_heart", "\u{2764}\u{FE0F}\u{200D}\u{1FA79}"),
+    (b"menorah", "\u{1F54E}"),
+    (b"mens", "\u{1F6B9}"),
+    (b"mermaid", "\u{1F9DC}\u{200D}\u{2640}\u{FE0F}"),
+    (b"merman", "\u{1F9DC}\u{200D}\u{2642}\u{FE0F}"),
+    (b"merperson", "\u{1F9DC}"),
+    (b"metal", "\u{1F918}"),
+    (b"metro", "\u{1F687}"),
+    (b"mexico", "\u{1F1F2}\u{1F1FD}"),
+    (b"microbe", "\u{1F9A0}"),
+    (b"micronesia", "\u{1F1EB}\u{1F1F2}"),
+    (b"microphone", "\u{1F3A4}"),
+    (b"microscope", "\u{1F52C}"),
+    (b"middle_finger", "\u{1F595}"),
+    (b"military_helmet", "\u{1FA96}"),
+    (b"milk_glass", "\u{1F95B}"),
+    (b"milky_way", "\u{1F30C}"),
+    (b"minibus", "\u{1F690}"),
+    (b"minidisc", "\u{1F4BD}"),
+    (b"mirror", "\u{1FA9E}"),
+    (b"mirror_ball", "\u{1FAA9}"),
+    (b"mobile_phone_off", "\u{1F4F4}"),
+    (b"moldova", "\u{1F1F2}\u{1F1E9}"),
+    (b"monaco", "\u{1F1F2}\u{1F1E8}"),
+    (b"money_mouth_face", "\u{1F911}"),
+    (b"money_with_wings", "\u{1F4B8}"),
+    (b"moneybag", "\u{1F4B0}"),
+    (b"mongolia", "\u{1F1F2}\u{1F1F3}"),
+    (b"monkey", "\u{1F412}"),
+    (b"monkey_face", "\u{1F435}"),
+    (b"monocle_face", "\u{1F9D0}"),
+    (b"monorail", "\u{1F69D}"),
+    (b"montenegro", "\u{1F1F2}\u{1F1EA}"),
+    (b"montserrat", "\u{1F1F2}\u{1F1F8}"),
+    (b"moon", "\u{1F314}"),
+    (b"moon_cake", "\u{1F96E}"),
+    (b"moose", "\u{1FACE}"),
+    (b"morocco", "\u{1F1F2}\u{1F1E6}"),
+    (b"mortar_board", "\u{1F393}"),
+    (b"mosque", "\u{1F54C}"),
+    (b"mosquito", "\u{1F99F}"),
+    (b"motor_boat", "\u{1F6E5}\u{FE0F}"),
+    (b"motor_scooter", "\u{1F6F5}"),
+    (b"motorcycle", "\u{1F3CD}\u{FE0F}"),
+    (b"motorized_wheelchair", "\u{1F9BC}"),
+    (b"motorway", "\u{1F6E3}\u{FE0F}"),
+    (b"mount_fuji", "\u{1F5FB}"),
+    (b"mountain", "\u{26F0}\u{FE0F}"),
+    (b"mountain_bicyclist", "\u{1F6B5}"),
+    (b"mountain_biking_man", "\u{1F6B5}\u{200D}\u{2642}\u{FE0F}"),
+    (
+        b"mountain_biking_woman",
+        "\u{1F6B5}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (b"mountain_cableway", "\u{1F6A0}"),
+    (b"mountain_railway", "\u{1F69E}"),
+    (b"mountain_snow", "\u{1F3D4}\u{FE0F}"),
+    (b"mouse", "\u{1F42D}"),
+    (b"mouse2", "\u{1F401}"),
+    (b"mouse_trap", "\u{1FAA4}"),
+    (b"movie_camera", "\u{1F3A5}"),
+    (b"moyai", "\u{1F5FF}"),
+    (b"mozambique", "\u{1F1F2}\u{1F1FF}"),
+    (b"mrs_claus", "\u{1F936}"),
+    (b"muscle", "\u{1F4AA}"),
+    (b"mushroom", "\u{1F344}"),
+    (b"musical_keyboard", "\u{1F3B9}"),
+    (b"musical_note", "\u{1F3B5}"),
+    (b"musical_score", "\u{1F3BC}"),
+    (b"mute", "\u{1F507}"),
+    (b"mx_claus", "\u{1F9D1}\u{200D}\u{1F384}"),
+    (b"myanmar", "\u{1F1F2}\u{1F1F2}"),
+    (b"nail_care", "\u{1F485}"),
+    (b"name_badge", "\u{1F4DB}"),
+    (b"namibia", "\u{1F1F3}\u{1F1E6}"),
+    (b"national_park", "\u{1F3DE}\u{FE0F}"),
+    (b"nauru", "\u{1F1F3}\u{1F1F7}"),
+    (b"nauseated_face", "\u{1F922}"),
+    (b"nazar_amulet", "\u{1F9FF}"),
+    (b"necktie", "\u{1F454}"),
+    (b"negative_squared_cross_mark", "\u{274E}"),
+    (b"nepal", "\u{1F1F3}\u{1F1F5}"),
+    (b"nerd_face", "\u{1F913}"),
+    (b"nest_with_eggs", "\u{1FABA}"),
+    (b"nesting_dolls", "\u{1FA86}"),
+    (b"netherlands", "\u{1F1F3}\u{1F1F1}"),
+    (b"neutral_face", "\u{1F610}"),
+    (b"new", "\u{1F195}"),
+    (b"new_caledonia", "\u{1F1F3}\u{1F1E8}"),
+    (b"new_moon", "\u{1F311}"),
+    (b"new_moon_with_face", "\u{1F31A}"),
+    (b"new_zealand", "\u{1F1F3}\u{1F1FF}"),
+    (b"newspaper", "\u{1F4F0}"),
+    (b"newspaper_roll", "\u{1F5DE}\u{FE0F}"),
+    (b"next_track_button", "\u{23ED}\u{FE0F}"),
+    (b"ng", "\u{1F196}"),
+    (b"ng_man", "\u{1F645}\u{200D}\u{2642}\u{FE0F}"),
+    (b"ng_woman", "\u{1F645}\u{200D}\u{2640}\u{FE0F}"),
+    (b"nicaragua", "\u{1F1F3}\u{1F1EE}"),
+    (b"niger", "\u{1F1F3}\u{1F1EA}"),
+    (b"nigeria", "\u{1F1F3}\u{1F1EC}"),
+    (b"night_with_stars", "\u{1F303}"),
+    (b"nine", "\u{0039}\u{FE0F}\u{20E3}"),
+    (b"ninja", "\u{1F977}"),
+    (b"niue", "\u{1F1F3}\u{1F1FA}"),
+    (b"no_bell", "\u{1F515}"),
+    (b"no_bicycles", "\u{1F6B3}"),
+    (b"no_entry", "\u{26D4}"),
+    (b"no_entry_sign", "\u{1F6AB}"),
+    (b"no_good", "\u{1F645}"),
+    (b"no_good_man", "\u{1F645}\u{200D}\u{2642}\u{FE0F}"),
+    (b"no_good_woman", "\u{1F645}\u{200D}\u{2640}\u{FE0F}"),
+    (b"no_mobile_phones", "\u{1F4F5}"),
+    (b"no_mouth", "\u{1F636}"),
+    (b"no_pedestrians", "\u{1F6B7}"),
+    (b"no_smoking", "\u{1F6AD}"),
+    (b"non-potable_water", "\u{1F6B1}"),
+    (b"norfolk_island", "\u{1F1F3}\u{1F1EB}"),
+    (b"north_korea", "\u{1F1F0}\u{1F1F5}"),
+    (b"northern_mariana_islands", "\u{1F1F2}\u{1F1F5}"),
+    (b"norway", "\u{1F1F3}\u{1F1F4}"),
+    (b"nose", "\u{1F443}"),
+    (b"notebook", "\u{1F4D3}"),
+    (b"notebook_with_decorative_cover", "\u{1F4D4}"),
+    (b"notes", "\u{1F3B6}"),
+    (b"nut_and_bolt", "\u{1F529}"),
+    (b"o", "\u{2B55}"),
+    (b"o2", "\u{1F17E}\u{FE0F}"),
+    (b"ocean", "\u{1F30A}"),
+    (b"octopus", "\u{1F419}"),
+    (b"oden", "\u{1F362}"),
+    (b"office", "\u{1F3E2}"),
+    (b"office_worker", "\u{1F9D1}\u{200D}\u{1F4BC}"),
+    (b"oil_drum", "\u{1F6E2}\u{FE0F}"),
+    (b"ok", "\u{1F197}"),
+    (b"ok_hand", "\u{1F44C}"),
+    (b"ok_man", "\u{1F646}\u{200D}\u{2642}\u{FE0F}"),
+    (b"ok_person", "\u{1F646}"),
+    (b"ok_woman", "\u{1F646}\u{200D}\u{2640}\u{FE0F}"),
+    (b"old_key", "\u{1F5DD}\u{FE0F}"),
+    (b"older_adult", "\u{1F9D3}"),
+    (b"older_man", "\u{1F474}"),
+    (b"older_woman", "\u{1F475}"),
+    (b"olive", "\u{1FAD2}"),
+    (b"om", "\u{1F549}\u{FE0F}"),
+    (b"oman", "\u{1F1F4}\u{1F1F2}"),
+    (b"on", "\u{1F51B}"),
+    (b"oncoming_automobile", "\u{1F698}"),
+    (b"oncoming_bus", "\u{1F68D}"),
+    (b"oncoming_police_car", "\u{1F694}"),
+    (b"oncoming_taxi", "\u{1F696}"),
+    (b"one", "\u{0031}\u{FE0F}\u{20E3}"),
+    (b"one_piece_swimsuit", "\u{1FA71}"),
+    (b"onion", "\u{1F9C5}"),
+    (b"open_book", "\u{1F4D6}"),
+    (b"open_file_folder", "\u{1F4C2}"),
+    (b"open_hands", "\u{1F450}"),
+    (b"open_mouth", "\u{1F62E}"),
+    (b"open_umbrella", "\u{2602}\u{FE0F}"),
+    (b"ophiuchus", "\u{26CE}"),
+    (b"orange", "\u{1F34A}"),
+    (b"orange_book", "\u{1F4D9}"),
+    (b"orange_circle", "\u{1F7E0}"),
+    (b"orange_heart", "\u{1F9E1}"),
+    (b"orange_square", "\u{1F7E7}"),
+    (b"orangutan", "\u{1F9A7}"),
+    (b"orthodox_cross", "\u{2626}\u{FE0F}"),
+    (b"otter", "\u{1F9A6}"),
+    (b"outbox_tray", "\u{1F4E4}"),
+    (b"owl", "\u{1F989}"),
+    (b"ox", "\u{1F402}"),
+    (b"oyster", "\u{1F9AA}"),
+    (b"package", "\u{1F4E6}"),
+    (b"page_facing_up", "\u{1F4C4}"),
+    (b"page_with_curl", "\u{1F4C3}"),
+    (b"pager", "\u{1F4DF}"),
+    (b"paintbrush", "\u{1F58C}\u{FE0F}"),
+    (b"pakistan", "\u{1F1F5}\u{1F1F0}"),
+    (b"palau", "\u{1F1F5}\u{1F1FC}"),
+    (b"palestinian_territories", "\u{1F1F5}\u{1F1F8}"),
+    (b"palm_down_hand", "\u{1FAF3}"),
+    (b"palm_tree", "\u{1F334}"),
+    (b"palm_up_hand", "\u{1FAF4}"),
+    (b"palms_up_together", "\u{1F932}"),
+    (b"panama", "\u{1F1F5}\u{1F1E6}"),
+    (b"pancakes", "\u{1F95E}"),
+    (b"panda_face", "\u{1F43C}"),
+    (b"paperclip", "\u{1F4CE}"),
+    (b"paperclips", "\u{1F587}\u{FE0F}"),
+    (b"papua_new_guinea", "\u{1F1F5}\u{1F1EC}"),
+    (b"parachute", "\u{1FA82}"),
+    (b"paraguay", "\u{1F1F5}\u{1F1FE}"),
+    (b"parasol_on_ground", "\u{26F1}\u{FE0F}"),
+    (b"parking", "\u{1F17F}\u{FE0F}"),
+    (b"parrot", "\u{1F99C}"),
+    (b"part_alternation_mark", "\u{303D}\u{FE0F}"),
+    (b"partly_sunny", "\u{26C5}"),
+    (b"partying_face", "\u{1F973}"),
+    (b"passenger_ship", "\u{1F6F3}\u{FE0F}"),
+    (b"passport_control", "\u{1F6C2}"),
+    (b"pause_button", "\u{23F8}\u{FE0F}"),
+    (b"paw_prints", "\u{1F43E}"),
+    (b"pea_pod", "\u{1FADB}"),
+    (b"peace_symbol", "\u{262E}\u{FE0F}"),
+    (b"peach", "\u{1F351}"),
+    (b"peacock", "\u{1F99A}"),
+    (b"peanuts", "\u{1F95C}"),
+    (b"pear", "\u{1F350}"),
+    (b"pen", "\u{1F58A}\u{FE0F}"),
+    (b"pencil", "\u{1F4DD}"),
+    (b"pencil2", "\u{270F}\u{FE0F}"),
+    (b"penguin", "\u{1F427}"),
+    (b"pensive", "\u{1F614}"),
+    (
+        b"people_holding_hands",
+        "\u{1F9D1}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}",
+    ),
+    (b"people_hugging", "\u{1FAC2}"),
+    (b"performing_arts", "\u{1F3AD}"),
+    (b"persevere", "\u{1F623}"),
+    (b"person_bald", "\u{1F9D1}\u{200D}\u{1F9B2}"),
+    (b"person_curly_hair", "\u{1F9D1}\u{200D}\u{1F9B1}"),
+    (b"person_feeding_baby", "\u{1F9D1}\u{200D}\u{1F37C}"),
+    (b"person_fencing", "\u{1F93A}"),
+    (b"person_in_manual_wheelchair", "\u{1F9D1}\u{200D}\u{1F9BD}"),
+    (
+        b"person_in_motorized_wheelchair",
+        "\u{1F9D1}\u{200D}\u{1F9BC}",
+    ),
+    (b"person_in_tuxedo", "\u{1F935}"),
+    (b"person_red_hair", "\u{1F9D1}\u{200D}\u{1F9B0}"),
+    (b"person_white_hair", "\u{1F9D1}\u{200D}\u{1F9B3}"),
+    (b"person_with_crown", "\u{1FAC5}"),
+    (b"person_with_probing_cane", "\u{1F9D1}\u{200D}\u{1F9AF}"),
+    (b"person_with_turban", "\u{1F473}"),
+    (b"person_with_veil", "\u{1F470}"),
+    (b"peru", "\u{1F1F5}\u{1F1EA}"),
+    (b"petri_dish", "\u{1F9EB}"),
+    (b"philippines", "\u{1F1F5}\u{1F1ED}"),
+    (b"phone", "\u{260E}\u{FE0F}"),
+    (b"pick", "\u{26CF}\u{FE0F}"),
+    (b"pickup_truck", "\u{1F6FB}"),
+    (b"pie", "\u{1F967}"),
+    (b"pig", "\u{1F437}"),
+    (b"pig2", "\u{1F416}"),
+    (b"pig_nose", "\u{1F43D}"),
+    (b"pill", "\u{1F48A}"),
+    (b"pilot", "\u{1F9D1}\u{200D}\u{2708}\u{FE0F}"),
+    (b"pinata", "\u{1FA85}"),
+    (b"pinched_fingers", "\u{1F90C}"),
+    (b"pinching_hand", "\u{1F90F}"),
+    (b"pineapple", "\u{1F34D}"),
+    (b"ping_pong", "\u{1F3D3}"),
+    (b"pink_heart", "\u{1FA77}"),
+    (b"pirate_flag", "\u{1F3F4}\u{200D}\u{2620}\u{FE0F}"),
+    (b"pisces", "\u{2653}"),
+    (b"pitcairn_islands", "\u{1F1F5}\u{1F1F3}"),
+    (b"pizza", "\u{1F355}"),
+    (b"placard", "\u{1FAA7}"),
+    (b"place_of_worship", "\u{1F6D0}"),
+    (b"plate_with_cutlery", "\u{1F37D}\u{FE0F}"),
+    (b"play_or_pause_button", "\u{23EF}\u{FE0F}"),
+    (b"playground_slide", "\u{1F6DD}"),
+    (b"pleading_face", "\u{1F97A}"),
+    (b"plunger", "\u{1FAA0}"),
+    (b"point_down", "\u{1F447}"),
+    (b"point_left", "\u{1F448}"),
+    (b"point_right", "\u{1F449}"),
+    (b"point_up", "\u{261D}\u{FE0F}"),
+    (b"point_up_2", "\u{1F446}"),
+    (b"poland", "\u{1F1F5}\u{1F1F1}"),
+    (b"polar_bear", "\u{1F43B}\u{200D}\u{2744}\u{FE0F}"),
+    (b"police_car", "\u{1F693}"),
+    (b"police_officer", "\u{1F46E}"),
+    (b"policeman", "\u{1F46E}\u{200D}\u{2642}\u{FE0F}"),
+    (b"policewoman", "\u{1F46E}\u{200D}\u{2640}\u{FE0F}"),
+    (b"poodle", "\u{1F429}"),
+    (b"poop", "\u{1F4A9}"),
+    (b"popcorn", "\u{1F37F}"),
+    (b"portugal", "\u{1F1F5}\u{1F1F9}"),
+    (b"post_office", "\u{1F3E3}"),
+    (b"postal_horn", "\u{1F4EF}"),
+    (b"postbox", "\u{1F4EE}"),
+    (b"potable_water", "\u{1F6B0}"),
+    (b"potato", "\u{1F954}"),
+    (b"potted_plant", "\u{1FAB4}"),
+    (b"pouch", "\u{1F45D}"),
+    (b"poultry_leg", "\u{1F357}"),
+    (b"pound", "\u{1F4B7}"),
+    (b"pouring_liquid", "\u{1FAD7}"),
+    (b"pout", "\u{1F621}"),
+    (b"pouting_cat", "\u{1F63E}"),
+    (b"pouting_face", "\u{1F64E}"),
+    (b"pouting_man", "\u{1F64E}\u{200D}\u{2642}\u{FE0F}"),
+    (b"pouting_woman", "\u{1F64E}\u{200D}\u{2640}\u{FE0F}"),
+    (b"pray", "\u{1F64F}"),
+    (b"prayer_beads", "\u{1F4FF}"),
+    (b"pregnant_man", "\u{1FAC3}"),
+    (b"pregnant_person", "\u{1FAC4}"),
+    (b"pregnant_woman", "\u{1F930}"),
+    (b"pretzel", "\u{1F968}"),
+    (b"previous_track_button", "\u{23EE}\u{FE0F}"),
+    (b"prince", "\u{1F934}"),
+    (b"princess", "\u{1F478}"),
+    (b"printer", "\u{1F5A8}\u{FE0F}"),
+    (b"probing_cane", "\u{1F9AF}"),
+    (b"puerto_rico", "\u{1F1F5}\u{1F1F7}"),
+    (b"punch", "\u{1F44A}"),
+    (b"purple_circle", "\u{1F7E3}"),
+    (b"purple_heart", "\u{1F49C}"),
+    (b"purple_square", "\u{1F7EA}"),
+    (b"purse", "\u{1F45B}"),
+    (b"pushpin", "\u{1F4CC}"),
+    (b"put_litter_in_its_place", "\u{1F6AE}"),
+    (b"qatar", "\u{1F1F6}\u{1F1E6}"),
+    (b"question", "\u{2753}"),
+    (b"rabbit", "\u{1F430}"),
+    (b"rabbit2", "\u{1F407}"),
+    (b"raccoon", "\u{1F99D}"),
+    (b"racehorse", "\u{1F40E}"),
+    (b"racing_car", "\u{1F3CE}\u{FE0F}"),
+    (b"radio", "\u{1F4FB}"),
+    (b"radio_button", "\u{1F518}"),
+    (b"radioactive", "\u{2622}\u{FE0F}"),
+    (b"rage", "\u{1F621}"),
+    (b"railway_car", "\u{1F683}"),
+    (b"railway_track", "\u{1F6E4}\u{FE0F}"),
+    (b"rainbow", "\u{1F308}"),
+    (b"rainbow_flag", "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}"),
+    (b"raised_back_of_hand", "\u{1F91A}"),
+    (b"raised_eyebrow", "\u{1F928}"),
+    (b"raised_hand", "\u{270B}"),
+    (b"raised_hand_with_fingers_splayed", "\u{1F590}\u{FE0F}"),
+    (b"raised_hands", "\u{1F64C}"),
+    (b"raising_hand", "\u{1F64B}"),
+    (b"raising_hand_man", "\u{1F64B}\u{200D}\u{2642}\u{FE0F}"),
+    (b"raising_hand_woman", "\u{1F64B}\u{200D}\u{2640}\u{FE0F}"),
+    (b"ram", "\u{1F40F}"),
+    (b"ramen", "\u{1F35C}"),
+    (b"rat", "\u{1F400}"),
+    (b"razor", "\u{1FA92}"),
+    (b"receipt", "\u{1F9FE}"),
+    (b"record_button", "\u{23FA}\u{FE0F}"),
+    (b"recycle", "\u{267B}\u{FE0F}"),
+    (b"red_car", "\u{1F697}"),
+    (b"red_circle", "\u{1F534}"),
+    (b"red_envelope", "\u{1F9E7}"),
+    (b"red_haired_man", "\u{1F468}\u{200D}\u{1F9B0}"),
+    (b"red_haired_woman", "\u{1F469}\u{200D}\u{1F9B0}"),
+    (b"red_square", "\u{1F7E5}"),
+    (b"registered", "\u{00AE}\u{FE0F}"),
+    (b"relaxed", "\u{263A}\u{FE0F}"),
+    (b"relieved", "\u{1F60C}"),
+    (b"reminder_ribbon", "\u{1F397}\u{FE0F}"),
+    (b"repeat", "\u{1F501}"),
+    (b"repeat_one", "\u{1F502}"),
+    (b"rescue_worker_helmet", "\u{26D1}\u{FE0F}"),
+    (b"restroom", "\u{1F6BB}"),
+    (b"reunion", "\u{1F1F7}\u{1F1EA}"),
+    (b"revolving_hearts", "\u{1F49E}"),
+    (b"rewind", "\u{23EA}"),
+    (b"rhinoceros", "\u{1F98F}"),
+    (b"ribbon", "\u{1F380}"),
+    (b"rice", "\u{1F35A}"),
+    (b"rice_ball", "\u{1F359}"),
+    (b"rice_cracker", "\u{1F358}"),
+    (b"rice_scene", "\u{1F391}"),
+    (b"right_anger_bubble", "\u{1F5EF}\u{FE0F}"),
+    (b"rightwards_hand", "\u{1FAF1}"),
+    (b"rightwards_pushing_hand", "\u{1FAF8}"),
+    (b"ring", "\u{1F48D}"),
+    (b"ring_buoy", "\u{1F6DF}"),
+    (b"ringed_planet", "\u{1FA90}"),
+    (b"robot", "\u{1F916}"),
+    (b"rock", "\u{1FAA8}"),
+    (b"rocket", "\u{1F680}"),
+    (b"rofl", "\u{1F923}"),
+    (b"roll_eyes", "\u{1F644}"),
+    (b"roll_of_paper", "\u{1F9FB}"),
+    (b"roller_coaster", "\u{1F3A2}"),
+    (b"roller_skate", "\u{1F6FC}"),
+    (b"romania", "\u{1F1F7}\u{1F1F4}"),
+    (b"rooster", "\u{1F413}"),
+    (b"rose", "\u{1F339}"),
+    (b"rosette", "\u{1F3F5}\u{FE0F}"),
+    (b"rotating_light", "\u{1F6A8}"),
+    (b"round_pushpin", "\u{1F4CD}"),
+    (b"rowboat", "\u{1F6A3}"),
+    (b"rowing_man", "\u{1F6A3}\u{200D}\u{2642}\u{FE0F}"),
+    (b"rowing_woman", "\u{1F6A3}\u{200D}\u{2640}\u{FE0F}"),
+    (b"ru", "\u{1F1F7}\u{1F1FA}"),
+    (b"rugby_football", "\u{1F3C9}"),
+    (b"runner", "\u{1F3C3}"),
+    (b"running", "\u{1F3C3}"),
+    (b"running_man", "\u{1F3C3}\u{200D}\u{2642}\u{FE0F}"),
+    (b"running_shirt_with_sash", "\u{1F3BD}"),
+    (b"running_woman", "\u{1F3C3}\u{200D}\u{2640}\u{FE0F}"),
+    (b"rwanda", "\u{1F1F7}\u{1F1FC}"),
+    (b"sa", "\u{1F202}\u{FE0F}"),
+    (b"safety_pin", "\u{1F9F7}"),
+    (b"safety_vest", "\u{1F9BA}"),
+    (b"sagittarius", "\u{2650}"),
+    (b"sailboat", "\u{26F5}"),
+    (b"sake", "\u{1F376}"),
+    (b"salt", "\u{1F9C2}"),
+    (b"saluting_face", "\u{1FAE1}"),
+    (b"samoa", "\u{1F1FC}\u{1F1F8}"),
+    (b"san_marino", "\u{1F1F8}\u{1F1F2}"),
+    (b"sandal", "\u{1F461}"),
+    (b"sandwich", "\u{1F96A}"),
+    (b"santa", "\u{1F385}"),
+    (b"sao_tome_principe", "\u{1F1F8}\u{1F1F9}"),
+    (b"sari", "\u{1F97B}"),
+    (b"sassy_man", "\u{1F481}\u{200D}\u{2642}\u{FE0F}"),
+    (b"sassy_woman", "\u{1F481}\u{200D}\u{2640}\u{FE0F}"),
+    (b"satellite", "\u{1F4E1}"),
+    (b"satisfied", "\u{1F606}"),
+    (b"saudi_arabia", "\u{1F1F8}\u{1F1E6}"),
+    (b"sauna_man", "\u{1F9D6}\u{200D}\u{2642}\u{FE0F}"),
+    (b"sauna_person", "\u{1F9D6}"),
+    (b"sauna_woman", "\u{1F9D6}\u{200D}\u{2640}\u{FE0F}"),
+    (b"sauropod", "\u{1F995}"),
+    (b"saxophone", "\u{1F3B7}"),
+    (b"scarf", "\u{1F9E3}"),
+    (b"school", "\u{1F3EB}"),
+    (b"school_satchel", "\u{1F392}"),
+    (b"scientist", "\u{1F9D1}\u{200D}\u{1F52C}"),
+    (b"scissors", "\u{2702}\u{FE0F}"),
+    (b"scorpion", "\u{1F982}"),
+    (b"scorpius", "\u{264F}"),
+    (
+        b"scotland",
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+    ),
+    (b"scream", "\u{1F631}"),
+    (b"scream_cat", "\u{1F640}"),
+    (b"screwdriver", "\u{1FA9B}"),
+    (b"scroll", "\u{1F4DC}"),
+    (b"seal", "\u{1F9AD}"),
+    (b"seat", "\u{1F4BA}"),
+    (b"secret", "\u{3299}\u{FE0F}"),
+    (b"see_no_evil", "\u{1F648}"),
+    (b"seedling", "\u{1F331}"),
+    (b"selfie", "\u{1F933}"),
+    (b"senegal", "\u{1F1F8}\u{1F1F3}"),
+    (b"serbia", "\u{1F1F7}\u{1F1F8}"),
+    (b"service_dog", "\u{1F415}\u{200D}\u{1F9BA}"),
+    (b"seven", "\u{0037}\u{FE0F}\u{20E3}"),
+    (b"sewing_needle", "\u{1FAA1}"),
+    (b"seychelles", "\u{1F1F8}\u{1F1E8}"),
+    (b"shaking_face", "\u{1FAE8}"),
+    (b"shallow_pan_of_food", "\u{1F958}"),
+    (b"shamrock", "\u{2618}\u{FE0F}"),
+    (b"shark", "\u{1F988}"),
+    (b"shaved_ice", "\u{1F367}"),
+    (b"sheep", "\u{1F411}"),
+    (b"shell", "\u{1F41A}"),
+    (b"shield", "\u{1F6E1}\u{FE0F}"),
+    (b"shinto_shrine", "\u{26E9}\u{FE0F}"),
+    (b"ship", "\u{1F6A2}"),
+    (b"shirt", "\u{1F455}"),
+    (b"shit", "\u{1F4A9}"),
+    (b"shoe", "\u{1F45E}"),
+    (b"shopping", "\u{1F6CD}\u{FE0F}"),
+    (b"shopping_cart", "\u{1F6D2}"),
+    (b"shorts", "\u{1FA73}"),
+    (b"shower", "\u{1F6BF}"),
+    (b"shrimp", "\u{1F990}"),
+    (b"shrug", "\u{1F937}"),
+    (b"shushing_face", "\u{1F92B}"),
+    (b"sierra_leone", "\u{1F1F8}\u{1F1F1}"),
+    (b"signal_strength", "\u{1F4F6}"),
+    (b"singapore", "\u{1F1F8}\u{1F1EC}"),
+    (b"singer", "\u{1F9D1}\u{200D}\u{1F3A4}"),
+    (b"sint_maarten", "\u{1F1F8}\u{1F1FD}"),
+    (b"six", "\u{0036}\u{FE0F}\u{20E3}"),
+    (b"six_pointed_star", "\u{1F52F}"),
+    (b"skateboard", "\u{1F6F9}"),
+    (b"ski", "\u{1F3BF}"),
+    (b"skier", "\u{26F7}\u{FE0F}"),
+    (b"skull", "\u{1F480}"),
+    (b"skull_and_crossbones", "\u{2620}\u{FE0F}"),
+    (b"skunk", "\u{1F9A8}"),
+    (b"sled", "\u{1F6F7}"),
+    (b"sleeping", "\u{1F634}"),
+    (b"sleeping_bed", "\u{1F6CC}"),
+    (b"sleepy", "\u{1F62A}"),
+    (b"slightly_frowning_face", "\u{1F641}"),
+    (b"slightly_smiling_face", "\u{1F642}"),
+    (b"slot_machine", "\u{1F3B0}"),
+    (b"sloth", "\u{1F9A5}"),
+    (b"slovakia", "\u{1F1F8}\u{1F1F0}"),
+    (b"slovenia", "\u{1F1F8}\u{1F1EE}"),
+    (b"small_airplane", "\u{1F6E9}\u{FE0F}"),
+    (b"small_blue_diamond", "\u{1F539}"),
+    (b"small_orange_diamond", "\u{1F538}"),
+    (b"small_red_triangle", "\u{1F53A}"),
+    (b"small_red_triangle_down", "\u{1F53B}"),
+    (b"smile", "\u{1F604}"),
+    (b"smile_cat", "\u{1F638}"),
+    (b"smiley", "\u{1F603}"),
+    (b"smiley_cat", "\u{1F63A}"),
+    (b"smiling_face_with_tear", "\u{1F972}"),
+    (b"smiling_face_with_three_hearts", "\u{1F970}"),
+    (b"smiling_imp", "\u{1F608}"),
+    (b"smirk", "\u{1F60F}"),
+    (b"smirk_cat", "\u{1F63C}"),
+    (b"smoking", "\u{1F6AC}"),
+    (b"snail", "\u{1F40C}"),
+    (b"snake", "\u{1F40D}"),
+    (b"sneezing_face", "\u{1F927}"),
+    (b"snowboarder", "\u{1F3C2}"),
+    (b"snowflake", "\u{2744}\u{FE0F}"),
+    (b"snowman", "\u{26C4}"),
+    (b"snowman_with_snow", "\u{2603}\u{FE0F}"),
+    (b"soap", "\u{1F9FC}"),
+    (b"sob", "\u{1F62D}"),
+    (b"soccer", "\u{26BD}"),
+    (b"socks", "\u{1F9E6}"),
+    (b"softball", "\u{1F94E}"),
+    (b"solomon_islands", "\u{1F1F8}\u{1F1E7}"),
+    (b"somalia", "\u{1F1F8}\u{1F1F4}"),
+    (b"soon", "\u{1F51C}"),
+    (b"sos", "\u{1F198}"),
+    (b"sound", "\u{1F509}"),
+    (b"south_africa", "\u{1F1FF}\u{1F1E6}"),
+    (
+        b"south_georgia_south_sandwich_islands",
+        "\u{1F1EC}\u{1F1F8}",
+    ),
+    (b"south_sudan", "\u{1F1F8}\u{1F1F8}"),
+    (b"space_invader", "\u{1F47E}"),
+    (b"spades", "\u{2660}\u{FE0F}"),
+    (b"spaghetti", "\u{1F35D}"),
+    (b"sparkle", "\u{2747}\u{FE0F}"),
+    (b"sparkler", "\u{1F387}"),
+    (b"sparkles", "\u{2728}"),
+    (b"sparkling_heart", "\u{1F496}"),
+    (b"speak_no_evil", "\u{1F64A}"),
+    (b"speaker", "\u{1F508}"),
+    (b"speaking_head", "\u{1F5E3}\u{FE0F}"),
+    (b"speech_balloon", "\u{1F4AC}"),
+    (b"speedboat", "\u{1F6A4}"),
+    (b"spider", "\u{1F577}\u{FE0F}"),
+    (b"spider_web", "\u{1F578}\u{FE0F}"),
+    (b"spiral_calendar", "\u{1F5D3}\u{FE0F}"),
+    (b"spiral_notepad", "\u{1F5D2}\u{FE0F}"),
+    (b"sponge", "\u{1F9FD}"),
+    (b"spoon", "\u{1F944}"),
+    (b"squid", "\u{1F991}"),
+    (b"sri_lanka", "\u{1F1F1}\u{1F1F0}"),
+    (b"st_barthelemy", "\u{1F1E7}\u{1F1F1}"),
+    (b"st_helena", "\u{1F1F8}\u{1F1ED}"),
+    (b"st_kitts_nevis", "\u{1F1F0}\u{1F1F3}"),
+    (b"st_lucia", "\u{1F1F1}\u{1F1E8}"),
+    (b"st_martin", "\u{1F1F2}\u{1F1EB}"),
+    (b"st_pierre_miquelon", "\u{1F1F5}\u{1F1F2}"),
+    (b"st_vincent_grenadines", "\u{1F1FB}\u{1F1E8}"),
+    (b"stadium", "\u{1F3DF}\u{FE0F}"),
+    (b"standing_man", "\u{1F9CD}\u{200D}\u{2642}\u{FE0F}"),
+    (b"standing_person", "\u{1F9CD}"),
+    (b"standing_woman", "\u{1F9CD}\u{200D}\u{2640}\u{FE0F}"),
+    (b"star", "\u{2B50}"),
+    (b"star2", "\u{1F31F}"),
+    (b"star_and_crescent", "\u{262A}\u{FE0F}"),
+    (b"star_of_david", "\u{2721}\u{FE0F}"),
+    (b"star_struck", "\u{1F929}"),
+    (b"stars", "\u{1F320}"),
+    (b"station", "\u{1F689}"),
+    (b"statue_of_liberty", "\u{1F5FD}"),
+    (b"steam_locomotive", "\u{1F682}"),
+    (b"stethoscope", "\u{1FA7A}"),
+    (b"stew", "\u{1F372}"),
+    (b"stop_button", "\u{23F9}\u{FE0F}"),
+    (b"stop_sign", "\u{1F6D1}"),
+    (b"stopwatch", "\u{23F1}\u{FE0F}"),
+    (b"straight_ruler", "\u{1F4CF}"),
+    (b"strawberry", "\u{1F353}"),
+    (b"stuck_out_tongue", "\u{1F61B}"),
+    (b"stuck_out_tongue_closed_eyes", "\u{1F61D}"),
+    (b"stuck_out_tongue_winking_eye", "\u{1F61C}"),
+    (b"student", "\u{1F9D1}\u{200D}\u{1F393}"),
+    (b"studio_microphone", "\u{1F399}\u{FE0F}"),
+    (b"stuffed_flatbread", "\u{1F959}"),
+    (b"sudan", "\u{1F1F8}\u{1F1E9}"),
+    (b"sun_behind_large_cloud", "\u{1F325}\u{FE0F}"),
+    (b"sun_behind_rain_cloud", "\u{1F326}\u{FE0F}"),
+    (b"sun_behind_small_cloud", "\u{1F324}\u{FE0F}"),
+    (b"sun_with_face", "\u{1F31E}"),
+    (b"sunflower", "\u{1F33B}"),
+    (b"sunglasses", "\u{1F60E}"),
+    (b"sunny", "\u{2600}\u{FE0F}"),
+    (b"sunrise", "\u{1F305}"),
+    (b"sunrise_over_mountains", "\u{1F304}"),
+    (b"superhero", "\u{1F9B8}"),
+    (b"superhero_man", "\u{1F9B8}\u{200D}\u{2642}\u{FE0F}"),
+    (b"superhero_woman", "\u{1F9B8}\u{200D}\u{2640}\u{FE0F}"),
+    (b"supervillain", "\u{1F9B9}"),
+    (b"supervillain_man", "\u{1F9B9}\u{200D}\u{2642}\u{FE0F}"),
+    (b"supervillain_woman", "\u{1F9B9}\u{200D}\u{2640}\u{FE0F}"),
+    (b"surfer", "\u{1F3C4}"),
+    (b"surfing_man", "\u{1F3C4}\u{200D}\u{2642}\u{FE0F}"),
+    (b"surfing_woman", "\u{1F3C4}\u{200D}\u{2640}\u{FE0F}"),
+    (b"suriname", "\u{1F1F8}\u{1F1F7}"),
+    (b"sushi", "\u{1F363}"),
+    (b"suspension_railway", "\u{1F69F}"),
+    (b"svalbard_jan_mayen", "\u{1F1F8}\u{1F1EF}"),
+    (b"swan", "\u{1F9A2}"),
+    (b"swaziland", "\u{1F1F8}\u{1F1FF}"),
+    (b"sweat", "\u{1F613}"),
+    (b"sweat_drops", "\u{1F4A6}"),
+    (b"sweat_smile", "\u{1F605}"),
+    (b"sweden", "\u{1F1F8}\u{1F1EA}"),
+    (b"sweet_potato", "\u{1F360}"),
+    (b"swim_brief", "\u{1FA72}"),
+    (b"swimmer", "\u{1F3CA}"),
+    (b"swimming_man", "\u{1F3CA}\u{200D}\u{2642}\u{FE0F}"),
+    (b"swimming_woman", "\u{1F3CA}\u{200D}\u{2640}\u{FE0F}"),
+    (b"switzerland", "\u{1F1E8}\u{1F1ED}"),
+    (b"symbols", "\u{1F523}"),
+    (b"synagogue", "\u{1F54D}"),
+    (b"syria", "\u{1F1F8}\u{1F1FE}"),
+    (b"syringe", "\u{1F489}"),
+    (b"t-rex", "\u{1F996}"),
+    (b"taco", "\u{1F32E}"),
+    (b"tada", "\u{1F389}"),
+    (b"taiwan", "\u{1F1F9}\u{1F1FC}"),
+    (b"tajikistan", "\u{1F1F9}\u{1F1EF}"),
+    (b"takeout_box", "\u{1F961}"),
+    (b"tamale", "\u{1FAD4}"),
+    (b"tanabata_tree", "\u{1F38B}"),
+    (b"tangerine", "\u{1F34A}"),
+    (b"tanzania", "\u{1F1F9}\u{1F1FF}"),
+    (b"taurus", "\u{2649}"),
+    (b"taxi", "\u{1F695}"),
+    (b"tea", "\u{1F375}"),
+    (b"teacher", "\u{1F9D1}\u{200D}\u{1F3EB}"),
+    (b"teapot", "\u{1FAD6}"),
+    (b"technologist", "\u{1F9D1}\u{200D}\u{1F4BB}"),
+    (b"teddy_bear", "\u{1F9F8}"),
+    (b"telephone", "\u{260E}\u{FE0F}"),
+    (b"telephone_receiver", "\u{1F4DE}"),
+    (b"telescope", "\u{1F52D}"),
+    (b"tennis", "\u{1F3BE}"),
+    (b"tent", "\u{26FA}"),
+    (b"test_tube", "\u{1F9EA}"),
+    (b"thailand", "\u{1F1F9}\u{1F1ED}"),
+    (b"thermometer", "\u{1F321}\u{FE0F}"),
+    (b"thinking", "\u{1F914}"),
+    (b"thong_sandal", "\u{1FA74}"),
+    (b"thought_balloon", "\u{1F4AD}"),
+    (b"thread", "\u{1F9F5}"),
+    (b"three", "\u{0033}\u{FE0F}\u{20E3}"),
+    (b"thumbsdown", "\u{1F44E}"),
+    (b"thumbsup", "\u{1F44D}"),
+    (b"ticket", "\u{1F3AB}"),
+    (b"tickets", "\u{1F39F}\u{FE0F}"),
+    (b"tiger", "\u{1F42F}"),
+    (b"tiger2", "\u{1F405}"),
+    (b"timer_clock", "\u{23F2}\u{FE0F}"),
+    (b"timor_leste", "\u{1F1F9}\u{1F1F1}"),
+    (b"tipping_hand_man", "\u{1F481}\u{200D}\u{2642}\u{FE0F}"),
+    (b"tipping_hand_person", "\u{1F481}"),
+    (b"tipping_hand_woman", "\u{1F481}\u{200D}\u{2640}\u{FE0F}"),
+    (b"tired_face", "\u{1F62B}"),
+    (b"tm", "\u{2122}\u{FE0F}"),
+    (b"togo", "\u{1F1F9}\u{1F1EC}"),
+    (b"toilet", "\u{1F6BD}"),
+    (b"tokelau", "\u{1F1F9}\u{1F1F0}"),
+    (b"tokyo_tower", "\u{1F5FC}"),
+    (b"tomato", "\u{1F345}"),
+    (b"tonga", "\u{1F1F9}\u{1F1F4}"),
+    (b"tongue", "\u{1F445}"),
+    (b"toolbox", "\u{1F9F0}"),
+    (b"tooth", "\u{1F9B7}"),
+    (b"toothbrush", "\u{1FAA5}"),
+    (b"top", "\u{1F51D}"),
+    (b"tophat", "\u{1F3A9}"),
+    (b"tornado", "\u{1F32A}\u{FE0F}"),
+    (b"tr", "\u{1F1F9}\u{1F1F7}"),
+    (b"trackball", "\u{1F5B2}\u{FE0F}"),
+    (b"tractor", "\u{1F69C}"),
+    (b"traffic_light", "\u{1F6A5}"),
+    (b"train", "\u{1F68B}"),
+    (b"train2", "\u{1F686}"),
+    (b"tram", "\u{1F68A}"),
+    (
+        b"transgender_flag",
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{26A7}\u{FE0F}",
+    ),
+    (b"transgender_symbol", "\u{26A7}\u{FE0F}"),
+    (b"triangular_flag_on_post", "\u{1F6A9}"),
+    (b"triangular_ruler", "\u{1F4D0}"),
+    (b"trident", "\u{1F531}"),
+    (b"trinidad_tobago", "\u{1F1F9}\u{1F1F9}"),
+    (b"tristan_da_cunha", "\u{1F1F9}\u{1F1E6}"),
+    (b"triumph", "\u{1F624}"),
+    (b"troll", "\u{1F9CC}"),
+    (b"trolleybus", "\u{1F68E}"),
+    (b"trophy", "\u{1F3C6}"),
+    (b"tropical_drink", "\u{1F379}"),
+    (b"tropical_fish", "\u{1F420}"),
+    (b"truck", "\u{1F69A}"),
+    (b"trumpet", "\u{1F3BA}"),
+    (b"tshirt", "\u{1F455}"),
+    (b"tulip", "\u{1F337}"),
+    (b"tumbler_glass", "\u{1F943}"),
+    (b"tunisia", "\u{1F1F9}\u{1F1F3}"),
+    (b"turkey", "\u{1F983}"),
+    (b"turkmenistan", "\u{1F1F9}\u{1F1F2}"),
+    (b"turks_caicos_islands", "\u{1F1F9}\u{1F1E8}"),
+    (b"turtle", "\u{1F422}"),
+    (b"tuvalu", "\u{1F1F9}\u{1F1FB}"),
+    (b"tv", "\u{1F4FA}"),
+    (b"twisted_rightwards_arrows", "\u{1F500}"),
+    (b"two", "\u{0032}\u{FE0F}\u{20E3}"),
+    (b"two_hearts", "\u{1F495}"),
+    (b"two_men_holding_hands", "\u{1F46C}"),
+    (b"two_women_holding_hands", "\u{1F46D}"),
+    (b"u5272", "\u{1F239}"),
+    (b"u5408", "\u{1F234}"),
+    (b"u55b6", "\u{1F23A}"),
+    (b"u6307", "\u{1F22F}"),
+    (b"u6708", "\u{1F237}\u{FE0F}"),
+    (b"u6709", "\u{1F236}"),
+    (b"u6e80", "\u{1F235}"),
+    (b"u7121", "\u{1F21A}"),
+    (b"u7533", "\u{1F238}"),
+    (b"u7981", "\u{1F232}"),
+    (b"u7a7a", "\u{1F233}"),
+    (b"uganda", "\u{1F1FA}\u{1F1EC}"),
+    (b"uk", "\u{1F1EC}\u{1F1E7}"),
+    (b"ukraine", "\u{1F1FA}\u{1F1E6}"),
+    (b"umbrella", "\u{2614}"),
+    (b"unamused", "\u{1F612}"),
+    (b"underage", "\u{1F51E}"),
+    (b"unicorn", "\u{1F984}"),
+    (b"united_arab_emirates", "\u{1F1E6}\u{1F1EA}"),
+    (b"united_nations", "\u{1F1FA}\u{1F1F3}"),
+    (b"unlock", "\u{1F513}"),
+    (b"up", "\u{1F199}"),
+    (b"upside_down_face", "\u{1F643}"),
+    (b"uruguay", "\u{1F1FA}\u{1F1FE}"),
+    (b"us", "\u{1F1FA}\u{1F1F8}"),
+    (b"us_outlying_islands", "\u{1F1FA}\u{1F1F2}"),
+    (b"us_virgin_islands", "\u{1F1FB}\u{1F1EE}"),
+    (b"uzbekistan", "\u{1F1FA}\u{1F1FF}"),
+    (b"v", "\u{270C}\u{FE0F}"),
+    (b"vampire", "\u{1F9DB}"),
+    (b"vampire_man", "\u{1F9DB}\u{200D}\u{2642}\u{FE0F}"),
+    (b"vampire_woman", "\u{1F9DB}\u{200D}\u{2640}\u{FE0F}"),
+    (b"vanuatu", "\u{1F1FB}\u{1F1FA}"),
+    (b"vatican_city", "\u{1F1FB}\u{1F1E6}"),
+    (b"venezuela", "\u{1F1FB}\u{1F1EA}"),
+    (b"vertical_traffic_light", "\u{1F6A6}"),
+    (b"vhs", "\u{1F4FC}"),
+    (b"vibration_mode", "\u{1F4F3}"),
+    (b"video_camera", "\u{1F4F9}"),
+    (b"video_game", "\u{1F3AE}"),
+    (b"vietnam", "\u{1F1FB}\u{1F1F3}"),
+    (b"violin", "\u{1F3BB}"),
+    (b"virgo", "\u{264D}"),
+    (b"volcano", "\u{1F30B}"),
+    (b"volleyball", "\u{1F3D0}"),
+    (b"vomiting_face", "\u{1F92E}"),
+    (b"vs", "\u{1F19A}"),
+    (b"vulcan_salute", "\u{1F596}"),
+    (b"waffle", "\u{1F9C7}"),
+    (
+        b"wales",
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}",
+    ),
+    (b"walking", "\u{1F6B6}"),
+    (b"walking_man", "\u{1F6B6}\u{200D}\u{2642}\u{FE0F}"),
+    (b"walking_woman", "\u{1F6B6}\u{200D}\u{2640}\u{FE0F}"),
+    (b"wallis_futuna", "\u{1F1FC}\u{1F1EB}"),
+    (b"waning_crescent_moon", "\u{1F318}"),
+    (b"waning_gibbous_moon", "\u{1F316}"),
+    (b"warning", "\u{26A0}\u{FE0F}"),
+    (b"wastebasket", "\u{1F5D1}\u{FE0F}"),
+    (b"watch", "\u{231A}"),
+    (b"water_buffalo", "\u{1F403}"),
+    (b"water_polo", "\u{1F93D}"),
+    (b"watermelon", "\u{1F349}"),
+    (b"wave", "\u{1F44B}"),
+    (b"wavy_dash", "\u{3030}\u{FE0F}"),
+    (b"waxing_crescent_moon", "\u{1F312}"),
+    (b"waxing_gibbous_moon", "\u{1F314}"),
+    (b"wc", "\u{1F6BE}"),
+    (b"weary", "\u{1F629}"),
+    (b"wedding", "\u{1F492}"),
+    (b"weight_lifting", "\u{1F3CB}\u{FE0F}"),
+    (
+        b"weight_lifting_man",
+        "\u{1F3CB}\u{FE0F}\u{200D}\u{2642}\u{FE0F}",
+    ),
+    (
+        b"weight_lifting_woman",
+        "\u{1F3CB}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (b"western_sahara", "\u{1F1EA}\u{1F1ED}"),
+    (b"whale", "\u{1F433}"),
+    (b"whale2", "\u{1F40B}"),
+    (b"wheel", "\u{1F6DE}"),
+    (b"wheel_of_dharma", "\u{2638}\u{FE0F}"),
+    (b"wheelchair", "\u{267F}"),
+    (b"white_check_mark", "\u{2705}"),
+    (b"white_circle", "\u{26AA}"),
+    (b"white_flag", "\u{1F3F3}\u{FE0F}"),
+    (b"white_flower", "\u{1F4AE}"),
+    (b"white_haired_man", "\u{1F468}\u{200D}\u{1F9B3}"),
+    (b"white_haired_woman", "\u{1F469}\u{200D}\u{1F9B3}"),
+    (b"white_heart", "\u{1F90D}"),
+    (b"white_large_square", "\u{2B1C}"),
+    (b"white_medium_small_square", "\u{25FD}"),
+    (b"white_medium_square", "\u{25FB}\u{FE0F}"),
+    (b"white_small_square", "\u{25AB}\u{FE0F}"),
+    (b"white_square_button", "\u{1F533}"),
+    (b"wilted_flower", "\u{1F940}"),
+    (b"wind_chime", "\u{1F390}"),
+    (b"wind_face", "\u{1F32C}\u{FE0F}"),
+    (b"window", "\u{1FA9F}"),
+    (b"wine_glass", "\u{1F377}"),
+    (b"wing", "\u{1FABD}"),
+    (b"wink", "\u{1F609}"),
+    (b"wireless", "\u{1F6DC}"),
+    (b"wolf", "\u{1F43A}"),
+    (b"woman", "\u{1F469}"),
+    (b"woman_artist", "\u{1F469}\u{200D}\u{1F3A8}"),
+    (b"woman_astronaut", "\u{1F469}\u{200D}\u{1F680}"),
+    (b"woman_beard", "\u{1F9D4}\u{200D}\u{2640}\u{FE0F}"),
+    (b"woman_cartwheeling", "\u{1F938}\u{200D}\u{2640}\u{FE0F}"),
+    (b"woman_cook", "\u{1F469}\u{200D}\u{1F373}"),
+    (b"woman_dancing", "\u{1F483}"),
+    (b"woman_facepalming", "\u{1F926}\u{200D}\u{2640}\u{FE0F}"),
+    (b"woman_factory_worker", "\u{1F469}\u{200D}\u{1F3ED}"),
+    (b"woman_farmer", "\u{1F469}\u{200D}\u{1F33E}"),
+    (b"woman_feeding_baby", "\u{1F469}\u{200D}\u{1F37C}"),
+    (b"woman_firefighter", "\u{1F469}\u{200D}\u{1F692}"),
+    (b"woman_health_worker", "\u{1F469}\u{200D}\u{2695}\u{FE0F}"),
+    (b"woman_in_manual_wheelchair", "\u{1F469}\u{200D}\u{1F9BD}"),
+    (
+        b"woman_in_motorized_wheelchair",
+        "\u{1F469}\u{200D}\u{1F9BC}",
+    ),
+    (b"woman_in_tuxedo", "\u{1F935}\u{200D}\u{2640}\u{FE0F}"),
+    (b"woman_judge", "\u{1F469}\u{200D}\u{2696}\u{FE0F}"),
+    (b"woman_juggling", "\u{1F939}\u{200D}\u{2640}\u{FE0F}"),
+    (b"woman_mechanic", "\u{1F469}\u{200D}\u{1F527}"),
+    (b"woman_office_worker", "\u{1F469}\u{200D}\u{1F4BC}"),
+    (b"woman_pilot", "\u{1F469}\u{200D}\u{2708}\u{FE0F}"),
+    (
+        b"woman_playing_handball",
+        "\u{1F93E}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (
+        b"woman_playing_water_polo",
+        "\u{1F93D}\u{200D}\u{2640}\u{FE0F}",
+    ),
+    (b"woman_scientist", "\u{1F469}\u{200D}\u{1F52C}"),
+    (b"woman_shrugging", "\u{1F937}\u{200D}\u{2640}\u{FE0F}"),
+    (b"woman_singer", "\u{1F469}\u{200D}\u{1F3A4}"),
+    (b"woman_student", "\u{1F469}\u{200D}\u{1F393}"),
+    (b"woman_teacher", "\u{1F469}\u{200D}\u{1F3EB}"),
+    (b"woman_technologist", "\u{1F469}\u{200D}\u{1F4BB}"),
+    (b"woman_with_headscarf", "\u{1F9D5}"),
+    (b"woman_with_probing_cane", "\u{1F469}\u{200D}\u{1F9AF}"),
+    (b"woman_with_turban", "\u{1F473}\u{200D}\u{2640}\u{FE0F}"),
+    (b"woman_with_veil", "\u{1F470}\u{200D}\u{2640}\u{FE0F}"),
+    (b"womans_clothes", "\u{1F45A}"),
+    (b"womans_hat", "\u{1F452}"),
+    (b"women_wrestling", "\u{1F93C}\u{200D}\u{2640}\u{FE0F}"),
+    (b"womens", "\u{1F6BA}"),
+    (b"wood", "\u{1FAB5}"),
+    (b"woozy_face", "\u{1F974}"),
+    (b"world_map", "\u{1F5FA}\u{FE0F}"),
+    (b"worm", "\u{1FAB1}"),
+    (b"worried", "\u{1F61F}"),
+    (b"wrench", "\u{1F527}"),
+    (b"wrestling", "\u{1F93C}"),
+    (b"writing_hand", "\u{270D}\u{FE0F}"),
+    (b"x", "\u{274C}"),
+    (b"x_ray", "\u{1FA7B}"),
+    (b"yarn", "\u{1F9F6}"),
+    (b"yawning_face", "\u{1F971}"),
+    (b"yellow_circle", "\u{1F7E1}"),
+    (b"yellow_heart", "\u{1F49B}"),
+    (b"yellow_square", "\u{1F7E8}"),
+    (b"yemen", "\u{1F1FE}\u{1F1EA}"),
+    (b"yen", "\u{1F4B4}"),
+    (b"yin_yang", "\u{262F}\u{FE0F}"),
+    (b"yo_yo", "\u{1FA80}"),
+    (b"yum", "\u{1F60B}"),
+    (b"zambia", "\u{1F1FF}\u{1F1F2}"),
+    (b"zany_face", "\u{1F92A}"),
+    (b"zap", "\u{26A1}"),
+    (b"zebra", "\u{1F993}"),
+    (b"zero", "\u{0030}\u{FE0F}\u{20E3}"),
+    (b"zimbabwe", "\u{1F1FF}\u{1F1FC}"),
+    (b"zipper_mouth_face", "\u{1F910}"),
+    (b"zombie", "\u{1F9DF}"),
+    (b"zombie_man", "\u{1F9DF}\u{200D}\u{2642}\u{FE0F}"),
+    (b"zombie_woman", "\u{1F9DF}\u{200D}\u{2640}\u{FE0F}"),
+    (b"zzz", "\u{1F4A4}"),
+];
+
+static SKIN_TONES: [(&[u8], [&str; 5]); 311] = [
+    (
+        b"+1",
+        [
+            "\u{1F44D}\u{1F3FB}",
+            "\u{1F44D}\u{1F3FC}",
+            "\u{1F44D}\u{1F3FD}",
+            "\u{1F44D}\u{1F3FE}",
+            "\u{1F44D}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"-1",
+        [
+            "\u{1F44E}\u{1F3FB}",
+            "\u{1F44E}\u{1F3FC}",
+            "\u{1F44E}\u{1F3FD}",
+            "\u{1F44E}\u{1F3FE}",
+            "\u{1F44E}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"adult",
+        [
+            "\u{1F9D1}\u{1F3FB}",
+            "\u{1F9D1}\u{1F3FC}",
+            "\u{1F9D1}\u{1F3FD}",
+            "\u{1F9D1}\u{1F3FE}",
+            "\u{1F9D1}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"angel",
+        [
+            "\u{1F47C}\u{1F3FB}",
+            "\u{1F47C}\u{1F3FC}",
+            "\u{1F47C}\u{1F3FD}",
+            "\u{1F47C}\u{1F3FE}",
+            "\u{1F47C}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"artist",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3A8}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3A8}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3A8}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3A8}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3A8}",
+        ],
+    ),
+    (
+        b"astronaut",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F680}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F680}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F680}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F680}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F680}",
+        ],
+    ),
+    (
+        b"baby",
+        [
+            "\u{1F476}\u{1F3FB}",
+            "\u{1F476}\u{1F3FC}",
+            "\u{1F476}\u{1F3FD}",
+            "\u{1F476}\u{1F3FE}",
+            "\u{1F476}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"bald_man",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B2}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B2}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B2}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B2}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B2}",
+        ],
+    ),
+    (
+        b"bald_woman",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B2}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B2}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B2}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B2}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B2}",
+        ],
+    ),
+    (
+        b"basketball_man",
+        [
+            "\u{26F9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{26F9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{26F9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{26F9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{26F9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"basketball_woman",
+        [
+            "\u{26F9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{26F9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{26F9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{26F9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{26F9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"bath",
+        [
+            "\u{1F6C0}\u{1F3FB}",
+            "\u{1F6C0}\u{1F3FC}",
+            "\u{1F6C0}\u{1F3FD}",
+            "\u{1F6C0}\u{1F3FE}",
+            "\u{1F6C0}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"bearded_person",
+        [
+            "\u{1F9D4}\u{1F3FB}",
+            "\u{1F9D4}\u{1F3FC}",
+            "\u{1F9D4}\u{1F3FD}",
+            "\u{1F9D4}\u{1F3FE}",
+            "\u{1F9D4}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"bicyclist",
+        [
+            "\u{1F6B4}\u{1F3FB}",
+            "\u{1F6B4}\u{1F3FC}",
+            "\u{1F6B4}\u{1F3FD}",
+            "\u{1F6B4}\u{1F3FE}",
+            "\u{1F6B4}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"biking_man",
+        [
+            "\u{1F6B4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"biking_woman",
+        [
+            "\u{1F6B4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"blond_haired_man",
+        [
+            "\u{1F471}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F471}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F471}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F471}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F471}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"blond_haired_person",
+        [
+            "\u{1F471}\u{1F3FB}",
+            "\u{1F471}\u{1F3FC}",
+            "\u{1F471}\u{1F3FD}",
+            "\u{1F471}\u{1F3FE}",
+            "\u{1F471}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"blond_haired_woman",
+        [
+            "\u{1F471}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F471}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F471}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F471}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F471}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"blonde_woman",
+        [
+            "\u{1F471}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F471}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F471}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F471}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F471}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"bouncing_ball_man",
+        [
+            "\u{26F9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{26F9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{26F9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{26F9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{26F9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"bouncing_ball_person",
+        [
+            "\u{26F9}\u{1F3FB}",
+            "\u{26F9}\u{1F3FC}",
+            "\u{26F9}\u{1F3FD}",
+            "\u{26F9}\u{1F3FE}",
+            "\u{26F9}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"bouncing_ball_woman",
+        [
+            "\u{26F9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{26F9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{26F9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{26F9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{26F9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"bow",
+        [
+            "\u{1F647}\u{1F3FB}",
+            "\u{1F647}\u{1F3FC}",
+            "\u{1F647}\u{1F3FD}",
+            "\u{1F647}\u{1F3FE}",
+            "\u{1F647}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"bowing_man",
+        [
+            "\u{1F647}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F647}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F647}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F647}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F647}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"bowing_woman",
+        [
+            "\u{1F647}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F647}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F647}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F647}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F647}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"boy",
+        [
+            "\u{1F466}\u{1F3FB}",
+            "\u{1F466}\u{1F3FC}",
+            "\u{1F466}\u{1F3FD}",
+            "\u{1F466}\u{1F3FE}",
+            "\u{1F466}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"breast_feeding",
+        [
+            "\u{1F931}\u{1F3FB}",
+            "\u{1F931}\u{1F3FC}",
+            "\u{1F931}\u{1F3FD}",
+            "\u{1F931}\u{1F3FE}",
+            "\u{1F931}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"bride_with_veil",
+        [
+            "\u{1F470}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F470}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F470}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F470}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F470}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"business_suit_levitating",
+        [
+            "\u{1F574}\u{1F3FB}",
+            "\u{1F574}\u{1F3FC}",
+            "\u{1F574}\u{1F3FD}",
+            "\u{1F574}\u{1F3FE}",
+            "\u{1F574}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"call_me_hand",
+        [
+            "\u{1F919}\u{1F3FB}",
+            "\u{1F919}\u{1F3FC}",
+            "\u{1F919}\u{1F3FD}",
+            "\u{1F919}\u{1F3FE}",
+            "\u{1F919}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"cartwheeling",
+        [
+            "\u{1F938}\u{1F3FB}",
+            "\u{1F938}\u{1F3FC}",
+            "\u{1F938}\u{1F3FD}",
+            "\u{1F938}\u{1F3FE}",
+            "\u{1F938}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"child",
+        [
+            "\u{1F9D2}\u{1F3FB}",
+            "\u{1F9D2}\u{1F3FC}",
+            "\u{1F9D2}\u{1F3FD}",
+            "\u{1F9D2}\u{1F3FE}",
+            "\u{1F9D2}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"clap",
+        [
+            "\u{1F44F}\u{1F3FB}",
+            "\u{1F44F}\u{1F3FC}",
+            "\u{1F44F}\u{1F3FD}",
+            "\u{1F44F}\u{1F3FE}",
+            "\u{1F44F}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"climbing",
+        [
+            "\u{1F9D7}\u{1F3FB}",
+            "\u{1F9D7}\u{1F3FC}",
+            "\u{1F9D7}\u{1F3FD}",
+            "\u{1F9D7}\u{1F3FE}",
+            "\u{1F9D7}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"climbing_man",
+        [
+            "\u{1F9D7}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D7}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D7}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D7}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D7}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"climbing_woman",
+        [
+            "\u{1F9D7}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D7}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D7}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D7}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D7}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"construction_worker",
+        [
+            "\u{1F477}\u{1F3FB}",
+            "\u{1F477}\u{1F3FC}",
+            "\u{1F477}\u{1F3FD}",
+            "\u{1F477}\u{1F3FE}",
+            "\u{1F477}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"construction_worker_man",
+        [
+            "\u{1F477}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F477}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F477}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F477}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F477}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"construction_worker_woman",
+        [
+            "\u{1F477}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F477}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F477}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F477}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F477}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"cook",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F373}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F373}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F373}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F373}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F373}",
+        ],
+    ),
+    (
+        b"cop",
+        [
+            "\u{1F46E}\u{1F3FB}",
+            "\u{1F46E}\u{1F3FC}",
+            "\u{1F46E}\u{1F3FD}",
+            "\u{1F46E}\u{1F3FE}",
+            "\u{1F46E}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"crossed_fingers",
+        [
+            "\u{1F91E}\u{1F3FB}",
+            "\u{1F91E}\u{1F3FC}",
+            "\u{1F91E}\u{1F3FD}",
+            "\u{1F91E}\u{1F3FE}",
+            "\u{1F91E}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"curly_haired_man",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B1}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B1}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B1}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B1}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B1}",
+        ],
+    ),
+    (
+        b"curly_haired_woman",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B1}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B1}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B1}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B1}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B1}",
+        ],
+    ),
+    (
+        b"dancer",
+        [
+            "\u{1F483}\u{1F3FB}",
+            "\u{1F483}\u{1F3FC}",
+            "\u{1F483}\u{1F3FD}",
+            "\u{1F483}\u{1F3FE}",
+            "\u{1F483}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"deaf_man",
+        [
+            "\u{1F9CF}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CF}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CF}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CF}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CF}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"deaf_person",
+        [
+            "\u{1F9CF}\u{1F3FB}",
+            "\u{1F9CF}\u{1F3FC}",
+            "\u{1F9CF}\u{1F3FD}",
+            "\u{1F9CF}\u{1F3FE}",
+            "\u{1F9CF}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"deaf_woman",
+        [
+            "\u{1F9CF}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CF}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CF}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CF}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CF}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"detective",
+        [
+            "\u{1F575}\u{1F3FB}",
+            "\u{1F575}\u{1F3FC}",
+            "\u{1F575}\u{1F3FD}",
+            "\u{1F575}\u{1F3FE}",
+            "\u{1F575}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"ear",
+        [
+            "\u{1F442}\u{1F3FB}",
+            "\u{1F442}\u{1F3FC}",
+            "\u{1F442}\u{1F3FD}",
+            "\u{1F442}\u{1F3FE}",
+            "\u{1F442}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"ear_with_hearing_aid",
+        [
+            "\u{1F9BB}\u{1F3FB}",
+            "\u{1F9BB}\u{1F3FC}",
+            "\u{1F9BB}\u{1F3FD}",
+            "\u{1F9BB}\u{1F3FE}",
+            "\u{1F9BB}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"elf",
+        [
+            "\u{1F9DD}\u{1F3FB}",
+            "\u{1F9DD}\u{1F3FC}",
+            "\u{1F9DD}\u{1F3FD}",
+            "\u{1F9DD}\u{1F3FE}",
+            "\u{1F9DD}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"elf_man",
+        [
+            "\u{1F9DD}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DD}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DD}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DD}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DD}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"elf_woman",
+        [
+            "\u{1F9DD}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DD}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DD}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DD}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DD}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"facepalm",
+        [
+            "\u{1F926}\u{1F3FB}",
+            "\u{1F926}\u{1F3FC}",
+            "\u{1F926}\u{1F3FD}",
+            "\u{1F926}\u{1F3FE}",
+            "\u{1F926}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"facepunch",
+        [
+            "\u{1F44A}\u{1F3FB}",
+            "\u{1F44A}\u{1F3FC}",
+            "\u{1F44A}\u{1F3FD}",
+            "\u{1F44A}\u{1F3FE}",
+            "\u{1F44A}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"factory_worker",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3ED}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3ED}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3ED}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3ED}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3ED}",
+        ],
+    ),
+    (
+        b"fairy",
+        [
+            "\u{1F9DA}\u{1F3FB}",
+            "\u{1F9DA}\u{1F3FC}",
+            "\u{1F9DA}\u{1F3FD}",
+            "\u{1F9DA}\u{1F3FE}",
+            "\u{1F9DA}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"fairy_man",
+        [
+            "\u{1F9DA}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DA}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DA}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DA}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DA}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"fairy_woman",
+        [
+            "\u{1F9DA}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DA}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DA}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DA}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DA}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"farmer",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F33E}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F33E}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F33E}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F33E}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F33E}",
+        ],
+    ),
+    (
+        b"female_detective",
+        [
+            "\u{1F575}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F575}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F575}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F575}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F575}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"firefighter",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F692}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F692}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F692}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F692}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F692}",
+        ],
+    ),
+    (
+        b"fist",
+        [
+            "\u{270A}\u{1F3FB}",
+            "\u{270A}\u{1F3FC}",
+            "\u{270A}\u{1F3FD}",
+            "\u{270A}\u{1F3FE}",
+            "\u{270A}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"fist_left",
+        [
+            "\u{1F91B}\u{1F3FB}",
+            "\u{1F91B}\u{1F3FC}",
+            "\u{1F91B}\u{1F3FD}",
+            "\u{1F91B}\u{1F3FE}",
+            "\u{1F91B}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"fist_oncoming",
+        [
+            "\u{1F44A}\u{1F3FB}",
+            "\u{1F44A}\u{1F3FC}",
+            "\u{1F44A}\u{1F3FD}",
+            "\u{1F44A}\u{1F3FE}",
+            "\u{1F44A}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"fist_raised",
+        [
+            "\u{270A}\u{1F3FB}",
+            "\u{270A}\u{1F3FC}",
+            "\u{270A}\u{1F3FD}",
+            "\u{270A}\u{1F3FE}",
+            "\u{270A}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"fist_right",
+        [
+            "\u{1F91C}\u{1F3FB}",
+            "\u{1F91C}\u{1F3FC}",
+            "\u{1F91C}\u{1F3FD}",
+            "\u{1F91C}\u{1F3FE}",
+            "\u{1F91C}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"foot",
+        [
+            "\u{1F9B6}\u{1F3FB}",
+            "\u{1F9B6}\u{1F3FC}",
+            "\u{1F9B6}\u{1F3FD}",
+            "\u{1F9B6}\u{1F3FE}",
+            "\u{1F9B6}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"frowning_man",
+        [
+            "\u{1F64D}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64D}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64D}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64D}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64D}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"frowning_person",
+        [
+            "\u{1F64D}\u{1F3FB}",
+            "\u{1F64D}\u{1F3FC}",
+            "\u{1F64D}\u{1F3FD}",
+            "\u{1F64D}\u{1F3FE}",
+            "\u{1F64D}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"frowning_woman",
+        [
+            "\u{1F64D}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64D}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64D}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64D}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64D}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"fu",
+        [
+            "\u{1F595}\u{1F3FB}",
+            "\u{1F595}\u{1F3FC}",
+            "\u{1F595}\u{1F3FD}",
+            "\u{1F595}\u{1F3FE}",
+            "\u{1F595}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"girl",
+        [
+            "\u{1F467}\u{1F3FB}",
+            "\u{1F467}\u{1F3FC}",
+            "\u{1F467}\u{1F3FD}",
+            "\u{1F467}\u{1F3FE}",
+            "\u{1F467}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"golfing",
+        [
+            "\u{1F3CC}\u{1F3FB}",
+            "\u{1F3CC}\u{1F3FC}",
+            "\u{1F3CC}\u{1F3FD}",
+            "\u{1F3CC}\u{1F3FE}",
+            "\u{1F3CC}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"golfing_man",
+        [
+            "\u{1F3CC}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CC}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CC}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CC}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CC}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"golfing_woman",
+        [
+            "\u{1F3CC}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CC}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CC}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CC}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CC}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"guard",
+        [
+            "\u{1F482}\u{1F3FB}",
+            "\u{1F482}\u{1F3FC}",
+            "\u{1F482}\u{1F3FD}",
+            "\u{1F482}\u{1F3FE}",
+            "\u{1F482}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"guardsman",
+        [
+            "\u{1F482}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F482}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F482}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F482}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F482}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"guardswoman",
+        [
+            "\u{1F482}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F482}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F482}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F482}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F482}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"haircut",
+        [
+            "\u{1F487}\u{1F3FB}",
+            "\u{1F487}\u{1F3FC}",
+            "\u{1F487}\u{1F3FD}",
+            "\u{1F487}\u{1F3FE}",
+            "\u{1F487}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"haircut_man",
+        [
+            "\u{1F487}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F487}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F487}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F487}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F487}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"haircut_woman",
+        [
+            "\u{1F487}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F487}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F487}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F487}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F487}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"hand",
+        [
+            "\u{270B}\u{1F3FB}",
+            "\u{270B}\u{1F3FC}",
+            "\u{270B}\u{1F3FD}",
+            "\u{270B}\u{1F3FE}",
+            "\u{270B}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"hand_with_index_finger_and_thumb_crossed",
+        [
+            "\u{1FAF0}\u{1F3FB}",
+            "\u{1FAF0}\u{1F3FC}",
+            "\u{1FAF0}\u{1F3FD}",
+            "\u{1FAF0}\u{1F3FE}",
+            "\u{1FAF0}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"handball_person",
+        [
+            "\u{1F93E}\u{1F3FB}",
+            "\u{1F93E}\u{1F3FC}",
+            "\u{1F93E}\u{1F3FD}",
+            "\u{1F93E}\u{1F3FE}",
+            "\u{1F93E}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"health_worker",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}",
+        ],
+    ),
+    (
+        b"heart_hands",
+        [
+            "\u{1FAF6}\u{1F3FB}",
+            "\u{1FAF6}\u{1F3FC}",
+            "\u{1FAF6}\u{1F3FD}",
+            "\u{1FAF6}\u{1F3FE}",
+            "\u{1FAF6}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"horse_racing",
+        [
+            "\u{1F3C7}\u{1F3FB}",
+            "\u{1F3C7}\u{1F3FC}",
+            "\u{1F3C7}\u{1F3FD}",
+            "\u{1F3C7}\u{1F3FE}",
+            "\u{1F3C7}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"index_pointing_at_the_viewer",
+        [
+            "\u{1FAF5}\u{1F3FB}",
+            "\u{1FAF5}\u{1F3FC}",
+            "\u{1FAF5}\u{1F3FD}",
+            "\u{1FAF5}\u{1F3FE}",
+            "\u{1FAF5}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"information_desk_person",
+        [
+            "\u{1F481}\u{1F3FB}",
+            "\u{1F481}\u{1F3FC}",
+            "\u{1F481}\u{1F3FD}",
+            "\u{1F481}\u{1F3FE}",
+            "\u{1F481}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"judge",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}",
+        ],
+    ),
+    (
+        b"juggling_person",
+        [
+            "\u{1F939}\u{1F3FB}",
+            "\u{1F939}\u{1F3FC}",
+            "\u{1F939}\u{1F3FD}",
+            "\u{1F939}\u{1F3FE}",
+            "\u{1F939}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"kneeling_man",
+        [
+            "\u{1F9CE}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CE}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CE}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CE}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CE}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"kneeling_person",
+        [
+            "\u{1F9CE}\u{1F3FB}",
+            "\u{1F9CE}\u{1F3FC}",
+            "\u{1F9CE}\u{1F3FD}",
+            "\u{1F9CE}\u{1F3FE}",
+            "\u{1F9CE}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"kneeling_woman",
+        [
+            "\u{1F9CE}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CE}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CE}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CE}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CE}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"leftwards_hand",
+        [
+            "\u{1FAF2}\u{1F3FB}",
+            "\u{1FAF2}\u{1F3FC}",
+            "\u{1FAF2}\u{1F3FD}",
+            "\u{1FAF2}\u{1F3FE}",
+            "\u{1FAF2}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"leftwards_pushing_hand",
+        [
+            "\u{1FAF7}\u{1F3FB}",
+            "\u{1FAF7}\u{1F3FC}",
+            "\u{1FAF7}\u{1F3FD}",
+            "\u{1FAF7}\u{1F3FE}",
+            "\u{1FAF7}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"leg",
+        [
+            "\u{1F9B5}\u{1F3FB}",
+            "\u{1F9B5}\u{1F3FC}",
+            "\u{1F9B5}\u{1F3FD}",
+            "\u{1F9B5}\u{1F3FE}",
+            "\u{1F9B5}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"lotus_position",
+        [
+            "\u{1F9D8}\u{1F3FB}",
+            "\u{1F9D8}\u{1F3FC}",
+            "\u{1F9D8}\u{1F3FD}",
+            "\u{1F9D8}\u{1F3FE}",
+            "\u{1F9D8}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"lotus_position_man",
+        [
+            "\u{1F9D8}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D8}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D8}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D8}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D8}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"lotus_position_woman",
+        [
+            "\u{1F9D8}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D8}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D8}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D8}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D8}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"love_you_gesture",
+        [
+            "\u{1F91F}\u{1F3FB}",
+            "\u{1F91F}\u{1F3FC}",
+            "\u{1F91F}\u{1F3FD}",
+            "\u{1F91F}\u{1F3FE}",
+            "\u{1F91F}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"mage",
+        [
+            "\u{1F9D9}\u{1F3FB}",
+            "\u{1F9D9}\u{1F3FC}",
+            "\u{1F9D9}\u{1F3FD}",
+            "\u{1F9D9}\u{1F3FE}",
+            "\u{1F9D9}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"mage_man",
+        [
+            "\u{1F9D9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"mage_woman",
+        [
+            "\u{1F9D9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"male_detective",
+        [
+            "\u{1F575}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F575}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F575}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F575}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F575}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man",
+        [
+            "\u{1F468}\u{1F3FB}",
+            "\u{1F468}\u{1F3FC}",
+            "\u{1F468}\u{1F3FD}",
+            "\u{1F468}\u{1F3FE}",
+            "\u{1F468}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"man_artist",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F3A8}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F3A8}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F3A8}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F3A8}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F3A8}",
+        ],
+    ),
+    (
+        b"man_astronaut",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F680}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F680}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F680}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F680}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F680}",
+        ],
+    ),
+    (
+        b"man_beard",
+        [
+            "\u{1F9D4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_cartwheeling",
+        [
+            "\u{1F938}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F938}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F938}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F938}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F938}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_cook",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F373}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F373}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F373}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F373}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F373}",
+        ],
+    ),
+    (
+        b"man_dancing",
+        [
+            "\u{1F57A}\u{1F3FB}",
+            "\u{1F57A}\u{1F3FC}",
+            "\u{1F57A}\u{1F3FD}",
+            "\u{1F57A}\u{1F3FE}",
+            "\u{1F57A}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"man_facepalming",
+        [
+            "\u{1F926}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F926}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F926}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F926}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F926}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_factory_worker",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F3ED}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F3ED}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F3ED}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F3ED}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F3ED}",
+        ],
+    ),
+    (
+        b"man_farmer",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F33E}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F33E}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F33E}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F33E}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F33E}",
+        ],
+    ),
+    (
+        b"man_feeding_baby",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F37C}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F37C}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F37C}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F37C}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F37C}",
+        ],
+    ),
+    (
+        b"man_firefighter",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F692}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F692}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F692}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F692}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F692}",
+        ],
+    ),
+    (
+        b"man_health_worker",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_in_manual_wheelchair",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BD}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BD}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BD}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BD}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BD}",
+        ],
+    ),
+    (
+        b"man_in_motorized_wheelchair",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BC}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BC}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BC}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BC}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BC}",
+        ],
+    ),
+    (
+        b"man_in_tuxedo",
+        [
+            "\u{1F935}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F935}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F935}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F935}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F935}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_judge",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_juggling",
+        [
+            "\u{1F939}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F939}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F939}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F939}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F939}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_mechanic",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F527}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F527}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F527}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F527}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F527}",
+        ],
+    ),
+    (
+        b"man_office_worker",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F4BC}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F4BC}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F4BC}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F4BC}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F4BC}",
+        ],
+    ),
+    (
+        b"man_pilot",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_playing_handball",
+        [
+            "\u{1F93E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F93E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F93E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F93E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F93E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_playing_water_polo",
+        [
+            "\u{1F93D}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F93D}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F93D}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F93D}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F93D}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_scientist",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F52C}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F52C}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F52C}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F52C}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F52C}",
+        ],
+    ),
+    (
+        b"man_shrugging",
+        [
+            "\u{1F937}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F937}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F937}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F937}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F937}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_singer",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F3A4}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F3A4}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F3A4}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F3A4}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F3A4}",
+        ],
+    ),
+    (
+        b"man_student",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F393}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F393}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F393}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F393}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F393}",
+        ],
+    ),
+    (
+        b"man_teacher",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F3EB}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F3EB}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F3EB}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F3EB}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F3EB}",
+        ],
+    ),
+    (
+        b"man_technologist",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F4BB}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F4BB}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F4BB}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F4BB}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F4BB}",
+        ],
+    ),
+    (
+        b"man_with_gua_pi_mao",
+        [
+            "\u{1F472}\u{1F3FB}",
+            "\u{1F472}\u{1F3FC}",
+            "\u{1F472}\u{1F3FD}",
+            "\u{1F472}\u{1F3FE}",
+            "\u{1F472}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"man_with_probing_cane",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F9AF}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F9AF}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F9AF}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F9AF}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F9AF}",
+        ],
+    ),
+    (
+        b"man_with_turban",
+        [
+            "\u{1F473}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F473}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F473}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F473}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F473}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"man_with_veil",
+        [
+            "\u{1F470}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F470}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F470}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F470}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F470}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"massage",
+        [
+            "\u{1F486}\u{1F3FB}",
+            "\u{1F486}\u{1F3FC}",
+            "\u{1F486}\u{1F3FD}",
+            "\u{1F486}\u{1F3FE}",
+            "\u{1F486}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"massage_man",
+        [
+            "\u{1F486}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F486}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F486}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F486}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F486}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"massage_woman",
+        [
+            "\u{1F486}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F486}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F486}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F486}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F486}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"mechanic",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F527}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F527}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F527}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F527}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F527}",
+        ],
+    ),
+    (
+        b"mermaid",
+        [
+            "\u{1F9DC}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DC}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DC}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DC}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DC}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"merman",
+        [
+            "\u{1F9DC}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DC}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DC}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DC}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DC}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"merperson",
+        [
+            "\u{1F9DC}\u{1F3FB}",
+            "\u{1F9DC}\u{1F3FC}",
+            "\u{1F9DC}\u{1F3FD}",
+            "\u{1F9DC}\u{1F3FE}",
+            "\u{1F9DC}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"metal",
+        [
+            "\u{1F918}\u{1F3FB}",
+            "\u{1F918}\u{1F3FC}",
+            "\u{1F918}\u{1F3FD}",
+            "\u{1F918}\u{1F3FE}",
+            "\u{1F918}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"middle_finger",
+        [
+            "\u{1F595}\u{1F3FB}",
+            "\u{1F595}\u{1F3FC}",
+            "\u{1F595}\u{1F3FD}",
+            "\u{1F595}\u{1F3FE}",
+            "\u{1F595}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"mountain_bicyclist",
+        [
+            "\u{1F6B5}\u{1F3FB}",
+            "\u{1F6B5}\u{1F3FC}",
+            "\u{1F6B5}\u{1F3FD}",
+            "\u{1F6B5}\u{1F3FE}",
+            "\u{1F6B5}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"mountain_biking_man",
+        [
+            "\u{1F6B5}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B5}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B5}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B5}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B5}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"mountain_biking_woman",
+        [
+            "\u{1F6B5}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B5}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B5}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B5}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B5}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"mrs_claus",
+        [
+            "\u{1F936}\u{1F3FB}",
+            "\u{1F936}\u{1F3FC}",
+            "\u{1F936}\u{1F3FD}",
+            "\u{1F936}\u{1F3FE}",
+            "\u{1F936}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"muscle",
+        [
+            "\u{1F4AA}\u{1F3FB}",
+            "\u{1F4AA}\u{1F3FC}",
+            "\u{1F4AA}\u{1F3FD}",
+            "\u{1F4AA}\u{1F3FE}",
+            "\u{1F4AA}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"mx_claus",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F384}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F384}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F384}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F384}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F384}",
+        ],
+    ),
+    (
+        b"nail_care",
+        [
+            "\u{1F485}\u{1F3FB}",
+            "\u{1F485}\u{1F3FC}",
+            "\u{1F485}\u{1F3FD}",
+            "\u{1F485}\u{1F3FE}",
+            "\u{1F485}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"ng_man",
+        [
+            "\u{1F645}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F645}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F645}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F645}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F645}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"ng_woman",
+        [
+            "\u{1F645}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F645}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F645}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F645}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F645}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"ninja",
+        [
+            "\u{1F977}\u{1F3FB}",
+            "\u{1F977}\u{1F3FC}",
+            "\u{1F977}\u{1F3FD}",
+            "\u{1F977}\u{1F3FE}",
+            "\u{1F977}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"no_good",
+        [
+            "\u{1F645}\u{1F3FB}",
+            "\u{1F645}\u{1F3FC}",
+            "\u{1F645}\u{1F3FD}",
+            "\u{1F645}\u{1F3FE}",
+            "\u{1F645}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"no_good_man",
+        [
+            "\u{1F645}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F645}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F645}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F645}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F645}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"no_good_woman",
+        [
+            "\u{1F645}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F645}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F645}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F645}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F645}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"nose",
+        [
+            "\u{1F443}\u{1F3FB}",
+            "\u{1F443}\u{1F3FC}",
+            "\u{1F443}\u{1F3FD}",
+            "\u{1F443}\u{1F3FE}",
+            "\u{1F443}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"office_worker",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F4BC}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F4BC}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BC}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F4BC}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F4BC}",
+        ],
+    ),
+    (
+        b"ok_hand",
+        [
+            "\u{1F44C}\u{1F3FB}",
+            "\u{1F44C}\u{1F3FC}",
+            "\u{1F44C}\u{1F3FD}",
+            "\u{1F44C}\u{1F3FE}",
+            "\u{1F44C}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"ok_man",
+        [
+            "\u{1F646}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F646}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F646}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F646}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F646}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"ok_person",
+        [
+            "\u{1F646}\u{1F3FB}",
+            "\u{1F646}\u{1F3FC}",
+            "\u{1F646}\u{1F3FD}",
+            "\u{1F646}\u{1F3FE}",
+            "\u{1F646}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"ok_woman",
+        [
+            "\u{1F646}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F646}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F646}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F646}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F646}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"older_adult",
+        [
+            "\u{1F9D3}\u{1F3FB}",
+            "\u{1F9D3}\u{1F3FC}",
+            "\u{1F9D3}\u{1F3FD}",
+            "\u{1F9D3}\u{1F3FE}",
+            "\u{1F9D3}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"older_man",
+        [
+            "\u{1F474}\u{1F3FB}",
+            "\u{1F474}\u{1F3FC}",
+            "\u{1F474}\u{1F3FD}",
+            "\u{1F474}\u{1F3FE}",
+            "\u{1F474}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"older_woman",
+        [
+            "\u{1F475}\u{1F3FB}",
+            "\u{1F475}\u{1F3FC}",
+            "\u{1F475}\u{1F3FD}",
+            "\u{1F475}\u{1F3FE}",
+            "\u{1F475}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"open_hands",
+        [
+            "\u{1F450}\u{1F3FB}",
+            "\u{1F450}\u{1F3FC}",
+            "\u{1F450}\u{1F3FD}",
+            "\u{1F450}\u{1F3FE}",
+            "\u{1F450}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"palm_down_hand",
+        [
+            "\u{1FAF3}\u{1F3FB}",
+            "\u{1FAF3}\u{1F3FC}",
+            "\u{1FAF3}\u{1F3FD}",
+            "\u{1FAF3}\u{1F3FE}",
+            "\u{1FAF3}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"palm_up_hand",
+        [
+            "\u{1FAF4}\u{1F3FB}",
+            "\u{1FAF4}\u{1F3FC}",
+            "\u{1FAF4}\u{1F3FD}",
+            "\u{1FAF4}\u{1F3FE}",
+            "\u{1FAF4}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"palms_up_together",
+        [
+            "\u{1F932}\u{1F3FB}",
+            "\u{1F932}\u{1F3FC}",
+            "\u{1F932}\u{1F3FD}",
+            "\u{1F932}\u{1F3FE}",
+            "\u{1F932}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"person_bald",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B2}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B2}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B2}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B2}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B2}",
+        ],
+    ),
+    (
+        b"person_curly_hair",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B1}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B1}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B1}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B1}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B1}",
+        ],
+    ),
+    (
+        b"person_feeding_baby",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F37C}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F37C}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F37C}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F37C}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F37C}",
+        ],
+    ),
+    (
+        b"person_in_manual_wheelchair",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BD}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BD}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BD}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BD}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BD}",
+        ],
+    ),
+    (
+        b"person_in_motorized_wheelchair",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BC}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BC}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BC}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BC}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BC}",
+        ],
+    ),
+    (
+        b"person_in_tuxedo",
+        [
+            "\u{1F935}\u{1F3FB}",
+            "\u{1F935}\u{1F3FC}",
+            "\u{1F935}\u{1F3FD}",
+            "\u{1F935}\u{1F3FE}",
+            "\u{1F935}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"person_red_hair",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B0}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B0}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B0}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B0}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B0}",
+        ],
+    ),
+    (
+        b"person_white_hair",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B3}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B3}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B3}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B3}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B3}",
+        ],
+    ),
+    (
+        b"person_with_crown",
+        [
+            "\u{1FAC5}\u{1F3FB}",
+            "\u{1FAC5}\u{1F3FC}",
+            "\u{1FAC5}\u{1F3FD}",
+            "\u{1FAC5}\u{1F3FE}",
+            "\u{1FAC5}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"person_with_probing_cane",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9AF}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9AF}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9AF}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9AF}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9AF}",
+        ],
+    ),
+    (
+        b"person_with_turban",
+        [
+            "\u{1F473}\u{1F3FB}",
+            "\u{1F473}\u{1F3FC}",
+            "\u{1F473}\u{1F3FD}",
+            "\u{1F473}\u{1F3FE}",
+            "\u{1F473}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"person_with_veil",
+        [
+            "\u{1F470}\u{1F3FB}",
+            "\u{1F470}\u{1F3FC}",
+            "\u{1F470}\u{1F3FD}",
+            "\u{1F470}\u{1F3FE}",
+            "\u{1F470}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"pilot",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}",
+        ],
+    ),
+    (
+        b"pinched_fingers",
+        [
+            "\u{1F90C}\u{1F3FB}",
+            "\u{1F90C}\u{1F3FC}",
+            "\u{1F90C}\u{1F3FD}",
+            "\u{1F90C}\u{1F3FE}",
+            "\u{1F90C}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"pinching_hand",
+        [
+            "\u{1F90F}\u{1F3FB}",
+            "\u{1F90F}\u{1F3FC}",
+            "\u{1F90F}\u{1F3FD}",
+            "\u{1F90F}\u{1F3FE}",
+            "\u{1F90F}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"point_down",
+        [
+            "\u{1F447}\u{1F3FB}",
+            "\u{1F447}\u{1F3FC}",
+            "\u{1F447}\u{1F3FD}",
+            "\u{1F447}\u{1F3FE}",
+            "\u{1F447}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"point_left",
+        [
+            "\u{1F448}\u{1F3FB}",
+            "\u{1F448}\u{1F3FC}",
+            "\u{1F448}\u{1F3FD}",
+            "\u{1F448}\u{1F3FE}",
+            "\u{1F448}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"point_right",
+        [
+            "\u{1F449}\u{1F3FB}",
+            "\u{1F449}\u{1F3FC}",
+            "\u{1F449}\u{1F3FD}",
+            "\u{1F449}\u{1F3FE}",
+            "\u{1F449}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"point_up",
+        [
+            "\u{261D}\u{1F3FB}",
+            "\u{261D}\u{1F3FC}",
+            "\u{261D}\u{1F3FD}",
+            "\u{261D}\u{1F3FE}",
+            "\u{261D}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"point_up_2",
+        [
+            "\u{1F446}\u{1F3FB}",
+            "\u{1F446}\u{1F3FC}",
+            "\u{1F446}\u{1F3FD}",
+            "\u{1F446}\u{1F3FE}",
+            "\u{1F446}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"police_officer",
+        [
+            "\u{1F46E}\u{1F3FB}",
+            "\u{1F46E}\u{1F3FC}",
+            "\u{1F46E}\u{1F3FD}",
+            "\u{1F46E}\u{1F3FE}",
+            "\u{1F46E}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"policeman",
+        [
+            "\u{1F46E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F46E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F46E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F46E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F46E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"policewoman",
+        [
+            "\u{1F46E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F46E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F46E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F46E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F46E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"pouting_face",
+        [
+            "\u{1F64E}\u{1F3FB}",
+            "\u{1F64E}\u{1F3FC}",
+            "\u{1F64E}\u{1F3FD}",
+            "\u{1F64E}\u{1F3FE}",
+            "\u{1F64E}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"pouting_man",
+        [
+            "\u{1F64E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"pouting_woman",
+        [
+            "\u{1F64E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"pray",
+        [
+            "\u{1F64F}\u{1F3FB}",
+            "\u{1F64F}\u{1F3FC}",
+            "\u{1F64F}\u{1F3FD}",
+            "\u{1F64F}\u{1F3FE}",
+            "\u{1F64F}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"pregnant_man",
+        [
+            "\u{1FAC3}\u{1F3FB}",
+            "\u{1FAC3}\u{1F3FC}",
+            "\u{1FAC3}\u{1F3FD}",
+            "\u{1FAC3}\u{1F3FE}",
+            "\u{1FAC3}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"pregnant_person",
+        [
+            "\u{1FAC4}\u{1F3FB}",
+            "\u{1FAC4}\u{1F3FC}",
+            "\u{1FAC4}\u{1F3FD}",
+            "\u{1FAC4}\u{1F3FE}",
+            "\u{1FAC4}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"pregnant_woman",
+        [
+            "\u{1F930}\u{1F3FB}",
+            "\u{1F930}\u{1F3FC}",
+            "\u{1F930}\u{1F3FD}",
+            "\u{1F930}\u{1F3FE}",
+            "\u{1F930}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"prince",
+        [
+            "\u{1F934}\u{1F3FB}",
+            "\u{1F934}\u{1F3FC}",
+            "\u{1F934}\u{1F3FD}",
+            "\u{1F934}\u{1F3FE}",
+            "\u{1F934}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"princess",
+        [
+            "\u{1F478}\u{1F3FB}",
+            "\u{1F478}\u{1F3FC}",
+            "\u{1F478}\u{1F3FD}",
+            "\u{1F478}\u{1F3FE}",
+            "\u{1F478}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"punch",
+        [
+            "\u{1F44A}\u{1F3FB}",
+            "\u{1F44A}\u{1F3FC}",
+            "\u{1F44A}\u{1F3FD}",
+            "\u{1F44A}\u{1F3FE}",
+            "\u{1F44A}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"raised_back_of_hand",
+        [
+            "\u{1F91A}\u{1F3FB}",
+            "\u{1F91A}\u{1F3FC}",
+            "\u{1F91A}\u{1F3FD}",
+            "\u{1F91A}\u{1F3FE}",
+            "\u{1F91A}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"raised_hand",
+        [
+            "\u{270B}\u{1F3FB}",
+            "\u{270B}\u{1F3FC}",
+            "\u{270B}\u{1F3FD}",
+            "\u{270B}\u{1F3FE}",
+            "\u{270B}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"raised_hand_with_fingers_splayed",
+        [
+            "\u{1F590}\u{1F3FB}",
+            "\u{1F590}\u{1F3FC}",
+            "\u{1F590}\u{1F3FD}",
+            "\u{1F590}\u{1F3FE}",
+            "\u{1F590}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"raised_hands",
+        [
+            "\u{1F64C}\u{1F3FB}",
+            "\u{1F64C}\u{1F3FC}",
+            "\u{1F64C}\u{1F3FD}",
+            "\u{1F64C}\u{1F3FE}",
+            "\u{1F64C}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"raising_hand",
+        [
+            "\u{1F64B}\u{1F3FB}",
+            "\u{1F64B}\u{1F3FC}",
+            "\u{1F64B}\u{1F3FD}",
+            "\u{1F64B}\u{1F3FE}",
+            "\u{1F64B}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"raising_hand_man",
+        [
+            "\u{1F64B}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64B}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64B}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64B}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F64B}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"raising_hand_woman",
+        [
+            "\u{1F64B}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64B}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64B}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64B}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F64B}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"red_haired_man",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B0}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B0}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B0}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B0}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B0}",
+        ],
+    ),
+    (
+        b"red_haired_woman",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B0}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B0}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B0}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B0}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B0}",
+        ],
+    ),
+    (
+        b"rightwards_hand",
+        [
+            "\u{1FAF1}\u{1F3FB}",
+            "\u{1FAF1}\u{1F3FC}",
+            "\u{1FAF1}\u{1F3FD}",
+            "\u{1FAF1}\u{1F3FE}",
+            "\u{1FAF1}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"rightwards_pushing_hand",
+        [
+            "\u{1FAF8}\u{1F3FB}",
+            "\u{1FAF8}\u{1F3FC}",
+            "\u{1FAF8}\u{1F3FD}",
+            "\u{1FAF8}\u{1F3FE}",
+            "\u{1FAF8}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"rowboat",
+        [
+            "\u{1F6A3}\u{1F3FB}",
+            "\u{1F6A3}\u{1F3FC}",
+            "\u{1F6A3}\u{1F3FD}",
+            "\u{1F6A3}\u{1F3FE}",
+            "\u{1F6A3}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"rowing_man",
+        [
+            "\u{1F6A3}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6A3}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6A3}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6A3}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6A3}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"rowing_woman",
+        [
+            "\u{1F6A3}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6A3}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6A3}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6A3}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6A3}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"runner",
+        [
+            "\u{1F3C3}\u{1F3FB}",
+            "\u{1F3C3}\u{1F3FC}",
+            "\u{1F3C3}\u{1F3FD}",
+            "\u{1F3C3}\u{1F3FE}",
+            "\u{1F3C3}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"running",
+        [
+            "\u{1F3C3}\u{1F3FB}",
+            "\u{1F3C3}\u{1F3FC}",
+            "\u{1F3C3}\u{1F3FD}",
+            "\u{1F3C3}\u{1F3FE}",
+            "\u{1F3C3}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"running_man",
+        [
+            "\u{1F3C3}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3C3}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3C3}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3C3}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3C3}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"running_woman",
+        [
+            "\u{1F3C3}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3C3}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3C3}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3C3}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3C3}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"santa",
+        [
+            "\u{1F385}\u{1F3FB}",
+            "\u{1F385}\u{1F3FC}",
+            "\u{1F385}\u{1F3FD}",
+            "\u{1F385}\u{1F3FE}",
+            "\u{1F385}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"sassy_man",
+        [
+            "\u{1F481}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F481}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F481}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F481}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F481}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"sassy_woman",
+        [
+            "\u{1F481}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F481}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F481}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F481}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F481}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"sauna_man",
+        [
+            "\u{1F9D6}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D6}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D6}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D6}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9D6}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"sauna_person",
+        [
+            "\u{1F9D6}\u{1F3FB}",
+            "\u{1F9D6}\u{1F3FC}",
+            "\u{1F9D6}\u{1F3FD}",
+            "\u{1F9D6}\u{1F3FE}",
+            "\u{1F9D6}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"sauna_woman",
+        [
+            "\u{1F9D6}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D6}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D6}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D6}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D6}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"scientist",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F52C}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F52C}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F52C}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F52C}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F52C}",
+        ],
+    ),
+    (
+        b"selfie",
+        [
+            "\u{1F933}\u{1F3FB}",
+            "\u{1F933}\u{1F3FC}",
+            "\u{1F933}\u{1F3FD}",
+            "\u{1F933}\u{1F3FE}",
+            "\u{1F933}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"shrug",
+        [
+            "\u{1F937}\u{1F3FB}",
+            "\u{1F937}\u{1F3FC}",
+            "\u{1F937}\u{1F3FD}",
+            "\u{1F937}\u{1F3FE}",
+            "\u{1F937}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"singer",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3A4}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3A4}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3A4}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3A4}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3A4}",
+        ],
+    ),
+    (
+        b"sleeping_bed",
+        [
+            "\u{1F6CC}\u{1F3FB}",
+            "\u{1F6CC}\u{1F3FC}",
+            "\u{1F6CC}\u{1F3FD}",
+            "\u{1F6CC}\u{1F3FE}",
+            "\u{1F6CC}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"snowboarder",
+        [
+            "\u{1F3C2}\u{1F3FB}",
+            "\u{1F3C2}\u{1F3FC}",
+            "\u{1F3C2}\u{1F3FD}",
+            "\u{1F3C2}\u{1F3FE}",
+            "\u{1F3C2}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"standing_man",
+        [
+            "\u{1F9CD}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CD}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CD}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CD}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9CD}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"standing_person",
+        [
+            "\u{1F9CD}\u{1F3FB}",
+            "\u{1F9CD}\u{1F3FC}",
+            "\u{1F9CD}\u{1F3FD}",
+            "\u{1F9CD}\u{1F3FE}",
+            "\u{1F9CD}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"standing_woman",
+        [
+            "\u{1F9CD}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CD}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CD}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CD}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9CD}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"student",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F393}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F393}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F393}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F393}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F393}",
+        ],
+    ),
+    (
+        b"superhero",
+        [
+            "\u{1F9B8}\u{1F3FB}",
+            "\u{1F9B8}\u{1F3FC}",
+            "\u{1F9B8}\u{1F3FD}",
+            "\u{1F9B8}\u{1F3FE}",
+            "\u{1F9B8}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"superhero_man",
+        [
+            "\u{1F9B8}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9B8}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9B8}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9B8}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9B8}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"superhero_woman",
+        [
+            "\u{1F9B8}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9B8}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9B8}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9B8}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9B8}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"supervillain",
+        [
+            "\u{1F9B9}\u{1F3FB}",
+            "\u{1F9B9}\u{1F3FC}",
+            "\u{1F9B9}\u{1F3FD}",
+            "\u{1F9B9}\u{1F3FE}",
+            "\u{1F9B9}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"supervillain_man",
+        [
+            "\u{1F9B9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9B9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9B9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9B9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9B9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"supervillain_woman",
+        [
+            "\u{1F9B9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9B9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9B9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9B9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9B9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"surfer",
+        [
+            "\u{1F3C4}\u{1F3FB}",
+            "\u{1F3C4}\u{1F3FC}",
+            "\u{1F3C4}\u{1F3FD}",
+            "\u{1F3C4}\u{1F3FE}",
+            "\u{1F3C4}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"surfing_man",
+        [
+            "\u{1F3C4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3C4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3C4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3C4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3C4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"surfing_woman",
+        [
+            "\u{1F3C4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3C4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3C4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3C4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3C4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"swimmer",
+        [
+            "\u{1F3CA}\u{1F3FB}",
+            "\u{1F3CA}\u{1F3FC}",
+            "\u{1F3CA}\u{1F3FD}",
+            "\u{1F3CA}\u{1F3FE}",
+            "\u{1F3CA}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"swimming_man",
+        [
+            "\u{1F3CA}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CA}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CA}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CA}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CA}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"swimming_woman",
+        [
+            "\u{1F3CA}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CA}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CA}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CA}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CA}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"teacher",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3EB}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3EB}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3EB}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3EB}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3EB}",
+        ],
+    ),
+    (
+        b"technologist",
+        [
+            "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F4BB}",
+            "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F4BB}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BB}",
+            "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F4BB}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F4BB}",
+        ],
+    ),
+    (
+        b"thumbsdown",
+        [
+            "\u{1F44E}\u{1F3FB}",
+            "\u{1F44E}\u{1F3FC}",
+            "\u{1F44E}\u{1F3FD}",
+            "\u{1F44E}\u{1F3FE}",
+            "\u{1F44E}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"thumbsup",
+        [
+            "\u{1F44D}\u{1F3FB}",
+            "\u{1F44D}\u{1F3FC}",
+            "\u{1F44D}\u{1F3FD}",
+            "\u{1F44D}\u{1F3FE}",
+            "\u{1F44D}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"tipping_hand_man",
+        [
+            "\u{1F481}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F481}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F481}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F481}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F481}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"tipping_hand_person",
+        [
+            "\u{1F481}\u{1F3FB}",
+            "\u{1F481}\u{1F3FC}",
+            "\u{1F481}\u{1F3FD}",
+            "\u{1F481}\u{1F3FE}",
+            "\u{1F481}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"tipping_hand_woman",
+        [
+            "\u{1F481}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F481}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F481}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F481}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F481}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"v",
+        [
+            "\u{270C}\u{1F3FB}",
+            "\u{270C}\u{1F3FC}",
+            "\u{270C}\u{1F3FD}",
+            "\u{270C}\u{1F3FE}",
+            "\u{270C}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"vampire",
+        [
+            "\u{1F9DB}\u{1F3FB}",
+            "\u{1F9DB}\u{1F3FC}",
+            "\u{1F9DB}\u{1F3FD}",
+            "\u{1F9DB}\u{1F3FE}",
+            "\u{1F9DB}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"vampire_man",
+        [
+            "\u{1F9DB}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DB}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DB}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DB}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F9DB}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"vampire_woman",
+        [
+            "\u{1F9DB}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DB}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DB}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DB}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9DB}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"vulcan_salute",
+        [
+            "\u{1F596}\u{1F3FB}",
+            "\u{1F596}\u{1F3FC}",
+            "\u{1F596}\u{1F3FD}",
+            "\u{1F596}\u{1F3FE}",
+            "\u{1F596}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"walking",
+        [
+            "\u{1F6B6}\u{1F3FB}",
+            "\u{1F6B6}\u{1F3FC}",
+            "\u{1F6B6}\u{1F3FD}",
+            "\u{1F6B6}\u{1F3FE}",
+            "\u{1F6B6}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"walking_man",
+        [
+            "\u{1F6B6}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B6}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B6}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B6}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F6B6}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"walking_woman",
+        [
+            "\u{1F6B6}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B6}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B6}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B6}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F6B6}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"water_polo",
+        [
+            "\u{1F93D}\u{1F3FB}",
+            "\u{1F93D}\u{1F3FC}",
+            "\u{1F93D}\u{1F3FD}",
+            "\u{1F93D}\u{1F3FE}",
+            "\u{1F93D}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"wave",
+        [
+            "\u{1F44B}\u{1F3FB}",
+            "\u{1F44B}\u{1F3FC}",
+            "\u{1F44B}\u{1F3FD}",
+            "\u{1F44B}\u{1F3FE}",
+            "\u{1F44B}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"weight_lifting",
+        [
+            "\u{1F3CB}\u{1F3FB}",
+            "\u{1F3CB}\u{1F3FC}",
+            "\u{1F3CB}\u{1F3FD}",
+            "\u{1F3CB}\u{1F3FE}",
+            "\u{1F3CB}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"weight_lifting_man",
+        [
+            "\u{1F3CB}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CB}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CB}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CB}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+            "\u{1F3CB}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+        ],
+    ),
+    (
+        b"weight_lifting_woman",
+        [
+            "\u{1F3CB}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CB}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CB}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CB}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F3CB}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"white_haired_man",
+        [
+            "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B3}",
+            "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B3}",
+            "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B3}",
+            "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B3}",
+            "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B3}",
+        ],
+    ),
+    (
+        b"white_haired_woman",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B3}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B3}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B3}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B3}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B3}",
+        ],
+    ),
+    (
+        b"woman",
+        [
+            "\u{1F469}\u{1F3FB}",
+            "\u{1F469}\u{1F3FC}",
+            "\u{1F469}\u{1F3FD}",
+            "\u{1F469}\u{1F3FE}",
+            "\u{1F469}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"woman_artist",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F3A8}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F3A8}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F3A8}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F3A8}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F3A8}",
+        ],
+    ),
+    (
+        b"woman_astronaut",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F680}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F680}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F680}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F680}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F680}",
+        ],
+    ),
+    (
+        b"woman_beard",
+        [
+            "\u{1F9D4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F9D4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_cartwheeling",
+        [
+            "\u{1F938}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F938}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F938}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F938}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F938}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_cook",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F373}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F373}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F373}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F373}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F373}",
+        ],
+    ),
+    (
+        b"woman_dancing",
+        [
+            "\u{1F483}\u{1F3FB}",
+            "\u{1F483}\u{1F3FC}",
+            "\u{1F483}\u{1F3FD}",
+            "\u{1F483}\u{1F3FE}",
+            "\u{1F483}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"woman_facepalming",
+        [
+            "\u{1F926}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F926}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F926}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F926}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F926}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_factory_worker",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F3ED}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F3ED}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F3ED}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F3ED}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F3ED}",
+        ],
+    ),
+    (
+        b"woman_farmer",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F33E}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F33E}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F33E}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F33E}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F33E}",
+        ],
+    ),
+    (
+        b"woman_feeding_baby",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F37C}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F37C}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F37C}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F37C}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F37C}",
+        ],
+    ),
+    (
+        b"woman_firefighter",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F692}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F692}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F692}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F692}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F692}",
+        ],
+    ),
+    (
+        b"woman_health_worker",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_in_manual_wheelchair",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BD}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BD}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BD}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BD}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BD}",
+        ],
+    ),
+    (
+        b"woman_in_motorized_wheelchair",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BC}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BC}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BC}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BC}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BC}",
+        ],
+    ),
+    (
+        b"woman_in_tuxedo",
+        [
+            "\u{1F935}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F935}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F935}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F935}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F935}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_judge",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_juggling",
+        [
+            "\u{1F939}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F939}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F939}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F939}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F939}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_mechanic",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F527}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F527}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F527}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F527}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F527}",
+        ],
+    ),
+    (
+        b"woman_office_worker",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F4BC}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F4BC}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BC}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F4BC}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F4BC}",
+        ],
+    ),
+    (
+        b"woman_pilot",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_playing_handball",
+        [
+            "\u{1F93E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F93E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F93E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F93E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F93E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_playing_water_polo",
+        [
+            "\u{1F93D}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F93D}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F93D}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F93D}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F93D}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_scientist",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F52C}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F52C}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F52C}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F52C}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F52C}",
+        ],
+    ),
+    (
+        b"woman_shrugging",
+        [
+            "\u{1F937}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F937}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F937}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F937}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F937}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_singer",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F3A4}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F3A4}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F3A4}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F3A4}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F3A4}",
+        ],
+    ),
+    (
+        b"woman_student",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F393}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F393}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F393}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F393}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F393}",
+        ],
+    ),
+    (
+        b"woman_teacher",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F3EB}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F3EB}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F3EB}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F3EB}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F3EB}",
+        ],
+    ),
+    (
+        b"woman_technologist",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F4BB}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F4BB}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BB}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F4BB}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F4BB}",
+        ],
+    ),
+    (
+        b"woman_with_headscarf",
+        [
+            "\u{1F9D5}\u{1F3FB}",
+            "\u{1F9D5}\u{1F3FC}",
+            "\u{1F9D5}\u{1F3FD}",
+            "\u{1F9D5}\u{1F3FE}",
+            "\u{1F9D5}\u{1F3FF}",
+        ],
+    ),
+    (
+        b"woman_with_probing_cane",
+        [
+            "\u{1F469}\u{1F3FB}\u{200D}\u{1F9AF}",
+            "\u{1F469}\u{1F3FC}\u{200D}\u{1F9AF}",
+            "\u{1F469}\u{1F3FD}\u{200D}\u{1F9AF}",
+            "\u{1F469}\u{1F3FE}\u{200D}\u{1F9AF}",
+            "\u{1F469}\u{1F3FF}\u{200D}\u{1F9AF}",
+        ],
+    ),
+    (
+        b"woman_with_turban",
+        [
+            "\u{1F473}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F473}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F473}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F473}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F473}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"woman_with_veil",
+        [
+            "\u{1F470}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F470}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F470}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F470}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+            "\u{1F470}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+        ],
+    ),
+    (
+        b"writing_hand",
+        [
+            "\u{270D}\u{1F3FB}",
+            "\u{270D}\u{1F3FC}",
+            "\u{270D}\u{1F3FD}",
+            "\u{270D}\u{1F3FE}",
+            "\u{270D}\u{1F3FF}",
+        ],
+    ),
+];
+
+/// 按 shortcode（不含两侧 `:`）查找 emoji
+pub(crate) fn lookup_emoji(name: &[u8]) -> Option<&'static str> {
+    EMOJIS
+        .binary_search_by_key(&name, |&(key, _value)| key)
+        .map(|i| EMOJIS[i].1)
+        .ok()
+}
+
+/// 查找带肤色修饰的 emoji，`tone` 取 gemoji 的 `skin-tone-2` ~ `skin-tone-6`
+pub(crate) fn lookup_emoji_skin_tone(name: &[u8], tone: u8) -> Option<&'static str> {
+    let index = usize::from(tone.checked_sub(2)?);
+    SKIN_TONES
+        .binary_search_by_key(&name, |&(key, _value)| key)
+        .ok()
+        .and_then(|i| SKIN_TONES[i].1.get(index).copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EMOJIS, SKIN_TONES, lookup_emoji, lookup_emoji_skin_tone};
+
+    #[test]
+    fn tables_are_sorted() {
+        assert!(EMOJIS.windows(2).all(|it| it[0].0 < it[1].0));
+        assert!(SKIN_TONES.windows(2).all(|it| it[0].0 < it[1].0));
+    }
+
+    #[test]
+    fn looks_up_shortcodes_and_skin_tones() {
+        assert_eq!(lookup_emoji(b"smile"), Some("\u{1F604}"));
+        assert_eq!(lookup_emoji(b"+1"), lookup_emoji(b"thumbsup"));
+        assert_eq!(lookup_emoji(b"not_an_emoji"), None);
+        assert_eq!(lookup_emoji_skin_tone(b"+1", 2), Some("\u{1F44D}\u{1F3FB}"));
+        assert_eq!(
+            lookup_emoji_skin_tone(b"wave", 6),
+            Some("\u{1F44B}\u{1F3FF}")
+        );
+        assert_eq!(lookup_emoji_skin_tone(b"wave", 1), None);
+        assert_eq!(lookup_emoji_skin_tone(b"wave", 7), None);
+        assert_eq!(lookup_emoji_skin_tone(b"smile", 3), None);
+    }
+}
@@ -0,0 +1,461 @@
+//! Link graph over many notes.
+//!
+//! A [`Vault`] records the outgoing wikilinks, embeds, and Markdown links of
+//! every note, resolves their targets the way Obsidian does, and answers
+//! backlink, unresolved-link, and orphan queries:
+//!
+//! ```
+//! use ptdgrp_markdown::Vault;
+//!
+//! let mut vault = Vault::new();
+//! vault.insert("index.md", "See [[Alpha]] and [[Missing]].")?;
+//! vault.insert("projects/alpha.md", "---\naliases: [A]\n---\nBack to [[index]].")?;
+//! vault.insert("lonely.md", "No links here.")?;
+//!
+//! let backlinks = vault.backlinks("projects/alpha.md");
+//! assert_eq!(backlinks[0].source, "index.md");
+//! assert_eq!(vault.unresolved()[0].link.path, "Missing");
+//! assert_eq!(vault.orphans(), ["lonely.md"]);
+//! # Ok::<(), ptdgrp_markdown::ParseError>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use crate::ast::link::Link;
+use crate::ast::reference::Reference;
+use crate::ast::text::SourceSpan;
+use crate::{Document, Location, MarkdownNode, ParseError, Parser, ParserOptions};
+
+/// Syntax of a [`VaultLink`].
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultLinkKind {
+    /// `[[Note]]`.
+    Wikilink,
+    /// `![[Note]]`.
+    Embed,
+    /// `[text](note.md)`.
+    Markdown,
+}
+
+/// One outgoing link of a note.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct VaultLink {
+    /// Link syntax.
+    pub kind: VaultLinkKind,
+    /// Link path as written, without the heading or block reference. Markdown
+    /// link URLs are percent-decoded. Empty for links within the same note,
+    /// such as `[[#Heading]]`.
+    pub path: String,
+    /// Heading or block reference after `#`, if any.
+    pub reference: Option<Reference>,
+    /// Source range of the whole link.
+    pub span: SourceSpan,
+    /// One-based position of the link start.
+    pub location: Location,
+}
+
+/// A [`VaultLink`] together with its source note and resolved target.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkEdge<'a> {
+    /// Path of the note containing the link.
+    pub source: &'a str,
+    /// The link itself.
+    pub link: &'a VaultLink,
+    /// Path of the file the link resolves to, or `None` when unresolved.
+    pub target: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct VaultFile {
+    markdown: bool,
+    aliases: Vec<String>,
+    links: Vec<VaultLink>,
+}
+
+/// 链接解析结果，在首次查询时建立。增删任何文件都可能改变已有链接的解析结果，
+/// 因此变更时整体丢弃
+#[derive(Debug, Clone, Default)]
+struct LinkIndex {
+    /// 源笔记 -> 与 `VaultFile::links` 一一对应的目标路径
+    targets: FxHashMap<String, Vec<Option<String>>>,
+    /// 目标路径 -> 链向它的 (源笔记, 链接序号)，按源笔记路径排序
+    backlinks: FxHashMap<String, Vec<(String, usize)>>,
+    /// 与自身以外的文件相连的路径
+    linked: FxHashSet<String>,
+}
+
+/// Notes and attachments of a vault, indexed by path.
+///
+/// Paths are vault-relative and use `/` as separator, e.g. `projects/alpha.md`.
+/// Link targets are resolved with Obsidian's rules, ignoring case:
+///
+/// 1. `./` and `../` paths are resolved against the folder of the linking note.
+/// 2. A path that names a file exactly, with or without `.md`, wins.
+/// 3. Otherwise any file whose path ends with the link path matches. Files in
+///    the linking note's folder are preferred, then the shortest path.
+/// 4. Otherwise a note whose frontmatter `aliases` contain the link path matches.
+///
+/// Resolved targets and backlinks are indexed on the first query and the
+/// index is rebuilt after a file is inserted or removed.
+#[derive(Debug, Clone, Default)]
+pub struct Vault {
+    options: ParserOptions,
+    files: BTreeMap<String, VaultFile>,
+    /// 小写文件名 -> 路径
+    names: FxHashMap<String, Vec<String>>,
+    /// 小写别名 -> 笔记路径
+    aliases: FxHashMap<String, Vec<String>>,
+    links: OnceLock<LinkIndex>,
+}
+
+impl Vault {
+    /// Creates an empty vault that parses notes as Obsidian Flavored Markdown.
+    pub fn new() -> Self {
+        Self::with_options(ParserOptions::default().enabled_ofm())
+    }
+
+    /// Creates an empty vault that parses notes with `options`.
+    pub fn with_options(options: ParserOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Parses `source` and adds or replaces the note at `path`.
+    pub fn insert(&mut self, path: impl Into<String>, source: &str) -> Result<(), ParseError> {
+        let document = Parser::new_with_options(source, self.options.clone()).parse()?;
+        self.insert_document(path, &document);
+        Ok(())
+    }
+
+    /// Adds or replaces the note at `path` using an already parsed document.
+    pub fn insert_document(&mut self, path: impl Into<String>, document: &Document<'_>) {
+        let file = VaultFile {
+            markdown: true,
+            aliases: document.aliases(),
+            links: collect_links(document),
+        };
+        self.insert_file(path.into(), file);
+    }
+
+    /// Adds a non-Markdown file such as an image, so embeds of it resolve.
+    pub fn insert_attachment(&mut self, path: impl Into<String>) {
+        self.insert_file(path.into(), VaultFile::default());
+    }
+
+    /// Removes the note or attachment at `path`. Returns whether it existed.
+    pub fn remove(&mut self, path: &str) -> bool {
+        let Some(file) = self.files.remove(path) else {
+            return false;
+        };
+        self.links.take();
+        unindex(&mut self.names, &file_name(path).to_lowercase(), path);
+        for alias in &file.aliases {
+            unindex(&mut self.aliases, &alias.to_lowercase(), path);
+        }
+        true
+    }
+
+    /// Returns whether a note or attachment exists at `path`.
+    pub fn contains(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+
+    /// Returns the paths of all Markdown notes in lexicographic order.
+    pub fn notes(&self) -> impl Iterator<Item = &str> {
+        self.files
+            .iter()
+            .filter(|(_, file)| file.markdown)
+            .map(|(path, _)| path.as_str())
+    }
+
+    /// Resolves a link path written in the note `source`. An empty path
+    /// refers to `source` itself.
+    pub fn resolve(&self, source: &str, path: &str) -> Option<&str> {
+        let path = path.trim_start_matches('/');
+        if path.is_empty() {
+            return self.files.get_key_value(source).map(|(it, _)| it.as_str());
+        }
+        let folder = source.rfind('/').map_or("", |i| &source[..i]);
+        if path.starts_with("./") || path.starts_with("../") {
+            return normalize_relative(folder, path).and_then(|it| self.find_exact(&it));
+        }
+        if let Some(found) = self.find_exact(path) {
+            return Some(found);
+        }
+        let lower = path.to_lowercase();
+        let name = file_name(&lower);
+        let suffixes = [format!("/{lower}"), format!("/{lower}.md")];
+        let candidates = [name.to_string(), format!("{name}.md")]
+            .into_iter()
+            .filter_map(|name| self.names.get(&name))
+            .flatten()
+            .filter(|candidate| {
+                let candidate = candidate.to_lowercase();
+                suffixes
+                    .iter()
+                    .any(|suffix| candidate.ends_with(suffix.as_str()))
+            });
+        let in_folder =
+            |candidate: &str| candidate.rfind('/').map_or("", |i| &candidate[..i]) == folder;
+        if let Some(found) = candidates
+            .min_by_key(|candidate| (!in_folder(candidate), candidate.len(), candidate.as_str()))
+        {
+            return Some(found);
+        }
+        self.aliases
+            .get(&lower)
+            .and_then(|notes| notes.iter().min())
+            .map(String::as_str)
+    }
+
+    /// Returns the outgoing links of `note` in document order.
+    pub fn outgoing(&self, note: &str) -> Vec<LinkEdge<'_>> {
+        self.files
+            .get_key_value(note)
+            .map(|(source, file)| self.file_edges(source, file).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the links from other notes, and from `note` itself, that
+    /// resolve to `note`, ordered by source path.
+    pub fn backlinks(&self, note: &str) -> Vec<LinkEdge<'_>> {
+        let Some((target, sources)) = self.index().backlinks.get_key_value(note) else {
+            return Vec::new();
+        };
+        sources
+            .iter()
+            .map(|(source, i)| LinkEdge {
+                source,
+                link: &self.files[source].links[*i],
+                target: Some(target),
+            })
+            .collect()
+    }
+
+    /// Returns every link whose target does not exist, ordered by source path.
+    pub fn unresolved(&self) -> Vec<LinkEdge<'_>> {
+        self.edges().filter(|edge| edge.target.is_none()).collect()
+    }
+
+    /// Returns the notes that neither link to another file nor are linked
+    /// from another note. Links of a note to itself are ignored.
+    pub fn orphans(&self) -> Vec<&str> {
+        let linked = &self.index().linked;
+        self.notes()
+            .filter(|note| !linked.contains(*note))
+            .collect()
+    }
+
+    /// Returns every link of every note, ordered by source path.
+    pub fn edges(&self) -> impl Iterator<Item = LinkEdge<'_>> {
+        self.files
+            .iter()
+            .flat_map(move |(source, file)| self.file_edges(source, file))
+    }
+
+    fn file_edges<'a>(
+        &'a self,
+        source: &'a str,
+        file: &'a VaultFile,
+    ) -> impl Iterator<Item = LinkEdge<'a>> {
+        let targets = &self.index().targets[source];
+        file.links
+            .iter()
+            .zip(targets)
+            .map(move |(link, target)| LinkEdge {
+                source,
+                link,
+                target: target.as_deref(),
+            })
+    }
+
+    /// 解析全部链接并建立反向索引；结果保留到下一次增删文件
+    fn index(&self) -> &LinkIndex {
+        self.links.get_or_init(|| {
+            let mut index = LinkIndex::default();
+            for (source, file) in &self.files {
+                let mut targets = Vec::with_capacity(file.links.len());
+                for (i, link) in file.links.iter().enumerate() {
+                    let target = self.resolve(source, &link.path);
+                    if let Some(target) = target {
+                        index
+                            .backlinks
+                            .entry(target.to_string())
+                            .or_default()
+                            .push((source.clone(), i));
+                        if target != source {
+                            index.linked.insert(source.clone());
+                            index.linked.insert(target.to_string());
+                        }
+                    }
+                    targets.push(target.map(str::to_string));
+                }
+                index.targets.insert(source.clone(), targets);
+            }
+            index
+        })
+    }
+
+    fn insert_file(&mut self, path: String, file: VaultFile) {
+        self.remove(&path);
+        self.links.take();
+        self.names
+            .entry(file_name(&path).to_lowercase())
+            .or_default()
+            .push(path.clone());
+        for alias in &file.aliases {
+            self.aliases
+                .entry(alias.to_lowercase())
+                .or_default()
+                .push(path.clone());
+        }
+        self.files.insert(path, file);
+    }
+
+    /// 完整路径匹配（忽略大小写），可省略 `.md`
+    fn find_exact(&self, path: &str) -> Option<&str> {
+        let lower = path.to_lowercase();
+        let with_extension = format!("{lower}.md");
+        self.names
+            .get(file_name(&lower))
+            .into_iter()
+            .chain(self.names.get(file_name(&with_extension)))
+            .flatten()
+            .find(|candidate| {
+                let candidate = candidate.to_lowercase();
+                candidate == lower || candidate == with_extension
+            })
+            .map(String::as_str)
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    path.rfind('/').map_or(path, |i| &path[i + 1..])
+}
+
+fn unindex(index: &mut FxHashMap<String, Vec<String>>, key: &str, path: &str) {
+    if let Some(paths) = index.get_mut(key) {
+        paths.retain(|it| it != path);
+        if paths.is_empty() {
+            index.remove(key);
+        }
+    }
+}
+
+/// 拼接相对路径并折叠 `.` 与 `..`；越过库根目录时返回 `None`
+fn normalize_relative(folder: &str, path: &str) -> Option<String> {
+    let mut segments = folder
+        .split('/')
+        .filter(|it| !it.is_empty())
+        .collect::<Vec<_>>();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+fn collect_links(document: &Document<'_>) -> Vec<VaultLink> {
+    let mut links = Vec::new();
+    for id in document.tree.descendants(0) {
+        let (kind, path, reference) = match &document.tree[id].body {
+            MarkdownNode::Link(link) => match link.as_ref() {
+                Link::Wikilink(wikilink) => (
+                    VaultLinkKind::Wikilink,
+                    wikilink.path.clone(),
+                    wikilink.reference.clone(),
+                ),
+                Link::Default(link) => {
+                    let Some((path, reference)) = markdown_target(document.text(&link.url)) else {
+                        continue;
+                    };
+                    (VaultLinkKind::Markdown, path, reference)
+                }
+                _ => continue,
+            },
+            MarkdownNode::Embed(embed) => (
+                VaultLinkKind::Embed,
+                embed.path.clone(),
+                embed.reference.clone(),
+            ),
+            _ => continue,
+        };
+        // `[[#Heading]]` 的路径部分以 `#` 开头，表示当前笔记内的引用
+        let (path, reference) = match (path.strip_prefix('#'), reference) {
+            (Some(fragment), None) => (String::new(), parse_fragment(fragment)),
+            (_, reference) => (path, reference),
+        };
+        let span = document.tree[id].span;
+        links.push(VaultLink {
+            kind,
+            path,
+            reference,
+            span,
+            location: document.location_at(span.start as usize),
+        });
+    }
+    links
+}
+
+/// 站内 Markdown 链接的目标；带协议的外部链接返回 `None`
+fn markdown_target(url: &str) -> Option<(String, Option<Reference>)> {
+    let scheme = url
+        .find(':')
+        .is_some_and(|i| !url[..i].contains(['/', '#', '?']));
+    if url.is_empty() || scheme {
+        return None;
+    }
+    let (path, fragment) = url.split_once('#').unwrap_or((url, ""));
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    Some((
+        percent_decode(path),
+        parse_fragment(&percent_decode(fragment)),
+    ))
+}
+
+pub(crate) fn parse_fragment(fragment: &str) -> Option<Reference> {
+    if fragment.is_empty() {
+        None
+    } else if let Some(id) = fragment.strip_prefix('^') {
+        Some(Reference::BlockId(id.to_string()))
+    } else if fragment.contains('#') {
+        Some(Reference::MultiHeading(
+            fragment.split('#').map(str::to_string).collect(),
+        ))
+    } else {
+        Some(Reference::Heading(fragment.to_string()))
+    }
+}
+
+/// 宽松解码：非法的 `%` 序列原样保留
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = |b: u8| (b as char).to_digit(16);
+        if bytes[i] == b'%'
+            && let Some(&[high, low]) = bytes.get(i + 1..i + 3)
+            && let (Some(high), Some(low)) = (hex(high), hex(low))
+        {
+            out.push((high * 16 + low) as u8);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
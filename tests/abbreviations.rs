@@ -0,0 +1,113 @@
+use ptdgrp_markdown::ast::MarkdownNode;
+use ptdgrp_markdown::{Parser, ParserOptions};
+
+fn render(input: &str) -> String {
+    Parser::new_with_options(input, ParserOptions::default().enabled_abbreviation())
+        .parse()
+        .unwrap()
+        .to_html()
+}
+
+#[test]
+fn wraps_defined_abbreviations() {
+    assert_eq!(
+        render(
+            "The HTML spec is maintained by the W3C.\n\n\
+             *[HTML]: Hyper Text Markup Language\n\
+             *[W3C]: World Wide Web Consortium"
+        ),
+        "<p>The <abbr title=\"Hyper Text Markup Language\">HTML</abbr> spec is maintained by \
+         the <abbr title=\"World Wide Web Consortium\">W3C</abbr>.</p>"
+    );
+}
+
+#[test]
+fn matches_whole_words_only() {
+    assert_eq!(
+        render("*[HTML]: Hyper Text\nHTML, XHTML, HTML5 and HTMLs"),
+        "<p><abbr title=\"Hyper Text\">HTML</abbr>, XHTML, HTML5 and HTMLs</p>"
+    );
+}
+
+#[test]
+fn skips_code_but_descends_into_formatting() {
+    assert_eq!(
+        render("*[API]: Application Programming Interface\n`API` **API** [API](/api)"),
+        "<p><code>API</code> <strong><abbr title=\"Application Programming Interface\">API</abbr></strong> \
+         <a href=\"/api\"><abbr title=\"Application Programming Interface\">API</abbr></a></p>"
+    );
+}
+
+#[test]
+fn definitions_are_removed_from_any_paragraph_line() {
+    assert_eq!(render("*[HTML]: Hyper Text"), "");
+    assert_eq!(
+        render("first line\n*[CSS]: Cascading Style Sheets\nsecond CSS line"),
+        "<p>first line\nsecond <abbr title=\"Cascading Style Sheets\">CSS</abbr> line</p>"
+    );
+    assert_eq!(
+        render("> CSS\n>\n> *[CSS]: Cascading Style Sheets"),
+        "<blockquote>\n<p><abbr title=\"Cascading Style Sheets\">CSS</abbr></p>\n</blockquote>"
+    );
+}
+
+#[test]
+fn first_definition_wins_and_empty_title_is_omitted() {
+    assert_eq!(
+        render("*[TBD]:\n*[TBD]: To Be Decided\nTBD"),
+        "<p><abbr>TBD</abbr></p>"
+    );
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(
+        Parser::new("*[HTML]: Hyper Text\nHTML")
+            .parse()
+            .unwrap()
+            .to_html(),
+        "<p>*[HTML]: Hyper Text\nHTML</p>"
+    );
+}
+
+#[test]
+fn definitions_exposed_on_document() {
+    let input = "Use HTML.\n\n*[HTML]: Hyper Text Markup Language\n";
+    let doc = Parser::new_with_options(input, ParserOptions::default().enabled_abbreviation())
+        .parse()
+        .unwrap();
+    let definitions = doc.abbreviations();
+    assert_eq!(definitions.len(), 1);
+    assert_eq!(definitions[0].label, "HTML");
+    assert_eq!(definitions[0].title, "Hyper Text Markup Language");
+    assert_eq!(
+        &input[definitions[0].span.start as usize..definitions[0].span.end as usize],
+        "*[HTML]: Hyper Text Markup Language"
+    );
+    // 定义所在段落被整体移除
+    assert_eq!(doc.get_next(doc.get_first_child(0).unwrap()), None);
+}
+
+#[test]
+fn abbreviation_node_spans_follow_source() {
+    let input = "It's HTML's &amp; HTML\n\n*[HTML]: Hyper Text";
+    let doc = Parser::new_with_options(
+        input,
+        ParserOptions::default()
+            .enabled_abbreviation()
+            .enabled_smart_punctuation(),
+    )
+    .parse()
+    .unwrap();
+    let paragraph = doc.get_first_child(0).unwrap();
+    let mut spans = Vec::new();
+    let mut child = doc.get_first_child(paragraph);
+    while let Some(id) = child {
+        if let MarkdownNode::Abbreviation(abbreviation) = &doc[id].body {
+            assert_eq!(abbreviation.title, "Hyper Text");
+            spans.push((doc[id].span.start, doc[id].span.end));
+        }
+        child = doc.get_next(id);
+    }
+    assert_eq!(spans, [(5, 9), (18, 22)]);
+}
@@ -0,0 +1,98 @@
+use ptdgrp_markdown::{ColumnEncoding, Document, Location, Parser};
+
+const SOURCE: &str = "# 😀 Title\n\n𠀀 and *é*\n\tx😀y\n";
+
+fn parse(source: &str) -> Document<'_> {
+    Parser::new(source).parse().unwrap()
+}
+
+#[test]
+fn columns_follow_encoding() {
+    let document = parse(SOURCE);
+    let after = SOURCE.find("and").unwrap();
+    assert_eq!(
+        document.location_at_with(after, ColumnEncoding::Utf8),
+        Location::new(3, 6)
+    );
+    assert_eq!(
+        document.location_at_with(after, ColumnEncoding::Utf16),
+        Location::new(3, 4)
+    );
+    assert_eq!(
+        document.location_at_with(after, ColumnEncoding::Utf32),
+        Location::new(3, 3)
+    );
+    assert_eq!(document.location_at(after), Location::new(3, 3));
+
+    // UTF-16 偏移与 JavaScript 字符串下标一致
+    let y = SOURCE.find('y').unwrap();
+    let utf16 = SOURCE[..y].encode_utf16().count();
+    assert_eq!(document.utf16_offset(y), utf16);
+    assert_eq!(document.offset_from_utf16(utf16), y);
+    assert_eq!(
+        document.location_at_with(y, ColumnEncoding::Utf16),
+        Location::new(4, 5)
+    );
+}
+
+#[test]
+fn offsets_round_trip_in_every_encoding() {
+    let document = parse(SOURCE);
+    let offsets = SOURCE
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain([SOURCE.len()])
+        .collect::<Vec<_>>();
+    for encoding in [
+        ColumnEncoding::Utf8,
+        ColumnEncoding::Utf16,
+        ColumnEncoding::Utf32,
+    ] {
+        let batch = document.locations_at(&offsets, encoding);
+        for (&offset, &location) in offsets.iter().zip(&batch) {
+            assert_eq!(document.location_at_with(offset, encoding), location);
+            assert_eq!(document.offset_at_with(location, encoding), offset);
+        }
+    }
+    for &offset in &offsets {
+        let utf16 = document.utf16_offset(offset);
+        assert_eq!(utf16, SOURCE[..offset].encode_utf16().count());
+        assert_eq!(document.offset_from_utf16(utf16), offset);
+    }
+
+    // 代理对中间的列落到字符起点
+    let emoji = SOURCE.find('😀').unwrap();
+    assert_eq!(
+        document.offset_at_with(Location::new(1, 4), ColumnEncoding::Utf16),
+        emoji
+    );
+    assert_eq!(document.offset_from_utf16(3), emoji);
+}
+
+#[test]
+fn node_locations_cover_every_node() {
+    let document = parse(SOURCE);
+    let locations = document.node_locations(ColumnEncoding::Utf16);
+    assert_eq!(locations.len(), document.tree.len());
+    assert_eq!(locations[0].node_id, 0);
+    for location in &locations {
+        let span = document.tree[location.node_id].span;
+        assert_eq!(
+            location.start,
+            document.location_at_with(span.start as usize, ColumnEncoding::Utf16)
+        );
+        assert_eq!(
+            location.end,
+            document.location_at_with(span.end as usize, ColumnEncoding::Utf16)
+        );
+    }
+    let emphasis = locations
+        .iter()
+        .find(|location| {
+            let span = document.tree[location.node_id].span;
+            &SOURCE[span.start as usize..span.end as usize] == "*é*"
+        })
+        .unwrap();
+    assert_eq!(emphasis.start, Location::new(3, 8));
+    assert_eq!(emphasis.end, Location::new(3, 11));
+}
@@ -0,0 +1,127 @@
+use ptdgrp_markdown::{CstElement, CstNode, Document, Parser, ParserOptions, TokenKind};
+
+const SOURCE: &str = "---\ntitle: CST\n---\n# Title ##\n\n> [!note] Callout\n> body with *em*\n\n- [ ] task\n- [x] **done**\n\n1. one\n   two\n\n    indented\n\n```rust\nlet x = 1;\n```\n\n| a | b |\n|---|---|\n| `c` | [d](/d) |\n\nText[^1] and ^[inline] note.\n\n[^1]: Footnote.\n\n[ref]: /url\n\n<div>\n\n*html*\n\n</div>\n***\n";
+
+fn parse(source: &str, options: ParserOptions) -> Document<'_> {
+    Parser::new_with_options(source, options).parse().unwrap()
+}
+
+fn tokens<'a>(source: &'a str, cst: &CstNode) -> Vec<(TokenKind, &'a str)> {
+    cst.tokens()
+        .map(|token| {
+            (
+                token.kind,
+                &source[token.span.start as usize..token.span.end as usize],
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn tokens_cover_every_byte() {
+    let sources = [
+        SOURCE,
+        "* a\r\n\r\n  b\r\n* c",
+        " - foo\n   - bar\n\t - baz",
+        "foo******bar*********baz",
+        "> - > a\n>   > b\nlazy\n\n    \n",
+    ];
+    for source in sources {
+        for options in [
+            ParserOptions::default(),
+            ParserOptions::default().enabled_all(),
+            ParserOptions::default()
+                .enabled_gfm()
+                .enabled_link_reference_definitions(),
+        ] {
+            let cst = parse(source, options).cst();
+            assert_eq!(cst.node_id, 0);
+            assert_eq!(cst.span.len(), source.len());
+            let mut offset = 0;
+            for token in cst.tokens() {
+                assert_eq!(token.span.start, offset, "{source:?}");
+                assert!(!token.span.is_empty());
+                offset = token.span.end;
+            }
+            assert_eq!(offset as usize, source.len());
+        }
+    }
+}
+
+#[test]
+fn classifies_prefixes_and_markers() {
+    let source = "# Title ##\n\n> - [x] *a*\n>\n> ```\n> code\n> ```\n";
+    let cst = parse(source, ParserOptions::default().enabled_gfm()).cst();
+    use TokenKind::*;
+    assert_eq!(
+        tokens(source, &cst),
+        [
+            (HeadingMarker, "#"),
+            (Whitespace, " "),
+            (Text, "Title"),
+            (Whitespace, " "),
+            (HeadingMarker, "##"),
+            (LineEnding, "\n"),
+            (BlankLine, "\n"),
+            (BlockQuoteMarker, ">"),
+            (Indent, " "),
+            (ListMarker, "-"),
+            (Whitespace, " "),
+            (TaskMarker, "[x]"),
+            (Whitespace, " "),
+            (DelimiterRun, "*"),
+            (Text, "a"),
+            (DelimiterRun, "*"),
+            (LineEnding, "\n"),
+            (BlockQuoteMarker, ">"),
+            (LineEnding, "\n"),
+            (BlockQuoteMarker, ">"),
+            (Indent, " "),
+            (Fence, "```"),
+            (LineEnding, "\n"),
+            (BlockQuoteMarker, ">"),
+            (Indent, " "),
+            (Text, "code"),
+            (LineEnding, "\n"),
+            (BlockQuoteMarker, ">"),
+            (Indent, " "),
+            (Fence, "```"),
+            (LineEnding, "\n"),
+        ]
+    );
+}
+
+#[test]
+fn nodes_follow_source_order() {
+    let source = "Text[^1].\n\n[^1]: Note.\n\nAfter.";
+    let document = parse(source, ParserOptions::default().enabled_gfm());
+    let cst = document.cst();
+    // 脚注定义留在书写位置，而非生成的脚注列表中
+    let blocks = cst
+        .children
+        .iter()
+        .filter_map(|element| match element {
+            CstElement::Node(node) => {
+                Some(&source[node.span.start as usize..node.span.end as usize])
+            }
+            CstElement::Token(_) => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(blocks, ["Text[^1].", "[^1]: Note.", "After."]);
+
+    // 嵌套强调的区间互不交叠
+    let source = "*foo **bar***";
+    let document = parse(source, ParserOptions::default());
+    let spans = document
+        .tree
+        .descendants(0)
+        .map(|id| {
+            let span = document.tree[id].span;
+            &source[span.start as usize..span.end as usize]
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        spans,
+        ["*foo **bar***", "*foo **bar***", "foo ", "**bar**", "bar"]
+    );
+}
@@ -0,0 +1,115 @@
+use ptdgrp_markdown::ast::MarkdownNode;
+use ptdgrp_markdown::{Parser, ParserOptions};
+
+fn render(input: &str) -> String {
+    Parser::new_with_options(input, ParserOptions::default().enabled_definition_list())
+        .parse()
+        .unwrap()
+        .to_html()
+}
+
+#[test]
+fn single_term_and_definition() {
+    assert_eq!(
+        render("Apple\n: A red fruit"),
+        "<dl>\n<dt>Apple</dt>\n<dd>A red fruit</dd>\n</dl>"
+    );
+}
+
+#[test]
+fn multiple_terms_and_definitions() {
+    assert_eq!(
+        render("Term 1\nTerm 2\n: First *definition*\n: Second definition"),
+        "<dl>\n<dt>Term 1</dt>\n<dt>Term 2</dt>\n<dd>First <em>definition</em></dd>\n<dd>Second definition</dd>\n</dl>"
+    );
+}
+
+#[test]
+fn following_terms_join_the_same_list() {
+    let expected =
+        "<dl>\n<dt>Apple</dt>\n<dd>Fruit</dd>\n<dt>Carrot</dt>\n<dd>Vegetable</dd>\n</dl>";
+    assert_eq!(render("Apple\n: Fruit\nCarrot\n: Vegetable"), expected);
+    assert_eq!(render("Apple\n: Fruit\n\nCarrot\n: Vegetable"), expected);
+}
+
+#[test]
+fn lazy_continuation() {
+    assert_eq!(
+        render("Apple\n: A red fruit\nthat grows on trees\n\nAfter"),
+        "<dl>\n<dt>Apple</dt>\n<dd>A red fruit\nthat grows on trees</dd>\n</dl>\n<p>After</p>"
+    );
+}
+
+#[test]
+fn nested_blocks_make_the_list_loose() {
+    assert_eq!(
+        render("Term\n: First paragraph\n\n  Second paragraph\n\n  - item\n\n  ```\n  code\n  ```"),
+        "<dl>\n<dt>Term</dt>\n<dd>\n<p>First paragraph</p>\n<p>Second paragraph</p>\n<ul>\n<li>item</li>\n</ul>\n<pre><code>code\n</code></pre>\n</dd>\n</dl>"
+    );
+    assert_eq!(
+        render("Term\n: one\n\n: two"),
+        "<dl>\n<dt>Term</dt>\n<dd>\n<p>one</p>\n</dd>\n<dd>\n<p>two</p>\n</dd>\n</dl>"
+    );
+}
+
+#[test]
+fn nested_in_containers() {
+    assert_eq!(
+        render("> Term\n> : Quoted"),
+        "<blockquote>\n<dl>\n<dt>Term</dt>\n<dd>Quoted</dd>\n</dl>\n</blockquote>"
+    );
+    assert_eq!(
+        render("Outer\n: Inner\n  : Nested"),
+        "<dl>\n<dt>Outer</dt>\n<dd>\n<dl>\n<dt>Inner</dt>\n<dd>Nested</dd>\n</dl>\n</dd>\n</dl>"
+    );
+}
+
+#[test]
+fn leading_reference_definitions_stay_in_paragraph() {
+    assert_eq!(
+        render("[home]: /home\nTerm\n: See [home]"),
+        "<dl>\n<dt>Term</dt>\n<dd>See <a href=\"/home\">home</a></dd>\n</dl>"
+    );
+}
+
+#[test]
+fn requires_marker_space_and_content() {
+    assert_eq!(render("Term\n:no space"), "<p>Term\n:no space</p>");
+    assert_eq!(render(": orphan"), "<p>: orphan</p>");
+}
+
+#[test]
+fn disabled_by_default() {
+    let html = Parser::new("Term\n: definition").parse().unwrap().to_html();
+    assert_eq!(html, "<p>Term\n: definition</p>");
+}
+
+#[test]
+fn ast_shape_and_spans() {
+    let input = "Term\n: Definition\n";
+    let doc = Parser::new_with_options(input, ParserOptions::default().enabled_definition_list())
+        .parse()
+        .unwrap();
+    let list = doc.get_first_child(0).unwrap();
+    match &doc[list].body {
+        MarkdownNode::DefinitionList(list) => assert!(list.tight()),
+        other => panic!("expected definition list, got {other:?}"),
+    }
+    let term = doc.get_first_child(list).unwrap();
+    assert_eq!(doc[term].body, MarkdownNode::DefinitionTerm);
+    assert_eq!(
+        &input[doc[term].span.start as usize..doc[term].span.end as usize],
+        "Term"
+    );
+    let details = doc.get_next(term).unwrap();
+    assert!(matches!(
+        doc[details].body,
+        MarkdownNode::DefinitionDetails(..)
+    ));
+    assert_eq!(
+        &input[doc[details].span.start as usize..doc[details].span.end as usize],
+        ": Definition"
+    );
+    assert_eq!(doc[list].span.start, 0);
+    assert_eq!(doc[list].span.end, doc[details].span.end);
+}
@@ -0,0 +1,197 @@
+use ptdgrp_markdown::frontmatter::YamlValue;
+use ptdgrp_markdown::frontmatter::edit::{FrontMatterEdit, FrontMatterEditError};
+use ptdgrp_markdown::{Parser, ParserOptions};
+
+fn edit(source: &str, f: impl FnOnce(&mut FrontMatterEdit<'_>)) -> String {
+    edit_with(source, ParserOptions::default(), f)
+}
+
+fn edit_with(
+    source: &str,
+    options: ParserOptions,
+    f: impl FnOnce(&mut FrontMatterEdit<'_>),
+) -> String {
+    let document = Parser::new_with_options(source, options.clone())
+        .parse()
+        .unwrap();
+    let mut edit = document.edit_frontmatter().unwrap();
+    f(&mut edit);
+    let output = edit.finish();
+    // 结果仍是可解析的 frontmatter
+    let reparsed = Parser::new_with_options(&output, options).parse().unwrap();
+    assert!(reparsed.frontmatter_diagnostics().is_empty(), "{output}");
+    output
+}
+
+#[test]
+fn yaml_set_keeps_comments_and_quotes() {
+    let source = "---\n# header\ntitle: 'Old' # keep\nmodified: 2024-01-01\nempty:\n---\nBody";
+    let output = edit(source, |edit| {
+        edit.set("title", "It's new").unwrap();
+        edit.set("modified", "2024-05-01").unwrap();
+        edit.set("empty", true).unwrap();
+    });
+    assert_eq!(
+        output,
+        "---\n# header\ntitle: 'It''s new' # keep\nmodified: 2024-05-01\nempty: true\n---\nBody"
+    );
+}
+
+#[test]
+fn yaml_set_switches_between_block_and_flow() {
+    let source = "---\ntags:\n    - a\n    - b\nauthor: Ann\nlinks: []\n---\n";
+    let output = edit(source, |edit| {
+        edit.set("tags", vec!["x", "y: z"]).unwrap();
+        edit.set("author", YamlValue::Null).unwrap();
+        edit.set("links", vec![1]).unwrap();
+    });
+    assert_eq!(
+        output,
+        "---\ntags:\n    - x\n    - \"y: z\"\nauthor: null\nlinks:\n  - 1\n---\n"
+    );
+    let output = edit(source, |edit| edit.set("tags", "single").unwrap());
+    assert_eq!(output, "---\ntags: single\nauthor: Ann\nlinks: []\n---\n");
+}
+
+#[test]
+fn yaml_push_appends_in_the_existing_style() {
+    let source = "---\ntags: [a, b]\naliases:\n  - One\nflow: []\n---\n";
+    let output = edit(source, |edit| {
+        edit.push("tags", "c").unwrap();
+        edit.push("aliases", "Two").unwrap();
+        edit.push("flow", 1).unwrap();
+        edit.push("new", "x").unwrap();
+    });
+    assert_eq!(
+        output,
+        "---\ntags: [a, b, c]\naliases:\n  - One\n  - Two\nflow: [1]\nnew:\n  - x\n---\n"
+    );
+}
+
+#[test]
+fn yaml_remove_and_rename() {
+    let source = "---\ntitle: T\nauthor:\n  name: Ann # who\n  url: x\ntags: [a, b, c]\nlist:\n  - 1\n  - 2\ncategory: c\n---\n";
+    let output = edit(source, |edit| {
+        edit.remove("author.name").unwrap();
+        edit.remove("tags.1").unwrap();
+        edit.remove("list.0").unwrap();
+        edit.rename("category", "categories").unwrap();
+    });
+    assert_eq!(
+        output,
+        "---\ntitle: T\nauthor:\n  url: x\ntags: [a, c]\nlist:\n  - 2\ncategories: c\n---\n"
+    );
+    let output = edit(source, |edit| edit.remove("author").unwrap());
+    assert_eq!(
+        output,
+        "---\ntitle: T\ntags: [a, b, c]\nlist:\n  - 1\n  - 2\ncategory: c\n---\n"
+    );
+}
+
+#[test]
+fn toml_edits() {
+    let source = "+++\ntitle = 'Old'\ntags = [\"a\"]\n\n[params]\ncolor = \"red\"\n+++\nBody";
+    let output = edit(source, |edit| {
+        edit.set("title", "New").unwrap();
+        edit.push("tags", "b").unwrap();
+        edit.set("date", "2024-05-01").unwrap();
+        edit.set("params.color", "blue").unwrap();
+        edit.rename("params", "extra").unwrap();
+    });
+    assert_eq!(
+        output,
+        "+++\ntitle = \"New\"\ntags = [\"a\", \"b\"]\ndate = 2024-05-01\n\n[extra]\ncolor = \"blue\"\n+++\nBody"
+    );
+    let document = Parser::new(source).parse().unwrap();
+    let mut edit = document.edit_frontmatter().unwrap();
+    assert_eq!(
+        edit.remove("params"),
+        Err(FrontMatterEditError::Unsupported("params".to_string()))
+    );
+    assert_eq!(
+        edit.set("draft", YamlValue::Null),
+        Err(FrontMatterEditError::Unsupported("draft".to_string()))
+    );
+}
+
+#[test]
+fn json_edits() {
+    let source =
+        ";;;\n{\n  \"title\": \"Old\",\n  \"tags\": [\"a\", \"b\"],\n  \"draft\": true\n}\n;;;\n";
+    let output = edit(source, |edit| {
+        edit.remove("tags.0").unwrap();
+        edit.remove("draft").unwrap();
+        edit.rename("title", "name").unwrap();
+    });
+    assert_eq!(
+        output,
+        ";;;\n{\n  \"name\": \"Old\",\n  \"tags\": [\"b\"]\n}\n;;;\n"
+    );
+    let output = edit(source, |edit| {
+        edit.remove("title").unwrap();
+        edit.set("date", "2024-05-01").unwrap();
+    });
+    assert_eq!(
+        output,
+        ";;;\n{\n  \"tags\": [\"a\", \"b\"],\n  \"draft\": true,\n  \"date\": \"2024-05-01\"\n}\n;;;\n"
+    );
+    let options = ParserOptions::default().enabled_leading_json_frontmatter();
+    let output = edit_with("{\"a\": 1}\nBody", options, |edit| {
+        edit.set("b", 2).unwrap()
+    });
+    assert_eq!(output, "{\"a\": 1, \"b\": 2}\nBody");
+}
+
+#[test]
+fn creates_frontmatter_when_missing() {
+    let output = edit("# Note\r\n", |edit| {
+        edit.set("title", "Note").unwrap();
+        edit.push("tags", "inbox").unwrap();
+    });
+    assert_eq!(
+        output,
+        "---\r\ntitle: Note\r\ntags:\r\n  - inbox\r\n---\r\n# Note\r\n"
+    );
+}
+
+#[test]
+fn rejects_invalid_edits() {
+    let document = Parser::new("---\ntitle: a\ntitle: b\n---\n")
+        .parse()
+        .unwrap();
+    assert_eq!(
+        document.edit_frontmatter().unwrap_err(),
+        FrontMatterEditError::InvalidFrontMatter
+    );
+
+    let document = Parser::new("---\ntitle: T\ntags: x\nauthor:\n  name: A\n---\n")
+        .parse()
+        .unwrap();
+    let mut edit = document.edit_frontmatter().unwrap();
+    assert_eq!(
+        edit.push("tags", "y"),
+        Err(FrontMatterEditError::NotAList("tags".to_string()))
+    );
+    assert_eq!(
+        edit.rename("title", "tags"),
+        Err(FrontMatterEditError::KeyExists("tags".to_string()))
+    );
+    assert_eq!(
+        edit.remove("missing"),
+        Err(FrontMatterEditError::NotFound("missing".to_string()))
+    );
+    assert_eq!(
+        edit.set("author.url", "x"),
+        Err(FrontMatterEditError::Unsupported("author.url".to_string()))
+    );
+    edit.set("title", "U").unwrap();
+    assert_eq!(
+        edit.remove("title"),
+        Err(FrontMatterEditError::Conflict("title".to_string()))
+    );
+    edit.set("new", 1).unwrap();
+    assert_eq!(
+        edit.set("new", 2),
+        Err(FrontMatterEditError::Conflict("new".to_string()))
+    );
+}
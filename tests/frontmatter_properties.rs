@@ -0,0 +1,55 @@
+use ptdgrp_markdown::{Parser, ParserOptions};
+
+fn sorted_tags(input: &str, options: ParserOptions) -> Vec<String> {
+    let document = Parser::new_with_options(input, options).parse().unwrap();
+    let mut tags = document.tags.into_iter().collect::<Vec<_>>();
+    tags.sort();
+    tags
+}
+
+#[test]
+fn merges_frontmatter_tags_with_inline_tags() {
+    let input = "---\ntags: [Project/Alpha, \"#inbox\", 2024]\n---\nBody #todo";
+    assert_eq!(
+        sorted_tags(input, ParserOptions::default().enabled_ofm()),
+        ["inbox", "project/alpha", "todo"]
+    );
+    // 非 OFM 模式不解释 Obsidian 属性
+    assert_eq!(
+        sorted_tags(input, ParserOptions::default()),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn accepts_legacy_and_string_forms() {
+    let options = ParserOptions::default().enabled_ofm();
+    assert_eq!(
+        sorted_tags("---\ntag: \"a, #b c\"\n---\n", options.clone()),
+        ["a", "b", "c"]
+    );
+    assert_eq!(
+        sorted_tags("---\ntags: single\ntag: ignored\n---\n", options),
+        ["single"]
+    );
+}
+
+#[test]
+fn exposes_aliases_cssclasses_and_publish() {
+    let document = Parser::new(
+        "---\naliases:\n  - Alpha Project\n  - AP\ncssclass: wide, cards\npublish: false\n---\n",
+    )
+    .parse()
+    .unwrap();
+    assert_eq!(document.aliases(), ["Alpha Project", "AP"]);
+    assert_eq!(document.cssclasses(), ["wide", "cards"]);
+    assert_eq!(document.frontmatter().unwrap().publish(), Some(false));
+
+    let document = Parser::new("---\nalias: One, Two\n---\n").parse().unwrap();
+    assert_eq!(document.aliases(), ["One", "Two"]);
+    assert_eq!(document.frontmatter().unwrap().publish(), None);
+
+    let document = Parser::new("No frontmatter").parse().unwrap();
+    assert!(document.aliases().is_empty());
+    assert!(document.cssclasses().is_empty());
+}
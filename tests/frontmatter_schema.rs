@@ -0,0 +1,111 @@
+use ptdgrp_markdown::Parser;
+use ptdgrp_markdown::frontmatter::schema::{
+    FieldType, FrontMatterSchema, FrontMatterValidationError, ValidationErrorKind,
+};
+
+fn publishing_schema() -> FrontMatterSchema {
+    FrontMatterSchema::new()
+        .required("title", FieldType::String)
+        .required("date", FieldType::Date)
+        .required("tags", FieldType::list_of(FieldType::String))
+        .optional("status", FieldType::one_of(["draft", "published"]))
+        .optional("author.name", FieldType::String)
+}
+
+fn validate(input: &str, schema: &FrontMatterSchema) -> Vec<FrontMatterValidationError> {
+    Parser::new(input)
+        .parse()
+        .unwrap()
+        .validate_frontmatter(schema)
+}
+
+fn summary(input: &str, errors: &[FrontMatterValidationError]) -> Vec<(String, String)> {
+    errors
+        .iter()
+        .map(|it| {
+            (
+                it.path.clone(),
+                input[it.span.start as usize..it.span.end as usize].to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn accepts_matching_frontmatter() {
+    let input = "---\ntitle: Post\ndate: 2024-02-29\ntags: [a, b]\nstatus: draft\nauthor:\n  name: Ann\n---\nBody";
+    assert_eq!(validate(input, &publishing_schema()), []);
+    let input = "+++\ntitle = \"Post\"\ndate = 2024-05-01T10:00:00Z\ntags = [\"a\"]\n+++\n";
+    assert_eq!(validate(input, &publishing_schema()), []);
+}
+
+#[test]
+fn reports_type_and_value_errors_with_spans() {
+    let input = "---\ntitle: 3\ndate: 2023-02-29\ntags:\n  - a\n  - [b]\nstatus: live\n---\n";
+    let errors = validate(input, &publishing_schema());
+    assert_eq!(
+        summary(input, &errors),
+        [
+            ("title".to_string(), "3".to_string()),
+            ("date".to_string(), "2023-02-29".to_string()),
+            ("tags.1".to_string(), "[b]".to_string()),
+            ("status".to_string(), "live".to_string()),
+        ]
+    );
+    assert_eq!(errors[0].kind, ValidationErrorKind::TypeMismatch);
+    assert_eq!(errors[0].message, "expected a string");
+    assert_eq!(errors[1].kind, ValidationErrorKind::InvalidValue);
+    assert_eq!(errors[2].message, "expected a string");
+    assert_eq!(errors[3].message, "expected one of `draft`, `published`");
+}
+
+#[test]
+fn reports_missing_keys_at_the_insertion_point() {
+    let input = "---\ntitle: Post\ntags: a\n---\n";
+    let errors = validate(input, &publishing_schema());
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].path, "tags");
+    assert_eq!(errors[0].message, "expected a list of strings");
+    assert_eq!(errors[1].kind, ValidationErrorKind::MissingKey);
+    assert_eq!(errors[1].message, "missing required key `date`");
+    assert_eq!(
+        errors[1].span.start as usize,
+        input.find("a\n---").unwrap() + 1
+    );
+    assert!(errors[1].span.is_empty());
+
+    let errors = validate("# No frontmatter", &publishing_schema());
+    assert_eq!(
+        errors.iter().map(|it| it.path.as_str()).collect::<Vec<_>>(),
+        ["title", "date", "tags"]
+    );
+    assert!(
+        errors
+            .iter()
+            .all(|it| it.span.start == 0 && it.span.is_empty())
+    );
+}
+
+#[test]
+fn denies_unknown_keys() {
+    let schema = publishing_schema().deny_unknown_keys();
+    let input = "---\ntitle: Post\ndate: 2024-01-01\ntags: []\nauthor: {name: Ann}\nslug: x\n---\n";
+    let errors = validate(input, &schema);
+    assert_eq!(
+        summary(input, &errors),
+        [("slug".to_string(), "x".to_string())]
+    );
+    assert_eq!(errors[0].kind, ValidationErrorKind::UnknownKey);
+}
+
+#[test]
+fn validates_after_the_frontmatter_phase() {
+    let input = "---\ntitle: Post\ndate: soon\ntags: [a]\n---\nBody";
+    let (document, _snapshot) = Parser::new(input).parse_frontmatter_phase().unwrap();
+    let errors = document.validate_frontmatter(&publishing_schema());
+    assert_eq!(
+        summary(input, &errors),
+        [("date".to_string(), "soon".to_string())]
+    );
+    assert_eq!(document.location_at(errors[0].span.start as usize).line, 3);
+}
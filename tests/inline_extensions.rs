@@ -0,0 +1,141 @@
+use ptdgrp_markdown::ast::MarkdownNode;
+use ptdgrp_markdown::{Parser, ParserOptions};
+
+fn render(input: &str, options: ParserOptions) -> String {
+    Parser::new_with_options(input, options)
+        .parse()
+        .unwrap()
+        .to_html()
+}
+
+#[test]
+fn superscript() {
+    let options = ParserOptions::default().enabled_superscript();
+    assert_eq!(
+        render("2^10^ is 1024", options.clone()),
+        "<p>2<sup>10</sup> is 1024</p>"
+    );
+    assert_eq!(render("a ^ b ^ c", options.clone()), "<p>a ^ b ^ c</p>");
+    assert_eq!(render("^^a^^", options), "<p>^^a^^</p>");
+}
+
+#[test]
+fn subscript() {
+    let options = ParserOptions::default().enabled_subscript();
+    assert_eq!(render("H~2~O", options.clone()), "<p>H<sub>2</sub>O</p>");
+    // 未启用 GFM 时 `~~` 不是任何语法
+    assert_eq!(render("~~a~~", options), "<p>~~a~~</p>");
+}
+
+#[test]
+fn insert() {
+    let options = ParserOptions::default().enabled_insert();
+    assert_eq!(
+        render("++added++ text", options.clone()),
+        "<p><ins>added</ins> text</p>"
+    );
+    assert_eq!(render("a+b +c+ +++d+++", options), "<p>a+b +c+ +++d+++</p>");
+}
+
+#[test]
+fn spoiler() {
+    let options = ParserOptions::default().enabled_spoiler();
+    assert_eq!(
+        render("the ||butler|| did it", options.clone()),
+        "<p>the <span class=\"spoiler\">butler</span> did it</p>"
+    );
+    assert_eq!(render("a | b || c", options), "<p>a | b || c</p>");
+}
+
+#[test]
+fn nested_with_emphasis() {
+    let options = ParserOptions::default()
+        .enabled_superscript()
+        .enabled_insert()
+        .enabled_spoiler();
+    assert_eq!(
+        render("||*x*^2^ ++**new**++||", options),
+        "<p><span class=\"spoiler\"><em>x</em><sup>2</sup> <ins><strong>new</strong></ins></span></p>"
+    );
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(
+        render("^a^ ~b~ ++c++ ||d||", ParserOptions::default()),
+        "<p>^a^ ~b~ ++c++ ||d||</p>"
+    );
+}
+
+#[test]
+fn gfm_single_tilde_is_strikethrough_without_subscript() {
+    assert_eq!(
+        render("~a~ ~~b~~", ParserOptions::default().enabled_gfm()),
+        "<p><del>a</del> <del>b</del></p>"
+    );
+}
+
+#[test]
+fn subscript_takes_single_tilde_from_gfm() {
+    let options = ParserOptions::default().enabled_gfm().enabled_subscript();
+    assert_eq!(
+        render("~a~ ~~b~~", options.clone()),
+        "<p><sub>a</sub> <del>b</del></p>"
+    );
+    assert_eq!(
+        render("~~H~2~O~~", options.clone()),
+        "<p><del>H<sub>2</sub>O</del></p>"
+    );
+    // 启用下标后 `~` 与 `~~` 不再互相配对
+    assert_eq!(render("~~a~ b", options.clone()), "<p>~~a~ b</p>");
+    assert_eq!(render("~a~~ b", options.clone()), "<p>~a~~ b</p>");
+    assert_eq!(render("b ~~~a~~~", options), "<p>b ~~~a~~~</p>");
+}
+
+#[test]
+fn superscript_with_ofm_caret_syntax() {
+    let options = ParserOptions::default().enabled_ofm().enabled_superscript();
+    assert_eq!(
+        render("x^2^ + y^2^", options.clone()),
+        "<p>x<sup>2</sup> + y<sup>2</sup></p>"
+    );
+    // block id 与内联脚注优先于上标
+    assert_eq!(
+        render("paragraph ^block-id", options.clone()),
+        "<p id=\"block-id\">paragraph </p>"
+    );
+    assert!(render("text^[note]", options).contains("<a href=\"#cont-fn-inline-footnote-1\""));
+}
+
+#[test]
+fn ast_nodes_and_spans() {
+    let input = "a^b^ ++c++";
+    let doc = Parser::new_with_options(
+        input,
+        ParserOptions::default()
+            .enabled_superscript()
+            .enabled_insert(),
+    )
+    .parse()
+    .unwrap();
+    let paragraph = doc.get_first_child(0).unwrap();
+    let mut found = Vec::new();
+    let mut child = doc.get_first_child(paragraph);
+    while let Some(id) = child {
+        if matches!(
+            doc[id].body,
+            MarkdownNode::Superscript | MarkdownNode::Insert
+        ) {
+            let span = &input[doc[id].span.start as usize..doc[id].span.end as usize];
+            found.push((doc[id].body.clone(), span));
+        }
+        child = doc.get_next(id);
+    }
+    assert_eq!(
+        found,
+        vec![
+            (MarkdownNode::Superscript, "^b^"),
+            (MarkdownNode::Insert, "++c++"),
+        ]
+    );
+}
@@ -0,0 +1,109 @@
+use ptdgrp_markdown::{LinkDiagnostic, LinkDiagnosticKind, Location, Parser, ParserOptions};
+
+fn check(source: &str) -> Vec<LinkDiagnostic> {
+    Parser::new_with_options(source, ParserOptions::default().enabled_ofm())
+        .parse()
+        .unwrap()
+        .check_links()
+}
+
+fn summary<'a>(
+    source: &'a str,
+    diagnostics: &[LinkDiagnostic],
+) -> Vec<(LinkDiagnosticKind, &'a str)> {
+    diagnostics
+        .iter()
+        .map(|it| {
+            (
+                it.kind,
+                &source[it.span.start as usize..it.span.end as usize],
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn reports_undefined_and_duplicate_references() {
+    let source = "[a][missing], [Known][] and ![img][gone].\n[plain] and [known] stay quiet.\n\n[known]: /a\n[KNOWN]: /b \"title\"  \n[other]: /c";
+    let diagnostics = check(source);
+    assert_eq!(
+        summary(source, &diagnostics),
+        [
+            (LinkDiagnosticKind::UndefinedReference, "[a][missing]"),
+            (LinkDiagnosticKind::UndefinedReference, "![img][gone]"),
+            (
+                LinkDiagnosticKind::DuplicateDefinition,
+                "[KNOWN]: /b \"title\""
+            ),
+        ]
+    );
+    assert_eq!(diagnostics[0].target, "missing");
+    assert_eq!(diagnostics[0].message, "undefined link reference `missing`");
+    assert_eq!(diagnostics[1].location, Location::new(1, 29));
+    assert_eq!(diagnostics[2].target, "KNOWN");
+    assert_eq!(diagnostics[2].location, Location::new(5, 1));
+    assert_eq!(
+        diagnostics[2].message,
+        "link reference `known` is already defined on line 4"
+    );
+
+    // 折叠引用使用链接文本作为标签
+    let diagnostics = check("[Nope][]");
+    assert_eq!(diagnostics[0].target, "Nope");
+}
+
+#[test]
+fn reports_footnote_problems() {
+    let source = "Used[^a] and missing[^b].\n\n[^a]: Defined.\n\n[^c]: Never used.";
+    let diagnostics = check(source);
+    assert_eq!(
+        summary(source, &diagnostics),
+        [
+            (LinkDiagnosticKind::UndefinedFootnote, "[^b]"),
+            (LinkDiagnosticKind::UnusedFootnote, "[^c]:"),
+        ]
+    );
+    assert_eq!(diagnostics[0].target, "b");
+    assert_eq!(diagnostics[1].location, Location::new(5, 1));
+}
+
+#[test]
+fn reports_missing_same_note_targets() {
+    let source =
+        "# Setup\n\nText ^here\n\n[[#Setup]] [[#^here]] [[#Nowhere]] ![[#^gone]] [[Other#Nowhere]]";
+    let diagnostics = check(source);
+    assert_eq!(
+        summary(source, &diagnostics),
+        [
+            (LinkDiagnosticKind::MissingTarget, "[[#Nowhere]]"),
+            (LinkDiagnosticKind::MissingTarget, "![[#^gone]]"),
+        ]
+    );
+    assert_eq!(diagnostics[0].target, "#Nowhere");
+    assert_eq!(diagnostics[1].location, Location::new(5, 36));
+}
+
+#[test]
+fn reports_malformed_urls() {
+    let source = "[ok](https://example.com:8080/path) [rel](../a.md) [mail](mailto:me@example.com)\n[e]() [s](http:/x) [h](https://) [p](https://a.b:x/) [d](https://a..b) [m](mailto:me) ![i](1http://x)";
+    let diagnostics = check(source);
+    let reasons = diagnostics
+        .iter()
+        .map(|it| {
+            assert_eq!(it.kind, LinkDiagnosticKind::MalformedUrl);
+            it.message.as_str()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        reasons,
+        [
+            "malformed URL `http:/x`: missing `//` after the scheme",
+            "malformed URL `https://`: missing host",
+            "malformed URL `https://a.b:x/`: invalid port",
+            "malformed URL `https://a..b`: empty host label",
+            "malformed URL `mailto:me`: missing `@` in the address",
+            "malformed URL `1http://x`: invalid scheme",
+        ]
+    );
+    assert!(check("[fine](https://[::1]:80/) [x](#local) [empty]() [angle](<>)").is_empty());
+}
@@ -0,0 +1,110 @@
+use ptdgrp_markdown::{Document, MarkdownNode, Parser, ParserOptions};
+
+const SOURCE: &str = "[Foo  Bar]: /url \"Title\"\n[b]: <x y>\nText [foo bar], [x][b] and ![B].\n\n[B]: /dup\n\n> [c]: /c\n> Heading\n> ===\n";
+
+fn parse(source: &str, options: ParserOptions) -> Document<'_> {
+    Parser::new_with_options(source, options).parse().unwrap()
+}
+
+fn slice<'a>(document: &'a Document, id: usize) -> &'a str {
+    let span = document.tree[id].span;
+    &document.source()[span.start as usize..span.end as usize]
+}
+
+#[test]
+fn keeps_definition_nodes() {
+    let document = parse(
+        SOURCE,
+        ParserOptions::default().enabled_link_reference_definitions(),
+    );
+    let tree = &document.tree;
+    let children = std::iter::successors(tree.get_first_child(0), |&id| tree.get_next(id))
+        .map(|id| (id, slice(&document, id)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        children.iter().map(|it| it.1).collect::<Vec<_>>(),
+        [
+            "[Foo  Bar]: /url \"Title\"",
+            "[b]: <x y>",
+            "Text [foo bar], [x][b] and ![B].",
+            "[B]: /dup",
+            "> [c]: /c\n> Heading\n> ===",
+        ]
+    );
+    let MarkdownNode::LinkReferenceDefinition(definition) = &tree[children[0].0].body else {
+        panic!("expected a link reference definition");
+    };
+    assert_eq!(definition.label, "Foo  Bar");
+    assert_eq!(definition.url, "/url");
+    assert_eq!(definition.title.as_deref(), Some("Title"));
+
+    // setext 标题前导的定义行同样保留，标题区间从剩余内容开始
+    let quote = children[4].0;
+    let nested = std::iter::successors(tree.get_first_child(quote), |&id| tree.get_next(id))
+        .map(|id| slice(&document, id))
+        .collect::<Vec<_>>();
+    assert_eq!(nested, ["[c]: /c", "Heading\n> ==="]);
+
+    // 定义节点不产生输出
+    assert_eq!(
+        document.to_html(),
+        parse(SOURCE, ParserOptions::default()).to_html()
+    );
+}
+
+#[test]
+fn definitions_are_consumed_by_default() {
+    let document = parse(SOURCE, ParserOptions::default());
+    assert!(document.tree.descendants(0).all(|id| !matches!(
+        document.tree[id].body,
+        MarkdownNode::LinkReferenceDefinition(..)
+    )));
+    let definitions = document.link_definitions();
+    assert_eq!(
+        definitions.keys().copied().collect::<Vec<_>>(),
+        ["b", "c", "foo bar"]
+    );
+    // 首个定义生效
+    assert_eq!(definitions["b"].url, "x%20y");
+    assert_eq!(definitions["b"].label, "b");
+    assert_eq!(
+        &SOURCE[definitions["c"].span.start as usize..definitions["c"].span.end as usize],
+        "[c]: /c"
+    );
+}
+
+#[test]
+fn resolves_reference_links_to_definitions() {
+    let source =
+        "[foo bar], [x][B], [Foo Bar][], ![b] and [inline](/i)\n\n[foo bar]: /a\n[b]: /b 'B'";
+    let document = parse(source, ParserOptions::default());
+    let resolved = document
+        .tree
+        .descendants(0)
+        .filter(|&id| {
+            matches!(
+                document.tree[id].body,
+                MarkdownNode::Link(..) | MarkdownNode::Image(..)
+            )
+        })
+        .map(|id| {
+            (
+                slice(&document, id),
+                document
+                    .resolved_link_definition(id)
+                    .map(|definition| definition.label.as_str()),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        resolved,
+        [
+            ("[foo bar]", Some("foo bar")),
+            ("[x][B]", Some("b")),
+            ("[Foo Bar][]", Some("foo bar")),
+            ("![b]", Some("b")),
+            ("[inline](/i)", None),
+        ]
+    );
+    assert!(document.resolved_link_definition(0).is_none());
+}
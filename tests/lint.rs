@@ -0,0 +1,147 @@
+use ptdgrp_markdown::{
+    LintConfig, LintRule, LintWarning, Location, Parser, ParserOptions, apply_lint_fixes,
+};
+
+fn lint(source: &str, config: &LintConfig) -> Vec<LintWarning> {
+    Parser::new_with_options(source, ParserOptions::default().enabled_ofm().enabled_gfm())
+        .parse()
+        .unwrap()
+        .lint(config)
+}
+
+fn rules(warnings: &[LintWarning]) -> Vec<&'static str> {
+    warnings.iter().map(|it| it.rule.id()).collect()
+}
+
+fn fix(source: &str) -> String {
+    apply_lint_fixes(source, &lint(source, &LintConfig::default()))
+}
+
+#[test]
+fn checks_headings() {
+    let source = "# Title\n\n### Deep\n\n## Title\n\n## *Title*\n\n#tag is not a heading\n";
+    let warnings = lint(source, &LintConfig::default());
+    assert_eq!(rules(&warnings), ["MD001", "MD024", "MD024"]);
+    assert_eq!(warnings[0].message, "expected h2, found h3");
+    assert_eq!(warnings[0].location, Location::new(3, 1));
+    assert_eq!(warnings[2].message, "duplicate heading `Title`");
+    assert!(warnings.iter().all(|it| it.fix.is_none()));
+}
+
+#[test]
+fn checks_markers_and_styles() {
+    let source = "- a\n  * b\n+ c\n- [ ] task\n\n*em* _em_ snake_case_name **st** __st__\n";
+    let warnings = lint(source, &LintConfig::default());
+    assert_eq!(rules(&warnings), ["MD004", "MD004", "MD049", "MD050"]);
+    assert_eq!(warnings[0].message, "expected `-` list marker, found `*`");
+    assert_eq!(warnings[3].message, "expected `**` style, found `__`");
+    assert_eq!(
+        fix(source),
+        "- a\n  - b\n- c\n- [ ] task\n\n*em* *em* snake_case_name **st** **st**\n"
+    );
+
+    // 词内的 `_` 强调改写会失去强调，只报告不修复
+    let warnings = lint("_a_ x*b*y\n", &LintConfig::default());
+    assert_eq!(rules(&warnings), ["MD049"]);
+    assert!(warnings[0].fix.is_none());
+}
+
+#[test]
+fn checks_whitespace_lines_and_code() {
+    let source = "Hard break  \nTrailing \t\n\n\n\nTabs\there\n\n```\ncode   \n```\n\n~~~rust\nfn main() {}\n~~~";
+    let warnings = lint(source, &LintConfig::default());
+    assert_eq!(
+        rules(&warnings),
+        [
+            "MD009", "MD010", "MD012", "MD012", "MD010", "MD040", "MD047"
+        ]
+    );
+    assert_eq!(warnings[0].location, Location::new(2, 9));
+    assert_eq!(warnings[2].message, "2 consecutive blank lines");
+    assert_eq!(
+        fix(source),
+        "Hard break  \nTrailing\n\nTabs    here\n\n```\ncode   \n```\n\n~~~rust\nfn main() {}\n~~~\n"
+    );
+
+    // 制表符展开到下一个 4 的倍数列，缩进代码与列表嵌套不变
+    let html = |source: &str| {
+        Parser::new_with_options(source, ParserOptions::default().enabled_gfm())
+            .parse()
+            .unwrap()
+            .to_html()
+    };
+    for source in ["\tcode\n", "- a\n\t- b\n\n\tmore\n", "-\t\tfoo\n"] {
+        let fixed = fix(source);
+        assert!(!fixed.contains('\t'));
+        assert_eq!(html(&fixed), html(source), "{source:?}");
+    }
+    assert_eq!(fix("\tcode\n"), "    code\n");
+    assert_eq!(fix("ab\tc\n"), "ab  c\n");
+    assert_eq!(fix("- a\n\t- b\n"), "- a\n    - b\n");
+    // 引用标记后的制表符只报告，不修复
+    assert_eq!(fix("> \tquoted\n"), "> \tquoted\n");
+}
+
+#[test]
+fn checks_line_length_and_bare_urls() {
+    let long = format!("{} tail end\n\n{}\n", "x".repeat(76), "y".repeat(100));
+    let warnings = lint(&long, &LintConfig::default());
+    assert_eq!(rules(&warnings), ["MD013"]);
+    assert_eq!(
+        warnings[0].message,
+        "line is 85 characters long, expected at most 80"
+    );
+    assert!(lint(&long, &LintConfig::default().with_line_length(90)).is_empty());
+
+    let source = "See https://example.com/a, <https://ok.example>, [x](https://ok.example)\nand [[https note]].\n";
+    let warnings = lint(source, &LintConfig::default());
+    assert_eq!(rules(&warnings), ["MD034"]);
+    assert_eq!(
+        fix(source),
+        "See <https://example.com/a>, <https://ok.example>, [x](https://ok.example)\nand [[https note]].\n"
+    );
+
+    // 配对的括号属于 URL，不配对的结尾括号不属于
+    assert_eq!(
+        fix("see https://example.com/a_(b). now\n"),
+        "see <https://example.com/a_(b)>. now\n"
+    );
+    assert_eq!(
+        fix("(see https://example.com/a)\n"),
+        "(see <https://example.com/a>)\n"
+    );
+
+    let autolinked = Parser::new_with_options(
+        "Visit https://example.com now\n",
+        ParserOptions::default().enabled_gfm_autolink(),
+    )
+    .parse()
+    .unwrap()
+    .lint(&LintConfig::default());
+    assert_eq!(rules(&autolinked), ["MD034"]);
+}
+
+#[test]
+fn configures_rules() {
+    let source = "# A\n\n### B\n\n* x\n- y\n";
+    let config = LintConfig::default().disabled(LintRule::HeadingIncrement);
+    assert_eq!(rules(&lint(source, &config)), ["MD004"]);
+    assert_eq!(
+        rules(&lint(source, &config.enabled(LintRule::HeadingIncrement))),
+        ["MD001", "MD004"]
+    );
+
+    let mut config = LintConfig::default();
+    assert!(config.set("default", false));
+    assert!(config.set("ul-style", true));
+    assert!(!config.set("MD999", true));
+    assert_eq!(rules(&lint(source, &config)), ["MD004"]);
+    assert_eq!(LintRule::from_name("md013"), Some(LintRule::LineLength));
+    assert_eq!(LintRule::LineLength.alias(), "line-length");
+}
+
+#[test]
+fn ignores_frontmatter_and_raw_blocks() {
+    let source = "---\ntitle: x   \n\n\n---\n> [!note] Callout\n> body\n\n<div>\n\n</div>\n";
+    assert!(lint(source, &LintConfig::default()).is_empty());
+}
@@ -0,0 +1,111 @@
+use ptdgrp_markdown::{Document, Location, MarkdownNode, Parser, ParserOptions};
+
+const SOURCE: &str = "# Title\n\n> Quote with **bold *nested* text**\n\n- one\n- two [link](/url)\n\n| a | b |\n|---|---|\n| `c` | d |\n\nLast 文字 paragraph.\n";
+
+fn parse(source: &str) -> Document<'_> {
+    Parser::new_with_options(source, ParserOptions::default().enabled_gfm())
+        .parse()
+        .unwrap()
+}
+
+fn slice<'a>(document: &'a Document, id: usize) -> &'a str {
+    let span = document.tree[id].span;
+    &document.source()[span.start as usize..span.end as usize]
+}
+
+/// 逐层下降的线性查找，作为区间索引的对照
+fn descend(document: &Document, offset: usize) -> usize {
+    let tree = &document.tree;
+    let mut node = 0;
+    'outer: loop {
+        let mut child = tree.get_first_child(node);
+        while let Some(id) = child {
+            let span = tree[id].span;
+            if (span.start as usize) <= offset && offset < span.end as usize {
+                node = id;
+                continue 'outer;
+            }
+            child = tree.get_next(id);
+        }
+        return node;
+    }
+}
+
+#[test]
+fn node_at_matches_linear_descent() {
+    let document = parse(SOURCE);
+    for offset in 0..SOURCE.len() {
+        assert_eq!(
+            document.node_at(offset),
+            Some(descend(&document, offset)),
+            "offset {offset}"
+        );
+    }
+    assert_eq!(document.node_at(SOURCE.len()), None);
+
+    let nested = SOURCE.find("nested").unwrap();
+    let id = document.node_at(nested).unwrap();
+    assert_eq!(slice(&document, id), "nested");
+    let block = document.enclosing_block(nested).unwrap();
+    assert!(matches!(document.tree[block].body, MarkdownNode::Paragraph));
+    assert_eq!(slice(&document, block), "Quote with **bold *nested* text**");
+
+    // 段落之间的空行只属于根节点
+    assert_eq!(document.node_at(8), Some(0));
+    assert_eq!(document.enclosing_block(8), Some(0));
+}
+
+#[test]
+fn nodes_in_range_are_in_document_order() {
+    let document = parse(SOURCE);
+    let start = SOURCE.find("two").unwrap();
+    let end = SOURCE.find("(/url)").unwrap();
+    let nodes = document
+        .nodes_in_range(start, end)
+        .into_iter()
+        .map(|id| slice(&document, id))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        nodes,
+        [
+            SOURCE.trim_end(),
+            "- one\n- two [link](/url)",
+            "- two [link](/url)",
+            "two [link](/url)",
+            "two ",
+            "[link](/url)",
+            "link",
+        ]
+    );
+    // 空区间等价于包含该偏移的节点
+    let cell = SOURCE.find('c').unwrap();
+    assert_eq!(
+        document.nodes_in_range(cell, cell).last().copied(),
+        document.node_at(cell)
+    );
+    assert!(
+        document
+            .nodes_in_range(SOURCE.len(), SOURCE.len() + 5)
+            .is_empty()
+    );
+}
+
+#[test]
+fn offset_at_inverts_location_at() {
+    let document = parse(SOURCE);
+    for offset in SOURCE.char_indices().map(|(offset, _)| offset) {
+        let location = document.location_at(offset);
+        assert_eq!(document.offset_at(location), offset, "{location:?}");
+    }
+    assert_eq!(document.offset_at(Location::new(1, 100)), "# Title".len());
+    assert_eq!(document.offset_at(Location::new(100, 1)), SOURCE.len());
+}
+
+#[test]
+fn plain_text_drops_formatting() {
+    let document = parse("## **Setup** guide\n\n> Quote with **bold *nested* text**\n");
+    let heading = document.tree.get_first_child(0).unwrap();
+    assert_eq!(document.plain_text(heading), "Setup guide");
+    let quote = document.tree.get_next(heading).unwrap();
+    assert_eq!(document.plain_text(quote), "Quote with bold nested text");
+}
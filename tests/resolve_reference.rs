@@ -0,0 +1,102 @@
+use ptdgrp_markdown::reference::Reference;
+use ptdgrp_markdown::{MarkdownNode, Parser, ParserOptions};
+
+const NOTE: &str = "# Guide\n\nIntro.\n\n## Install\n\nRun **cargo**.\n\n### Linux\n\nUse apt.\n\n## Usage: *Basics*\n\n### Linux\n\nRun it. ^run\n\n> Quoted.\n\n^quote\n\n- first ^item\n- second\n\n# Appendix";
+
+fn heading(text: &str) -> Reference {
+    Reference::Heading(text.to_string())
+}
+
+fn resolve(reference: &Reference) -> Option<String> {
+    Parser::new_with_options(NOTE, ParserOptions::default().enabled_ofm())
+        .resolve_reference(reference)
+        .unwrap()
+        .map(|resolved| resolved.text().to_string())
+}
+
+#[test]
+fn resolves_heading_sections() {
+    assert_eq!(
+        resolve(&heading("Install")).unwrap(),
+        "## Install\n\nRun **cargo**.\n\n### Linux\n\nUse apt."
+    );
+    // 忽略大小写、格式与链接中不允许的字符
+    assert_eq!(
+        resolve(&heading("usage basics")).unwrap(),
+        "## Usage: *Basics*\n\n### Linux\n\nRun it. ^run\n\n> Quoted.\n\n^quote\n\n- first ^item\n- second"
+    );
+    assert_eq!(resolve(&heading("Linux")).unwrap(), "### Linux\n\nUse apt.");
+    assert_eq!(resolve(&heading("Missing")), None);
+}
+
+#[test]
+fn resolves_nested_heading_paths() {
+    let path = |segments: &[&str]| {
+        Reference::MultiHeading(segments.iter().map(|it| it.to_string()).collect())
+    };
+    assert_eq!(
+        resolve(&path(&["Usage: Basics", "Linux"])).unwrap(),
+        "### Linux\n\nRun it. ^run\n\n> Quoted.\n\n^quote\n\n- first ^item\n- second"
+    );
+    assert_eq!(
+        resolve(&path(&["Guide", "Install", "Linux"])).unwrap(),
+        "### Linux\n\nUse apt."
+    );
+    assert_eq!(resolve(&path(&["Install", "Usage: Basics"])), None);
+}
+
+#[test]
+fn resolves_block_ids() {
+    assert_eq!(
+        resolve(&Reference::BlockId("run".to_string())).unwrap(),
+        "Run it. ^run"
+    );
+    assert_eq!(
+        resolve(&Reference::BlockId("quote".to_string())).unwrap(),
+        "> Quoted."
+    );
+    assert_eq!(
+        resolve(&Reference::BlockId("item".to_string())).unwrap(),
+        "- first ^item"
+    );
+    assert_eq!(resolve(&Reference::BlockId("none".to_string())), None);
+}
+
+#[test]
+fn materializes_only_the_target() {
+    let resolved = Parser::new_with_options(NOTE, ParserOptions::default().enabled_ofm())
+        .resolve_reference(&heading("Install"))
+        .unwrap()
+        .unwrap();
+    let tree = &resolved.document.tree;
+    let paragraph = resolved.target.nodes[1];
+    assert!(matches!(tree[paragraph].body, MarkdownNode::Paragraph));
+    assert!(tree.get_first_child(paragraph).is_some());
+    // 章节外的段落保持未物化
+    let intro = tree.get_prev(resolved.target.nodes[0]).unwrap();
+    assert!(matches!(tree[intro].body, MarkdownNode::Paragraph));
+    assert!(tree.get_first_child(intro).is_none());
+}
+
+#[test]
+fn finds_references_in_parsed_documents() {
+    let document = Parser::new_with_options(NOTE, ParserOptions::default().enabled_ofm())
+        .parse()
+        .unwrap();
+    for reference in [
+        heading("install"),
+        heading("Usage Basics"),
+        Reference::MultiHeading(vec!["Usage: Basics".to_string(), "Linux".to_string()]),
+        Reference::BlockId("quote".to_string()),
+        Reference::BlockId("item".to_string()),
+    ] {
+        let found = document.find_reference(&reference).unwrap();
+        let span = found.span;
+        assert_eq!(
+            Some(&NOTE[span.start as usize..span.end as usize]),
+            resolve(&reference).as_deref(),
+            "{reference:?}"
+        );
+    }
+    assert!(document.find_reference(&heading("nope")).is_none());
+}
@@ -0,0 +1,142 @@
+use ptdgrp_markdown::ast::MarkdownNode;
+use ptdgrp_markdown::ast::html::{Html, HtmlType};
+use ptdgrp_markdown::ast::ruby::RubyAnnotation;
+use ptdgrp_markdown::{Parser, ParserOptions};
+
+fn render(input: &str, options: ParserOptions) -> String {
+    Parser::new_with_options(input, options)
+        .parse()
+        .unwrap()
+        .to_html()
+}
+
+fn ruby() -> ParserOptions {
+    ParserOptions::default().enabled_ruby()
+}
+
+#[test]
+fn braced_syntax() {
+    assert_eq!(
+        render("{漢字|かんじ}を読む", ruby()),
+        "<p><ruby><rb>漢字</rb><rt>かんじ</rt></ruby>を読む</p>"
+    );
+}
+
+#[test]
+fn bracketed_syntax() {
+    assert_eq!(
+        render("[漢字]^(かんじ)を読む", ruby()),
+        "<p><ruby><rb>漢字</rb><rt>かんじ</rt></ruby>を読む</p>"
+    );
+}
+
+#[test]
+fn per_character_annotations() {
+    let expected = "<p><ruby><rb>漢</rb><rt>かん</rt><rb>字</rb><rt>じ</rt></ruby></p>";
+    assert_eq!(render("{漢字|かん|じ}", ruby()), expected);
+    assert_eq!(render("[漢字]^(かん|じ)", ruby()), expected);
+    // 注音数量与字符数不一致时保持原文
+    assert_eq!(render("{漢字|か|ん|じ}", ruby()), "<p>{漢字|か|ん|じ}</p>");
+}
+
+#[test]
+fn rejects_non_ruby_text() {
+    assert_eq!(render("{x | x > 0}", ruby()), "<p>{x | x &gt; 0}</p>");
+    assert_eq!(render("{漢字}", ruby()), "<p>{漢字}</p>");
+    assert_eq!(render("{|かんじ}", ruby()), "<p>{|かんじ}</p>");
+    assert_eq!(
+        render("[漢字](/kanji)", ruby()),
+        "<p><a href=\"/kanji\">漢字</a></p>"
+    );
+}
+
+#[test]
+fn escapes_and_html() {
+    assert_eq!(
+        render(r"{a\|b|<x>}", ruby()),
+        "<p><ruby><rb>a|b</rb><rt>&lt;x&gt;</rt></ruby></p>"
+    );
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(
+        render("{漢字|かんじ} [漢字]^(かんじ)", ParserOptions::default()),
+        "<p>{漢字|かんじ} [漢字]^(かんじ)</p>"
+    );
+}
+
+#[test]
+fn cjk_autocorrect_keeps_ruby_base_intact() {
+    let options = ruby().enabled_cjk_autocorrect();
+    assert_eq!(
+        render("乗るJR東日本{JR東日本|ジェイアールひがしにほん}", options),
+        "<p>乗る JR 東日本<ruby><rb>JR東日本</rb><rt>ジェイアールひがしにほん</rt></ruby></p>"
+    );
+}
+
+#[test]
+fn jsx_components_still_parse_after_ruby_attempt() {
+    let options = ruby().enabled_jsx_like_component();
+    assert_eq!(
+        render("{漢字|かんじ}", options.clone()),
+        "<p><ruby><rb>漢字</rb><rt>かんじ</rt></ruby></p>"
+    );
+
+    // 不构成注音的 `{expr}` 与组件回退到 JSX/HTML 路径，输出与未启用 ruby 时一致
+    let jsx = ParserOptions::default().enabled_jsx_like_component();
+    for input in [
+        "{count}",
+        "{props.a || props.b}",
+        "<Card>\n\n{items.map(x => x)}\n\n</Card>",
+        "Hi <Comp value={1} /> there",
+    ] {
+        assert_eq!(
+            render(input, options.clone()),
+            render(input, jsx.clone()),
+            "{input:?}"
+        );
+    }
+    assert_eq!(render("{count}", options.clone()), "<p>{count}</p>");
+    let input = "<Card>\n\n{items.map(x => x)}\n\n</Card>";
+    assert_eq!(
+        render(input, options.clone()),
+        "<Card>\n<p>{items.map(x => x)}</p>\n</Card>"
+    );
+    let doc = Parser::new_with_options(input, options).parse().unwrap();
+    let component = doc.get_first_child(0).unwrap();
+    assert!(
+        matches!(&doc[component].body, MarkdownNode::Html(html) if matches!(html.as_ref(), Html::Block(HtmlType::Component(..)))),
+        "{:?}",
+        doc[component].body
+    );
+}
+
+#[test]
+fn ast_node_payload_and_span() {
+    let input = "今日は{今日|きょ|う}";
+    let doc = Parser::new_with_options(input, ruby()).parse().unwrap();
+    let paragraph = doc.get_first_child(0).unwrap();
+    let node = doc.get_last_child(paragraph).unwrap();
+    let MarkdownNode::Ruby(ruby) = &doc[node].body else {
+        panic!("expected ruby node, got {:?}", doc[node].body);
+    };
+    assert_eq!(ruby.base, "今日");
+    assert_eq!(
+        ruby.annotations,
+        [
+            RubyAnnotation {
+                base: "今".into(),
+                text: "きょ".into()
+            },
+            RubyAnnotation {
+                base: "日".into(),
+                text: "う".into()
+            },
+        ]
+    );
+    assert_eq!(
+        &input[doc[node].span.start as usize..doc[node].span.end as usize],
+        "{今日|きょ|う}"
+    );
+}
@@ -0,0 +1,112 @@
+use ptdgrp_markdown::ast::syntax::{HardBreakSyntax, LinkSyntax, NodeSyntax};
+use ptdgrp_markdown::{Document, Parser, ParserOptions};
+
+fn syntax(source: &str) -> Vec<(&str, NodeSyntax)> {
+    let document = Parser::new_with_options(
+        source,
+        ParserOptions::default()
+            .enabled_gfm_autolink()
+            .enabled_source_fidelity(),
+    )
+    .parse()
+    .unwrap();
+    collect(source, &document)
+}
+
+fn collect<'a>(source: &'a str, document: &Document) -> Vec<(&'a str, NodeSyntax)> {
+    document
+        .tree
+        .descendants(0)
+        .filter_map(|id| {
+            let span = document.tree[id].span;
+            let syntax = document.node_syntax(id)?.clone();
+            Some((&source[span.start as usize..span.end as usize], syntax))
+        })
+        .collect()
+}
+
+#[test]
+fn records_link_forms() {
+    let source = "[a](/a) [b][ref] [ref][] [ref] ![ref] ![c](/c) <https://x.example> <foo@bar.example.com> www.example.com [[Note]]\n\n[ref]: /ref";
+    let link = |form| NodeSyntax::Link { form };
+    assert_eq!(
+        syntax(source),
+        [
+            ("[a](/a)", link(LinkSyntax::Inline)),
+            ("[b][ref]", link(LinkSyntax::Full)),
+            ("[ref][]", link(LinkSyntax::Collapsed)),
+            ("[ref]", link(LinkSyntax::Shortcut)),
+            (
+                "![ref]",
+                NodeSyntax::Image {
+                    form: LinkSyntax::Shortcut
+                }
+            ),
+            (
+                "![c](/c)",
+                NodeSyntax::Image {
+                    form: LinkSyntax::Inline
+                }
+            ),
+            ("<https://x.example>", link(LinkSyntax::Autolink)),
+            ("<foo@bar.example.com>", link(LinkSyntax::Autolink)),
+            ("www.example.com", link(LinkSyntax::ExtendedAutolink)),
+        ]
+    );
+}
+
+#[test]
+fn records_inline_markers() {
+    let source = "*a* _b_ **c** __d__ ``e`` f\\\ng  \nh";
+    let recorded = syntax(source);
+    assert_eq!(
+        recorded[..5],
+        [
+            ("*a*", NodeSyntax::Emphasis { marker: '*' }),
+            ("_b_", NodeSyntax::Emphasis { marker: '_' }),
+            ("**c**", NodeSyntax::Strong { marker: '*' }),
+            ("__d__", NodeSyntax::Strong { marker: '_' }),
+            ("``e``", NodeSyntax::Code { backticks: 2 }),
+        ]
+    );
+    assert_eq!(
+        recorded[5..]
+            .iter()
+            .map(|(_, syntax)| syntax.clone())
+            .collect::<Vec<_>>(),
+        [
+            NodeSyntax::HardBreak {
+                form: HardBreakSyntax::Backslash
+            },
+            NodeSyntax::HardBreak {
+                form: HardBreakSyntax::Spaces
+            },
+        ]
+    );
+}
+
+#[test]
+fn records_block_markers() {
+    let source = "# Closed ##\n## Open\n\n- one\n* two\n\n007) seven\n8) eight";
+    let list_item = |marker: &str| NodeSyntax::ListItem {
+        marker: marker.to_string(),
+    };
+    assert_eq!(
+        syntax(source),
+        [
+            ("# Closed ##", NodeSyntax::AtxHeading { closing: 2 }),
+            ("## Open", NodeSyntax::AtxHeading { closing: 0 }),
+            ("- one", list_item("-")),
+            ("* two", list_item("*")),
+            ("007) seven", list_item("007)")),
+            ("8) eight", list_item("8)")),
+        ]
+    );
+}
+
+#[test]
+fn records_nothing_by_default() {
+    let source = "# A #\n\n*b* [c](/c)\n\n- d";
+    let document = Parser::new(source).parse().unwrap();
+    assert!(collect(source, &document).is_empty());
+}
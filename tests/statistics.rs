@@ -0,0 +1,91 @@
+use ptdgrp_markdown::{DocumentStatistics, Parser, ParserOptions, StatisticsConfig};
+
+fn statistics(source: &str, config: &StatisticsConfig) -> DocumentStatistics {
+    Parser::new_with_options(source, ParserOptions::default().enabled_ofm().enabled_gfm())
+        .parse()
+        .unwrap()
+        .statistics_with(config)
+}
+
+fn words(source: &str) -> usize {
+    statistics(source, &StatisticsConfig::default()).words
+}
+
+#[test]
+fn counts_cjk_ideographs_and_latin_words() {
+    assert_eq!(words("我在用 Obsidian 写笔记。"), 7);
+    assert_eq!(words("中文English混排"), 5);
+    assert_eq!(words("ひらがなとカタカナ"), 9);
+    // CJK 标点和全角符号不算词
+    assert_eq!(words("，。！？「」（）"), 0);
+    assert_eq!(words("한국어 문장"), 2);
+
+    let stats = statistics("你好，world 🌍", &StatisticsConfig::default());
+    assert_eq!(stats.words, 3);
+    assert_eq!(stats.cjk_words, 2);
+    assert_eq!(stats.characters, 10);
+    assert_eq!(stats.characters_without_spaces, 9);
+}
+
+#[test]
+fn joins_contractions_hyphens_and_numbers() {
+    assert_eq!(words("don't rock’n’roll well-known"), 3);
+    assert_eq!(words("1,000.50 dollars in 2024-10"), 4);
+    assert_eq!(words("end. Next,word e.g."), 5);
+    assert_eq!(words("**bold** _it_ [[Note#Part|alias]] #tag"), 6);
+    assert_eq!(words("- [ ] task\n- item\n\n---\n"), 2);
+}
+
+#[test]
+fn skips_code_math_frontmatter_and_html_as_configured() {
+    let source = "---\ntitle: Draft note\n---\n# Title\n\nUse `cargo build` and $e^x$ here.\n\n```rs\nfn main() {}\n```\n\n$$\nx + y\n$$\n\n<div>\nraw html\n</div>\n\n<b>bold</b> <!-- hidden -->\n";
+    let config = StatisticsConfig::default();
+    // Title、Use、and、here、bold
+    assert_eq!(statistics(source, &config).words, 5);
+    assert_eq!(
+        statistics(source, &config.clone().with_code(true)).words,
+        10
+    );
+    assert_eq!(statistics(source, &config.clone().with_math(true)).words, 9);
+    assert_eq!(
+        statistics(source, &config.clone().with_frontmatter(true)).words,
+        8
+    );
+    assert_eq!(
+        statistics(source, &config.clone().with_html(true)).words,
+        12
+    );
+}
+
+#[test]
+fn counts_blocks_links_and_images() {
+    let source = "# A\n\nSee [site](https://example.com), <https://x.io>, [[Note]] and [ref][r].\n\n![pic](a.png) ![[photo.JPG]] ![[Other note]]\n\nText[^1].\n\n    indented\n\n~~~\nfenced\n~~~\n\n## B\n\n[r]: /r\n[^1]: Footnote.\n";
+    let stats = statistics(source, &StatisticsConfig::default());
+    assert_eq!(stats.headings, 2);
+    assert_eq!(stats.links, 4);
+    assert_eq!(stats.images, 2);
+    assert_eq!(stats.code_blocks, 2);
+    // 正文三段，加脚注内一段
+    assert_eq!(stats.paragraphs, 4);
+}
+
+#[test]
+fn estimates_reading_time() {
+    let latin = "word ".repeat(450);
+    let stats = statistics(&latin, &StatisticsConfig::default());
+    assert_eq!(stats.reading_seconds, 135);
+    assert_eq!(stats.reading_minutes(), 3);
+
+    let cjk = "字".repeat(600);
+    let stats = statistics(&cjk, &StatisticsConfig::default());
+    assert_eq!(stats.reading_seconds, 120);
+    let config = StatisticsConfig::default()
+        .with_words_per_minute(300)
+        .with_cjk_characters_per_minute(500);
+    assert_eq!(statistics(&cjk, &config).reading_seconds, 72);
+    assert_eq!(statistics(&latin, &config).reading_seconds, 90);
+
+    let empty = Parser::new("").parse().unwrap().statistics();
+    assert_eq!(empty, DocumentStatistics::default());
+    assert_eq!(empty.reading_minutes(), 0);
+}
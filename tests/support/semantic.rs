@@ -86,12 +86,23 @@ fn node_kind(node: &MarkdownNode) -> &'static str {
         MarkdownNode::TableDataCol => "TableDataCol",
         MarkdownNode::Strikethrough => "Strikethrough",
         MarkdownNode::Highlighting => "Highlighting",
+        MarkdownNode::Superscript => "Superscript",
+        MarkdownNode::Subscript => "Subscript",
+        MarkdownNode::Insert => "Insert",
+        MarkdownNode::Spoiler => "Spoiler",
+        MarkdownNode::Abbreviation(..) => "Abbreviation",
+        MarkdownNode::Ruby(..) => "Ruby",
         MarkdownNode::ThematicBreak => "ThematicBreak",
         MarkdownNode::Footnote(..) => "Footnote",
         MarkdownNode::FootnoteList => "FootnoteList",
         MarkdownNode::Math(..) => "Math",
         MarkdownNode::Callout(..) => "Callout",
         MarkdownNode::Html(..) => "Html",
+        MarkdownNode::DefinitionList(..) => "DefinitionList",
+        MarkdownNode::DefinitionTerm => "DefinitionTerm",
+        MarkdownNode::DefinitionDetails(..) => "DefinitionDetails",
+        MarkdownNode::Transclusion(..) => "Transclusion",
+        MarkdownNode::LinkReferenceDefinition(..) => "LinkReferenceDefinition",
     }
 }
 
@@ -99,6 +110,7 @@ fn canonical_payload(node: &MarkdownNode, source: &str) -> String {
     match node {
         MarkdownNode::FrontMatter(values) => {
             let mut entries: Vec<_> = values
+                .data
                 .iter()
                 .map(|(key, value)| format!("{key}={value:?}"))
                 .collect();
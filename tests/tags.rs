@@ -0,0 +1,110 @@
+use ptdgrp_markdown::{Parser, ParserOptions, TagSource, TagTree, normalize_tag};
+
+fn parse(input: &str) -> ptdgrp_markdown::Document<'_> {
+    Parser::new_with_options(input, ParserOptions::default().enabled_ofm())
+        .parse()
+        .unwrap()
+}
+
+fn paths(tree: &TagTree, prefix: &str) -> Vec<String> {
+    tree.get(prefix)
+        .unwrap()
+        .children()
+        .map(|it| it.path().to_string())
+        .collect()
+}
+
+#[test]
+fn collects_occurrences_with_spans() {
+    let input = "---\ntags:\n  - Project/Alpha\n  - '#inbox'\ntag: ignored\n---\nSee #project/alpha/design and #todo.";
+    let document = parse(input);
+    let occurrences = document.tag_occurrences();
+    let summary = occurrences
+        .iter()
+        .map(|it| {
+            (
+                it.tag.as_str(),
+                &input[it.span.start as usize..it.span.end as usize],
+                it.source,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            ("Project/Alpha", "Project/Alpha", TagSource::FrontMatter),
+            ("inbox", "'#inbox'", TagSource::FrontMatter),
+            (
+                "project/alpha/design",
+                "#project/alpha/design",
+                TagSource::Inline
+            ),
+            ("todo", "#todo", TagSource::Inline),
+        ]
+    );
+    assert_eq!(
+        occurrences[2].segments().collect::<Vec<_>>(),
+        ["project", "alpha", "design"]
+    );
+
+    // 字符串值中的多个标签共用整个值的区间
+    let input = "---\ntags: a, b\n---\n";
+    let occurrences = parse(input).tag_occurrences();
+    assert_eq!(occurrences.len(), 2);
+    assert_eq!(occurrences[0].span, occurrences[1].span);
+    assert_eq!(
+        &input[occurrences[0].span.start as usize..occurrences[0].span.end as usize],
+        "a, b"
+    );
+}
+
+#[test]
+fn builds_a_case_insensitive_tree() {
+    let mut tree = TagTree::new();
+    tree.add_document("a.md", &parse("#Project/Alpha #project/beta #project"));
+    tree.add_document("b.md", &parse("#PROJECT/alpha/design #projects"));
+
+    let project = tree.get("#project/").unwrap();
+    assert_eq!(project.name(), "Project");
+    assert_eq!(project.occurrences().len(), 1);
+    assert_eq!(project.count(), 4);
+    assert_eq!(paths(&tree, "project"), ["Project/Alpha", "Project/beta"]);
+    assert_eq!(tree.get("project/ALPHA").unwrap().count(), 2);
+    assert_eq!(tree.notes_under("project"), ["a.md", "b.md"]);
+    assert_eq!(tree.notes_under("project/beta"), ["a.md"]);
+    assert_eq!(
+        tree.occurrences_under("project/alpha")
+            .iter()
+            .map(|it| (it.note.as_str(), it.occurrence.tag.as_str()))
+            .collect::<Vec<_>>(),
+        [("a.md", "Project/Alpha"), ("b.md", "PROJECT/alpha/design")]
+    );
+    assert_eq!(
+        tree.roots().map(|it| it.path()).collect::<Vec<_>>(),
+        ["Project", "projects"]
+    );
+    assert!(tree.get("proj").is_none());
+    assert!(tree.get("").is_none());
+    assert!(tree.notes_under("missing").is_empty());
+}
+
+#[test]
+fn supports_case_sensitive_trees_and_removal() {
+    let mut tree = TagTree::new().case_sensitive();
+    tree.add_document("a.md", &parse("#Work/x #work/y"));
+    tree.add_document("b.md", &parse("#work/y/z"));
+    assert_eq!(tree.roots().count(), 2);
+    assert_eq!(tree.get("work").unwrap().count(), 2);
+
+    tree.remove_note("b.md");
+    assert_eq!(tree.get("work").unwrap().count(), 1);
+    assert!(tree.get("work/y/z").is_none());
+    tree.remove_note("a.md");
+    assert_eq!(tree.roots().count(), 0);
+}
+
+#[test]
+fn normalizes_tags() {
+    assert_eq!(normalize_tag("#Project//Alpha/"), "project/alpha");
+    assert_eq!(normalize_tag("Inbox"), "inbox");
+}
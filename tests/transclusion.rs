@@ -0,0 +1,206 @@
+use ptdgrp_markdown::ast::text::SourceSpan;
+use ptdgrp_markdown::{
+    Document, LintConfig, LoadedNote, MarkdownNode, Parser, ParserOptions, Transcluder,
+    TransclusionIssueKind,
+};
+
+fn options() -> ParserOptions {
+    ParserOptions::default().enabled_ofm().enabled_gfm()
+}
+
+fn loader(notes: &[(&str, &str)]) -> impl FnMut(&str, &str) -> Option<LoadedNote> {
+    let notes = notes
+        .iter()
+        .map(|(name, source)| (name.to_string(), source.to_string()))
+        .collect::<Vec<_>>();
+    move |path, _from| {
+        notes
+            .iter()
+            .find(|(name, _)| name == path)
+            .map(|(name, source)| LoadedNote {
+                note: format!("{name}.md"),
+                source: source.clone(),
+            })
+    }
+}
+
+fn expand(host: &str, notes: &[(&str, &str)]) -> (Document<'static>, Vec<TransclusionIssueKind>) {
+    let mut document = Parser::parse_string(host.to_string(), options()).unwrap();
+    let issues = Transcluder::new(options())
+        .expand(&mut document, "host.md", loader(notes))
+        .unwrap();
+    (document, issues.into_iter().map(|it| it.kind).collect())
+}
+
+#[test]
+fn grafts_whole_notes_sections_and_blocks() {
+    let note =
+        "---\ntitle: Note\n---\n# Intro\n\nHello.\n\n# Details\n\n- a\n- b ^item\n\nKept. ^para";
+    let (document, issues) = expand("![[Note]]", &[("Note", note)]);
+    assert!(issues.is_empty());
+    assert_eq!(
+        document.to_html(),
+        "<div class=\"internal-embed markdown-embed\" src=\"Note\">\n<h1>Intro</h1>\n<p>Hello.</p>\n<h1>Details</h1>\n<ul>\n<li>a</li>\n<li>b </li>\n</ul>\n<p id=\"para\">Kept. </p>\n</div>"
+    );
+
+    let (document, _) = expand("![[Note#Details]]\n\n![[Note#^item]]", &[("Note", note)]);
+    let html = document.to_html();
+    assert!(html.starts_with(
+        "<div class=\"internal-embed markdown-embed\" src=\"Note#Details\">\n<h1>Details</h1>"
+    ));
+    assert!(html.ends_with(
+        "<div class=\"internal-embed markdown-embed\" src=\"Note#^item\">\n<ul>\n<li>b </li>\n</ul>\n</div>"
+    ));
+}
+
+#[test]
+fn records_provenance() {
+    let host = "Before\n![[Note]]\nafter";
+    let (document, _) = expand(host, &[("Note", "Grafted **text**")]);
+    let tree = &document.tree;
+    let mut children = std::iter::successors(tree.get_first_child(0), |&id| tree.get_next(id));
+    let (first, transclusion, last) = (
+        children.next().unwrap(),
+        children.next().unwrap(),
+        children.next().unwrap(),
+    );
+    assert!(matches!(tree[first].body, MarkdownNode::Paragraph));
+    assert_eq!(tree[first].span.end, 6);
+    let MarkdownNode::Transclusion(payload) = &tree[transclusion].body else {
+        panic!("expected a transclusion");
+    };
+    assert_eq!(payload.note, "Note.md");
+    assert_eq!(payload.path, "Note");
+    assert_eq!(
+        &host[tree[transclusion].span.start as usize..tree[transclusion].span.end as usize],
+        "![[Note]]"
+    );
+    // 嫁接节点取嵌入在宿主中的区间，原区间记录在载荷中
+    assert_eq!(payload.span, SourceSpan::new(0, 16));
+    let paragraph = tree.get_first_child(transclusion).unwrap();
+    assert_eq!(tree[paragraph].span, tree[transclusion].span);
+    for id in tree.descendants(transclusion) {
+        assert_eq!(tree[id].span, tree[transclusion].span);
+    }
+    assert!(matches!(tree[last].body, MarkdownNode::Paragraph));
+    assert_eq!(
+        document.to_html(),
+        "<p>Before</p>\n<div class=\"internal-embed markdown-embed\" src=\"Note\">\n<p>Grafted <strong>text</strong></p>\n</div>\n<p>after</p>"
+    );
+}
+
+#[test]
+fn expands_recursively_and_breaks_cycles() {
+    let notes = [
+        ("A", "A1\n\n![[B]]"),
+        ("B", "B1\n\n![[A]]\n\n![[C]]"),
+        ("C", "C1"),
+    ];
+    let (document, issues) = expand("![[A]]", &notes);
+    assert_eq!(issues, [TransclusionIssueKind::Cycle]);
+    let html = document.to_html();
+    assert!(html.contains("<p>A1</p>"));
+    assert!(html.contains("<p>B1</p>"));
+    assert!(html.contains("<p>C1</p>"));
+    assert!(html.contains("<span class=\"internal-embed\" src=\"A\"></span>"));
+
+    let (_, issues) = expand("![[Host]]", &[("Host", "![[host]]")]);
+    assert_eq!(issues, [TransclusionIssueKind::NotFound]);
+    let mut document = Parser::parse_string("![[A]]".to_string(), options()).unwrap();
+    let issues = Transcluder::new(options())
+        .with_max_depth(1)
+        .expand(&mut document, "host.md", loader(&notes))
+        .unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, TransclusionIssueKind::DepthLimit);
+    assert_eq!(issues[0].note, "A.md");
+    assert_eq!(issues[0].path, "B");
+}
+
+#[test]
+fn expands_only_the_referenced_section() {
+    let notes = [
+        (
+            "Note",
+            "# A\n\nIntro ![[Inner]]\ntail\n\n# B\n\n![[Missing]]\n\n![[Note]]",
+        ),
+        ("Inner", "Inner text"),
+    ];
+    let mut document = Parser::parse_string("![[Note#A]]".to_string(), options()).unwrap();
+    let mut load = loader(&notes);
+    let mut loaded = Vec::new();
+    let issues = Transcluder::new(options())
+        .expand(&mut document, "host.md", |path: &str, from: &str| {
+            loaded.push(path.to_string());
+            load(path, from)
+        })
+        .unwrap();
+    // 未选中的 B 节中的缺失与循环嵌入不加载也不报告
+    assert!(issues.is_empty(), "{issues:?}");
+    assert_eq!(loaded, ["Note", "Inner"]);
+    assert_eq!(
+        document.to_html(),
+        "<div class=\"internal-embed markdown-embed\" src=\"Note#A\">\n<h1>A</h1>\n<p>Intro </p>\n<div class=\"internal-embed markdown-embed\" src=\"Inner\">\n<p>Inner text</p>\n</div>\n<p>tail</p>\n</div>"
+    );
+}
+
+#[test]
+fn reports_unexpandable_embeds() {
+    let (document, issues) = expand(
+        "![[Missing]]\n\n![[Note#Nope]]\n\n# ![[Note]]\n\n![[image.png]]",
+        &[("Note", "Text")],
+    );
+    assert_eq!(
+        issues,
+        [
+            TransclusionIssueKind::NotFound,
+            TransclusionIssueKind::ReferenceNotFound,
+            TransclusionIssueKind::InlineContext,
+        ]
+    );
+    assert!(document.to_html().contains("<img src=\"image.png\""));
+}
+
+#[test]
+fn renumbers_footnotes_and_deduplicates_ids() {
+    let host = "Host[^a] ^dup\n\n![[Note]]\n\nEnd[^b]\n\n[^a]: Host A\n[^b]: Host B";
+    let note = "Note[^a] ^dup\n\n[^a]: Note A";
+    let (document, _) = expand(host, &[("Note", note)]);
+    assert_eq!(
+        document.to_html(),
+        "<p id=\"dup\">Host<a href=\"#cont-fn-a\" id=\"cont-fn-ref-a\">[1]</a> </p>\n<div class=\"internal-embed markdown-embed\" src=\"Note\">\n<p id=\"dup-1\">Note<a href=\"#cont-fn-a-1\" id=\"cont-fn-ref-a-1\">[2]</a> </p>\n</div>\n<p>End<a href=\"#cont-fn-b\" id=\"cont-fn-ref-b\">[3]</a></p>\n<section>\n<h2>Footnotes</h2>\n<ol>\n<li id=\"cont-fn-a\">\n<p>Host A<a href=\"#cont-fn-ref-a\">↩</a></p>\n</li>\n<li id=\"cont-fn-a-1\">\n<p>Note A<a href=\"#cont-fn-ref-a-1\">↩</a></p>\n</li>\n<li id=\"cont-fn-b\">\n<p>Host B<a href=\"#cont-fn-ref-b\">↩</a></p>\n</li>\n</ol>\n</section>"
+    );
+
+    // 宿主没有脚注时新建脚注列表
+    let (document, _) = expand("![[Note]]", &[("Note", note)]);
+    assert!(document.to_html().ends_with(
+        "<li id=\"cont-fn-a\">\n<p>Note A<a href=\"#cont-fn-ref-a\">↩</a></p>\n</li>\n</ol>\n</section>"
+    ));
+}
+
+#[test]
+fn grafted_spans_stay_inside_a_shorter_host() {
+    let filler = "x".repeat(200);
+    let notes = [
+        format!("{filler}\n\n<div>\n\n*hi*\n\n</div>\n"),
+        format!("{filler}\n\n<details>\n\n*hi*\n\n</details>\n"),
+        format!("{filler}\n\n<Card>\n\n*hi*\n\n</Card>\n"),
+    ];
+    for note in &notes {
+        for options in [options(), options().enabled_jsx_like_component()] {
+            let mut document =
+                Parser::parse_string("![[Note]]".to_string(), options.clone()).unwrap();
+            Transcluder::new(options)
+                .expand(&mut document, "host.md", loader(&[("Note", note)]))
+                .unwrap();
+            let length = document.source().len() as u32;
+            for id in document.tree.descendants(0) {
+                assert!(document.tree[id].span.end <= length);
+            }
+            assert!(document.to_html().contains("<em>hi</em>"));
+            document.statistics();
+            document.cst();
+            document.lint(&LintConfig::default());
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use ptdgrp_markdown::reference::Reference;
+use ptdgrp_markdown::{Location, Vault, VaultLinkKind};
+
+fn vault() -> Vault {
+    let mut vault = Vault::new();
+    vault
+        .insert(
+            "index.md",
+            "[[Alpha#Goals]] ![[diagram.png]] [[beta|B]]\n[beta](projects/beta.md#^x) [web](https://example.com) [[Nowhere]]",
+        )
+        .unwrap();
+    vault
+        .insert(
+            "projects/alpha.md",
+            "---\naliases: [First]\n---\n[[#Goals]] [[../index]]",
+        )
+        .unwrap();
+    vault.insert("projects/beta.md", "[[./alpha]]").unwrap();
+    vault.insert("archive/beta.md", "Old").unwrap();
+    vault.insert("lonely.md", "[[lonely]]").unwrap();
+    vault.insert_attachment("assets/diagram.png");
+    vault
+}
+
+#[test]
+fn records_outgoing_links() {
+    let vault = vault();
+    let outgoing = vault.outgoing("index.md");
+    let summary = outgoing
+        .iter()
+        .map(|edge| (edge.link.kind, edge.link.path.as_str(), edge.target))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (VaultLinkKind::Wikilink, "Alpha", Some("projects/alpha.md")),
+            (
+                VaultLinkKind::Embed,
+                "diagram.png",
+                Some("assets/diagram.png")
+            ),
+            (VaultLinkKind::Wikilink, "beta", Some("archive/beta.md")),
+            (
+                VaultLinkKind::Markdown,
+                "projects/beta.md",
+                Some("projects/beta.md")
+            ),
+            (VaultLinkKind::Wikilink, "Nowhere", None),
+        ]
+    );
+    assert_eq!(
+        outgoing[0].link.reference,
+        Some(Reference::Heading("Goals".to_string()))
+    );
+    assert_eq!(
+        outgoing[3].link.reference,
+        Some(Reference::BlockId("x".to_string()))
+    );
+    assert_eq!(outgoing[3].link.location, Location::new(2, 1));
+    assert_eq!(outgoing[0].link.span.start, 0);
+    assert_eq!(outgoing[0].link.span.end, 15);
+
+    let alpha = vault.outgoing("projects/alpha.md");
+    assert_eq!(alpha[0].link.path, "");
+    assert_eq!(alpha[0].target, Some("projects/alpha.md"));
+    assert_eq!(alpha[1].target, Some("index.md"));
+    assert!(vault.outgoing("missing.md").is_empty());
+}
+
+#[test]
+fn resolves_targets_like_obsidian() {
+    let vault = vault();
+    // 同名文件：优先同目录，其次最短路径
+    assert_eq!(
+        vault.resolve("projects/alpha.md", "beta"),
+        Some("projects/beta.md")
+    );
+    assert_eq!(vault.resolve("index.md", "BETA"), Some("archive/beta.md"));
+    assert_eq!(
+        vault.resolve("index.md", "archive/beta"),
+        Some("archive/beta.md")
+    );
+    assert_eq!(
+        vault.resolve("index.md", "/projects/beta.md"),
+        Some("projects/beta.md")
+    );
+    assert_eq!(
+        vault.resolve("index.md", "first"),
+        Some("projects/alpha.md")
+    );
+    assert_eq!(vault.resolve("index.md", "jects/beta"), None);
+    assert_eq!(vault.resolve("index.md", "../index"), None);
+    assert_eq!(
+        vault.resolve("projects/beta.md", "../archive/beta"),
+        Some("archive/beta.md")
+    );
+}
+
+#[test]
+fn answers_backlinks_unresolved_and_orphans() {
+    let mut vault = vault();
+    let backlinks = vault
+        .backlinks("projects/alpha.md")
+        .iter()
+        .map(|edge| edge.source)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        backlinks,
+        ["index.md", "projects/alpha.md", "projects/beta.md"]
+    );
+    let unresolved = vault.unresolved();
+    assert_eq!(unresolved.len(), 1);
+    assert_eq!(unresolved[0].source, "index.md");
+    assert_eq!(unresolved[0].link.path, "Nowhere");
+    assert_eq!(vault.orphans(), ["lonely.md"]);
+
+    // 删除与替换笔记后索引同步更新
+    vault.remove("archive/beta.md");
+    assert_eq!(vault.resolve("index.md", "beta"), Some("projects/beta.md"));
+    vault
+        .insert("lonely.md", "[[Nowhere]] ![[chart.svg]]")
+        .unwrap();
+    assert_eq!(vault.unresolved().len(), 3);
+    vault.insert("nowhere.md", "").unwrap();
+    assert_eq!(vault.unresolved().len(), 1);
+    assert_eq!(vault.backlinks("nowhere.md").len(), 2);
+    vault.insert_attachment("chart.svg");
+    assert!(vault.unresolved().is_empty());
+    assert!(vault.orphans().is_empty());
+    vault.remove("chart.svg");
+    assert_eq!(vault.unresolved()[0].link.path, "chart.svg");
+    assert!(vault.backlinks("chart.svg").is_empty());
+    vault.insert_attachment("chart.svg");
+    assert!(!vault.remove("archive/beta.md"));
+    assert_eq!(
+        vault.notes().collect::<Vec<_>>(),
+        [
+            "index.md",
+            "lonely.md",
+            "nowhere.md",
+            "projects/alpha.md",
+            "projects/beta.md"
+        ]
+    );
+}
@@ -1,12 +1,27 @@
 import type {
   AstData,
   AstNode,
+  ColumnEncoding,
+  CstNode,
   Document as WasmDocument,
+  DocumentStatistics,
+  FrontmatterDiagnostic,
+  FrontmatterFormatOrNull,
   FrontmatterOrNull,
+  FrontmatterSpan,
   HeadingMatch,
+  LinkDiagnostic,
   LinkMatch,
+  LinkReferenceDefinition,
+  LintConfig,
+  LintWarning,
+  Location,
+  NodeLocation,
+  NodeSyntax,
   ParserOptions,
   SemanticTarget,
+  StatisticsConfig,
+  TagOccurrence,
   Tags,
 } from "./markdown_binding";
 
@@ -124,11 +139,51 @@ export class Document {
     return this.#inner.tags;
   }
 
+  get aliases(): string[] {
+    this.#requireLive();
+    return this.#inner.aliases;
+  }
+
+  get cssclasses(): string[] {
+    this.#requireLive();
+    return this.#inner.cssclasses;
+  }
+
+  get tagOccurrences(): TagOccurrence[] {
+    this.#requireLive();
+    return this.#inner.tag_occurrences;
+  }
+
+  get linkDiagnostics(): LinkDiagnostic[] {
+    this.#requireLive();
+    return this.#inner.link_diagnostics;
+  }
+
+  get linkDefinitions(): LinkReferenceDefinition[] {
+    this.#requireLive();
+    return this.#inner.link_definitions;
+  }
+
   get frontmatter(): FrontmatterOrNull {
     this.#requireLive();
     return this.#inner.frontmatter;
   }
 
+  get frontmatterFormat(): FrontmatterFormatOrNull {
+    this.#requireLive();
+    return this.#inner.frontmatter_format;
+  }
+
+  get frontmatterSpans(): FrontmatterSpan[] {
+    this.#requireLive();
+    return this.#inner.frontmatter_spans;
+  }
+
+  get frontmatterDiagnostics(): FrontmatterDiagnostic[] {
+    this.#requireLive();
+    return this.#inner.frontmatter_diagnostics;
+  }
+
   get totalNodes(): number {
     this.#requireLive();
     return this.#inner.total_nodes;
@@ -149,6 +204,61 @@ export class Document {
     return this.#inner.query_links();
   }
 
+  lint(config?: LintConfig): LintWarning[] {
+    this.#requireLive();
+    return this.#inner.lint(config);
+  }
+
+  statistics(config?: StatisticsConfig): DocumentStatistics {
+    this.#requireLive();
+    return this.#inner.statistics(config);
+  }
+
+  nodeSyntax(nodeId: number): NodeSyntax | null {
+    this.#requireLive();
+    return this.#inner.node_syntax(nodeId);
+  }
+
+  cst(): CstNode {
+    this.#requireLive();
+    return this.#inner.cst();
+  }
+
+  nodeAt(offset: number): number | undefined {
+    this.#requireLive();
+    return this.#inner.node_at(offset);
+  }
+
+  nodesInRange(start: number, end: number): Uint32Array {
+    this.#requireLive();
+    return this.#inner.nodes_in_range(start, end);
+  }
+
+  enclosingBlock(offset: number): number | undefined {
+    this.#requireLive();
+    return this.#inner.enclosing_block(offset);
+  }
+
+  locationAt(offset: number, encoding?: ColumnEncoding): Location {
+    this.#requireLive();
+    return this.#inner.location_at(offset, encoding);
+  }
+
+  nodeLocations(encoding?: ColumnEncoding): NodeLocation[] {
+    this.#requireLive();
+    return this.#inner.node_locations(encoding);
+  }
+
+  utf16Offset(offset: number): number {
+    this.#requireLive();
+    return this.#inner.utf16_offset(offset);
+  }
+
+  byteOffsetFromUtf16(offset: number): number {
+    this.#requireLive();
+    return this.#inner.byte_offset_from_utf16(offset);
+  }
+
   continueParse(): void {
     this.#requireLive();
     this.#inner.continue_parse();
@@ -13,15 +13,33 @@ export const version = api.version;
 
 export type {
   AstNode,
+  ColumnEncoding,
+  CstNode,
+  CstToken,
+  CstTokenKind,
+  DocumentStatistics,
   Frontmatter,
+  FrontmatterDiagnostic,
+  FrontmatterFormat,
+  FrontmatterFormatOrNull,
   FrontmatterOrNull,
+  FrontmatterSpan,
   HeadingMatch,
+  LinkDiagnostic,
   LinkMatch,
+  LinkReferenceDefinition,
+  LinkSyntax,
+  LintConfig,
+  LintWarning,
   Location,
   Node,
+  NodeLocation,
+  NodeSyntax,
   ParserOptions,
   Reference,
   SemanticTarget,
+  StatisticsConfig,
+  TagOccurrence,
   Tags,
   YamlValue,
 } from "./markdown_binding";
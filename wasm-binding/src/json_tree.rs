@@ -90,7 +90,12 @@ fn write_content(doc: &Document, body: &MarkdownNode, out: &mut Vec<u8>) -> serd
         | MarkdownNode::ThematicBreak
         | MarkdownNode::Strikethrough
         | MarkdownNode::Highlighting
+        | MarkdownNode::Superscript
+        | MarkdownNode::Subscript
+        | MarkdownNode::Insert
+        | MarkdownNode::Spoiler
         | MarkdownNode::FootnoteList
+        | MarkdownNode::DefinitionTerm
         | MarkdownNode::TableHead
         | MarkdownNode::TableBody
         | MarkdownNode::TableRow
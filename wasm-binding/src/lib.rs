@@ -2,10 +2,11 @@ use js_sys::{Object, Reflect, Uint32Array, Uint8Array};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+use ptdgrp_markdown::ast::frontmatter::FrontMatterFormat;
 use ptdgrp_markdown::ast::link::Link;
 use ptdgrp_markdown::{
-    Document as MarkdownDocument, MarkdownNode, Node, ParseError, Parser, ParserOptions,
-    ParserPhaseSnapshot,
+    ColumnEncoding, Document as MarkdownDocument, LintConfig, LintRule, MarkdownNode, Node,
+    ParseError, Parser, ParserOptions, ParserPhaseSnapshot, StatisticsConfig,
 };
 
 mod json_tree;
@@ -19,6 +20,8 @@ extern "C" {
     pub type Frontmatter;
     #[wasm_bindgen(typescript_type = "FrontmatterOrNull")]
     pub type FrontmatterOrNull;
+    #[wasm_bindgen(typescript_type = "FrontmatterFormatOrNull")]
+    pub type FrontmatterFormatOrNull;
     // #[wasm_bindgen(typescript_type = "Location")]
     // pub type Location;
     #[wasm_bindgen(typescript_type = "Tags")]
@@ -38,6 +41,44 @@ extern "C" {
 
     #[wasm_bindgen(typescript_type = "LinkMatch[]")]
     pub type TLinkMatches;
+
+    #[wasm_bindgen(typescript_type = "AbbreviationDefinition[]")]
+    pub type TAbbreviationDefinitions;
+    #[wasm_bindgen(typescript_type = "LinkReferenceDefinition[]")]
+    pub type TLinkReferenceDefinitions;
+    #[wasm_bindgen(typescript_type = "NodeSyntax | null")]
+    pub type TNodeSyntaxOrNull;
+    #[wasm_bindgen(typescript_type = "CstNode")]
+    pub type TCstNode;
+    #[wasm_bindgen(typescript_type = "Location")]
+    pub type TLocation;
+    #[wasm_bindgen(typescript_type = "NodeLocation[]")]
+    pub type TNodeLocations;
+    #[wasm_bindgen(typescript_type = "StatisticsConfig")]
+    pub type TStatisticsConfig;
+    #[wasm_bindgen(typescript_type = "DocumentStatistics")]
+    pub type TDocumentStatistics;
+
+    #[wasm_bindgen(typescript_type = "string[]")]
+    pub type TStrings;
+
+    #[wasm_bindgen(typescript_type = "FrontmatterSpan[]")]
+    pub type TFrontmatterSpans;
+
+    #[wasm_bindgen(typescript_type = "FrontmatterDiagnostic[]")]
+    pub type TFrontmatterDiagnostics;
+
+    #[wasm_bindgen(typescript_type = "TagOccurrence[]")]
+    pub type TTagOccurrences;
+
+    #[wasm_bindgen(typescript_type = "LinkDiagnostic[]")]
+    pub type TLinkDiagnostics;
+
+    #[wasm_bindgen(typescript_type = "LintConfig")]
+    pub type TLintConfig;
+
+    #[wasm_bindgen(typescript_type = "LintWarning[]")]
+    pub type TLintWarnings;
 }
 
 /// Parsed markdown document with AST and metadata
@@ -50,7 +91,7 @@ pub struct Document {
 }
 
 const NO_NODE: u32 = u32::MAX;
-const NODE_KIND_NAMES: [&str; 32] = [
+const NODE_KIND_NAMES: [&str; 43] = [
     "document",
     "frontmatter",
     "paragraph",
@@ -83,6 +124,17 @@ const NODE_KIND_NAMES: [&str; 32] = [
     "math",
     "callout",
     "html",
+    "definition-list",
+    "definition-term",
+    "definition-details",
+    "superscript",
+    "subscript",
+    "insert",
+    "spoiler",
+    "abbreviation",
+    "ruby",
+    "transclusion",
+    "link-reference-definition",
 ];
 
 struct NodeArrays {
@@ -187,6 +239,17 @@ fn node_kind_code(node: &MarkdownNode) -> u8 {
         MarkdownNode::Math(..) => 29,
         MarkdownNode::Callout(..) => 30,
         MarkdownNode::Html(..) => 31,
+        MarkdownNode::DefinitionList(..) => 32,
+        MarkdownNode::DefinitionTerm => 33,
+        MarkdownNode::DefinitionDetails(..) => 34,
+        MarkdownNode::Superscript => 35,
+        MarkdownNode::Subscript => 36,
+        MarkdownNode::Insert => 37,
+        MarkdownNode::Spoiler => 38,
+        MarkdownNode::Abbreviation(..) => 39,
+        MarkdownNode::Ruby(..) => 40,
+        MarkdownNode::Transclusion(..) => 41,
+        MarkdownNode::LinkReferenceDefinition(..) => 42,
     }
 }
 
@@ -230,6 +293,26 @@ struct WasmParserOptions {
     normalize_chinese_punctuation: bool,
     /// Enable CJK-friendly delimiter rules.
     cjk_friendly_delimiters: bool,
+    /// Enable definition lists.
+    definition_list: bool,
+    /// Enable `^superscript^`.
+    superscript: bool,
+    /// Enable `~subscript~`.
+    subscript: bool,
+    /// Enable `++insert++`.
+    insert: bool,
+    /// Enable `||spoiler||`.
+    spoiler: bool,
+    /// Enable `*[abbr]: expansion` abbreviations.
+    abbreviation: bool,
+    /// Enable `{漢字|かんじ}` ruby annotations.
+    ruby: bool,
+    /// Keep link reference definitions as AST nodes.
+    link_reference_definitions: bool,
+    /// Record which syntax each node was written with.
+    source_fidelity: bool,
+    /// Read a leading JSON object as frontmatter.
+    leading_json_frontmatter: bool,
     /// Optional input size guard (bytes).
     max_input_bytes: Option<usize>,
     /// Optional node-count guard.
@@ -238,6 +321,8 @@ struct WasmParserOptions {
     cjk_nouns: Vec<String>,
     /// Read extra CJK nouns from frontmatter field.
     cjk_nouns_from_frontmatter: Option<String>,
+    /// Custom emoji shortcodes resolved before the built-in table.
+    emoji_aliases: std::collections::HashMap<String, String>,
 }
 
 /// Converts wasm options payload into core parser options and parse mode.
@@ -268,6 +353,36 @@ fn build_parser_options(input: WasmParserOptions) -> (ParserOptions, ParseMode)
     if input.cjk_friendly_delimiters {
         options = options.enabled_cjk_friendly_delimiters();
     }
+    if input.definition_list {
+        options = options.enabled_definition_list();
+    }
+    if input.superscript {
+        options = options.enabled_superscript();
+    }
+    if input.subscript {
+        options = options.enabled_subscript();
+    }
+    if input.insert {
+        options = options.enabled_insert();
+    }
+    if input.spoiler {
+        options = options.enabled_spoiler();
+    }
+    if input.abbreviation {
+        options = options.enabled_abbreviation();
+    }
+    if input.ruby {
+        options = options.enabled_ruby();
+    }
+    if input.link_reference_definitions {
+        options = options.enabled_link_reference_definitions();
+    }
+    if input.source_fidelity {
+        options = options.enabled_source_fidelity();
+    }
+    if input.leading_json_frontmatter {
+        options = options.enabled_leading_json_frontmatter();
+    }
     if let Some(max_input_bytes) = input.max_input_bytes {
         options = options.with_max_input_bytes(max_input_bytes);
     }
@@ -280,6 +395,9 @@ fn build_parser_options(input: WasmParserOptions) -> (ParserOptions, ParseMode)
     if let Some(field) = input.cjk_nouns_from_frontmatter {
         options = options.with_cjk_nouns_from_frontmatter(field)
     }
+    if !input.emoji_aliases.is_empty() {
+        options = options.with_emoji_aliases(input.emoji_aliases);
+    }
     (options, parse_mode)
 }
 
@@ -344,6 +462,82 @@ fn parser_options_from_js(options: TParserOptions) -> Result<(ParserOptions, Par
     Ok(build_parser_options(parsed))
 }
 
+/// markdownlint 风格配置：`default`/规则 ID/别名映射到布尔值，
+/// 或映射到对象（视为启用，`MD013` 可携带 `line_length`）
+fn lint_config_from_js(config: Option<TLintConfig>) -> Result<LintConfig, JsValue> {
+    let mut lint_config = LintConfig::default();
+    let Some(config) = config else {
+        return Ok(lint_config);
+    };
+    let raw = serde_wasm_bindgen::from_value::<serde_json::Value>(config.unchecked_into())
+        .map_err(|err| value_error_to_js("invalid lint config", err))?;
+    let serde_json::Value::Object(entries) = raw else {
+        return Err(JsValue::from_str("invalid lint config: expected an object"));
+    };
+    for (name, value) in &entries {
+        let enabled = match value {
+            serde_json::Value::Bool(enabled) => *enabled,
+            serde_json::Value::Object(settings) => {
+                let length = settings.get("line_length").and_then(|it| it.as_u64());
+                if let (Some(LintRule::LineLength), Some(length)) =
+                    (LintRule::from_name(name), length)
+                {
+                    lint_config = lint_config.with_line_length(length as usize);
+                }
+                true
+            }
+            _ => {
+                return Err(JsValue::from_str(&format!(
+                    "invalid lint config: `{name}` must be a boolean or an object"
+                )));
+            }
+        };
+        if !lint_config.set(name, enabled) {
+            return Err(JsValue::from_str(&format!(
+                "invalid lint config: unknown rule `{name}`"
+            )));
+        }
+    }
+    Ok(lint_config)
+}
+
+/// 统计配置：未给出的字段使用 `StatisticsConfig::default()` 的值
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct WasmStatisticsConfig {
+    code: bool,
+    math: bool,
+    frontmatter: bool,
+    html: bool,
+    words_per_minute: Option<u32>,
+    cjk_characters_per_minute: Option<u32>,
+}
+
+fn statistics_config_from_js(
+    config: Option<TStatisticsConfig>,
+) -> Result<StatisticsConfig, JsValue> {
+    let Some(config) = config else {
+        return Ok(StatisticsConfig::default());
+    };
+    let raw = serde_wasm_bindgen::from_value::<serde_json::Value>(config.unchecked_into())
+        .map_err(|err| value_error_to_js("invalid statistics config", err))?;
+    let parsed = serde_json::from_value::<WasmStatisticsConfig>(raw)
+        .map_err(|err| value_error_to_js("invalid statistics config", err))?;
+    let mut statistics_config = StatisticsConfig::default()
+        .with_code(parsed.code)
+        .with_math(parsed.math)
+        .with_frontmatter(parsed.frontmatter)
+        .with_html(parsed.html);
+    if let Some(words_per_minute) = parsed.words_per_minute {
+        statistics_config = statistics_config.with_words_per_minute(words_per_minute);
+    }
+    if let Some(cjk_characters_per_minute) = parsed.cjk_characters_per_minute {
+        statistics_config =
+            statistics_config.with_cjk_characters_per_minute(cjk_characters_per_minute);
+    }
+    Ok(statistics_config)
+}
+
 #[wasm_bindgen]
 impl Document {
     /// Private transport used by the published JS wrapper to materialize an
@@ -359,7 +553,8 @@ impl Document {
             return Err(JsValue::from_str("failed to initialize AST data cache"));
         };
         let object = Object::new();
-        let kind_names = serialize_to_js(&NODE_KIND_NAMES, "failed to serialize node kinds")?;
+        let kind_names =
+            serialize_to_js(NODE_KIND_NAMES.as_slice(), "failed to serialize node kinds")?;
         let set = |name: &str, property_value: &JsValue| -> Result<(), JsValue> {
             let written = Reflect::set(&object, &JsValue::from_str(name), property_value)?;
             if !written {
@@ -437,6 +632,177 @@ impl Document {
         Ok(serialize_to_js(&tags, "failed to serialize document tags")?.unchecked_into::<Tags>())
     }
 
+    /// Returns the Obsidian `aliases` frontmatter property.
+    /// 返回 Obsidian frontmatter 中的 `aliases` 属性
+    #[wasm_bindgen(getter)]
+    pub fn aliases(&self) -> Result<TStrings, JsValue> {
+        Ok(
+            serialize_to_js(&self.inner.aliases(), "failed to serialize aliases")?
+                .unchecked_into::<TStrings>(),
+        )
+    }
+
+    /// Returns the Obsidian `cssclasses` frontmatter property.
+    /// 返回 Obsidian frontmatter 中的 `cssclasses` 属性
+    #[wasm_bindgen(getter)]
+    pub fn cssclasses(&self) -> Result<TStrings, JsValue> {
+        Ok(
+            serialize_to_js(&self.inner.cssclasses(), "failed to serialize cssclasses")?
+                .unchecked_into::<TStrings>(),
+        )
+    }
+
+    /// Returns inline and frontmatter tags with their source spans.
+    /// 返回行内与 frontmatter 标签及其源码区间
+    #[wasm_bindgen(getter)]
+    pub fn tag_occurrences(&self) -> Result<TTagOccurrences, JsValue> {
+        Ok(serialize_to_js(
+            &self.inner.tag_occurrences(),
+            "failed to serialize tag occurrences",
+        )?
+        .unchecked_into::<TTagOccurrences>())
+    }
+
+    /// Returns broken reference links, footnotes, same-note references and URLs.
+    /// 返回未定义的引用链接与脚注、笔记内失效的引用以及格式错误的 URL
+    #[wasm_bindgen(getter)]
+    pub fn link_diagnostics(&self) -> Result<TLinkDiagnostics, JsValue> {
+        Ok(serialize_to_js(
+            &self.inner.check_links(),
+            "failed to serialize link diagnostics",
+        )?
+        .unchecked_into::<TLinkDiagnostics>())
+    }
+
+    /// Runs the markdownlint-compatible rules and returns warnings with fixes.
+    /// 运行与 markdownlint 兼容的规则，返回警告及自动修复
+    pub fn lint(&self, config: Option<TLintConfig>) -> Result<TLintWarnings, JsValue> {
+        let config = lint_config_from_js(config)?;
+        Ok(serialize_to_js(
+            &self.inner.lint(&config),
+            "failed to serialize lint warnings",
+        )?
+        .unchecked_into::<TLintWarnings>())
+    }
+
+    /// Counts words (each CJK ideograph as one), characters, blocks, and reading time.
+    /// 统计字数（每个 CJK 表意文字计一词）、字符数、块数量与阅读时间
+    pub fn statistics(
+        &self,
+        config: Option<TStatisticsConfig>,
+    ) -> Result<TDocumentStatistics, JsValue> {
+        let config = statistics_config_from_js(config)?;
+        Ok(serialize_to_js(
+            &self.inner.statistics_with(&config),
+            "failed to serialize statistics",
+        )?
+        .unchecked_into::<TDocumentStatistics>())
+    }
+
+    /// Returns abbreviation definitions in document order.
+    /// 按文档顺序返回缩写定义
+    #[wasm_bindgen(getter)]
+    pub fn abbreviations(&self) -> Result<TAbbreviationDefinitions, JsValue> {
+        Ok(serialize_to_js(
+            self.inner.abbreviations(),
+            "failed to serialize abbreviation definitions",
+        )?
+        .unchecked_into::<TAbbreviationDefinitions>())
+    }
+
+    /// Returns link reference definitions in document order, one per label.
+    /// 按文档顺序返回链接引用定义，同名标签仅保留首个定义
+    #[wasm_bindgen(getter)]
+    pub fn link_definitions(&self) -> Result<TLinkReferenceDefinitions, JsValue> {
+        let mut definitions = self
+            .inner
+            .link_definitions()
+            .into_values()
+            .collect::<Vec<_>>();
+        definitions.sort_by_key(|definition| definition.span.start);
+        Ok(serialize_to_js(
+            &definitions,
+            "failed to serialize link reference definitions",
+        )?
+        .unchecked_into::<TLinkReferenceDefinitions>())
+    }
+
+    /// Returns how a node was written; requires the `source_fidelity` option.
+    /// 返回节点的书写形式，需启用 `source_fidelity`
+    pub fn node_syntax(&self, node_id: u32) -> Result<TNodeSyntaxOrNull, JsValue> {
+        let value = match self.inner.node_syntax(node_id as usize) {
+            Some(syntax) => serialize_to_js(syntax, "failed to serialize node syntax")?,
+            None => JsValue::NULL,
+        };
+        Ok(value.unchecked_into::<TNodeSyntaxOrNull>())
+    }
+
+    /// Returns the deepest node containing a UTF-8 byte offset.
+    /// 返回包含该 UTF-8 字节偏移的最深节点
+    pub fn node_at(&self, offset: u32) -> Option<u32> {
+        self.inner.node_at(offset as usize).map(|id| id as u32)
+    }
+
+    /// Returns the nodes overlapping a UTF-8 byte range, in document order.
+    /// 按文档顺序返回与 UTF-8 字节区间重叠的节点
+    pub fn nodes_in_range(&self, start: u32, end: u32) -> Vec<u32> {
+        self.inner
+            .nodes_in_range(start as usize, end as usize)
+            .into_iter()
+            .map(|id| id as u32)
+            .collect()
+    }
+
+    /// Returns the deepest block-level node containing a UTF-8 byte offset.
+    /// 返回包含该 UTF-8 字节偏移的最深块级节点
+    pub fn enclosing_block(&self, offset: u32) -> Option<u32> {
+        self.inner
+            .enclosing_block(offset as usize)
+            .map(|id| id as u32)
+    }
+
+    /// Returns the lossless concrete syntax tree of the source.
+    /// 返回覆盖全部源码字节的无损具体语法树
+    pub fn cst(&self) -> Result<TCstNode, JsValue> {
+        Ok(
+            serialize_to_js(&self.inner.cst(), "failed to serialize cst")?
+                .unchecked_into::<TCstNode>(),
+        )
+    }
+
+    /// Converts a UTF-8 byte offset into a one-based location; `encoding`
+    /// is `"utf-8"`, `"utf-16"` or `"utf-32"` (the default).
+    /// 将 UTF-8 字节偏移转换为指定列编码下的位置
+    pub fn location_at(&self, offset: u32, encoding: Option<String>) -> Result<TLocation, JsValue> {
+        let location = self
+            .inner
+            .location_at_with(offset as usize, column_encoding(encoding)?);
+        Ok(serialize_to_js(&location, "failed to serialize location")?
+            .unchecked_into::<TLocation>())
+    }
+
+    /// Returns the start and end locations of every node, root first.
+    /// 按文档顺序返回所有节点的起止位置
+    pub fn node_locations(&self, encoding: Option<String>) -> Result<TNodeLocations, JsValue> {
+        let locations = self.inner.node_locations(column_encoding(encoding)?);
+        Ok(
+            serialize_to_js(&locations, "failed to serialize node locations")?
+                .unchecked_into::<TNodeLocations>(),
+        )
+    }
+
+    /// Converts a UTF-8 byte offset into a JavaScript string index.
+    /// 将 UTF-8 字节偏移转换为 UTF-16 偏移
+    pub fn utf16_offset(&self, offset: u32) -> u32 {
+        self.inner.utf16_offset(offset as usize) as u32
+    }
+
+    /// Converts a JavaScript string index into a UTF-8 byte offset.
+    /// 将 UTF-16 偏移转换为 UTF-8 字节偏移
+    pub fn byte_offset_from_utf16(&self, offset: u32) -> u32 {
+        self.inner.offset_from_utf16(offset as usize) as u32
+    }
+
     /// Get total number of nodes in the AST
     /// 获取 AST 中的节点总数
     #[wasm_bindgen(getter)]
@@ -459,7 +825,7 @@ impl Document {
         if let Some(first_child_idx) = self.inner.tree.get_first_child(0) {
             if let MarkdownNode::FrontMatter(fm) = &self.inner.tree[first_child_idx].body {
                 return Ok(
-                    serialize_to_js(fm.as_ref(), "failed to serialize frontmatter")?
+                    serialize_to_js(&fm.data, "failed to serialize frontmatter")?
                         .unchecked_into::<FrontmatterOrNull>(),
                 );
             }
@@ -467,6 +833,48 @@ impl Document {
         Ok(JsValue::NULL.unchecked_into::<FrontmatterOrNull>())
     }
 
+    /// Get the frontmatter source format (`"yaml"`, `"toml"` or `"json"`) if present
+    /// 获取 frontmatter 的源格式（`"yaml"`、`"toml"` 或 `"json"`），不存在时为 null
+    #[wasm_bindgen(getter)]
+    pub fn frontmatter_format(&self) -> FrontmatterFormatOrNull {
+        if let Some(first_child_idx) = self.inner.tree.get_first_child(0) {
+            if let MarkdownNode::FrontMatter(fm) = &self.inner.tree[first_child_idx].body {
+                let format = match fm.format {
+                    FrontMatterFormat::Yaml => "yaml",
+                    FrontMatterFormat::Toml => "toml",
+                    FrontMatterFormat::Json => "json",
+                };
+                return JsValue::from_str(format).unchecked_into::<FrontmatterFormatOrNull>();
+            }
+        }
+        JsValue::NULL.unchecked_into::<FrontmatterFormatOrNull>()
+    }
+
+    /// Get the source ranges of every frontmatter key and value, empty when absent
+    /// 获取 frontmatter 中每个键与值的源码区间，不存在时为空数组
+    #[wasm_bindgen(getter)]
+    pub fn frontmatter_spans(&self) -> Result<TFrontmatterSpans, JsValue> {
+        let spans = self
+            .inner
+            .frontmatter()
+            .map_or(&[][..], |fm| fm.spans.as_slice());
+        Ok(
+            serialize_to_js(spans, "failed to serialize frontmatter spans")?
+                .unchecked_into::<TFrontmatterSpans>(),
+        )
+    }
+
+    /// Get diagnostics for a fenced frontmatter block that failed to parse
+    /// 获取围栏完整但解析失败的 frontmatter 的诊断信息
+    #[wasm_bindgen(getter)]
+    pub fn frontmatter_diagnostics(&self) -> Result<TFrontmatterDiagnostics, JsValue> {
+        Ok(serialize_to_js(
+            self.inner.frontmatter_diagnostics(),
+            "failed to serialize frontmatter diagnostics",
+        )?
+        .unchecked_into::<TFrontmatterDiagnostics>())
+    }
+
     /// Completes phase 2 parse when `parse_mode = "frontmatter_only"`.
     /// No-op if document is already fully parsed.
     /// 当 `parse_mode = "frontmatter_only"` 时完成第二阶段解析
@@ -674,6 +1082,18 @@ pub fn version() -> String {
     Parser::version().to_string()
 }
 
+/// 解析列编码名称，缺省为 Unicode 标量值
+fn column_encoding(name: Option<String>) -> Result<ColumnEncoding, JsValue> {
+    match name {
+        None => Ok(ColumnEncoding::default()),
+        Some(name) => ColumnEncoding::from_name(&name).ok_or_else(|| {
+            JsValue::from_str(&format!(
+                "invalid column encoding: {name}, expected utf-8, utf-16 or utf-32"
+            ))
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,10 +3,16 @@ use wasm_bindgen::prelude::wasm_bindgen;
 #[wasm_bindgen(typescript_custom_section)]
 const TYPESCRIPT_TYPE_CONST: &'static str = r##"
 /**
- * YAML 值类型，支持字符串、数字、布尔值、null 或嵌套数组
- * YAML value type, supports string, number, boolean, null or nested arrays
+ * YAML 值类型，支持字符串、数字、布尔值、null、嵌套数组或嵌套映射（保持键顺序）
+ * YAML value type, supports string, number, boolean, null, nested arrays or nested maps (key order preserved)
  */
-export type YamlValue = string | number | boolean | null | readonly YamlValue[];
+export type YamlValue =
+  | string
+  | number
+  | boolean
+  | null
+  | readonly YamlValue[]
+  | Map<string, YamlValue>;
 
 /**
  * Frontmatter 对象，键值对映射
@@ -20,6 +26,110 @@ export type Frontmatter = Map<string, YamlValue>;
  */
 export type FrontmatterOrNull = Frontmatter | null;
 
+/**
+ * Frontmatter 源格式
+ * Frontmatter source format
+ */
+export type FrontmatterFormat = "yaml" | "toml" | "json";
+
+/**
+ * Frontmatter format returned from document getter, nullable when absent.
+ * 文档 getter 返回的 Frontmatter 源格式；不存在时为 null
+ */
+export type FrontmatterFormatOrNull = FrontmatterFormat | null;
+
+/**
+ * Frontmatter 条目的源码区间（UTF-8 字节偏移，半开区间）
+ * Source ranges of a frontmatter entry (UTF-8 byte offsets, half-open)
+ */
+export interface FrontmatterSpan {
+    /** 点分路径，序列项使用从 0 开始的下标 / Dotted path; sequence items use zero-based indexes */
+    readonly path: string
+    /** 键的区间，序列项没有键 / Key range, missing for sequence items */
+    readonly key?: { readonly start: number; readonly end: number } | null
+    /** 值的区间，不含行尾注释 / Value range, excluding trailing comments */
+    readonly value: { readonly start: number; readonly end: number }
+}
+
+/**
+ * 围栏完整但无法解析的 frontmatter 的诊断
+ * Diagnostic for a fenced frontmatter block that failed to parse
+ */
+export interface FrontmatterDiagnostic {
+    /** 围栏对应的格式 / Format implied by the fence */
+    readonly format: FrontmatterFormat
+    /** 错误原因 / Reason, e.g. "duplicate mapping key" */
+    readonly message: string
+    /** 从错误位置到行尾的 UTF-8 字节区间 / UTF-8 byte range from the error to the end of its line */
+    readonly span: { readonly start: number; readonly end: number }
+    /** 错误位置（行列从 1 开始）/ Error position (1-based) */
+    readonly location: Location
+}
+
+/**
+ * 笔记中的一次标签出现
+ * One tag written in a note
+ */
+export interface TagOccurrence {
+    /** 原样的标签文本，不含 `#` / Tag text as written, without the leading `#` */
+    readonly tag: string
+    /** UTF-8 字节区间 / UTF-8 byte range */
+    readonly span: { readonly start: number; readonly end: number }
+    /** 标签来源 / Where the tag was written */
+    readonly source: "inline" | "front_matter"
+}
+
+/**
+ * 失效链接的诊断
+ * A broken link found in the document
+ */
+export interface LinkDiagnostic {
+    /** 诊断类别 / Diagnostic category */
+    readonly kind:
+        | "undefined_reference"
+        | "duplicate_definition"
+        | "undefined_footnote"
+        | "unused_footnote"
+        | "missing_target"
+        | "malformed_url"
+    /** 出错的标签、引用或 URL / The label, reference, or URL at fault */
+    readonly target: string
+    /** 错误描述 / Human-readable description */
+    readonly message: string
+    /** 链接或定义的 UTF-8 字节区间 / UTF-8 byte range of the link or definition */
+    readonly span: { readonly start: number; readonly end: number }
+    /** 起始位置（行列从 1 开始）/ Start position (1-based) */
+    readonly location: Location
+}
+
+/**
+ * markdownlint 风格的规则配置：`default`、规则 ID 或别名映射到布尔值或规则参数
+ * markdownlint-style rule configuration: `default`, rule IDs, or aliases mapped to a boolean or rule settings
+ */
+export type LintConfig = {
+    readonly [rule: string]: boolean | { readonly line_length?: number }
+}
+
+/**
+ * 规则违例
+ * A rule violation
+ */
+export interface LintWarning {
+    /** markdownlint 规则 ID / markdownlint rule ID, e.g. "MD013" */
+    readonly rule: string
+    /** 描述 / Human-readable description */
+    readonly message: string
+    /** UTF-8 字节区间 / UTF-8 byte range */
+    readonly span: { readonly start: number; readonly end: number }
+    /** 起始位置（行列从 1 开始）/ Start position (1-based) */
+    readonly location: Location
+    /** 自动修复 / Automatic fix, when available */
+    readonly fix: {
+        readonly span: { readonly start: number; readonly end: number }
+        readonly replacement: string
+    } | null
+}
+
 /**
  * 文档中的位置信息
  * Location information in the document
@@ -85,6 +195,104 @@ export interface HeadingMatch {
   readonly end_offset: number;
 }
 
+/** An abbreviation definition such as `*[HTML]: Hyper Text Markup Language`. */
+export interface AbbreviationDefinition {
+  readonly label: string;
+  readonly title: string;
+  /** UTF-8 source byte range of the definition line. */
+  readonly span: { readonly start: number; readonly end: number };
+}
+
+/** A link reference definition such as `[label]: /url "title"`. */
+export interface LinkReferenceDefinition {
+  /** Label as written between the brackets. */
+  readonly label: string;
+  readonly url: string;
+  readonly title: string | null;
+  /** UTF-8 source byte range of the definition. */
+  readonly span: { readonly start: number; readonly end: number };
+}
+
+/** How a link or image was written. */
+export type LinkSyntax = "inline" | "full" | "collapsed" | "shortcut" | "autolink" | "extended_autolink";
+
+/** Source syntax of a node, recorded with the `source_fidelity` option. */
+export type NodeSyntax =
+  | { readonly kind: "link" | "image"; readonly form: LinkSyntax }
+  | { readonly kind: "emphasis" | "strong"; readonly marker: "*" | "_" }
+  | { readonly kind: "code"; readonly backticks: number }
+  | { readonly kind: "hard_break"; readonly form: "backslash" | "spaces" }
+  | { readonly kind: "atx_heading"; readonly closing: number }
+  | { readonly kind: "list_item"; readonly marker: string };
+
+/** The category of a concrete syntax tree token. */
+export type CstTokenKind =
+  | "text"
+  | "whitespace"
+  | "indent"
+  | "line_ending"
+  | "blank_line"
+  | "block_quote_marker"
+  | "list_marker"
+  | "task_marker"
+  | "heading_marker"
+  | "fence"
+  | "delimiter_run"
+  | "syntax";
+
+/** A leaf of the concrete syntax tree. */
+export interface CstToken {
+  readonly kind: CstTokenKind;
+  /** UTF-8 source byte range of the token. */
+  readonly span: { readonly start: number; readonly end: number };
+}
+
+/** An AST node with every source byte it owns; tokens cover the source without gaps. */
+export interface CstNode {
+  readonly node_id: number;
+  /** UTF-8 source byte range, including container prefixes and markers. */
+  readonly span: { readonly start: number; readonly end: number };
+  readonly children: readonly (CstNode | CstToken)[];
+}
+
+/** How location columns are counted, named after LSP position encodings. */
+export type ColumnEncoding = "utf-8" | "utf-16" | "utf-32";
+
+/** Start and end location of a node. */
+export interface NodeLocation {
+  readonly node_id: number;
+  readonly start: Location;
+  readonly end: Location;
+}
+
+/** What `statistics` counts; code, math, frontmatter, and HTML are skipped by default. */
+export interface StatisticsConfig {
+  readonly code?: boolean;
+  readonly math?: boolean;
+  readonly frontmatter?: boolean;
+  readonly html?: boolean;
+  /** Reading speed for non-CJK words, 200 by default. */
+  readonly words_per_minute?: number;
+  /** Reading speed for CJK characters, 300 by default. */
+  readonly cjk_characters_per_minute?: number;
+}
+
+/** Word, character, and block counts of a document. */
+export interface DocumentStatistics {
+  /** Words, counting each CJK ideograph or kana as one word. */
+  readonly words: number;
+  readonly cjk_words: number;
+  readonly characters: number;
+  readonly characters_without_spaces: number;
+  readonly paragraphs: number;
+  readonly headings: number;
+  readonly links: number;
+  readonly images: number;
+  readonly code_blocks: number;
+  /** Estimated reading time in seconds, rounded up. */
+  readonly reading_seconds: number;
+}
+
 /** A link found without serializing the complete AST. */
 export interface LinkMatch {
   readonly node_id: number;
@@ -127,6 +335,26 @@ export interface ParserOptions {
     readonly normalize_chinese_punctuation?: boolean
     /** 启用 CJK 友好的分隔符规则 / Enable CJK-friendly delimiter rules */
     readonly cjk_friendly_delimiters?: boolean
+    /** 启用定义列表 / Enable definition lists */
+    readonly definition_list?: boolean
+    /** 启用上标 `^sup^` / Enable `^superscript^` */
+    readonly superscript?: boolean
+    /** 启用下标 `~sub~`（与 GFM 同时启用时删除线仅接受 `~~`）/ Enable `~subscript~` (strikethrough then requires `~~`) */
+    readonly subscript?: boolean
+    /** 启用插入文本 `++ins++` / Enable `++insert++` */
+    readonly insert?: boolean
+    /** 启用剧透文本 `||spoiler||` / Enable `||spoiler||` */
+    readonly spoiler?: boolean
+    /** 启用缩写 `*[HTML]: ...` / Enable `*[HTML]: ...` abbreviations */
+    readonly abbreviation?: boolean
+    /** 启用注音 `{漢字|かんじ}` / Enable `{漢字|かんじ}` ruby annotations */
+    readonly ruby?: boolean
+    /** 保留链接引用定义节点 / Keep link reference definitions as AST nodes */
+    readonly link_reference_definitions?: boolean
+    /** 记录节点的书写形式 / Record which syntax each node was written with */
+    readonly source_fidelity?: boolean
+    /** 将文档开头的 JSON 对象读作 frontmatter / Read a leading JSON object as frontmatter */
+    readonly leading_json_frontmatter?: boolean
     /** 输入大小限制（字节）/ Input size limit (bytes) */
     readonly max_input_bytes?: number
     /** 节点数量限制 / Node count limit */
@@ -135,6 +363,11 @@ export interface ParserOptions {
     readonly cjk_nouns?: readonly string[]
     /** 从 frontmatter 字段读取额外的 CJK 名词 / Read extra CJK nouns from frontmatter field */
     readonly cjk_nouns_from_frontmatter?: string
+    /**
+     * 自定义 emoji shortcode，优先于内置表；值写作 `:name:` 时指向内置 shortcode
+     * Custom emoji shortcodes resolved before the built-in table; a `:name:` value refers to a built-in shortcode
+     */
+    readonly emoji_aliases?: Readonly<Record<string, string>>
 }
 /**
  * 引用类型，用于 Obsidian 风格的内部链接
@@ -194,10 +427,17 @@ export interface DocumentNode extends Node{
     readonly kind: "document"
 }
 
-/** Frontmatter 节点（YAML 元数据）/ Frontmatter node (YAML metadata) */
+/** Frontmatter 节点（YAML、TOML 或 JSON 元数据）/ Frontmatter node (YAML, TOML or JSON metadata) */
 export interface FrontMatterNode extends Node{
     readonly kind: "frontmatter"
-    readonly content: Frontmatter
+    readonly content: {
+        /** 源格式 / Source format */
+        readonly format: FrontmatterFormat
+        /** 键值对映射 / Key-value mapping */
+        readonly data: Frontmatter
+        /** 每个键与值的源码区间 / Source ranges of every key and value */
+        readonly spans: FrontmatterSpan[]
+    }
 }
 
 /** 段落节点 / Paragraph node */
@@ -363,8 +603,14 @@ export interface TagNode extends Node{
 /** Emoji 节点 / Emoji node */
 export interface EmojiNode extends Node{
     readonly kind: "emoji"
-    /** Emoji 内容 / Emoji content */
-    readonly content: string
+    readonly content: {
+        /** 书写的 shortcode（不含 `:`）/ Shortcode as written, without colons */
+        readonly name: string
+        /** 解析后的 Unicode 序列 / Resolved Unicode sequence */
+        readonly value: string
+        /** 肤色修饰 2~6 / Skin tone modifier 2-6 */
+        readonly skin_tone: number | null
+    }
 }
 
 /** 引用块节点 / Block quote node */
@@ -440,6 +686,73 @@ export interface HighlightingNode extends Node{
     readonly kind: "highlighting"
 }
 
+/** 上标节点 / Superscript node */
+export interface SuperscriptNode extends Node{
+    readonly kind: "superscript"
+}
+
+/** 下标节点 / Subscript node */
+export interface SubscriptNode extends Node{
+    readonly kind: "subscript"
+}
+
+/** 插入文本节点 / Insert node */
+export interface InsertNode extends Node{
+    readonly kind: "insert"
+}
+
+/** 剧透节点 / Spoiler node */
+export interface SpoilerNode extends Node{
+    readonly kind: "spoiler"
+}
+
+/** 缩写节点 / Abbreviation node */
+export interface AbbreviationNode extends Node{
+    readonly kind: "abbreviation"
+    readonly content: {
+        readonly title: string
+    }
+}
+
+/** 注音节点 / Ruby annotation node */
+export interface RubyNode extends Node{
+    readonly kind: "ruby"
+    readonly content: {
+        readonly base: string
+        readonly annotations: {
+            readonly base: string
+            readonly text: string
+        }[]
+    }
+}
+
+/**
+ * 嵌入笔记展开后的内容，子节点区间均为嵌入在宿主中的区间
+ * Embedded note content; child spans are the embed's span in the host
+ */
+export interface TransclusionNode extends Node{
+    readonly kind: "transclusion"
+    readonly content: {
+        /** 加载器返回的笔记标识 / Note identifier returned by the loader */
+        readonly note: string
+        /** 原样的嵌入路径 / Embed path as written */
+        readonly path: string
+        /** 引用信息 / Reference information */
+        readonly reference: Reference | undefined
+        /** 内容在被嵌入笔记中的区间 / Source range of the content in the embedded note */
+        readonly span: { readonly start: number; readonly end: number }
+    }
+}
+
+/**
+ * 链接引用定义节点，仅在启用 `link_reference_definitions` 时保留
+ * Link reference definition node, kept only when `link_reference_definitions` is enabled
+ */
+export interface LinkReferenceDefinitionNode extends Node{
+    readonly kind: "link-reference-definition"
+    readonly content: LinkReferenceDefinition
+}
+
 /** 主题分隔线节点 / Thematic break node */
 export interface ThematicBreakNode extends Node{
     readonly kind: "thematic-break"
@@ -523,6 +836,28 @@ export interface HtmlNode extends Node{
     }
 }
 
+/**
+ * 定义列表节点
+ * Definition list node
+ */
+export interface DefinitionListNode extends Node{
+    readonly kind: "definition-list"
+    readonly content: {
+        /** 是否为紧凑列表 / Whether the list is tight */
+        readonly tight: boolean
+    }
+}
+
+/** 定义列表术语节点 / Definition list term node */
+export interface DefinitionTermNode extends Node{
+    readonly kind: "definition-term"
+}
+
+/** 定义列表描述节点 / Definition list details node */
+export interface DefinitionDetailsNode extends Node{
+    readonly kind: "definition-details"
+}
+
 /**
  * AST 节点联合类型
  * AST node union type
@@ -532,5 +867,7 @@ export type AstNode = DocumentNode | FrontMatterNode | ParagraphNode | SoftBreak
     | ImageNode | LinkNode | TagNode | EmojiNode | BlockQuoteNode | CodeNode | TableNode
     | TableHeadNode | TableHeadColNode | TableBodyNode | TableRowNode | TableDataColNode
     | StrikethroughNode | HighlightingNode | ThematicBreakNode | FootnoteNode | FootnoteListNode
-    | MathNode | CalloutNode | HtmlNode
+    | MathNode | CalloutNode | HtmlNode | DefinitionListNode | DefinitionTermNode
+    | DefinitionDetailsNode | SuperscriptNode | SubscriptNode | InsertNode | SpoilerNode
+    | AbbreviationNode | RubyNode | TransclusionNode | LinkReferenceDefinitionNode
 "##;